@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::topology::EdgeId;
+
 /// Top-level error type for the Geolis CAD kernel.
 #[derive(Debug, Error)]
 pub enum GeolisError {
@@ -32,6 +34,9 @@ pub enum GeometryError {
 
     #[error("zero-length vector")]
     ZeroVector,
+
+    #[error("parse error: {0}")]
+    Parse(String),
 }
 
 /// Errors related to topological operations.
@@ -45,6 +50,11 @@ pub enum TopologyError {
 
     #[error("invalid topology: {0}")]
     InvalidTopology(String),
+
+    /// The listed edges are not shared by exactly two faces of a shell
+    /// that must be closed.
+    #[error("shell is not closed: {} edge(s) not shared by exactly two faces", .0.len())]
+    OpenShell(Vec<EdgeId>),
 }
 
 /// Errors related to CAD operations.
@@ -55,6 +65,21 @@ pub enum OperationError {
 
     #[error("operation failed: {0}")]
     Failed(String),
+
+    #[error(transparent)]
+    Offset(#[from] OffsetError),
+}
+
+/// Errors specific to offset operations.
+#[derive(Debug, Error)]
+pub enum OffsetError {
+    /// No part of the offset survived.
+    ///
+    /// `largest_remaining_area` is the area of the largest raw offset loop
+    /// that kept the input's winding but was rejected by the distance
+    /// filter; `0.0` when every loop inverted or the input is open.
+    #[error("offset collapsed completely (largest remaining area {largest_remaining_area})")]
+    Collapsed { largest_remaining_area: f64 },
 }
 
 /// Errors related to tessellation.
@@ -65,6 +90,12 @@ pub enum TessellationError {
 
     #[error("tessellation failed: {0}")]
     Failed(String),
+
+    #[error("tessellation cancelled")]
+    Cancelled,
+
+    #[error("failed to write mesh: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// Convenience type alias for results using [`GeolisError`].
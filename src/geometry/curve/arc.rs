@@ -1,7 +1,10 @@
+use std::f64::consts::TAU;
+
 use crate::error::{GeometryError, Result};
+use crate::geometry::pline::PlineVertex;
 use crate::math::{Point3, Vector3, TOLERANCE};
 
-use super::{Curve, CurveDomain};
+use super::{Circle, Curve, CurveDomain};
 
 /// A circular arc in 3D space.
 ///
@@ -75,6 +78,67 @@ impl Arc {
         })
     }
 
+    /// Creates the arc through three points, starting at `p0`, passing
+    /// through `p1` and ending at `p2`.
+    ///
+    /// The arc lies on the circumcircle of the points; its normal is
+    /// oriented so the traversal `p0 → p1 → p2` is counter-clockwise, with
+    /// `ref_dir` pointing at `p0` (`start_angle = 0`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the points are collinear or coincident within
+    /// `TOLERANCE`.
+    pub fn from_three_points(p0: Point3, p1: Point3, p2: Point3) -> Result<Self> {
+        let (center, radius, normal) = super::circle::circumcircle(&p0, &p1, &p2)?;
+        let ref_dir = (p0 - center) / radius;
+        let binormal = normal.cross(&ref_dir);
+        let d = p2 - center;
+        let end_angle = d.dot(&binormal).atan2(d.dot(&ref_dir)).rem_euclid(TAU);
+        Self::new(center, radius, normal, ref_dir, 0.0, end_angle)
+    }
+
+    /// Creates the arc starting at `start` with direction `start_tangent`
+    /// and ending at `end`.
+    ///
+    /// The arc lies in the plane spanned by the tangent and the chord, with
+    /// `ref_dir` pointing at `start` (`start_angle = 0`), so its tangent at
+    /// the start parameter equals the normalized `start_tangent`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tangent is zero-length, the points coincide,
+    /// or `end` lies on the tangent line through `start`.
+    pub fn tangent_arc(start: Point3, start_tangent: Vector3, end: Point3) -> Result<Self> {
+        let tangent_len = start_tangent.norm();
+        if tangent_len < TOLERANCE {
+            return Err(GeometryError::ZeroVector.into());
+        }
+        let tangent = start_tangent / tangent_len;
+        let chord = end - start;
+        if chord.norm() < TOLERANCE {
+            return Err(GeometryError::Degenerate("arc endpoints coincide".into()).into());
+        }
+        let normal = tangent.cross(&chord);
+        let normal_len = normal.norm();
+        if normal_len < TOLERANCE * chord.norm() {
+            return Err(GeometryError::Degenerate(
+                "arc end point lies on the start tangent line".into(),
+            )
+            .into());
+        }
+        let normal = normal / normal_len;
+        // Unit vector from `start` towards the center, on the chord's side.
+        let inward = normal.cross(&tangent);
+        let radius = chord.norm_squared() / (2.0 * chord.dot(&inward));
+        let center = start + inward * radius;
+        let ref_dir = -inward;
+        let binormal = normal.cross(&ref_dir);
+        let d = end - center;
+        let end_angle = d.dot(&binormal).atan2(d.dot(&ref_dir)).rem_euclid(TAU);
+        Self::new(center, radius, normal, ref_dir, 0.0, end_angle)
+    }
+
     /// Returns the center of the arc.
     #[must_use]
     pub fn center(&self) -> &Point3 {
@@ -93,6 +157,68 @@ impl Arc {
         &self.normal
     }
 
+    /// Returns whether the arc sweeps a full turn: `|end_angle - start_angle|`
+    /// is within `tol` of `2π` (or beyond it).
+    #[must_use]
+    pub fn is_full_circle(&self, tol: f64) -> bool {
+        (self.end_angle - self.start_angle).abs() >= TAU - tol
+    }
+
+    /// Returns the circle traced by a full-turn arc (see
+    /// [`is_full_circle`](Self::is_full_circle) with `TOLERANCE`), or `None`
+    /// for a partial arc.
+    ///
+    /// The circle's `t = 0` lies at the arc's start point and it runs in the
+    /// arc's direction of travel, so a negative sweep flips the normal.
+    #[must_use]
+    pub fn to_circle(&self) -> Option<Circle> {
+        if !self.is_full_circle(TOLERANCE) {
+            return None;
+        }
+        let (sin, cos) = self.start_angle.sin_cos();
+        let ref_dir = self.ref_dir * cos + self.binormal() * sin;
+        let normal = if self.end_angle < self.start_angle {
+            -self.normal
+        } else {
+            self.normal
+        };
+        Circle::new(self.center, self.radius, normal, ref_dir).ok()
+    }
+
+    /// Builds the arc of `circle` from `start_angle` to `end_angle`, reusing
+    /// the circle's already normalized frame.
+    pub(super) fn on_circle(circle: &Circle, start_angle: f64, end_angle: f64) -> Self {
+        Self {
+            center: *circle.center(),
+            radius: circle.radius(),
+            normal: *circle.normal(),
+            ref_dir: *circle.ref_dir(),
+            start_angle,
+            end_angle,
+        }
+    }
+
+    /// Returns the start and end of the arc as polyline vertices in the XY
+    /// plane, the start carrying the bulge `tan(sweep / 4)`.
+    ///
+    /// The arc is projected along Z, so its normal should be `±Z`; the bulge
+    /// is positive when the arc runs counter-clockwise seen from `+Z`. A
+    /// full-turn arc has no finite bulge.
+    #[must_use]
+    pub fn to_pline_vertices(&self) -> [PlineVertex; 2] {
+        let binormal = self.binormal();
+        let at = |t: f64| self.center + (self.ref_dir * t.cos() + binormal * t.sin()) * self.radius;
+        let (start, end) = (at(self.start_angle), at(self.end_angle));
+        let mut bulge = ((self.end_angle - self.start_angle) / 4.0).tan();
+        if self.normal.z < 0.0 {
+            bulge = -bulge;
+        }
+        [
+            PlineVertex::new(start.x, start.y, bulge),
+            PlineVertex::line(end.x, end.y),
+        ]
+    }
+
     /// Computes the second axis direction (perpendicular to both normal and `ref_dir`).
     fn binormal(&self) -> Vector3 {
         self.normal.cross(&self.ref_dir)
@@ -124,6 +250,245 @@ impl Curve for Arc {
     }
 
     fn is_closed(&self) -> bool {
-        (self.end_angle - self.start_angle - std::f64::consts::TAU).abs() < TOLERANCE
+        self.is_full_circle(TOLERANCE)
+    }
+
+    /// Flipping the normal mirrors the angle, so the reversed arc spans
+    /// `[-end_angle, -start_angle]` with `reversed(t) = self(-t)`.
+    fn reversed(&self) -> Result<Box<dyn Curve>> {
+        Ok(Box::new(Self {
+            normal: -self.normal,
+            start_angle: -self.end_angle,
+            end_angle: -self.start_angle,
+            ..self.clone()
+        }))
+    }
+
+    fn split_at(&self, t: f64) -> Result<(Box<dyn Curve>, Box<dyn Curve>)> {
+        super::check_split_parameter(self.domain(), t)?;
+        Ok((
+            Box::new(Self {
+                end_angle: t,
+                ..self.clone()
+            }),
+            Box::new(Self {
+                start_angle: t,
+                ..self.clone()
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    /// Point at angle `t` on a circle of radius 2.5 centred at (1, 2, 3) in
+    /// a tilted plane.
+    fn on_circle(t: f64) -> Point3 {
+        let u = Vector3::new(1.0, 1.0, 0.0).normalize();
+        let v = Vector3::new(-1.0, 1.0, 2.0).normalize();
+        Point3::new(1.0, 2.0, 3.0) + (u * t.cos() + v * t.sin()) * 2.5
+    }
+
+    #[test]
+    fn quarter_arc_round_trips_through_pline() {
+        use crate::geometry::pline::{Pline, PlineSegment};
+        use std::f64::consts::FRAC_PI_2;
+
+        let arc = Arc::new(
+            Point3::new(1.0, 2.0, 0.0),
+            3.0,
+            Vector3::z(),
+            Vector3::x(),
+            0.0,
+            FRAC_PI_2,
+        )
+        .unwrap();
+        let [v0, v1] = arc.to_pline_vertices();
+        assert!((v0.bulge - (FRAC_PI_2 / 4.0).tan()).abs() < TOLERANCE);
+        assert!((v0.x - 4.0).abs() < TOLERANCE && (v0.y - 2.0).abs() < TOLERANCE);
+        assert!((v1.x - 1.0).abs() < TOLERANCE && (v1.y - 5.0).abs() < TOLERANCE);
+
+        let pline = Pline::from_arcs(std::slice::from_ref(&arc));
+        assert!(!pline.closed);
+        let Some(PlineSegment::Arc {
+            center,
+            radius,
+            ccw,
+            ..
+        }) = pline.segments().next()
+        else {
+            panic!("expected an arc segment");
+        };
+        assert!(ccw);
+        assert!((center.x - 1.0).abs() < TOLERANCE && (center.y - 2.0).abs() < TOLERANCE);
+        assert!((radius - arc.radius()).abs() < TOLERANCE);
+
+        // A clockwise arc (normal −Z) gets a negative bulge.
+        let [cw, _] = Arc::new(
+            Point3::origin(),
+            1.0,
+            -Vector3::z(),
+            Vector3::x(),
+            0.0,
+            FRAC_PI_2,
+        )
+        .unwrap()
+        .to_pline_vertices();
+        assert!((cw.bulge + (FRAC_PI_2 / 4.0).tan()).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn from_three_points_recovers_circle() {
+        let (p0, p1, p2) = (on_circle(0.3), on_circle(1.5), on_circle(4.0));
+        let arc = Arc::from_three_points(p0, p1, p2).unwrap();
+        assert!((arc.center() - Point3::new(1.0, 2.0, 3.0)).norm() < 1e-9);
+        assert!((arc.radius() - 2.5).abs() < 1e-9);
+
+        let d = arc.domain();
+        assert!(
+            (d.t_max - d.t_min - 3.7).abs() < 1e-9,
+            "sweep {}",
+            d.t_max - d.t_min
+        );
+        assert!((arc.evaluate(d.t_min).unwrap() - p0).norm() < 1e-9);
+        assert!((arc.evaluate(d.t_max).unwrap() - p2).norm() < 1e-9);
+        assert!((arc.evaluate(d.t_min + 1.2).unwrap() - p1).norm() < 1e-9);
+    }
+
+    #[test]
+    fn from_three_points_clockwise_order_still_passes_through_middle() {
+        let (p0, p1, p2) = (on_circle(4.0), on_circle(1.5), on_circle(0.3));
+        let arc = Arc::from_three_points(p0, p1, p2).unwrap();
+        let d = arc.domain();
+        assert!((d.t_max - d.t_min - 3.7).abs() < 1e-9);
+        assert!((arc.evaluate(d.t_min + 2.5).unwrap() - p1).norm() < 1e-9);
+    }
+
+    #[test]
+    fn from_three_points_rejects_collinear() {
+        let r = Arc::from_three_points(
+            Point3::origin(),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 2.0, 2.0),
+        );
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn tangent_arc_is_tangent_continuous_at_start() {
+        let start = Point3::new(1.0, 2.0, 3.0);
+        let tangent = Vector3::new(2.0, 0.5, -1.0);
+        for end in [
+            Point3::new(3.0, 4.0, 2.0),
+            Point3::new(0.0, 1.0, 5.0),
+            Point3::new(-1.0, 2.5, 2.0),
+        ] {
+            let arc = Arc::tangent_arc(start, tangent, end).unwrap();
+            let d = arc.domain();
+            assert!((arc.evaluate(d.t_min).unwrap() - start).norm() < 1e-9);
+            assert!((arc.evaluate(d.t_max).unwrap() - end).norm() < 1e-9);
+            let t0 = arc.tangent(d.t_min).unwrap();
+            assert!((t0 - tangent.normalize()).norm() < TOLERANCE, "t0={t0:?}");
+        }
+    }
+
+    #[test]
+    fn tangent_arc_rejects_end_on_tangent_line() {
+        let start = Point3::origin();
+        let r = Arc::tangent_arc(start, Vector3::x(), Point3::new(3.0, 0.0, 0.0));
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn advance_by_arc_length() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        let arc = Arc::new(Point3::origin(), 1.0, Vector3::z(), Vector3::x(), 0.0, PI).unwrap();
+        assert!((arc.advance(0.0, FRAC_PI_2).unwrap() - FRAC_PI_2).abs() < 1e-9);
+        assert!((arc.advance(FRAC_PI_2, -FRAC_PI_4).unwrap() - FRAC_PI_4).abs() < 1e-9);
+        // Clamped to the domain.
+        assert!((arc.advance(FRAC_PI_2, 10.0).unwrap() - PI).abs() < 1e-12);
+        assert!(arc.advance(4.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn split_semicircle_into_quarters() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        let arc = Arc::new(
+            Point3::new(1.0, 2.0, 3.0),
+            2.5,
+            Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(1.0, -1.0, 0.0),
+            0.0,
+            PI,
+        )
+        .unwrap();
+        let (first, second) = arc.split_at(FRAC_PI_2).unwrap();
+        let (d1, d2) = (first.domain(), second.domain());
+        assert!((d1.t_min - 0.0).abs() < 1e-12 && (d1.t_max - FRAC_PI_2).abs() < 1e-12);
+        assert!((d2.t_min - FRAC_PI_2).abs() < 1e-12 && (d2.t_max - PI).abs() < 1e-12);
+        for i in 0..=16 {
+            let t = PI * f64::from(i) / 16.0;
+            let piece = if t <= FRAC_PI_2 { &first } else { &second };
+            assert!((piece.evaluate(t).unwrap() - arc.evaluate(t).unwrap()).norm() < 1e-12);
+        }
+        assert!(arc.split_at(0.0).is_err());
+        assert!(arc.split_at(4.0).is_err());
+    }
+
+    #[test]
+    fn reversed_starts_at_end() {
+        let arc = Arc::new(
+            Point3::new(1.0, 2.0, 3.0),
+            2.5,
+            Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(1.0, -1.0, 0.0),
+            0.3,
+            2.0,
+        )
+        .unwrap();
+        let rev = arc.reversed().unwrap();
+        let (d, rd) = (arc.domain(), rev.domain());
+        assert!(((rd.t_max - rd.t_min) - (d.t_max - d.t_min)).abs() < 1e-12);
+        for s in [0.0, 0.25, 1.0] {
+            let t = d.t_min + s * (d.t_max - d.t_min);
+            let rt = rd.t_max - s * (rd.t_max - rd.t_min);
+            assert!((rev.evaluate(rt).unwrap() - arc.evaluate(t).unwrap()).norm() < 1e-12);
+            assert!((rev.tangent(rt).unwrap() + arc.tangent(t).unwrap()).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn near_full_sweep_converts_to_circle() {
+        let u = Vector3::new(1.0, 1.0, 0.0).normalize();
+        let v = Vector3::new(-1.0, 1.0, 2.0).normalize();
+        let (start, sweep) = (0.7, TAU - 1e-12);
+        let arc = Arc::new(
+            Point3::new(1.0, 2.0, 3.0),
+            2.5,
+            u.cross(&v),
+            u,
+            start,
+            start + sweep,
+        )
+        .unwrap();
+        assert!(arc.is_full_circle(TOLERANCE));
+        assert!(arc.is_closed());
+        let circle = arc.to_circle().unwrap();
+        assert!((circle.radius() - 2.5).abs() < TOLERANCE);
+        for k in 0..8 {
+            let t = f64::from(k) * 0.8;
+            let p = circle.evaluate(t).unwrap();
+            assert!((p - on_circle(start + t)).norm() < 1e-9);
+        }
+
+        // A partial arc is not a circle; the reverse conversion round-trips.
+        let half = circle.to_arc(0.0, TAU / 2.0);
+        assert!(!half.is_full_circle(TOLERANCE));
+        assert!(!half.is_closed());
+        assert!(half.to_circle().is_none());
+        assert!((half.evaluate(0.0).unwrap() - on_circle(start)).norm() < 1e-9);
     }
 }
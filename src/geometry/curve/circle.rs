@@ -1,7 +1,7 @@
 use crate::error::{GeometryError, Result};
 use crate::math::{Point3, Vector3, TOLERANCE};
 
-use super::{Curve, CurveDomain};
+use super::{Arc, Curve, CurveDomain};
 
 /// A full circle in 3D space.
 ///
@@ -65,6 +65,19 @@ impl Circle {
         })
     }
 
+    /// Creates the circle through three points, oriented so that
+    /// `p0 → p1 → p2` runs counter-clockwise about its normal, with
+    /// `t = 0` at `p0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the points are collinear or coincident within
+    /// `TOLERANCE`.
+    pub fn from_three_points(p0: Point3, p1: Point3, p2: Point3) -> Result<Self> {
+        let (center, radius, normal) = circumcircle(&p0, &p1, &p2)?;
+        Self::new(center, radius, normal, (p0 - center) / radius)
+    }
+
     /// Returns the center of the circle.
     #[must_use]
     pub fn center(&self) -> &Point3 {
@@ -89,12 +102,41 @@ impl Circle {
         &self.ref_dir
     }
 
+    /// Returns the arc of this circle from angle `a0` to `a1`, measured
+    /// from [`ref_dir`](Self::ref_dir) about the normal.
+    #[must_use]
+    pub fn to_arc(&self, a0: f64, a1: f64) -> Arc {
+        Arc::on_circle(self, a0, a1)
+    }
+
     /// Computes the binormal direction (`normal x ref_dir`).
     fn binormal(&self) -> Vector3 {
         self.normal.cross(&self.ref_dir)
     }
 }
 
+/// Circumcircle of three points: `(center, radius, unit normal)`, with the
+/// normal oriented so `p0 → p1 → p2` is counter-clockwise about it.
+pub(crate) fn circumcircle(
+    p0: &Point3,
+    p1: &Point3,
+    p2: &Point3,
+) -> Result<(Point3, f64, Vector3)> {
+    let a = p1 - p0;
+    let b = p2 - p0;
+    let axb = a.cross(&b);
+    let chord = b.norm();
+    if chord < TOLERANCE || a.norm() < TOLERANCE || axb.norm() / chord < TOLERANCE {
+        return Err(
+            GeometryError::Degenerate("three points are collinear or coincident".into()).into(),
+        );
+    }
+    let center =
+        p0 + (b * a.norm_squared() - a * b.norm_squared()).cross(&axb) / (2.0 * axb.norm_squared());
+    let radius = (p0 - center).norm();
+    Ok((center, radius, axb.normalize()))
+}
+
 impl Curve for Circle {
     fn evaluate(&self, t: f64) -> Result<Point3> {
         let binormal = self.binormal();
@@ -122,6 +164,24 @@ impl Curve for Circle {
     fn is_closed(&self) -> bool {
         true
     }
+
+    /// Flipping the normal gives `reversed(t) = self(-t) = self(TAU - t)`
+    /// over the same `[0, TAU]` domain.
+    fn reversed(&self) -> Result<Box<dyn Curve>> {
+        Ok(Box::new(Self {
+            normal: -self.normal,
+            ..self.clone()
+        }))
+    }
+
+    /// A circle splits into two [`Arc`]s on the same carrier circle.
+    fn split_at(&self, t: f64) -> Result<(Box<dyn Curve>, Box<dyn Curve>)> {
+        super::check_split_parameter(self.domain(), t)?;
+        Ok((
+            Box::new(self.to_arc(0.0, t)),
+            Box::new(self.to_arc(t, std::f64::consts::TAU)),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -156,12 +216,38 @@ mod tests {
         assert!((t - Vector3::new(0.0, 1.0, 0.0)).norm() < 1e-9);
     }
 
+    #[test]
+    fn from_three_points_recovers_center_and_radius() {
+        let center = Point3::new(-1.0, 4.0, 0.5);
+        let at = |t: f64| center + Vector3::new(t.cos(), 0.0, t.sin()) * 3.0;
+        let c = Circle::from_three_points(at(0.1), at(2.0), at(5.0)).unwrap();
+        assert!((c.center() - center).norm() < 1e-9);
+        assert!((c.radius() - 3.0).abs() < 1e-9);
+        assert!((c.evaluate(0.0).unwrap() - at(0.1)).norm() < 1e-9);
+        assert!(Circle::from_three_points(at(0.1), at(0.1), at(2.0)).is_err());
+    }
+
     #[test]
     fn is_always_closed() {
         let c = xy_circle(1.0);
         assert!(c.is_closed());
     }
 
+    #[test]
+    fn split_gives_two_arcs_covering_circle() {
+        let c = xy_circle(2.0);
+        let (first, second) = c.split_at(1.0).unwrap();
+        assert!(!first.is_closed() && !second.is_closed());
+        assert!((first.domain().t_max - 1.0).abs() < 1e-12);
+        assert!((second.domain().t_max - TAU).abs() < 1e-12);
+        for t in [0.0, 0.5, 1.0] {
+            assert!((first.evaluate(t).unwrap() - c.evaluate(t).unwrap()).norm() < 1e-12);
+        }
+        for t in [1.0, 4.0, TAU] {
+            assert!((second.evaluate(t).unwrap() - c.evaluate(t).unwrap()).norm() < 1e-12);
+        }
+    }
+
     #[test]
     fn domain_is_full_circle() {
         let c = xy_circle(1.0);
@@ -164,6 +164,31 @@ impl Curve for Ellipse {
     fn is_closed(&self) -> bool {
         (self.end_angle - self.start_angle - std::f64::consts::TAU).abs() < TOLERANCE
     }
+
+    /// Flipping the normal flips the minor axis, so the reversed ellipse
+    /// spans `[-end_angle, -start_angle]` with `reversed(t) = self(-t)`.
+    fn reversed(&self) -> Result<Box<dyn Curve>> {
+        Ok(Box::new(Self {
+            normal: -self.normal,
+            start_angle: -self.end_angle,
+            end_angle: -self.start_angle,
+            ..self.clone()
+        }))
+    }
+
+    fn split_at(&self, t: f64) -> Result<(Box<dyn Curve>, Box<dyn Curve>)> {
+        super::check_split_parameter(self.domain(), t)?;
+        Ok((
+            Box::new(Self {
+                end_angle: t,
+                ..self.clone()
+            }),
+            Box::new(Self {
+                start_angle: t,
+                ..self.clone()
+            }),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -266,4 +291,33 @@ mod tests {
         );
         assert!(r.is_err());
     }
+
+    #[test]
+    fn reversed_starts_at_end() {
+        let e = xy_ellipse_arc(3.0, 1.0, 0.2, FRAC_PI_2 + 0.4);
+        let rev = e.reversed().unwrap();
+        let (d, rd) = (e.domain(), rev.domain());
+        let start = rev.evaluate(rd.t_min).unwrap();
+        assert!((start - e.evaluate(d.t_max).unwrap()).norm() < 1e-12);
+        let end = rev.evaluate(rd.t_max).unwrap();
+        assert!((end - e.evaluate(d.t_min).unwrap()).norm() < 1e-12);
+        let mid = rev.evaluate(0.5 * (rd.t_min + rd.t_max)).unwrap();
+        assert!((mid - e.evaluate(0.5 * (d.t_min + d.t_max)).unwrap()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn split_keeps_parameterization() {
+        let e = xy_ellipse_arc(3.0, 1.0, 0.2, FRAC_PI_2 + 0.4);
+        let (first, second) = e.split_at(1.0).unwrap();
+        assert_eq!(first.domain(), CurveDomain::new(0.2, 1.0));
+        assert_eq!(second.domain(), CurveDomain::new(1.0, FRAC_PI_2 + 0.4));
+        for t in [0.2, 0.6, 1.0] {
+            assert!((first.evaluate(t).unwrap() - e.evaluate(t).unwrap()).norm() < 1e-12);
+        }
+        for t in [1.0, 1.5, FRAC_PI_2 + 0.4] {
+            assert!((second.evaluate(t).unwrap() - e.evaluate(t).unwrap()).norm() < 1e-12);
+        }
+        assert!(e.split_at(0.2).is_err());
+        assert!(e.split_at(3.0).is_err());
+    }
 }
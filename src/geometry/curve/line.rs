@@ -3,13 +3,16 @@ use crate::math::{Point3, Vector3};
 
 use super::{Curve, CurveDomain};
 
-/// An infinite line defined by an origin point and a direction vector.
+/// A line defined by an origin point and a direction vector.
 ///
-/// The parametric form is: `P(t) = origin + t * direction`.
+/// The parametric form is: `P(t) = origin + t * direction`. A constructed
+/// line is infinite; the rays produced by [`Curve::split_at`] keep the same
+/// parameterization over a half-bounded domain.
 #[derive(Debug, Clone)]
 pub struct Line {
     origin: Point3,
     direction: Vector3,
+    domain: CurveDomain,
 }
 
 impl Line {
@@ -26,9 +29,27 @@ impl Line {
         Ok(Self {
             origin,
             direction: direction / len,
+            domain: CurveDomain::new(f64::NEG_INFINITY, f64::INFINITY),
         })
     }
 
+    /// Creates the line through `a` and `b`, with its origin at `a`.
+    ///
+    /// The direction is normalized, so the segment `a → b` spans the
+    /// parameter range `[0, |b - a|]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `a` and `b` coincide within `TOLERANCE`.
+    pub fn from_points(a: Point3, b: Point3) -> Result<Self> {
+        if (b - a).norm() < crate::math::TOLERANCE {
+            return Err(
+                crate::error::GeometryError::Degenerate("line endpoints coincide".into()).into(),
+            );
+        }
+        Self::new(a, b - a)
+    }
+
     /// Returns the origin point of the line.
     #[must_use]
     pub fn origin(&self) -> &Point3 {
@@ -52,10 +73,90 @@ impl Curve for Line {
     }
 
     fn domain(&self) -> CurveDomain {
-        CurveDomain::new(f64::NEG_INFINITY, f64::INFINITY)
+        self.domain
     }
 
     fn is_closed(&self) -> bool {
         false
     }
+
+    fn as_line(&self) -> Option<&Line> {
+        Some(self)
+    }
+
+    /// The reversed line shares the origin, so `reversed(t) = self(-t)`.
+    fn reversed(&self) -> Result<Box<dyn Curve>> {
+        Ok(Box::new(Self {
+            origin: self.origin,
+            direction: -self.direction,
+            domain: CurveDomain::new(-self.domain.t_max, -self.domain.t_min),
+        }))
+    }
+
+    fn split_at(&self, t: f64) -> Result<(Box<dyn Curve>, Box<dyn Curve>)> {
+        super::check_split_parameter(self.domain, t)?;
+        Ok((
+            Box::new(Self {
+                domain: CurveDomain::new(self.domain.t_min, t),
+                ..self.clone()
+            }),
+            Box::new(Self {
+                domain: CurveDomain::new(t, self.domain.t_max),
+                ..self.clone()
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_points_spans_distance() {
+        let (a, b) = (Point3::new(1.0, 2.0, 3.0), Point3::new(4.0, -2.0, 3.0));
+        let line = Line::from_points(a, b).unwrap();
+        let length = (b - a).norm();
+        assert!((length - 5.0).abs() < 1e-12);
+        assert!((line.evaluate(0.0).unwrap() - a).norm() < 1e-12);
+        assert!((line.evaluate(length).unwrap() - b).norm() < 1e-12);
+    }
+
+    #[test]
+    fn from_points_rejects_coincident() {
+        let a = Point3::new(1.0, 1.0, 1.0);
+        assert!(Line::from_points(a, a).is_err());
+    }
+
+    #[test]
+    fn reversed_runs_backwards() {
+        // The domain is unbounded, so check `reversed(t) = self(-t)`.
+        let line = Line::new(Point3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0)).unwrap();
+        let rev = line.reversed().unwrap();
+        for t in [-2.0, 0.0, 1.5] {
+            assert!((rev.evaluate(t).unwrap() - line.evaluate(-t).unwrap()).norm() < 1e-12);
+        }
+        assert!((rev.tangent(0.0).unwrap() + line.tangent(0.0).unwrap()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn split_gives_two_rays() {
+        let line = Line::new(Point3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0)).unwrap();
+        let (first, second) = line.split_at(3.0).unwrap();
+        assert_eq!(first.domain(), CurveDomain::new(f64::NEG_INFINITY, 3.0));
+        assert_eq!(second.domain(), CurveDomain::new(3.0, f64::INFINITY));
+        for t in [-1.0, 3.0, 5.0] {
+            assert!((first.evaluate(t).unwrap() - line.evaluate(t).unwrap()).norm() < 1e-12);
+            assert!((second.evaluate(t).unwrap() - line.evaluate(t).unwrap()).norm() < 1e-12);
+        }
+
+        // Splitting a ray again bounds it on both sides; reversing mirrors it.
+        let (segment, _) = second.split_at(4.0).unwrap();
+        assert_eq!(segment.domain(), CurveDomain::new(3.0, 4.0));
+        assert_eq!(
+            segment.reversed().unwrap().domain(),
+            CurveDomain::new(-4.0, -3.0)
+        );
+    }
 }
@@ -4,12 +4,20 @@ mod ellipse;
 mod line;
 
 pub use arc::Arc;
+pub(crate) use circle::circumcircle;
 pub use circle::Circle;
 pub use ellipse::Ellipse;
 pub use line::Line;
 
-use crate::error::Result;
-use crate::math::{Point3, Vector3};
+use crate::error::{GeometryError, Result};
+use crate::math::{Point3, Vector3, TOLERANCE};
+
+/// Equal parameter pieces [`Curve::advance`] splits its search span into.
+const ADVANCE_PIECES: u32 = 256;
+
+/// Bisection steps [`Curve::advance`] spends locating the target inside
+/// one piece.
+const ADVANCE_BISECTIONS: u32 = 60;
 
 /// Parameter domain for a curve.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -49,4 +57,155 @@ pub trait Curve {
 
     /// Returns whether the curve is closed.
     fn is_closed(&self) -> bool;
+
+    /// Returns this curve as a [`Line`] if it is one, so callers can use
+    /// closed-form line algorithms. The default returns `None`.
+    fn as_line(&self) -> Option<&Line> {
+        None
+    }
+
+    /// Returns a copy of the curve traversed in the opposite direction.
+    ///
+    /// The reversed curve starts where `self` ends and traces the same
+    /// points backwards over a domain of the same length; its tangents are
+    /// negated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reversed curve cannot be constructed.
+    fn reversed(&self) -> Result<Box<dyn Curve>>;
+
+    /// Splits the curve at parameter `t` into the pieces before and after
+    /// it.
+    ///
+    /// The pieces keep this curve's parameterization: the first covers
+    /// `[t_min, t]` and the second `[t, t_max]`, each evaluating exactly as
+    /// `self` does there.
+    ///
+    /// The default implementation reports the operation as unsupported, so
+    /// curve types outside this crate opt in by overriding it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `t` does not lie strictly inside the domain, or
+    /// the curve does not support splitting.
+    fn split_at(&self, t: f64) -> Result<(Box<dyn Curve>, Box<dyn Curve>)> {
+        check_split_parameter(self.domain(), t)?;
+        Err(GeometryError::Degenerate("curve does not support splitting".into()).into())
+    }
+
+    /// Returns the parameter `distance` units of arc length along the
+    /// curve from `t`; a negative distance marches backwards.
+    ///
+    /// The result is clamped to the domain, so a closed curve is not
+    /// wrapped around its seam. Arc length is integrated numerically from
+    /// chord lengths (Richardson-extrapolated): exact for lines up to
+    /// rounding, and high-order accurate for smooth curves such as circular
+    /// arcs, whose per-piece error shrinks with the fifth power of the
+    /// piece's parameter span.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `t` lies outside the domain, `distance` is not
+    /// finite, or evaluation fails.
+    fn advance(&self, t: f64, distance: f64) -> Result<f64> {
+        let domain = self.domain();
+        if !t.is_finite() || t < domain.t_min - TOLERANCE || t > domain.t_max + TOLERANCE {
+            return Err(GeometryError::ParameterOutOfRange {
+                parameter: "t",
+                value: t,
+                min: domain.t_min,
+                max: domain.t_max,
+            }
+            .into());
+        }
+        if !distance.is_finite() {
+            return Err(GeometryError::ParameterOutOfRange {
+                parameter: "distance",
+                value: distance,
+                min: f64::NEG_INFINITY,
+                max: f64::INFINITY,
+            }
+            .into());
+        }
+        let t = t.clamp(domain.t_min, domain.t_max);
+        let target = distance.abs();
+        if target < TOLERANCE {
+            return Ok(t);
+        }
+        let limit = if distance > 0.0 {
+            domain.t_max
+        } else {
+            domain.t_min
+        };
+        if limit.is_finite() {
+            return Ok(march(self, t, limit, target)?.unwrap_or(limit));
+        }
+        // Unbounded: widen the span until it reaches the target.
+        let mut span = target;
+        loop {
+            let end = t + span.copysign(distance);
+            if let Some(found) = march(self, t, end, target)? {
+                return Ok(found);
+            }
+            span *= 2.0;
+            if !span.is_finite() {
+                return Ok(end);
+            }
+        }
+    }
+}
+
+/// Walks from parameter `from` towards `to` and returns the parameter at
+/// arc length `target`, or `None` when the whole span is shorter.
+fn march<C: Curve + ?Sized>(curve: &C, from: f64, to: f64, target: f64) -> Result<Option<f64>> {
+    let mut walked = 0.0;
+    for k in 0..ADVANCE_PIECES {
+        let a = from + (to - from) * f64::from(k) / f64::from(ADVANCE_PIECES);
+        let b = from + (to - from) * f64::from(k + 1) / f64::from(ADVANCE_PIECES);
+        let piece = span_length(curve, a, b)?;
+        if walked + piece >= target {
+            let need = target - walked;
+            let (mut lo, mut hi) = (a, b);
+            for _ in 0..ADVANCE_BISECTIONS {
+                let mid = 0.5 * (lo + hi);
+                if span_length(curve, a, mid)? < need {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            return Ok(Some(0.5 * (lo + hi)));
+        }
+        walked += piece;
+    }
+    Ok(None)
+}
+
+/// Arc length of the curve between parameters `a` and `b`, from one and
+/// two chords combined by Richardson extrapolation.
+fn span_length<C: Curve + ?Sized>(curve: &C, a: f64, b: f64) -> Result<f64> {
+    let (pa, pm, pb) = (
+        curve.evaluate(a)?,
+        curve.evaluate(0.5 * (a + b))?,
+        curve.evaluate(b)?,
+    );
+    let one = (pb - pa).norm();
+    let two = (pm - pa).norm() + (pb - pm).norm();
+    Ok((4.0 * two - one) / 3.0)
+}
+
+/// Checks that `t` lies strictly inside `domain`, as splitting requires.
+fn check_split_parameter(domain: CurveDomain, t: f64) -> Result<()> {
+    if t.is_finite() && t > domain.t_min + TOLERANCE && t < domain.t_max - TOLERANCE {
+        Ok(())
+    } else {
+        Err(GeometryError::ParameterOutOfRange {
+            parameter: "t",
+            value: t,
+            min: domain.t_min,
+            max: domain.t_max,
+        }
+        .into())
+    }
 }
@@ -1,12 +1,13 @@
 pub mod curve;
 pub mod nurbs;
 pub mod pline;
+pub mod pline_bounds;
 pub mod pline_fillet;
 pub mod pline_sampling;
 pub mod surface;
 
 pub use curve::{Arc, Curve, CurveDomain, Line};
 pub use nurbs::{NurbsCurve2D, NurbsCurve3D, NurbsSurface};
-pub use pline::{Pline, PlineVertex};
+pub use pline::{Pline, PlineBuilder, PlineRegion, PlineSegment, PlineVertex};
 pub use pline_sampling::PlineSample;
 pub use surface::{Plane, Surface, SurfaceDomain};
@@ -767,6 +767,15 @@ impl Curve for NurbsCurve3D {
     fn is_closed(&self) -> bool {
         self.is_endpoint_closed()
     }
+
+    fn reversed(&self) -> Result<Box<dyn Curve>> {
+        Ok(Box::new(self.reverse()?))
+    }
+
+    fn split_at(&self, t: f64) -> Result<(Box<dyn Curve>, Box<dyn Curve>)> {
+        let (first, second) = self.split(t)?;
+        Ok((Box::new(first), Box::new(second)))
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,16 @@
+use crate::geometry::curve::Arc;
 use crate::math::arc_2d::{arc_from_bulge, arc_point_at};
-use crate::math::Point3;
+use crate::math::distance_2d::point_to_pline;
+use crate::math::polygon_2d::winding_number_2d;
+use crate::math::{orient2d, Point2, Point3, Vector2, TOLERANCE};
+
+/// Samples per segment (an even count, so chord midpoints are included)
+/// when measuring [`Pline::max_chord_error`].
+const CHORD_ERROR_SAMPLES: u32 = 8;
+
+/// Chords per arc of the polygon that debug builds compare
+/// [`Pline::signed_area`] against.
+const AREA_CHECK_ARC_CHORDS: u32 = 64;
 
 /// Self-intersection detection primitives. `find_self_intersection` is
 /// reused by the `WallOutline2D` test oracle (P3.1 S2) and by the
@@ -9,6 +20,14 @@ use crate::math::Point3;
 #[cfg(test)]
 pub(crate) mod self_intersection;
 
+mod clip;
+#[cfg(feature = "debug-dump")]
+pub(crate) mod dump;
+mod nesting;
+mod svg;
+
+pub use nesting::PlineRegion;
+
 /// Bulge-encoded polyline vertex for mixed line/arc segments.
 ///
 /// `bulge = tan(sweep_angle / 4)`:
@@ -37,6 +56,95 @@ impl PlineVertex {
     }
 }
 
+/// A single typed segment of a [`Pline`], as yielded by [`Pline::segments`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlineSegment {
+    /// Straight segment from `a` to `b`.
+    Line { a: Point2, b: Point2 },
+    /// Circular arc from `a` to `b` with the source vertex's `bulge`, its
+    /// circle's `center` and `radius`, and `ccw` for a counter-clockwise
+    /// sweep (positive bulge).
+    Arc {
+        a: Point2,
+        b: Point2,
+        bulge: f64,
+        center: Point2,
+        radius: f64,
+        ccw: bool,
+    },
+}
+
+impl PlineSegment {
+    /// Returns the segment's start point.
+    #[must_use]
+    pub fn start(&self) -> Point2 {
+        match *self {
+            Self::Line { a, .. } | Self::Arc { a, .. } => a,
+        }
+    }
+
+    /// Returns the segment's end point.
+    #[must_use]
+    pub fn end(&self) -> Point2 {
+        match *self {
+            Self::Line { b, .. } | Self::Arc { b, .. } => b,
+        }
+    }
+}
+
+/// Fluent construction of a [`Pline`], one segment at a time.
+///
+/// The first `line_to` or `arc_to` call sets the start point; each later
+/// call adds a segment ending at the given point.
+///
+/// ```text
+/// Pline::builder().line_to(0.0, 0.0).line_to(4.0, 0.0).arc_to(4.0, 2.0, PI).close()
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PlineBuilder {
+    vertices: Vec<PlineVertex>,
+}
+
+impl PlineBuilder {
+    /// Adds a straight segment to `(x, y)`.
+    #[must_use]
+    pub fn line_to(mut self, x: f64, y: f64) -> Self {
+        self.vertices.push(PlineVertex::line(x, y));
+        self
+    }
+
+    /// Adds an arc to `(x, y)` sweeping `sweep` radians, counter-clockwise
+    /// when positive. A full turn cannot be encoded as a bulge.
+    #[must_use]
+    pub fn arc_to(mut self, x: f64, y: f64, sweep: f64) -> Self {
+        if let Some(last) = self.vertices.last_mut() {
+            last.bulge = (sweep / 4.0).tan();
+        }
+        self.vertices.push(PlineVertex::line(x, y));
+        self
+    }
+
+    /// Finishes an open polyline.
+    #[must_use]
+    pub fn build(self) -> Pline {
+        Pline {
+            vertices: self.vertices,
+            closed: false,
+        }
+    }
+
+    /// Finishes a closed polyline. A final point repeating the start (as
+    /// left by an `arc_to` back to it) is dropped, keeping that segment's
+    /// bulge; otherwise the closing segment is a straight line.
+    #[must_use]
+    pub fn close(self) -> Pline {
+        let mut pline = self.build();
+        pline.close_if_coincident(TOLERANCE);
+        pline.closed = true;
+        pline
+    }
+}
+
 /// A polyline with mixed straight-line and circular-arc segments.
 ///
 /// Each segment between consecutive vertices is either a line (bulge=0)
@@ -49,6 +157,13 @@ pub struct Pline {
 }
 
 impl Pline {
+    /// Starts a [`PlineBuilder`] for constructing a polyline segment by
+    /// segment.
+    #[must_use]
+    pub fn builder() -> PlineBuilder {
+        PlineBuilder::default()
+    }
+
     /// Creates a `Pline` from `Point3` vertices with all-zero bulges (line segments only).
     #[must_use]
     pub fn from_points(points: &[Point3], closed: bool) -> Self {
@@ -56,6 +171,33 @@ impl Pline {
         Self { vertices, closed }
     }
 
+    /// Creates a `Pline` from a chain of arcs, projected onto the XY plane
+    /// as by [`Arc::to_pline_vertices`].
+    ///
+    /// An arc starting where the previous one ends (within [`TOLERANCE`])
+    /// shares its vertex; a gap between arcs becomes a line segment. The
+    /// result is closed when the last arc ends at the first one's start.
+    #[must_use]
+    pub fn from_arcs(arcs: &[Arc]) -> Self {
+        let mut pline = Self {
+            vertices: Vec::with_capacity(arcs.len() + 1),
+            closed: false,
+        };
+        for arc in arcs {
+            let [start, end] = arc.to_pline_vertices();
+            if let Some(last) = pline.vertices.last_mut() {
+                if (last.x - start.x).hypot(last.y - start.y) <= TOLERANCE {
+                    last.bulge = start.bulge;
+                    pline.vertices.push(end);
+                    continue;
+                }
+            }
+            pline.vertices.extend([start, end]);
+        }
+        pline.close_if_coincident(TOLERANCE);
+        pline
+    }
+
     /// Converts this polyline to a list of `Point3` by tessellating arcs into line segments.
     ///
     /// `tolerance` controls the maximum deviation between the arc and its chord approximation.
@@ -126,10 +268,49 @@ impl Pline {
         (points, sources)
     }
 
+    /// Returns an all-line copy with every arc replaced by chords deviating
+    /// at most `tolerance` from it (see [`Self::to_points`]).
+    #[must_use]
+    pub fn flatten(&self, tolerance: f64) -> Self {
+        let mut points = self.to_points(tolerance);
+        if self.closed && points.len() > 1 {
+            // A closed outline's points end back on the first vertex.
+            points.pop();
+        }
+        Self::from_points(&points, self.closed)
+    }
+
+    /// Returns the largest distance between this polyline and `flattened`,
+    /// an approximation of it such as [`Self::flatten`] produces.
+    ///
+    /// Both polylines are sampled at several points per segment (always
+    /// including chord midpoints, where a chord's deviation from its arc
+    /// peaks) and each sample is measured against the other polyline; the
+    /// maximum over both directions is returned. An empty polyline on
+    /// either side yields infinity.
+    #[must_use]
+    pub fn max_chord_error(&self, flattened: &Self) -> f64 {
+        let one_way = |from: &Self, to: &Self| {
+            from.segments()
+                .flat_map(|seg| {
+                    (0..=CHORD_ERROR_SAMPLES).map(move |i| {
+                        segment_point(&seg, f64::from(i) / f64::from(CHORD_ERROR_SAMPLES))
+                    })
+                })
+                .map(|p| point_to_pline(p, to).0)
+                .fold(0.0_f64, f64::max)
+        };
+        if self.vertices.is_empty() || flattened.vertices.is_empty() {
+            return f64::INFINITY;
+        }
+        one_way(flattened, self).max(one_way(self, flattened))
+    }
+
     /// Returns a new polyline with vertices in reverse order and negated bulges.
     ///
     /// For a segment `v[i] → v[i+1]` with bulge `b`, the reversed segment
-    /// `v[i+1] → v[i]` has bulge `-b` (arc direction flips).
+    /// `v[i+1] → v[i]` has bulge `-b` (arc direction flips). For a closed
+    /// polyline the closing segment `v[m-1] → v[0]` is reversed the same way.
     #[must_use]
     pub fn reversed(&self) -> Self {
         let m = self.vertices.len();
@@ -141,8 +322,11 @@ impl Pline {
             let orig_idx = m - 1 - j;
             // In the reversed polyline, vertex j connects to vertex j+1,
             // which corresponds to the reverse of original segment (m-2-j).
+            // The last vertex carries the reversed closing segment, if any.
             let bulge = if j < m - 1 {
                 -self.vertices[m - 2 - j].bulge
+            } else if self.closed {
+                -self.vertices[m - 1].bulge
             } else {
                 0.0
             };
@@ -160,6 +344,22 @@ impl Pline {
 
     /// Returns the signed area enclosed by this polyline.
     ///
+    /// Computed by [`Self::area_by_greens`]. Debug builds cross-check the
+    /// result against the shoelace area of a finely tessellated copy and
+    /// panic if the two disagree beyond the chord error.
+    #[must_use]
+    pub fn signed_area(&self) -> f64 {
+        let area = self.area_by_greens();
+        debug_assert!(
+            self.area_matches_tessellation(area),
+            "Pline::signed_area: analytic area {area} disagrees with the tessellated area"
+        );
+        area
+    }
+
+    /// Returns the signed area enclosed by this polyline by Green's
+    /// theorem, with arcs handled analytically.
+    ///
     /// Counter-clockwise orientation yields a positive area, clockwise a
     /// negative one. Circular-arc segments are accounted for exactly:
     /// each bulged segment contributes its chord to the shoelace sum plus
@@ -171,7 +371,7 @@ impl Pline {
     /// convention); the last vertex's bulge is ignored because it has no
     /// segment. Fewer than two vertices → `0.0`.
     #[must_use]
-    pub fn signed_area(&self) -> f64 {
+    pub fn area_by_greens(&self) -> f64 {
         let n = self.vertices.len();
         if n < 2 {
             return 0.0;
@@ -206,6 +406,262 @@ impl Pline {
         area
     }
 
+    /// Returns whether `area` matches the shoelace area of this polyline
+    /// with every arc flattened to [`AREA_CHECK_ARC_CHORDS`] chords, within
+    /// a bound on the chord error plus floating-point noise.
+    fn area_matches_tessellation(&self, area: f64) -> bool {
+        let n = self.vertices.len();
+        if n < 2 {
+            return area == 0.0;
+        }
+        let chords = f64::from(AREA_CHECK_ARC_CHORDS);
+        let mut ring: Vec<(f64, f64)> = Vec::with_capacity(n);
+        let mut tol = 0.0;
+        let mut scale = 1.0_f64;
+        for i in 0..n {
+            let v0 = &self.vertices[i];
+            ring.push((v0.x, v0.y));
+            scale = scale.max(v0.x.abs()).max(v0.y.abs());
+            if i >= self.segment_count() || v0.bulge.abs() < 1e-12 {
+                continue;
+            }
+            let v1 = &self.vertices[(i + 1) % n];
+            let (cx, cy, radius, start, sweep) = arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge);
+            if radius < 1e-12 {
+                continue;
+            }
+            for k in 1..AREA_CHECK_ARC_CHORDS {
+                ring.push(arc_point_at(
+                    cx,
+                    cy,
+                    radius,
+                    start,
+                    sweep,
+                    f64::from(k) / chords,
+                ));
+            }
+            // Each chord cuts off at most r²·φ³/12 for its sweep φ.
+            tol += 4.0 * radius * radius * sweep.abs() / (chords * chords);
+            scale = scale.max(cx.abs() + radius).max(cy.abs() + radius);
+        }
+        let m = ring.len();
+        let tessellated = 0.5
+            * (0..m)
+                .map(|i| {
+                    let (a, b) = (ring[i], ring[(i + 1) % m]);
+                    a.0 * b.1 - b.0 * a.1
+                })
+                .sum::<f64>();
+        // A NaN area does not count as a mismatch.
+        let diff = (tessellated - area).abs();
+        diff.is_nan() || diff <= tol + 1e-9 * scale * scale
+    }
+
+    /// Returns whether `(x, y)` lies strictly inside the region bounded by
+    /// this polyline (even-odd rule).
+    ///
+    /// Arc segments are handled exactly: the parity of the chord polygon is
+    /// flipped once for every bulged segment whose circular segment (the
+    /// region between chord and arc) contains the point. As with
+    /// [`Self::signed_area`], an open polyline is treated as implicitly
+    /// closed by a straight chord. Points on the boundary may report
+    /// either side.
+    #[must_use]
+    pub fn contains_point(&self, x: f64, y: f64) -> bool {
+        let n = self.vertices.len();
+        if n < 2 {
+            return false;
+        }
+
+        // Ray cast along +X over the chord polygon.
+        let mut inside = false;
+        for i in 0..n {
+            let v0 = &self.vertices[i];
+            let v1 = &self.vertices[(i + 1) % n];
+            if (v0.y > y) != (v1.y > y) {
+                let x_cross = v0.x + (y - v0.y) / (v1.y - v0.y) * (v1.x - v0.x);
+                if x < x_cross {
+                    inside = !inside;
+                }
+            }
+        }
+
+        for i in 0..self.segment_count() {
+            let v0 = &self.vertices[i];
+            let v1 = &self.vertices[(i + 1) % n];
+            if v0.bulge.abs() >= 1e-12 && in_circular_segment(v0, v1, x, y) {
+                inside = !inside;
+            }
+        }
+
+        inside
+    }
+
+    /// Returns the winding number of this polyline around `p`: the net
+    /// number of counter-clockwise turns the boundary makes about it.
+    ///
+    /// Unlike [`Self::contains_point`] (even-odd), this distinguishes
+    /// self-overlapping or multiply-wound polylines; for a simple polyline
+    /// `winding_number(p) != 0` agrees with `contains_point`. Arcs are
+    /// handled exactly: each bulged segment adds `sign(bulge)` to the chord
+    /// polygon's winding when its circular segment contains `p`. An open
+    /// polyline is implicitly closed by a straight chord. Points on the
+    /// boundary may report either side.
+    #[must_use]
+    pub fn winding_number(&self, p: Point2) -> i32 {
+        let n = self.vertices.len();
+        if n < 2 {
+            return 0;
+        }
+
+        // Winding of the chord polygon, then one turn per containing bulge.
+        let pt = |v: &PlineVertex| Point2::new(v.x, v.y);
+        let chords = (0..n).map(|i| (pt(&self.vertices[i]), pt(&self.vertices[(i + 1) % n])));
+        let mut winding = winding_number_2d(chords, p);
+
+        for i in 0..self.segment_count() {
+            let v0 = &self.vertices[i];
+            let v1 = &self.vertices[(i + 1) % n];
+            if v0.bulge.abs() >= 1e-12 && in_circular_segment(v0, v1, p.x, p.y) {
+                winding += if v0.bulge > 0.0 { 1 } else { -1 };
+            }
+        }
+
+        winding
+    }
+
+    /// Returns whether `other` describes the same polyline within `tol`.
+    ///
+    /// Both must agree on `closed`, vertex count and traversal direction,
+    /// with every vertex position and bulge matching within `tol`. Closed
+    /// polylines may start at different vertices (any rotation matches);
+    /// open ones must match vertex by vertex.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tol: f64) -> bool {
+        let n = self.vertices.len();
+        if self.closed != other.closed || n != other.vertices.len() {
+            return false;
+        }
+        let vertex_eq = |a: &PlineVertex, b: &PlineVertex| {
+            (a.x - b.x).abs() <= tol && (a.y - b.y).abs() <= tol && (a.bulge - b.bulge).abs() <= tol
+        };
+        let shifts = if self.closed { n.max(1) } else { 1 };
+        (0..shifts)
+            .any(|k| (0..n).all(|i| vertex_eq(&self.vertices[i], &other.vertices[(i + k) % n])))
+    }
+
+    /// Returns a canonical form for comparisons: a closed polyline is
+    /// oriented CCW and rotated to start at its leftmost vertex (ties
+    /// broken by smallest y, as in
+    /// [`rotate_to_canonical_start`](crate::math::polygon_2d::rotate_to_canonical_start)).
+    /// Open polylines are returned unchanged.
+    #[must_use]
+    pub fn canonicalize(&self) -> Self {
+        if !self.closed || self.vertices.len() < 2 {
+            return self.clone();
+        }
+        let mut out = if self.signed_area() < 0.0 {
+            self.reversed()
+        } else {
+            self.clone()
+        };
+        let mut best = 0;
+        for (i, v) in out.vertices.iter().enumerate().skip(1) {
+            let b = &out.vertices[best];
+            if v.x < b.x - TOLERANCE || ((v.x - b.x).abs() < TOLERANCE && v.y < b.y) {
+                best = i;
+            }
+        }
+        out.vertices.rotate_left(best);
+        out
+    }
+
+    /// Merges runs of consecutive arc segments that lie on the same circle
+    /// (centers and radii within `tol`) and turn the same way into single
+    /// arcs with the combined sweep. For a closed polyline the run may wrap
+    /// around the closing vertex.
+    ///
+    /// A merge that would reach a full turn is skipped, since a bulge can
+    /// only encode sweeps below `2π`.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn merge_coarcs(&self, tol: f64) -> Self {
+        let mut vertices = self.vertices.clone();
+        let arc_of = |v0: &PlineVertex, v1: &PlineVertex| {
+            (v0.bulge.abs() >= 1e-12).then(|| arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge))
+        };
+
+        let mut i = 0;
+        loop {
+            let n = vertices.len();
+            // Pairs of consecutive segments `(i, i + 1)`; closed polylines
+            // also pair the closing segment with the first.
+            let pairs = if self.closed { n } else { n.saturating_sub(2) };
+            if n < 3 || i >= pairs {
+                break;
+            }
+            let (a, b, c) = (i, (i + 1) % n, (i + 2) % n);
+            let merged = match (
+                arc_of(&vertices[a], &vertices[b]),
+                arc_of(&vertices[b], &vertices[c]),
+            ) {
+                (Some((cx0, cy0, r0, _, sw0)), Some((cx1, cy1, r1, _, sw1))) => {
+                    let sweep = sw0 + sw1;
+                    let same_arc = (sw0 > 0.0) == (sw1 > 0.0)
+                        && (cx0 - cx1).hypot(cy0 - cy1) <= tol
+                        && (r0 - r1).abs() <= tol
+                        && sweep.abs() < std::f64::consts::TAU - 1e-9;
+                    same_arc.then_some(sweep)
+                }
+                _ => None,
+            };
+            if let Some(sweep) = merged {
+                vertices[a].bulge = (sweep / 4.0).tan();
+                vertices.remove(b);
+                // Removing the first vertex shifts the merged one down.
+                if b < a {
+                    i -= 1;
+                }
+            } else {
+                i += 1;
+            }
+        }
+        Self {
+            vertices,
+            closed: self.closed,
+        }
+    }
+
+    /// Iterates over the typed segments of this polyline: `n - 1` for an
+    /// open polyline, `n` for a closed one (the last closing back to the
+    /// first vertex).
+    ///
+    /// A bulged segment whose endpoints coincide has no defined circle and
+    /// is yielded as a zero-length line.
+    pub fn segments(&self) -> impl Iterator<Item = PlineSegment> + '_ {
+        let n = self.vertices.len();
+        (0..self.segment_count()).map(move |i| {
+            let v0 = &self.vertices[i];
+            let v1 = &self.vertices[(i + 1) % n];
+            let (a, b) = (Point2::new(v0.x, v0.y), Point2::new(v1.x, v1.y));
+            if v0.bulge.abs() < 1e-12 {
+                return PlineSegment::Line { a, b };
+            }
+            let (cx, cy, radius, _, _) = arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge);
+            if radius < 1e-12 {
+                return PlineSegment::Line { a, b };
+            }
+            PlineSegment::Arc {
+                a,
+                b,
+                bulge: v0.bulge,
+                center: Point2::new(cx, cy),
+                radius,
+                ccw: v0.bulge > 0.0,
+            }
+        })
+    }
+
     /// Returns the number of segments in this polyline.
     #[must_use]
     pub fn segment_count(&self) -> usize {
@@ -219,6 +675,117 @@ impl Pline {
             n - 1
         }
     }
+
+    /// Returns a copy of this polyline moved by `shift`.
+    #[must_use]
+    pub fn translated(&self, shift: Vector2) -> Self {
+        let vertices = self
+            .vertices
+            .iter()
+            .map(|v| PlineVertex::new(v.x + shift.x, v.y + shift.y, v.bulge))
+            .collect();
+        Self {
+            vertices,
+            closed: self.closed,
+        }
+    }
+
+    /// Returns a copy of this polyline moved so the centre of its vertex
+    /// bounding box sits at the origin, together with the shift applied.
+    ///
+    /// Geometry far from the origin (survey or geographic coordinates)
+    /// leaves too few significant digits for [`TOLERANCE`]-scale
+    /// comparisons; operate on the recentred copy and move results back
+    /// with [`translated`](Self::translated)`(-shift)`.
+    #[must_use]
+    pub fn recenter(&self) -> (Self, Vector2) {
+        let Some(first) = self.vertices.first() else {
+            return (self.clone(), Vector2::zeros());
+        };
+        let (mut min, mut max) = ([first.x, first.y], [first.x, first.y]);
+        for v in &self.vertices {
+            min = [min[0].min(v.x), min[1].min(v.y)];
+            max = [max[0].max(v.x), max[1].max(v.y)];
+        }
+        let shift = -Vector2::new(f64::midpoint(min[0], max[0]), f64::midpoint(min[1], max[1]));
+        (self.translated(shift), shift)
+    }
+
+    /// Returns whether the last vertex coincides with the first within
+    /// `tol`, regardless of the `closed` flag.
+    #[must_use]
+    pub fn is_geometrically_closed(&self, tol: f64) -> bool {
+        match (self.vertices.first(), self.vertices.last()) {
+            (Some(first), Some(last)) if self.vertices.len() >= 2 => {
+                (first.x - last.x).hypot(first.y - last.y) <= tol
+            }
+            _ => false,
+        }
+    }
+
+    /// Normalizes a polyline whose last vertex repeats the first within
+    /// `tol`: the duplicate endpoint is dropped and `closed` is set, so the
+    /// closing segment takes its place. Other polylines are left unchanged.
+    pub fn close_if_coincident(&mut self, tol: f64) {
+        // Keep at least two vertices so a closed result still has area.
+        if self.vertices.len() < 3 || !self.is_geometrically_closed(tol) {
+            return;
+        }
+        self.vertices.pop();
+        self.closed = true;
+    }
+}
+
+/// Returns whether `(x, y)` lies inside the circular segment bounded by the
+/// bulged segment `v0 → v1` and its chord.
+///
+/// The circular segment is the part of the arc's disk on the arc's side of
+/// the chord. A positive bulge (CCW arc) bows to the right of `v0 → v1`, a
+/// negative one to the left.
+fn in_circular_segment(v0: &PlineVertex, v1: &PlineVertex, x: f64, y: f64) -> bool {
+    let (cx, cy, radius, _, _) = arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge);
+    if radius < 1e-12 {
+        return false;
+    }
+    if (x - cx).powi(2) + (y - cy).powi(2) >= radius * radius {
+        return false;
+    }
+    let side = orient2d(
+        Point2::new(v0.x, v0.y),
+        Point2::new(v1.x, v1.y),
+        Point2::new(x, y),
+    );
+    if v0.bulge > 0.0 {
+        side < 0
+    } else {
+        side > 0
+    }
+}
+
+/// Point at the normalized parameter `t ∈ [0, 1]` along `seg` (fraction of
+/// the sweep for arcs).
+fn segment_point(seg: &PlineSegment, t: f64) -> Point2 {
+    match *seg {
+        PlineSegment::Line { a, b } => a + (b - a) * t,
+        PlineSegment::Arc {
+            a,
+            bulge,
+            center,
+            radius,
+            ..
+        } => {
+            let start_angle = (a.y - center.y).atan2(a.x - center.x);
+            let (x, y) = arc_point_at(
+                center.x,
+                center.y,
+                radius,
+                start_angle,
+                4.0 * bulge.atan(),
+                t,
+            );
+            Point2::new(x, y)
+        }
+    }
 }
 
 /// Computes the number of line segments needed to approximate an arc
@@ -244,6 +811,238 @@ fn arc_subdivision_count(radius: f64, abs_sweep: f64, tolerance: f64) -> u32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn from_arcs_chains_quarters_into_closed_circle() {
+        use crate::math::Vector3;
+        use std::f64::consts::FRAC_PI_2;
+
+        let arcs: Vec<Arc> = (0..4)
+            .map(|i| {
+                let start = FRAC_PI_2 * f64::from(i);
+                Arc::new(
+                    Point3::origin(),
+                    2.0,
+                    Vector3::z(),
+                    Vector3::x(),
+                    start,
+                    start + FRAC_PI_2,
+                )
+                .unwrap()
+            })
+            .collect();
+        let pline = Pline::from_arcs(&arcs);
+        assert!(pline.closed);
+        assert_eq!(pline.vertices.len(), 4);
+        assert!((pline.signed_area() - 4.0 * std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn builder_matches_rounded_rectangle_literal() {
+        use std::f64::consts::FRAC_PI_2;
+
+        let b = (FRAC_PI_2 / 4.0).tan();
+        let literal = Pline {
+            vertices: vec![
+                PlineVertex::line(1.0, 0.0),
+                PlineVertex::new(9.0, 0.0, b),
+                PlineVertex::line(10.0, 1.0),
+                PlineVertex::new(10.0, 5.0, b),
+                PlineVertex::line(9.0, 6.0),
+                PlineVertex::new(1.0, 6.0, b),
+                PlineVertex::line(0.0, 5.0),
+                PlineVertex::new(0.0, 1.0, b),
+            ],
+            closed: true,
+        };
+        let built = Pline::builder()
+            .line_to(1.0, 0.0)
+            .line_to(9.0, 0.0)
+            .arc_to(10.0, 1.0, FRAC_PI_2)
+            .line_to(10.0, 5.0)
+            .arc_to(9.0, 6.0, FRAC_PI_2)
+            .line_to(1.0, 6.0)
+            .arc_to(0.0, 5.0, FRAC_PI_2)
+            .line_to(0.0, 1.0)
+            .arc_to(1.0, 0.0, FRAC_PI_2)
+            .close();
+        assert!(built.approx_eq(&literal, 1e-12));
+
+        let open = Pline::builder().line_to(0.0, 0.0).line_to(1.0, 0.0).build();
+        assert!(!open.closed);
+        assert_eq!(open.segment_count(), 1);
+    }
+
+    #[test]
+    fn close_if_coincident_drops_duplicate_endpoint() {
+        let mut pline = Pline {
+            vertices: vec![
+                PlineVertex::line(0.0, 0.0),
+                PlineVertex::new(2.0, 0.0, 0.5),
+                PlineVertex::line(2.0, 2.0),
+                PlineVertex::line(1e-9, -1e-9),
+            ],
+            closed: false,
+        };
+        assert!(pline.is_geometrically_closed(1e-6));
+        assert!(!pline.is_geometrically_closed(1e-12));
+        pline.close_if_coincident(1e-6);
+        assert!(pline.closed);
+        assert_eq!(pline.vertices.len(), 3);
+        assert!((pline.vertices[1].bulge - 0.5).abs() < 1e-12);
+        assert!(!pline.is_geometrically_closed(1e-6));
+
+        // An open polyline with a real gap is left alone.
+        let mut open = Pline::from_points(
+            &[
+                Point3::origin(),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+            ],
+            false,
+        );
+        open.close_if_coincident(1e-6);
+        assert!(!open.closed);
+        assert_eq!(open.vertices.len(), 3);
+    }
+
+    #[test]
+    fn merge_coarcs_joins_quarter_arcs_into_semicircle() {
+        // Two CCW quarter arcs of the unit circle, then a closing diameter.
+        let quarter = (std::f64::consts::FRAC_PI_2 / 4.0).tan();
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::new(1.0, 0.0, quarter),
+                PlineVertex::new(0.0, 1.0, quarter),
+                PlineVertex::line(-1.0, 0.0),
+            ],
+            closed: true,
+        };
+        let merged = pline.merge_coarcs(1e-9);
+        assert_eq!(merged.vertices.len(), 2);
+        assert!((merged.vertices[0].bulge - 1.0).abs() < 1e-12);
+        assert!(merged.vertices[1].bulge.abs() < 1e-12);
+        assert!((merged.signed_area() - pline.signed_area()).abs() < 1e-12);
+
+        // The same semicircle split across the closing vertex.
+        let wrapped = Pline {
+            vertices: vec![
+                PlineVertex::new(0.0, 1.0, quarter),
+                PlineVertex::line(-1.0, 0.0),
+                PlineVertex::new(1.0, 0.0, quarter),
+            ],
+            closed: true,
+        };
+        let merged = wrapped.merge_coarcs(1e-9);
+        assert_eq!(merged.vertices.len(), 2);
+        assert!(merged
+            .vertices
+            .iter()
+            .any(|v| (v.bulge - 1.0).abs() < 1e-12));
+        assert!((merged.signed_area() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn merge_coarcs_keeps_distinct_and_full_turn_arcs() {
+        let quarter = (std::f64::consts::FRAC_PI_2 / 4.0).tan();
+        // Opposite turn directions on the same circle are not merged.
+        let zigzag = Pline {
+            vertices: vec![
+                PlineVertex::new(1.0, 0.0, quarter),
+                PlineVertex::new(0.0, 1.0, -quarter),
+                PlineVertex::line(1.0, 0.0),
+            ],
+            closed: false,
+        };
+        assert_eq!(zigzag.merge_coarcs(1e-9).vertices.len(), 3);
+
+        // A circle made of four quarters merges down to two arcs, not one.
+        let circle = Pline {
+            vertices: vec![
+                PlineVertex::new(1.0, 0.0, quarter),
+                PlineVertex::new(0.0, 1.0, quarter),
+                PlineVertex::new(-1.0, 0.0, quarter),
+                PlineVertex::new(0.0, -1.0, quarter),
+            ],
+            closed: true,
+        };
+        let merged = circle.merge_coarcs(1e-9);
+        assert_eq!(merged.vertices.len(), 2);
+        assert!((merged.signed_area() - std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn winding_number_of_doubly_traced_circle() {
+        // Unit circle in thirds, traced twice counter-clockwise.
+        let bulge = (std::f64::consts::TAU / 3.0 / 4.0).tan();
+        let third = |k: i32| {
+            let a = f64::from(k) * std::f64::consts::TAU / 3.0;
+            PlineVertex::new(a.cos(), a.sin(), bulge)
+        };
+        let twice = Pline {
+            vertices: (0..6).map(third).collect(),
+            closed: true,
+        };
+        assert_eq!(twice.winding_number(Point2::origin()), 2);
+        // Between the chord triangle and the arc.
+        assert_eq!(twice.winding_number(Point2::new(0.9, 0.3)), 2);
+        assert_eq!(twice.winding_number(Point2::new(1.5, 0.0)), 0);
+
+        let once = Pline {
+            vertices: (0..3).map(third).collect(),
+            closed: true,
+        };
+        assert_eq!(once.winding_number(Point2::new(0.9, 0.3)), 1);
+        assert_eq!(once.reversed().winding_number(Point2::origin()), -1);
+        assert!(once.contains_point(0.9, 0.3));
+    }
+
+    #[test]
+    fn segments_of_rounded_rectangle() {
+        // 10×4 stadium: bottom line, right CCW semicircle, top line, left
+        // CCW semicircle.
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::line(0.0, 0.0),
+                PlineVertex::new(10.0, 0.0, 1.0),
+                PlineVertex::line(10.0, 4.0),
+                PlineVertex::new(0.0, 4.0, 1.0),
+            ],
+            closed: true,
+        };
+        let segs: Vec<PlineSegment> = pline.segments().collect();
+        assert_eq!(segs.len(), 4);
+        assert_eq!(
+            segs[0],
+            PlineSegment::Line {
+                a: Point2::new(0.0, 0.0),
+                b: Point2::new(10.0, 0.0)
+            }
+        );
+        assert!(matches!(segs[2], PlineSegment::Line { .. }));
+        for (seg, cx) in [(segs[1], 10.0), (segs[3], 0.0)] {
+            let PlineSegment::Arc {
+                center,
+                radius,
+                ccw,
+                ..
+            } = seg
+            else {
+                panic!("expected an arc, got {seg:?}");
+            };
+            assert!((center - Point2::new(cx, 2.0)).norm() < 1e-12);
+            assert!((radius - 2.0).abs() < 1e-12);
+            assert!(ccw);
+        }
+        // The closing segment returns to the first vertex.
+        assert_eq!(segs[3].end(), segs[0].start());
+
+        let open = Pline {
+            closed: false,
+            ..pline
+        };
+        assert_eq!(open.segments().count(), 3);
+    }
+
     #[test]
     fn from_points_creates_line_only() {
         let pts = vec![
@@ -368,6 +1167,23 @@ mod tests {
         assert!(rev.vertices[1].bulge.abs() < 1e-12); // (2,0), line to (0,0)
     }
 
+    #[test]
+    fn reversed_closed_keeps_closing_arc() {
+        // Two semicircles forming a full circle: both segments are arcs,
+        // including the closing one.
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::new(0.0, 0.0, 1.0),
+                PlineVertex::new(2.0, 0.0, 1.0),
+            ],
+            closed: true,
+        };
+        let rev = pline.reversed();
+        assert!((rev.vertices[0].bulge + 1.0).abs() < 1e-12);
+        assert!((rev.vertices[1].bulge + 1.0).abs() < 1e-12);
+        assert!((rev.signed_area() + std::f64::consts::PI).abs() < 1e-10);
+    }
+
     #[test]
     fn signed_area_unit_square_ccw() {
         let pts = vec![
@@ -501,6 +1317,126 @@ mod tests {
         assert!((area - 1.0).abs() < 1e-12, "area={area}");
     }
 
+    #[test]
+    fn signed_area_cross_check_catches_wrong_arc_area() {
+        // 4 × 2 rectangle with quarter-circle corners of radius 0.5.
+        let b = (std::f64::consts::PI / 8.0).tan();
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::line(0.5, 0.0),
+                PlineVertex::new(3.5, 0.0, b),
+                PlineVertex::line(4.0, 0.5),
+                PlineVertex::new(4.0, 1.5, b),
+                PlineVertex::line(3.5, 2.0),
+                PlineVertex::new(0.5, 2.0, b),
+                PlineVertex::line(0.0, 1.5),
+                PlineVertex::new(0.0, 0.5, b),
+            ],
+            closed: true,
+        };
+        let expected = 8.0 - (4.0 - std::f64::consts::PI) * 0.25;
+        let area = pline.signed_area();
+        assert!((area - expected).abs() < 1e-12, "area={area}");
+        assert!(pline.area_matches_tessellation(area));
+
+        // Dropping the circular segments (arcs taken as chords) is caught.
+        let mut chords = pline.clone();
+        for v in &mut chords.vertices {
+            v.bulge = 0.0;
+        }
+        let chord_area = chords.signed_area();
+        assert!(!pline.area_matches_tessellation(chord_area));
+    }
+
+    #[test]
+    fn contains_point_square() {
+        let pts = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(2.0, 0.0, 0.0),
+            Point3::new(2.0, 2.0, 0.0),
+            Point3::new(0.0, 2.0, 0.0),
+        ];
+        let pline = Pline::from_points(&pts, true);
+        assert!(pline.contains_point(1.0, 1.0));
+        assert!(!pline.contains_point(3.0, 1.0));
+        assert!(pline.reversed().contains_point(1.0, 1.0));
+    }
+
+    #[test]
+    fn contains_point_two_vertex_circle_is_exact() {
+        // Full circle of radius 1 centered at (1, 0): the chord polygon is
+        // degenerate, so containment comes entirely from the arcs.
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::new(0.0, 0.0, 1.0),
+                PlineVertex::new(2.0, 0.0, 1.0),
+            ],
+            closed: true,
+        };
+        assert!(pline.contains_point(1.0, 0.5));
+        assert!(pline.contains_point(1.0, -0.99));
+        assert!(!pline.contains_point(1.8, 0.8));
+        assert!(!pline.reversed().contains_point(1.8, 0.8));
+        assert!(pline.reversed().contains_point(1.0, 0.99));
+    }
+
+    #[test]
+    fn contains_point_inward_bulge_excludes_bite() {
+        // 4x3 rectangle whose bottom edge bows inward (bulge -0.5).
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::new(0.0, 0.0, -0.5),
+                PlineVertex::line(4.0, 0.0),
+                PlineVertex::line(4.0, 3.0),
+                PlineVertex::line(0.0, 3.0),
+            ],
+            closed: true,
+        };
+        assert!(!pline.contains_point(2.0, 0.2), "inside the bite");
+        assert!(pline.contains_point(2.0, 2.0));
+        assert!(pline.contains_point(0.2, 0.5), "beside the bite");
+    }
+
+    fn square(start: usize, ccw: bool) -> Pline {
+        let mut pts = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ];
+        if !ccw {
+            pts.reverse();
+        }
+        pts.rotate_left(start);
+        Pline::from_points(&pts, true)
+    }
+
+    #[test]
+    fn approx_eq_matches_rotations_of_closed_square() {
+        let a = square(0, true);
+        let b = square(2, true);
+        assert!(a.approx_eq(&b, 1e-12));
+        assert!(b.approx_eq(&a, 1e-12));
+        // Opposite winding is a different polyline.
+        assert!(!a.approx_eq(&square(0, false), 1e-12));
+        // Open polylines do not match up to rotation.
+        let open = |p: Pline| Pline { closed: false, ..p };
+        assert!(!open(a.clone()).approx_eq(&open(b), 1e-12));
+        // Bulges must match too.
+        let mut bulged = a.clone();
+        bulged.vertices[1].bulge = 0.5;
+        assert!(!a.approx_eq(&bulged, 1e-12));
+    }
+
+    #[test]
+    fn canonicalize_orients_ccw_from_leftmost_bottom() {
+        let c = square(3, false).canonicalize();
+        assert!(c.signed_area() > 0.0);
+        assert_eq!((c.vertices[0].x, c.vertices[0].y), (0.0, 0.0));
+        assert!(c.approx_eq(&square(0, true), 0.0));
+        assert_eq!(c.vertices, square(1, true).canonicalize().vertices);
+    }
+
     #[test]
     fn arc_subdivision_count_large_tolerance() {
         // Large tolerance → fewer subdivisions.
@@ -514,4 +1450,28 @@ mod tests {
         let n = arc_subdivision_count(1.0, std::f64::consts::PI, 0.001);
         assert!(n > 10, "expected many subdivisions, got {n}");
     }
+
+    #[test]
+    fn flatten_respects_tolerance_on_semicircle() {
+        // Unit semicircle from (1, 0) to (-1, 0), closed by its diameter.
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::new(1.0, 0.0, 1.0),
+                PlineVertex::line(-1.0, 0.0),
+            ],
+            closed: true,
+        };
+        let flat = pline.flatten(0.01);
+        assert!(flat.vertices.iter().all(|v| v.bulge == 0.0));
+        assert!(flat.vertices.len() > 3);
+        let err = pline.max_chord_error(&flat);
+        assert!(err > 0.0 && err <= 0.01, "max chord error {err}");
+
+        // The bare diameter misses the semicircle's apex by its radius.
+        let chord = Pline::from_points(
+            &[Point3::new(1.0, 0.0, 0.0), Point3::new(-1.0, 0.0, 0.0)],
+            true,
+        );
+        assert!((pline.max_chord_error(&chord) - 1.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,312 @@
+//! Half-plane clipping for plines, preserving arc segments.
+
+use std::f64::consts::TAU;
+
+use super::{Pline, PlineVertex};
+use crate::math::arc_2d::{arc_from_bulge, arc_point_at};
+use crate::math::{Point2, Vector2, TOLERANCE};
+
+/// A piece of a source segment lying entirely on one side of the clip line.
+#[derive(Debug, Clone, Copy)]
+struct Piece {
+    start: (f64, f64),
+    bulge: f64,
+    end: (f64, f64),
+    inside: bool,
+}
+
+impl Pline {
+    /// Clips this polyline to the half-plane on the negative side of the
+    /// line through `line_point` with normal `line_normal`, i.e. keeps the
+    /// points `p` with `(p - line_point) · line_normal < 0`.
+    ///
+    /// Segments crossing the line are split there; arcs are split into
+    /// sub-arcs of the same circle. For a closed polyline each kept region
+    /// is closed along the clip line with straight segments, so a
+    /// non-convex input may yield several plines. An open polyline yields
+    /// its kept runs as open plines. A zero `line_normal` keeps nothing.
+    #[must_use]
+    pub fn clip_halfplane(&self, line_point: Point2, line_normal: Vector2) -> Vec<Self> {
+        let len = line_normal.norm();
+        if len < TOLERANCE || self.segment_count() == 0 {
+            return Vec::new();
+        }
+        let normal = line_normal / len;
+        let side =
+            |(x, y): (f64, f64)| (x - line_point.x) * normal.x + (y - line_point.y) * normal.y;
+
+        let n = self.vertices.len();
+        let mut pieces = Vec::new();
+        for i in 0..self.segment_count() {
+            split_segment(
+                &self.vertices[i],
+                &self.vertices[(i + 1) % n],
+                &normal,
+                &side,
+                &mut pieces,
+            );
+        }
+
+        if pieces.iter().all(|p| p.inside) {
+            return vec![self.clone()];
+        }
+        if !self.closed {
+            return pieces
+                .split(|p| !p.inside)
+                .filter(|run| !run.is_empty())
+                .map(|run| chain_to_pline(run, false))
+                .collect();
+        }
+
+        // Rotate so the ring starts on an outside piece; inside runs are
+        // then contiguous chains running from an entry to an exit point.
+        if let Some(first_out) = pieces.iter().position(|p| !p.inside) {
+            pieces.rotate_left(first_out);
+        }
+        let chains: Vec<&[Piece]> = pieces
+            .split(|p| !p.inside)
+            .filter(|run| !run.is_empty())
+            .collect();
+        if chains.is_empty() {
+            return Vec::new();
+        }
+
+        // Along the clip line, kept stretches lie between consecutive
+        // crossings; each joins one chain's exit to another chain's entry.
+        let along =
+            |(x, y): (f64, f64)| (x - line_point.x) * -normal.y + (y - line_point.y) * normal.x;
+        let mut crossings: Vec<(f64, usize, bool)> = Vec::with_capacity(chains.len() * 2);
+        for (c, chain) in chains.iter().enumerate() {
+            crossings.push((along(chain[0].start), c, true));
+            crossings.push((along(chain[chain.len() - 1].end), c, false));
+        }
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut next_chain: Vec<Option<usize>> = vec![None; chains.len()];
+        for pair in crossings.chunks_exact(2) {
+            match (pair[0], pair[1]) {
+                ((_, exit, false), (_, entry, true)) | ((_, entry, true), (_, exit, false)) => {
+                    next_chain[exit] = Some(entry);
+                }
+                _ => {}
+            }
+        }
+
+        let mut visited = vec![false; chains.len()];
+        let mut result = Vec::new();
+        for start in 0..chains.len() {
+            if visited[start] {
+                continue;
+            }
+            let mut vertices = Vec::new();
+            let mut c = start;
+            while !visited[c] {
+                visited[c] = true;
+                let chain = chains[c];
+                vertices.extend(
+                    chain
+                        .iter()
+                        .map(|p| PlineVertex::new(p.start.0, p.start.1, p.bulge)),
+                );
+                let exit = chain[chain.len() - 1].end;
+                let Some(next) = next_chain[c] else {
+                    vertices.push(PlineVertex::line(exit.0, exit.1));
+                    break;
+                };
+                let entry = chains[next][0].start;
+                if (exit.0 - entry.0).hypot(exit.1 - entry.1) > TOLERANCE {
+                    vertices.push(PlineVertex::line(exit.0, exit.1));
+                }
+                c = next;
+            }
+            if vertices.len() >= 2 {
+                result.push(Self {
+                    vertices,
+                    closed: true,
+                });
+            }
+        }
+        result
+    }
+}
+
+/// Splits the segment `v0 → v1` where it crosses the clip line (zero set
+/// of `side`, with unit `normal`) and appends the pieces, each classified by its midpoint.
+fn split_segment(
+    v0: &PlineVertex,
+    v1: &PlineVertex,
+    normal: &Vector2,
+    side: &impl Fn((f64, f64)) -> f64,
+    out: &mut Vec<Piece>,
+) {
+    let a = (v0.x, v0.y);
+    let b = (v1.x, v1.y);
+    let is_inside = |p: (f64, f64)| side(p) < -TOLERANCE;
+
+    if v0.bulge.abs() < 1e-12 {
+        let (fa, fb) = (side(a), side(b));
+        let lerp = |t: f64| (a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1));
+        if (fa < -TOLERANCE && fb > TOLERANCE) || (fa > TOLERANCE && fb < -TOLERANCE) {
+            let m = lerp(fa / (fa - fb));
+            out.push(Piece {
+                start: a,
+                bulge: 0.0,
+                end: m,
+                inside: fa < 0.0,
+            });
+            out.push(Piece {
+                start: m,
+                bulge: 0.0,
+                end: b,
+                inside: fb < 0.0,
+            });
+        } else {
+            out.push(Piece {
+                start: a,
+                bulge: 0.0,
+                end: b,
+                inside: is_inside(lerp(0.5)),
+            });
+        }
+        return;
+    }
+
+    let (cx, cy, r, start_angle, sweep) = arc_from_bulge(a.0, a.1, b.0, b.1, v0.bulge);
+    // Circle/line crossings: side(c) + r·cos(θ - φ) = 0 with φ the
+    // normal's angle.
+    let ratio = -side((cx, cy)) / r;
+    let mut ts = Vec::new();
+    if r > 1e-12 && ratio.abs() < 1.0 {
+        let phi = normal.y.atan2(normal.x);
+        let half = ratio.acos();
+        for theta in [phi + half, phi - half] {
+            let delta = if sweep >= 0.0 {
+                (theta - start_angle).rem_euclid(TAU)
+            } else {
+                (start_angle - theta).rem_euclid(TAU)
+            };
+            let t = delta / sweep.abs();
+            if t > 1e-9 && t < 1.0 - 1e-9 {
+                ts.push(t);
+            }
+        }
+    }
+    ts.sort_by(f64::total_cmp);
+
+    let at = |t: f64| {
+        if t <= 0.0 {
+            a
+        } else if t >= 1.0 {
+            b
+        } else {
+            arc_point_at(cx, cy, r, start_angle, sweep, t)
+        }
+    };
+    let mut t0 = 0.0;
+    for t1 in ts.into_iter().chain(std::iter::once(1.0)) {
+        out.push(Piece {
+            start: at(t0),
+            bulge: (sweep * (t1 - t0) / 4.0).tan(),
+            end: at(t1),
+            inside: is_inside(arc_point_at(cx, cy, r, start_angle, sweep, 0.5 * (t0 + t1))),
+        });
+        t0 = t1;
+    }
+}
+
+/// Builds a pline from a contiguous run of pieces.
+fn chain_to_pline(run: &[Piece], closed: bool) -> Pline {
+    let mut vertices: Vec<PlineVertex> = run
+        .iter()
+        .map(|p| PlineVertex::new(p.start.0, p.start.1, p.bulge))
+        .collect();
+    let end = run[run.len() - 1].end;
+    vertices.push(PlineVertex::line(end.0, end.1));
+    Pline { vertices, closed }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn unit_circle() -> Pline {
+        Pline {
+            vertices: vec![
+                PlineVertex::new(1.0, 0.0, 1.0),
+                PlineVertex::new(-1.0, 0.0, 1.0),
+            ],
+            closed: true,
+        }
+    }
+
+    #[test]
+    fn circle_through_center_gives_semicircle_with_diameter() {
+        let result = unit_circle().clip_halfplane(Point2::origin(), Vector2::new(1.0, 0.0));
+        assert_eq!(result.len(), 1);
+        let half = &result[0];
+        assert!(half.closed);
+        assert!(
+            (half.signed_area() - PI / 2.0).abs() < 1e-9,
+            "area={}",
+            half.signed_area()
+        );
+        assert!(half.vertices.iter().all(|v| v.x <= 1e-9));
+
+        // Exactly one straight segment: the diameter on x = 0.
+        let n = half.vertices.len();
+        let lines: Vec<usize> = (0..n)
+            .filter(|&i| half.vertices[i].bulge.abs() < 1e-12)
+            .collect();
+        assert_eq!(lines.len(), 1, "{half:?}");
+        let (p, q) = (half.vertices[lines[0]], half.vertices[(lines[0] + 1) % n]);
+        assert!(p.x.abs() < 1e-9 && q.x.abs() < 1e-9);
+        assert!(((p.y - q.y).abs() - 2.0).abs() < 1e-9);
+
+        assert!(half.contains_point(-0.5, 0.0));
+        assert!(!half.contains_point(0.5, 0.0));
+    }
+
+    #[test]
+    fn line_through_arc_endpoints_keeps_lower_half() {
+        let result = unit_circle().clip_halfplane(Point2::origin(), Vector2::new(0.0, 1.0));
+        assert_eq!(result.len(), 1);
+        assert!((result[0].signed_area() - PI / 2.0).abs() < 1e-9);
+        assert!(result[0].contains_point(0.0, -0.5));
+    }
+
+    #[test]
+    fn concave_u_splits_into_two_pieces() {
+        let u = Pline::from_points(
+            &[
+                crate::math::Point3::new(0.0, 0.0, 0.0),
+                crate::math::Point3::new(3.0, 0.0, 0.0),
+                crate::math::Point3::new(3.0, 3.0, 0.0),
+                crate::math::Point3::new(2.0, 3.0, 0.0),
+                crate::math::Point3::new(2.0, 1.0, 0.0),
+                crate::math::Point3::new(1.0, 1.0, 0.0),
+                crate::math::Point3::new(1.0, 3.0, 0.0),
+                crate::math::Point3::new(0.0, 3.0, 0.0),
+            ],
+            true,
+        );
+        // Keep y > 2: the two prongs.
+        let result = u.clip_halfplane(Point2::new(0.0, 2.0), Vector2::new(0.0, -1.0));
+        assert_eq!(result.len(), 2);
+        for piece in &result {
+            assert!((piece.signed_area() - 1.0).abs() < 1e-9);
+            assert!(piece.vertices.iter().all(|v| v.y >= 2.0 - 1e-9));
+        }
+    }
+
+    #[test]
+    fn fully_outside_is_empty_and_fully_inside_is_unchanged() {
+        let c = unit_circle();
+        assert!(c
+            .clip_halfplane(Point2::new(-2.0, 0.0), Vector2::new(1.0, 0.0))
+            .is_empty());
+        let kept = c.clip_halfplane(Point2::new(2.0, 0.0), Vector2::new(1.0, 0.0));
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].vertices, c.vertices);
+    }
+}
@@ -0,0 +1,61 @@
+//! Single-line JSON dumps of plines for bug reports (`debug-dump` feature).
+
+use std::fmt::Write as _;
+
+use super::Pline;
+
+impl Pline {
+    /// Formats this polyline as one line of JSON:
+    /// `{"closed":true,"vertices":[[x,y,bulge],...]}`.
+    ///
+    /// Coordinates are written with full round-trip precision; non-finite
+    /// values become `null`.
+    #[must_use]
+    pub fn dump_json(&self) -> String {
+        let mut out = format!("{{\"closed\":{},\"vertices\":[", self.closed);
+        for (i, v) in self.vertices.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                "[{},{},{}]",
+                json_f64(v.x),
+                json_f64(v.y),
+                json_f64(v.bulge)
+            );
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+/// A JSON number literal for `value`, or `null` when it is not finite.
+pub(crate) fn json_f64(value: f64) -> String {
+    if value.is_finite() {
+        format!("{value:?}")
+    } else {
+        "null".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::pline::PlineVertex;
+
+    #[test]
+    fn dumps_vertices_and_closure() {
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::new(0.0, 0.5, 1.0),
+                PlineVertex::line(2.0, -1e-20),
+            ],
+            closed: false,
+        };
+        assert_eq!(
+            pline.dump_json(),
+            r#"{"closed":false,"vertices":[[0.0,0.5,1.0],[2.0,-1e-20,0.0]]}"#
+        );
+    }
+}
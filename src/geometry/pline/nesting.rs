@@ -0,0 +1,120 @@
+//! Grouping of closed plines into outer boundaries and holes by nesting.
+
+use super::Pline;
+
+/// A connected region bounded by closed plines: one outer boundary and
+/// the holes nested directly inside it.
+///
+/// Outers are wound counter-clockwise, holes clockwise.
+#[derive(Debug, Clone)]
+pub struct PlineRegion {
+    /// The outer boundary loop.
+    pub outer: Pline,
+    /// Hole loops contained in `outer`.
+    pub holes: Vec<Pline>,
+}
+
+impl Pline {
+    /// Organises closed loops into regions by containment depth.
+    ///
+    /// Loops are ranked by absolute area; each loop's depth is the number
+    /// of larger loops that contain its first vertex. Solid and hole
+    /// alternate with depth: even-depth loops become region outers
+    /// (re-oriented CCW), odd-depth loops become holes (re-oriented CW) of
+    /// the smallest loop that contains them. An island inside a hole is
+    /// thus its own region. Loops with fewer than 2 vertices are skipped.
+    #[must_use]
+    pub fn classify_nesting(plines: &[Pline]) -> Vec<PlineRegion> {
+        let mut loops: Vec<(f64, &Pline)> = plines
+            .iter()
+            .filter(|p| p.vertices.len() >= 2)
+            .map(|p| (p.signed_area(), p))
+            .collect();
+        loops.sort_by(|a, b| b.0.abs().total_cmp(&a.0.abs()));
+
+        // For each loop: index of its immediate container (if any) and depth.
+        let mut parent: Vec<Option<usize>> = vec![None; loops.len()];
+        let mut depth = vec![0_usize; loops.len()];
+        for i in 0..loops.len() {
+            let probe = loops[i].1.vertices[0];
+            for j in (0..i).rev() {
+                if loops[j].1.contains_point(probe.x, probe.y) {
+                    parent[i] = Some(j);
+                    depth[i] = depth[j] + 1;
+                    break;
+                }
+            }
+        }
+
+        // Region slot for every even-depth loop, in area order.
+        let mut region_of: Vec<Option<usize>> = vec![None; loops.len()];
+        let mut regions: Vec<PlineRegion> = Vec::new();
+        for (i, &(area, pline)) in loops.iter().enumerate() {
+            if depth[i].is_multiple_of(2) {
+                region_of[i] = Some(regions.len());
+                regions.push(PlineRegion {
+                    outer: oriented(pline, area, true),
+                    holes: Vec::new(),
+                });
+            }
+        }
+        for (i, &(area, pline)) in loops.iter().enumerate() {
+            if !depth[i].is_multiple_of(2) {
+                if let Some(r) = parent[i].and_then(|p| region_of[p]) {
+                    regions[r].holes.push(oriented(pline, area, false));
+                }
+            }
+        }
+
+        regions
+    }
+}
+
+/// Returns `pline` wound CCW when `ccw` is set, CW otherwise.
+fn oriented(pline: &Pline, area: f64, ccw: bool) -> Pline {
+    if (area > 0.0) == ccw {
+        pline.clone()
+    } else {
+        pline.reversed()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::Point3;
+
+    fn square(half: f64, ccw: bool) -> Pline {
+        let mut pts = vec![
+            Point3::new(-half, -half, 0.0),
+            Point3::new(half, -half, 0.0),
+            Point3::new(half, half, 0.0),
+            Point3::new(-half, half, 0.0),
+        ];
+        if !ccw {
+            pts.reverse();
+        }
+        Pline::from_points(&pts, true)
+    }
+
+    #[test]
+    fn concentric_squares_alternate_solid_and_hole() {
+        // Input order and windings are arbitrary.
+        let plines = [square(1.0, false), square(3.0, false), square(2.0, true)];
+        let regions = Pline::classify_nesting(&plines);
+        assert_eq!(regions.len(), 2);
+
+        let (outer, island) = (&regions[0], &regions[1]);
+        assert!((outer.outer.signed_area() - 36.0).abs() < 1e-9);
+        assert_eq!(outer.holes.len(), 1);
+        let hole = &outer.holes[0];
+        assert!((hole.signed_area() + 16.0).abs() < 1e-9);
+
+        // The island is a solid region of its own, inside the hole.
+        assert!((island.outer.signed_area() - 4.0).abs() < 1e-9);
+        assert!(island.holes.is_empty());
+        let probe = island.outer.vertices[0];
+        assert!(hole.contains_point(probe.x, probe.y));
+    }
+}
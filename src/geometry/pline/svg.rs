@@ -0,0 +1,302 @@
+//! Conversion between plines and SVG path data (`d` attribute strings).
+
+use std::f64::consts::{PI, TAU};
+use std::fmt::Write as _;
+
+use super::{Pline, PlineVertex};
+use crate::error::{GeometryError, Result};
+use crate::math::arc_2d::arc_from_bulge;
+use crate::math::TOLERANCE;
+
+/// A token of SVG path data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Command(char),
+    Number(f64),
+}
+
+impl Pline {
+    /// Parses SVG path data into plines, one per subpath.
+    ///
+    /// Supports the `M`, `L`, `H`, `V`, `A` and `Z` commands in absolute and
+    /// relative (lowercase) form, including implicit repetition. A subpath
+    /// ended by `Z` is closed; a final vertex repeating the start is then
+    /// dropped. Arcs become bulge segments: an elliptical arc (`rx != ry`)
+    /// is approximated by a circular arc of radius `(rx + ry) / 2`, and
+    /// radii too small for the chord are scaled up as SVG specifies.
+    /// Coordinates are taken as-is, so SVG's positive-angle sweep flag maps
+    /// to a counter-clockwise (positive) bulge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeometryError::Parse`] for malformed data, a missing
+    /// leading `M`, or an unsupported command such as `C` or `Q`.
+    #[allow(clippy::many_single_char_names)]
+    pub fn from_svg_path(d: &str) -> Result<Vec<Self>> {
+        let tokens = tokenize(d)?;
+        let mut plines: Vec<Self> = Vec::new();
+        let mut current: Option<Self> = None;
+        let (mut x, mut y) = (0.0, 0.0);
+        let (mut start_x, mut start_y) = (0.0, 0.0);
+
+        let mut i = 0;
+        let mut command = None;
+        while i < tokens.len() {
+            let cmd = match tokens[i] {
+                Token::Command(c) => {
+                    i += 1;
+                    c
+                }
+                // Implicit repetition; a moveto repeats as a lineto.
+                Token::Number(_) => match command {
+                    Some('M') => 'L',
+                    Some('m') => 'l',
+                    Some(c) if !matches!(c, 'Z' | 'z') => c,
+                    _ => return Err(parse_error("path data must start with a command")),
+                },
+            };
+            command = Some(cmd);
+            let relative = cmd.is_ascii_lowercase();
+            let (ox, oy) = if relative { (x, y) } else { (0.0, 0.0) };
+
+            match cmd.to_ascii_uppercase() {
+                'M' => {
+                    let [px, py] = take_numbers(&tokens, &mut i)?;
+                    plines.extend(current.take().filter(|p| p.vertices.len() >= 2));
+                    (x, y) = (ox + px, oy + py);
+                    (start_x, start_y) = (x, y);
+                    current = Some(Self {
+                        vertices: vec![PlineVertex::line(x, y)],
+                        closed: false,
+                    });
+                }
+                'L' => {
+                    let [px, py] = take_numbers(&tokens, &mut i)?;
+                    (x, y) = (ox + px, oy + py);
+                    push_vertex(&mut current, x, y)?;
+                }
+                'H' => {
+                    let [px] = take_numbers(&tokens, &mut i)?;
+                    x = ox + px;
+                    push_vertex(&mut current, x, y)?;
+                }
+                'V' => {
+                    let [py] = take_numbers(&tokens, &mut i)?;
+                    y = oy + py;
+                    push_vertex(&mut current, x, y)?;
+                }
+                'A' => {
+                    let [rx, ry, _rotation, large, sweep, px, py] = take_numbers(&tokens, &mut i)?;
+                    let (x0, y0) = (x, y);
+                    (x, y) = (ox + px, oy + py);
+                    let bulge = svg_arc_bulge(x0, y0, x, y, rx, ry, large != 0.0, sweep != 0.0);
+                    if let Some(last) = current.as_mut().and_then(|p| p.vertices.last_mut()) {
+                        last.bulge = bulge;
+                    }
+                    push_vertex(&mut current, x, y)?;
+                }
+                'Z' => {
+                    let Some(mut pline) = current.take() else {
+                        return Err(parse_error("'Z' without an open subpath"));
+                    };
+                    if pline.vertices.len() >= 2 {
+                        if let Some(last) = pline.vertices.last() {
+                            if (last.x - start_x).hypot(last.y - start_y) < TOLERANCE {
+                                pline.vertices.pop();
+                            }
+                        }
+                    }
+                    pline.closed = true;
+                    plines.push(pline);
+                    (x, y) = (start_x, start_y);
+                }
+                other => {
+                    return Err(parse_error(&format!(
+                        "unsupported SVG path command '{other}'"
+                    )));
+                }
+            }
+        }
+        plines.extend(current.filter(|p| p.vertices.len() >= 2));
+        Ok(plines)
+    }
+
+    /// Formats this polyline as SVG path data using absolute `M`, `L`, `A`
+    /// and `Z` commands.
+    ///
+    /// Arc segments are written as circular `A` commands; a closed
+    /// polyline's closing arc is written explicitly before the `Z`.
+    #[must_use]
+    pub fn to_svg_path(&self) -> String {
+        let Some(first) = self.vertices.first() else {
+            return String::new();
+        };
+        let mut out = format!("M {} {}", first.x, first.y);
+        let n = self.vertices.len();
+        for i in 0..self.segment_count() {
+            let (v0, v1) = (&self.vertices[i], &self.vertices[(i + 1) % n]);
+            let closing = i + 1 == n;
+            if v0.bulge.abs() < 1e-12 {
+                if !closing {
+                    let _ = write!(out, " L {} {}", v1.x, v1.y);
+                }
+                continue;
+            }
+            let (_, _, r, _, sweep) = arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge);
+            let _ = write!(
+                out,
+                " A {r} {r} 0 {} {} {} {}",
+                u8::from(sweep.abs() > PI),
+                u8::from(v0.bulge > 0.0),
+                v1.x,
+                v1.y
+            );
+        }
+        if self.closed {
+            out.push_str(" Z");
+        }
+        out
+    }
+}
+
+/// Builds a parse error.
+fn parse_error(msg: &str) -> crate::error::GeolisError {
+    GeometryError::Parse(msg.to_owned()).into()
+}
+
+/// Appends a line vertex to the open subpath.
+fn push_vertex(current: &mut Option<Pline>, x: f64, y: f64) -> Result<()> {
+    let Some(pline) = current.as_mut() else {
+        return Err(parse_error("path data must start with 'M'"));
+    };
+    pline.vertices.push(PlineVertex::line(x, y));
+    Ok(())
+}
+
+/// Consumes `N` numeric arguments following a command.
+fn take_numbers<const N: usize>(tokens: &[Token], i: &mut usize) -> Result<[f64; N]> {
+    let mut out = [0.0; N];
+    for slot in &mut out {
+        let Some(&Token::Number(v)) = tokens.get(*i) else {
+            return Err(parse_error("missing numeric argument"));
+        };
+        *slot = v;
+        *i += 1;
+    }
+    Ok(out)
+}
+
+/// Bulge of the SVG arc from `(x0, y0)` to `(x1, y1)`, approximated as a
+/// circle of the mean radius. A zero radius gives a straight segment.
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+fn svg_arc_bulge(
+    x0: f64,
+    y0: f64,
+    x1: f64,
+    y1: f64,
+    rx: f64,
+    ry: f64,
+    large_arc: bool,
+    positive_sweep: bool,
+) -> f64 {
+    let chord = (x1 - x0).hypot(y1 - y0);
+    let r = (rx.abs() + ry.abs()) * 0.5;
+    if chord < TOLERANCE || r < TOLERANCE {
+        return 0.0;
+    }
+    let half = (chord / (2.0 * r)).min(1.0).asin();
+    let minor = 2.0 * half;
+    let sweep = if large_arc { TAU - minor } else { minor };
+    let bulge = (sweep / 4.0).tan();
+    if positive_sweep {
+        bulge
+    } else {
+        -bulge
+    }
+}
+
+/// Splits path data into commands and numbers.
+fn tokenize(d: &str) -> Result<Vec<Token>> {
+    let bytes = d.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() || c == b',' {
+            i += 1;
+        } else if c.is_ascii_alphabetic() && c != b'e' && c != b'E' {
+            tokens.push(Token::Command(char::from(c)));
+            i += 1;
+        } else {
+            // A number: sign, digits, at most one '.', optional exponent.
+            let start = i;
+            if matches!(bytes[i], b'+' | b'-') {
+                i += 1;
+            }
+            let mut seen_dot = false;
+            while i < bytes.len() && (bytes[i].is_ascii_digit() || (bytes[i] == b'.' && !seen_dot))
+            {
+                seen_dot |= bytes[i] == b'.';
+                i += 1;
+            }
+            if i < bytes.len() && matches!(bytes[i], b'e' | b'E') {
+                i += 1;
+                if i < bytes.len() && matches!(bytes[i], b'+' | b'-') {
+                    i += 1;
+                }
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            let text = &d[start..i];
+            let value = text
+                .parse::<f64>()
+                .map_err(|_| parse_error(&format!("invalid number '{text}'")))?;
+            tokens.push(Token::Number(value));
+        }
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_lines_and_arc() {
+        // The elliptical arc is read as a circle of radius 6.2.
+        let d = "M0,0 L10,0 A6 6.4 0 0,1 10,10 h-10z";
+        let plines = Pline::from_svg_path(d).unwrap();
+        assert_eq!(plines.len(), 1);
+        let pline = &plines[0];
+        assert!(pline.closed);
+        assert_eq!(pline.vertices.len(), 4);
+        assert!(pline.vertices[1].bulge > 0.0);
+        let (_, _, r, _, _) = arc_from_bulge(10.0, 0.0, 10.0, 10.0, pline.vertices[1].bulge);
+        assert!((r - 6.2).abs() < 1e-9, "r={r}");
+
+        let text = pline.to_svg_path();
+        let again = Pline::from_svg_path(&text).unwrap();
+        assert_eq!(again.len(), 1);
+        assert!(again[0].approx_eq(pline, 1e-9), "{text}");
+    }
+
+    #[test]
+    fn relative_commands_and_multiple_subpaths() {
+        let plines = Pline::from_svg_path("m1 1 l2 0 v3 M5-5 H7").unwrap();
+        assert_eq!(plines.len(), 2);
+        let pts: Vec<(f64, f64)> = plines[0].vertices.iter().map(|v| (v.x, v.y)).collect();
+        assert_eq!(pts, [(1.0, 1.0), (3.0, 1.0), (3.0, 4.0)]);
+        assert!(!plines[0].closed);
+        let pts: Vec<(f64, f64)> = plines[1].vertices.iter().map(|v| (v.x, v.y)).collect();
+        assert_eq!(pts, [(5.0, -5.0), (7.0, -5.0)]);
+    }
+
+    #[test]
+    fn unsupported_command_is_rejected() {
+        let err = Pline::from_svg_path("M0 0 C1 1 2 2 3 3").unwrap_err();
+        assert!(err.to_string().contains("'C'"), "{err}");
+        assert!(Pline::from_svg_path("L1 1").is_err());
+    }
+}
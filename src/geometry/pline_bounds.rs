@@ -0,0 +1,195 @@
+//! Bounding primitives of [`Pline`]s, for quick proximity culling.
+
+use crate::math::{Point2, Point3, Vector2, TOLERANCE};
+
+use super::curve;
+use super::pline::{Pline, PlineSegment};
+
+/// Maximum angular step when sampling arc segments for
+/// [`Pline::min_enclosing_circle`].
+const ARC_SAMPLE_STEP: f64 = std::f64::consts::TAU / 256.0;
+
+impl Pline {
+    /// Returns the `(center, radius)` of the smallest circle enclosing the
+    /// polyline's vertices (Welzl's algorithm, iterative form).
+    ///
+    /// With `include_arcs`, arc segments are sampled as well and the radius
+    /// grows by the largest chord sagitta of that sampling, so the circle
+    /// encloses every arc bulging past the vertices (at most a relative
+    /// `~1e-4` of the arc radius larger than the true minimum). Without
+    /// it, arcs are treated as chords. An empty polyline yields a zero
+    /// circle at the origin.
+    #[must_use]
+    pub fn min_enclosing_circle(&self, include_arcs: bool) -> (Point2, f64) {
+        let mut points: Vec<Point2> = self
+            .vertices
+            .iter()
+            .map(|v| Point2::new(v.x, v.y))
+            .collect();
+        let mut margin = 0.0_f64;
+        if include_arcs {
+            for seg in self.segments() {
+                let PlineSegment::Arc {
+                    a,
+                    bulge,
+                    center,
+                    radius,
+                    ..
+                } = seg
+                else {
+                    continue;
+                };
+                let sweep = 4.0 * bulge.atan();
+                let start = (a.y - center.y).atan2(a.x - center.x);
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let n = (sweep.abs() / ARC_SAMPLE_STEP).ceil().max(1.0) as u32;
+                let step = sweep / f64::from(n);
+                for i in 1..n {
+                    let angle = start + step * f64::from(i);
+                    points.push(center + radius * Vector2::new(angle.cos(), angle.sin()));
+                }
+                margin = margin.max(radius * (1.0 - (0.5 * step).cos()));
+            }
+        }
+
+        shuffle(&mut points);
+        let (center, radius) = welzl(&points);
+        (center, radius + margin)
+    }
+}
+
+/// Seed for the point shuffle, fixed so results are reproducible.
+const SHUFFLE_SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Fisher–Yates shuffle driven by a fixed-seed splitmix64 stream.
+///
+/// Polyline vertices arrive in boundary order, the worst case for the
+/// incremental algorithm; shuffling restores its expected linear time.
+fn shuffle(points: &mut [Point2]) {
+    let mut state = SHUFFLE_SEED;
+    for i in (1..points.len()).rev() {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        #[allow(clippy::cast_possible_truncation)]
+        let j = (z % (i as u64 + 1)) as usize;
+        points.swap(i, j);
+    }
+}
+
+/// Minimum enclosing circle of `points`; expected linear time for
+/// randomly ordered input (see [`shuffle`]), quadratic to cubic in
+/// adversarial orders.
+fn welzl(points: &[Point2]) -> (Point2, f64) {
+    let Some(&first) = points.first() else {
+        return (Point2::origin(), 0.0);
+    };
+    let outside = |c: &(Point2, f64), p: &Point2| (p - c.0).norm() > c.1 + TOLERANCE;
+
+    let mut circle = (first, 0.0);
+    for (i, p) in points.iter().enumerate() {
+        if !outside(&circle, p) {
+            continue;
+        }
+        circle = (*p, 0.0);
+        for (j, q) in points[..i].iter().enumerate() {
+            if !outside(&circle, q) {
+                continue;
+            }
+            circle = diameter_circle(p, q);
+            for r in &points[..j] {
+                if outside(&circle, r) {
+                    circle = circumcircle(p, q, r);
+                }
+            }
+        }
+    }
+    circle
+}
+
+fn diameter_circle(a: &Point2, b: &Point2) -> (Point2, f64) {
+    (
+        Point2::from((a.coords + b.coords) * 0.5),
+        0.5 * (b - a).norm(),
+    )
+}
+
+/// Circle through `a`, `b`, `c`; for collinear points, the circle on the
+/// farthest pair as diameter.
+fn circumcircle(a: &Point2, b: &Point2, c: &Point2) -> (Point2, f64) {
+    let lift = |p: &Point2| Point3::new(p.x, p.y, 0.0);
+    if let Ok((center, radius, _)) = curve::circumcircle(&lift(a), &lift(b), &lift(c)) {
+        return (Point2::new(center.x, center.y), radius);
+    }
+    [(a, b), (a, c), (b, c)]
+        .into_iter()
+        .map(|(p, q)| diameter_circle(p, q))
+        .max_by(|x, y| x.1.total_cmp(&y.1))
+        .unwrap_or((*a, 0.0))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::pline::PlineVertex;
+
+    #[test]
+    fn square_circle_has_half_diagonal_radius() {
+        let pline = Pline::from_points(
+            &[
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(3.0, 1.0, 0.0),
+                Point3::new(3.0, 3.0, 0.0),
+                Point3::new(1.0, 3.0, 0.0),
+            ],
+            true,
+        );
+        let (center, radius) = pline.min_enclosing_circle(false);
+        assert!((center - Point2::new(2.0, 2.0)).norm() < TOLERANCE);
+        assert!((radius - std::f64::consts::SQRT_2).abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn arcs_widen_the_circle_when_included() {
+        // A diameter closed by a CCW semicircle bulging to y = -1.
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::new(-1.0, 0.0, 1.0),
+                PlineVertex::line(1.0, 0.0),
+            ],
+            closed: true,
+        };
+        let (_, chord_radius) = pline.min_enclosing_circle(false);
+        assert!((chord_radius - 1.0).abs() < TOLERANCE);
+
+        let (center, radius) = pline.min_enclosing_circle(true);
+        assert!(center.coords.norm() < 1e-3, "{center:?}");
+        assert!((1.0..1.0 + 1e-3).contains(&radius), "{radius}");
+        let bottom = Point2::new(0.0, -1.0);
+        assert!((bottom - center).norm() <= radius);
+    }
+
+    #[test]
+    fn boundary_ordered_vertices_are_shuffled() {
+        // Thousands of vertices in boundary order, the incremental
+        // algorithm's worst case; the fixed-seed shuffle keeps the result
+        // reproducible.
+        let points: Vec<Point3> = (0..4000)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * f64::from(i) / 4000.0;
+                Point3::new(5.0 + 2.0 * angle.cos(), -1.0 + 2.0 * angle.sin(), 0.0)
+            })
+            .collect();
+        let pline = Pline::from_points(&points, true);
+        let (center, radius) = pline.min_enclosing_circle(false);
+        assert!(
+            (center - Point2::new(5.0, -1.0)).norm() < 1e-9,
+            "{center:?}"
+        );
+        assert!((radius - 2.0).abs() < 1e-9, "{radius}");
+        assert_eq!(pline.min_enclosing_circle(false), (center, radius));
+    }
+}
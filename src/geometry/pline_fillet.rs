@@ -4,6 +4,7 @@
 //! tangent circular arc (bulge edge) of the requested radius.
 
 use crate::error::{GeometryError, Result};
+use crate::math::{signed_angle, Vector2};
 
 use super::pline::{Pline, PlineVertex};
 
@@ -139,17 +140,13 @@ impl Pline {
             if la < EPS || lb < EPS {
                 continue;
             }
-            let (uax, uay) = (ax / la, ay / la);
-            let (ubx, uby) = (bx / lb, by / lb);
-            let cross = uax * uby - uay * ubx;
-            let dot = uax * ubx + uay * uby;
-            let turn = cross.atan2(dot); // signed turn angle at the corner
+            let turn = signed_angle(Vector2::new(ax, ay), Vector2::new(bx, by));
             if turn.abs() < 1e-9 {
                 continue; // collinear
             }
             let half = (std::f64::consts::PI - turn.abs()) / 2.0;
             trims[i] = radius / half.tan();
-            sweeps[i] = (std::f64::consts::PI - 2.0 * half).copysign(cross);
+            sweeps[i] = (std::f64::consts::PI - 2.0 * half).copysign(turn);
         }
         (trims, sweeps)
     }
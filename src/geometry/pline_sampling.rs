@@ -7,7 +7,7 @@
 
 use crate::error::{GeometryError, Result};
 use crate::math::arc_2d::{arc_from_bulge, arc_point_at};
-use crate::math::{Point3, Vector3};
+use crate::math::{Point2, Point3, Vector3};
 
 use super::pline::Pline;
 
@@ -150,6 +150,29 @@ impl Pline {
         Ok(samples)
     }
 
+    /// Samples every `spacing` of arc length like
+    /// [`Self::divide_by_length`], pairing each position with its heading:
+    /// the angle of the (arc-aware) tangent from `+X`, in `(-π, π]`.
+    ///
+    /// Meant for orienting scattered instances along the path. Returns no
+    /// samples when `spacing` is invalid or the polyline has no length.
+    #[must_use]
+    pub fn sample_frames(&self, spacing: f64) -> Vec<(Point2, f64)> {
+        self.divide_by_length(spacing)
+            .map(|samples| {
+                samples
+                    .iter()
+                    .map(|s| {
+                        (
+                            Point2::new(s.point.x, s.point.y),
+                            s.tangent.y.atan2(s.tangent.x),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Exact arc length per segment (lines: chord, arcs:
     /// `radius * |sweep|`, degenerate arcs fall back to the chord).
     fn segment_arc_lengths(&self) -> Vec<f64> {
@@ -350,6 +373,32 @@ mod tests {
         assert!((samples[2].point.x - 4.0).abs() < TOL);
     }
 
+    #[test]
+    fn sample_frames_turn_through_quarter_circle() {
+        // CCW quarter circle of radius 10 from (10, 0) to (0, 10).
+        let arc = Pline {
+            vertices: vec![
+                PlineVertex::new(10.0, 0.0, (PI / 8.0).tan()),
+                PlineVertex::line(0.0, 10.0),
+            ],
+            closed: false,
+        };
+        let frames = arc.sample_frames(arc.arc_length() / 20.0);
+        assert_eq!(frames.len(), 21);
+        assert!((frames[0].1 - PI / 2.0).abs() < TOL);
+        assert!((frames[20].1 - PI).abs() < TOL);
+        for pair in frames.windows(2) {
+            let step = pair[1].1 - pair[0].1;
+            assert!((step - PI / 40.0).abs() < 1e-6, "step {step}");
+        }
+        for (p, heading) in &frames {
+            assert!((p.coords.norm() - 10.0).abs() < TOL);
+            // The heading is perpendicular to the radius.
+            assert!((heading.cos() * p.x + heading.sin() * p.y).abs() < 1e-6);
+        }
+        assert!(arc.sample_frames(0.0).is_empty());
+    }
+
     #[test]
     fn divide_by_length_rejects_bad_input() {
         let line = Pline::from_points(
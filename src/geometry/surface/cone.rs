@@ -127,9 +127,9 @@ impl Surface for Cone {
 
     fn normal(&self, u: f64, v: f64) -> Result<Vector3> {
         if v.abs() < TOLERANCE {
-            return Err(
-                GeometryError::Degenerate("cone normal is degenerate at apex".into()).into(),
-            );
+            // No unique normal at the apex; use the mean of the normals
+            // around it, which points back along the axis.
+            return Ok(-self.axis);
         }
         let binormal = self.binormal();
         let ca = self.half_angle.cos();
@@ -192,10 +192,12 @@ mod tests {
     }
 
     #[test]
-    fn normal_degenerate_at_apex() {
-        let c = z_cone_45();
-        let r = c.normal(0.0, 0.0);
-        assert!(r.is_err());
+    fn normal_at_apex_points_back_along_axis() {
+        let c = Cone::new(Point3::origin(), Vector3::x(), FRAC_PI_4, Vector3::y()).unwrap();
+        for u in [0.0, 1.0, 4.0] {
+            let n = c.normal(u, 0.0).unwrap();
+            assert!((n + Vector3::x()).norm() < TOLERANCE);
+        }
     }
 
     #[test]
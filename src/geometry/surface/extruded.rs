@@ -0,0 +1,215 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::error::{GeometryError, Result};
+use crate::geometry::curve::Curve;
+use crate::math::{Point3, Vector3, TOLERANCE};
+
+use super::{Surface, SurfaceDomain};
+
+/// Coarse samples along the profile seeding [`ExtrudedSurface::inverse`].
+const INVERSE_SAMPLES: u32 = 64;
+
+/// Gauss-Newton iterations refining [`ExtrudedSurface::inverse`].
+const INVERSE_ITERATIONS: usize = 30;
+
+/// Finite-difference step for the profile derivative.
+const DU_STEP: f64 = 1e-6;
+
+/// A linear sweep of a profile curve along a fixed direction.
+///
+/// `P(u, v) = profile(u) + v * dir` where `dir` is the unit extrusion
+/// direction, `u` spans the profile's parameter domain and `v` runs from
+/// `0` to the extrusion length.
+///
+/// The normal is `profile'(u) × dir`, which is perpendicular to the
+/// extrusion direction everywhere.
+#[derive(Clone)]
+pub struct ExtrudedSurface {
+    profile: Arc<dyn Curve>,
+    direction: Vector3,
+    length: f64,
+}
+
+impl ExtrudedSurface {
+    /// Creates an extruded surface sweeping `profile` by `direction`.
+    ///
+    /// The length of `direction` is the extrusion length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `direction` is zero-length.
+    pub fn new(profile: Arc<dyn Curve>, direction: Vector3) -> Result<Self> {
+        let length = direction.norm();
+        if length < TOLERANCE {
+            return Err(GeometryError::ZeroVector.into());
+        }
+        Ok(Self {
+            profile,
+            direction: direction / length,
+            length,
+        })
+    }
+
+    /// Returns the profile curve (at `v = 0`).
+    #[must_use]
+    pub fn profile(&self) -> &Arc<dyn Curve> {
+        &self.profile
+    }
+
+    /// Returns the unit extrusion direction.
+    #[must_use]
+    pub fn direction(&self) -> &Vector3 {
+        &self.direction
+    }
+
+    /// Returns the extrusion length.
+    #[must_use]
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    /// Computes the `(u, v)` parameters of the surface point closest to
+    /// `point`.
+    ///
+    /// `u` minimizes the distance between `point` and the ruling through
+    /// `profile(u)` (coarse sampling over a bounded profile domain, then
+    /// Gauss-Newton refinement); `v` is the signed distance along the
+    /// extrusion direction. `u` is clamped to the profile domain; `v` is not
+    /// clamped to the extrusion length.
+    #[must_use]
+    pub fn inverse(&self, point: &Point3) -> (f64, f64) {
+        let d = self.profile.domain();
+        // Component of `p - profile(u)` orthogonal to the rulings.
+        let residual = |u: f64| -> Option<Vector3> {
+            let q = point - self.profile.evaluate(u).ok()?;
+            Some(q - self.direction * q.dot(&self.direction))
+        };
+
+        let mut u = if d.t_min.is_finite() && d.t_max.is_finite() {
+            (0..=INVERSE_SAMPLES)
+                .map(|i| d.t_min + (d.t_max - d.t_min) * f64::from(i) / f64::from(INVERSE_SAMPLES))
+                .filter_map(|u| residual(u).map(|r| (u, r.norm_squared())))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(d.t_min, |(u, _)| u)
+        } else {
+            0.0_f64.clamp(d.t_min, d.t_max)
+        };
+
+        for _ in 0..INVERSE_ITERATIONS {
+            let (ua, ub) = ((u - DU_STEP).max(d.t_min), (u + DU_STEP).min(d.t_max));
+            let (Some(r), Some(ra), Some(rb)) = (residual(u), residual(ua), residual(ub)) else {
+                break;
+            };
+            // d(residual)/du = -perp(profile'(u)).
+            let j = (rb - ra) / (ub - ua);
+            let jj = j.norm_squared();
+            if jj < TOLERANCE * TOLERANCE {
+                break;
+            }
+            let step = r.dot(&j) / jj;
+            u = (u - step).clamp(d.t_min, d.t_max);
+            if step.abs() < TOLERANCE {
+                break;
+            }
+        }
+
+        let v = self
+            .profile
+            .evaluate(u)
+            .map_or(0.0, |p0| (point - p0).dot(&self.direction));
+        (u, v)
+    }
+}
+
+impl fmt::Debug for ExtrudedSurface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtrudedSurface")
+            .field("profile_domain", &self.profile.domain())
+            .field("direction", &self.direction)
+            .field("length", &self.length)
+            .finish()
+    }
+}
+
+impl Surface for ExtrudedSurface {
+    fn evaluate(&self, u: f64, v: f64) -> Result<Point3> {
+        Ok(self.profile.evaluate(u)? + self.direction * v)
+    }
+
+    fn normal(&self, u: f64, _v: f64) -> Result<Vector3> {
+        let n = self.profile.tangent(u)?.cross(&self.direction);
+        let len = n.norm();
+        if len < TOLERANCE {
+            return Err(GeometryError::ZeroVector.into());
+        }
+        Ok(n / len)
+    }
+
+    fn domain(&self) -> SurfaceDomain {
+        let d = self.profile.domain();
+        SurfaceDomain::new(d.t_min, d.t_max, 0.0, self.length)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::curve::Arc as ArcCurve;
+    use std::f64::consts::PI;
+
+    fn arc_extrusion(direction: Vector3) -> ExtrudedSurface {
+        let arc =
+            ArcCurve::new(Point3::origin(), 2.0, Vector3::z(), Vector3::x(), 0.0, PI).unwrap();
+        ExtrudedSurface::new(Arc::new(arc), direction).unwrap()
+    }
+
+    #[test]
+    fn evaluate_offsets_profile_along_direction() {
+        let s = arc_extrusion(Vector3::new(0.0, 0.0, 3.0));
+        let d = s.domain();
+        assert!((d.v_max - 3.0).abs() < 1e-12);
+        let p = s.evaluate(0.0, 1.5).unwrap();
+        assert!((p - Point3::new(2.0, 0.0, 1.5)).norm() < 1e-12);
+    }
+
+    #[test]
+    #[allow(clippy::many_single_char_names)]
+    fn normals_perpendicular_to_direction() {
+        // Oblique extrusion: normals must still be orthogonal to it.
+        let dir = Vector3::new(1.0, 0.5, 2.0);
+        let s = arc_extrusion(dir);
+        let d = s.domain();
+        let unit = dir.normalize();
+        for i in 0..=8 {
+            for j in 0..=4 {
+                let u = d.u_min + (d.u_max - d.u_min) * f64::from(i) / 8.0;
+                let v = d.v_max * f64::from(j) / 4.0;
+                let n = s.normal(u, v).unwrap();
+                assert!((n.norm() - 1.0).abs() < 1e-12);
+                assert!(n.dot(&unit).abs() < 1e-12, "n·dir at ({u},{v})");
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_round_trips() {
+        let s = arc_extrusion(Vector3::new(0.0, 1.0, 2.0));
+        for &(u, v) in &[(0.3, 0.5), (1.7, 2.0), (3.0, 0.1)] {
+            let p = s.evaluate(u, v).unwrap();
+            let (iu, iv) = s.inverse(&p);
+            assert!(
+                (iu - u).abs() < 1e-8 && (iv - v).abs() < 1e-8,
+                "({iu},{iv})"
+            );
+        }
+    }
+
+    #[test]
+    fn zero_direction_is_rejected() {
+        let arc =
+            ArcCurve::new(Point3::origin(), 1.0, Vector3::z(), Vector3::x(), 0.0, PI).unwrap();
+        assert!(ExtrudedSurface::new(Arc::new(arc), Vector3::zeros()).is_err());
+    }
+}
@@ -1,12 +1,16 @@
 mod cone;
 mod cylinder;
+mod extruded;
 mod plane;
+mod ruled;
 mod sphere;
 mod torus;
 
 pub use cone::Cone;
 pub use cylinder::Cylinder;
+pub use extruded::ExtrudedSurface;
 pub use plane::Plane;
+pub use ruled::RuledSurface;
 pub use sphere::Sphere;
 pub use torus::Torus;
 
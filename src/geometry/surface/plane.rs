@@ -1,4 +1,7 @@
 use crate::error::{GeometryError, Result};
+use crate::math::intersect_3d::{
+    classify_point_plane, signed_distance_to_plane, PointPlaneClassification,
+};
 use crate::math::{Point3, Vector3, TOLERANCE};
 
 use super::{Surface, SurfaceDomain};
@@ -107,6 +110,20 @@ impl Plane {
     pub fn plane_normal(&self) -> &Vector3 {
         &self.normal
     }
+
+    /// Returns the distance from the plane to `p`, positive on the side
+    /// the normal points to.
+    #[must_use]
+    pub fn signed_distance(&self, p: &Point3) -> f64 {
+        signed_distance_to_plane(p, self)
+    }
+
+    /// Classifies `p` against the plane, treating points within
+    /// [`TOLERANCE`] as [`PointPlaneClassification::On`].
+    #[must_use]
+    pub fn side(&self, p: &Point3) -> PointPlaneClassification {
+        classify_point_plane(p, self)
+    }
 }
 
 impl Surface for Plane {
@@ -127,3 +144,23 @@ impl Surface for Plane {
         )
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn side_and_signed_distance_about_xy_plane() {
+        let plane = Plane::from_normal(Point3::origin(), Vector3::z()).unwrap();
+        let above = Point3::new(1.0, -2.0, 3.0);
+        let below = Point3::new(-4.0, 0.5, -0.25);
+        let on = Point3::new(7.0, 7.0, TOLERANCE * 0.5);
+
+        assert!((plane.signed_distance(&above) - 3.0).abs() < 1e-12);
+        assert!((plane.signed_distance(&below) + 0.25).abs() < 1e-12);
+        assert_eq!(plane.side(&above), PointPlaneClassification::Front);
+        assert_eq!(plane.side(&below), PointPlaneClassification::Back);
+        assert_eq!(plane.side(&on), PointPlaneClassification::On);
+    }
+}
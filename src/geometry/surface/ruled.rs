@@ -0,0 +1,228 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::error::{GeometryError, Result};
+use crate::geometry::curve::Curve;
+use crate::math::{Point3, Vector3, TOLERANCE};
+
+use super::{Surface, SurfaceDomain};
+
+/// Step used for the finite-difference `∂S/∂u`.
+const DU_STEP: f64 = 1e-6;
+
+/// Coarse grid resolution seeding [`RuledSurface::inverse`].
+const INVERSE_GRID: u32 = 16;
+
+/// Gauss-Newton iterations refining [`RuledSurface::inverse`].
+const INVERSE_ITERATIONS: usize = 30;
+
+/// A ruled surface spanned by straight rulings between two curves.
+///
+/// `P(u, v) = (1 - v) * c0(u) + v * c1(u)` with `u, v ∈ [0, 1]`, where
+/// `u` is mapped linearly onto each curve's own parameter domain.
+///
+/// The normal is `∂S/∂u × ∂S/∂v`, so it points to the left of `c0`'s
+/// direction when looking from `c0` towards `c1`.
+#[derive(Clone)]
+pub struct RuledSurface {
+    c0: Arc<dyn Curve>,
+    c1: Arc<dyn Curve>,
+}
+
+impl RuledSurface {
+    /// Creates a ruled surface between `c0` (at `v = 0`) and `c1` (at `v = 1`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either curve has an unbounded or empty
+    /// parameter domain.
+    pub fn new(c0: Arc<dyn Curve>, c1: Arc<dyn Curve>) -> Result<Self> {
+        for c in [&c0, &c1] {
+            let d = c.domain();
+            if !d.t_min.is_finite() || !d.t_max.is_finite() || d.t_max - d.t_min < TOLERANCE {
+                return Err(GeometryError::Degenerate(
+                    "ruled surface curves need a bounded parameter domain".into(),
+                )
+                .into());
+            }
+        }
+        Ok(Self { c0, c1 })
+    }
+
+    /// Returns the boundary curve at `v = 0`.
+    #[must_use]
+    pub fn c0(&self) -> &Arc<dyn Curve> {
+        &self.c0
+    }
+
+    /// Returns the boundary curve at `v = 1`.
+    #[must_use]
+    pub fn c1(&self) -> &Arc<dyn Curve> {
+        &self.c1
+    }
+
+    /// Computes the `(u, v)` parameters of the surface point closest to
+    /// `point`, clamped to the unit domain.
+    ///
+    /// Seeds from a coarse grid over the domain, then refines with
+    /// Gauss-Newton on finite-difference partials.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn inverse(&self, point: &Point3) -> (f64, f64) {
+        let dist2 = |u: f64, v: f64| {
+            self.evaluate(u, v)
+                .map_or(f64::INFINITY, |p| (p - point).norm_squared())
+        };
+        let grid = f64::from(INVERSE_GRID);
+        let (mut u, mut v, mut best) = (0.0, 0.0, f64::INFINITY);
+        for i in 0..=INVERSE_GRID {
+            for j in 0..=INVERSE_GRID {
+                let (gu, gv) = (f64::from(i) / grid, f64::from(j) / grid);
+                let d = dist2(gu, gv);
+                if d < best {
+                    (u, v, best) = (gu, gv, d);
+                }
+            }
+        }
+
+        for _ in 0..INVERSE_ITERATIONS {
+            let Ok(p) = self.evaluate(u, v) else { break };
+            let (ua, ub) = ((u - DU_STEP).max(0.0), (u + DU_STEP).min(1.0));
+            let (Ok(pa), Ok(pb), Ok((p0, p1))) = (
+                self.evaluate(ua, v),
+                self.evaluate(ub, v),
+                self.rail_points(u),
+            ) else {
+                break;
+            };
+            let su = (pb - pa) / (ub - ua);
+            let sv = p1 - p0;
+            let r = p - point;
+            // Normal equations of the 2×2 least-squares step.
+            let (a, b, c) = (su.dot(&su), su.dot(&sv), sv.dot(&sv));
+            let det = a * c - b * b;
+            if det.abs() < TOLERANCE * TOLERANCE {
+                break;
+            }
+            let (gu, gv) = (su.dot(&r), sv.dot(&r));
+            let du = (c * gu - b * gv) / det;
+            let dv = (a * gv - b * gu) / det;
+            u = (u - du).clamp(0.0, 1.0);
+            v = (v - dv).clamp(0.0, 1.0);
+            if du.abs() < TOLERANCE && dv.abs() < TOLERANCE {
+                break;
+            }
+        }
+        (u, v)
+    }
+
+    /// Evaluates both boundary curves at the normalized parameter `u`.
+    fn rail_points(&self, u: f64) -> Result<(Point3, Point3)> {
+        let at = |c: &Arc<dyn Curve>| {
+            let d = c.domain();
+            c.evaluate(d.t_min + u * (d.t_max - d.t_min))
+        };
+        Ok((at(&self.c0)?, at(&self.c1)?))
+    }
+}
+
+impl fmt::Debug for RuledSurface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuledSurface")
+            .field("c0_domain", &self.c0.domain())
+            .field("c1_domain", &self.c1.domain())
+            .finish()
+    }
+}
+
+impl Surface for RuledSurface {
+    fn evaluate(&self, u: f64, v: f64) -> Result<Point3> {
+        let (p0, p1) = self.rail_points(u)?;
+        Ok(p0 + (p1 - p0) * v)
+    }
+
+    fn normal(&self, u: f64, v: f64) -> Result<Vector3> {
+        // Central difference in u, one-sided at the domain ends.
+        let ua = (u - DU_STEP).max(0.0);
+        let ub = (u + DU_STEP).min(1.0);
+        let su = (self.evaluate(ub, v)? - self.evaluate(ua, v)?) / (ub - ua);
+        let (p0, p1) = self.rail_points(u)?;
+        let n = su.cross(&(p1 - p0));
+        let len = n.norm();
+        if len < TOLERANCE {
+            return Err(GeometryError::ZeroVector.into());
+        }
+        Ok(n / len)
+    }
+
+    fn domain(&self) -> SurfaceDomain {
+        SurfaceDomain::new(0.0, 1.0, 0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::curve::Line;
+    use crate::geometry::nurbs::{KnotVector, NurbsCurve3D};
+
+    fn segment(a: Point3, b: Point3) -> Arc<dyn Curve> {
+        Arc::new(
+            NurbsCurve3D::from_unweighted(
+                vec![a, b],
+                KnotVector::new(vec![0.0, 0.0, 1.0, 1.0]).unwrap(),
+                1,
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn evaluate_interpolates_between_rails() {
+        let s = RuledSurface::new(
+            segment(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)),
+            segment(Point3::new(0.0, 1.0, 1.0), Point3::new(2.0, 1.0, 1.0)),
+        )
+        .unwrap();
+        let p = s.evaluate(0.5, 0.25).unwrap();
+        assert!((p - Point3::new(1.0, 0.25, 0.25)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn normal_of_flat_quad_is_plane_normal() {
+        let s = RuledSurface::new(
+            segment(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)),
+            segment(Point3::new(0.0, 3.0, 0.0), Point3::new(2.0, 3.0, 0.0)),
+        )
+        .unwrap();
+        for &(u, v) in &[(0.0, 0.0), (0.5, 0.5), (1.0, 1.0)] {
+            let n = s.normal(u, v).unwrap();
+            assert!((n - Vector3::z()).norm() < 1e-9, "n={n:?}");
+        }
+    }
+
+    #[test]
+    fn inverse_round_trips_on_twisted_surface() {
+        let s = RuledSurface::new(
+            segment(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0)),
+            segment(Point3::new(0.0, 1.0, 1.0), Point3::new(2.0, 1.0, -1.0)),
+        )
+        .unwrap();
+        for &(u, v) in &[(0.2, 0.7), (0.5, 0.5), (0.9, 0.1)] {
+            let p = s.evaluate(u, v).unwrap();
+            let (iu, iv) = s.inverse(&p);
+            assert!(
+                (iu - u).abs() < 1e-8 && (iv - v).abs() < 1e-8,
+                "({iu},{iv})"
+            );
+        }
+    }
+
+    #[test]
+    fn unbounded_curve_is_rejected() {
+        let line: Arc<dyn Curve> = Arc::new(Line::new(Point3::origin(), Vector3::x()).unwrap());
+        let seg = segment(Point3::origin(), Point3::new(1.0, 0.0, 0.0));
+        assert!(RuledSurface::new(line, seg).is_err());
+    }
+}
@@ -124,6 +124,11 @@ impl Surface for Sphere {
     }
 
     fn normal(&self, u: f64, v: f64) -> Result<Vector3> {
+        // The u-partial vanishes at the poles, but the normal is still
+        // the axis there.
+        if (v.abs() - std::f64::consts::FRAC_PI_2).abs() < TOLERANCE {
+            return Ok(self.axis * v.signum());
+        }
         let binormal = self.binormal();
         let cv = v.cos();
         let sv = v.sin();
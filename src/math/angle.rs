@@ -0,0 +1,76 @@
+//! Angles between vectors.
+
+use super::{Vector2, Vector3};
+
+/// Unsigned angle from `a` to `b`, in `[0, π]`.
+///
+/// Computed with `atan2` of the cross and dot products, which stays accurate
+/// for nearly parallel vectors. Neither vector needs to be normalized; a zero
+/// vector yields `0`.
+#[must_use]
+pub fn angle_between(a: Vector2, b: Vector2) -> f64 {
+    signed_angle(a, b).abs()
+}
+
+/// Signed angle turning `a` onto `b`, in `[-π, π]`.
+///
+/// Positive when `b` lies counter-clockwise of `a` (a left turn).
+#[must_use]
+pub fn signed_angle(a: Vector2, b: Vector2) -> f64 {
+    a.perp(&b).atan2(a.dot(&b))
+}
+
+/// Unsigned angle from `a` to `b` measured in the plane perpendicular to
+/// `normal`, in `[0, π]`.
+///
+/// Components along `normal` are ignored.
+#[must_use]
+pub fn planar_angle_between(a: Vector3, b: Vector3, normal: Vector3) -> f64 {
+    planar_signed_angle(a, b, normal).abs()
+}
+
+/// Signed angle turning `a` onto `b` about `normal`, in `[-π, π]`.
+///
+/// Positive when the turn is counter-clockwise looking down `normal` (right
+/// hand rule). Components along `normal` are ignored; `normal` need not be
+/// normalized, and a zero `normal` yields `0`.
+#[must_use]
+pub fn planar_signed_angle(a: Vector3, b: Vector3, normal: Vector3) -> f64 {
+    let Some(n) = normal.try_normalize(0.0) else {
+        return 0.0;
+    };
+    let a = a - n * a.dot(&n);
+    let b = b - n * b.dot(&n);
+    a.cross(&b).dot(&n).atan2(a.dot(&b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn perpendicular_vectors_are_quarter_turn() {
+        let (x, y) = (Vector2::new(2.0, 0.0), Vector2::new(0.0, 0.5));
+        assert!((angle_between(x, y) - FRAC_PI_2).abs() < 1e-12);
+        assert!((angle_between(y, x) - FRAC_PI_2).abs() < 1e-12);
+        assert!((angle_between(x, -x) - PI).abs() < 1e-12);
+
+        let tilt = Vector3::new(0.0, 1.0, 3.0);
+        let angle = planar_angle_between(Vector3::x(), tilt, Vector3::z());
+        assert!((angle - FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn left_turn_is_positive() {
+        let east = Vector2::new(1.0, 0.0);
+        let north_east = Vector2::new(1.0, 1.0);
+        assert!((signed_angle(east, north_east) - PI / 4.0).abs() < 1e-12);
+        assert!((signed_angle(north_east, east) + PI / 4.0).abs() < 1e-12);
+
+        // Counter-clockwise about +z, clockwise about -z.
+        let (x, y) = (Vector3::x(), Vector3::y());
+        assert!((planar_signed_angle(x, y, Vector3::z()) - FRAC_PI_2).abs() < 1e-12);
+        assert!((planar_signed_angle(x, y, -Vector3::z()) + FRAC_PI_2).abs() < 1e-12);
+    }
+}
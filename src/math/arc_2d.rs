@@ -5,7 +5,9 @@
 /// - `bulge > 0`: counter-clockwise arc
 /// - `bulge < 0`: clockwise arc
 /// - `|bulge| = 1`: semicircle
-use std::f64::consts::PI;
+use std::f64::consts::{PI, TAU};
+
+use super::Point2;
 
 /// Converts a bulge-defined arc segment to center-radius-angle form.
 ///
@@ -93,6 +95,46 @@ pub fn arc_point_at(
     (cx + radius * angle.cos(), cy + radius * angle.sin())
 }
 
+/// Samples the arc of `radius` about `center` from angle `a0` to `a1`
+/// (radians, either direction) with the fewest equally spaced points whose
+/// chords all deviate from the arc by at most `max_chord_error`.
+///
+/// A chord spanning angle `Δ` has sagitta `r (1 − cos(Δ/2))`, so the step
+/// is `2 acos(1 − e/r)`. The first and last points lie exactly at `a0` and
+/// `a1`. A non-positive `radius` or `max_chord_error` yields just the two
+/// endpoints.
+#[must_use]
+pub fn sample_arc_chord(
+    center: Point2,
+    radius: f64,
+    a0: f64,
+    a1: f64,
+    max_chord_error: f64,
+) -> Vec<Point2> {
+    let point = |a: f64| Point2::new(center.x + radius * a.cos(), center.y + radius * a.sin());
+    let sweep = a1 - a0;
+    if radius <= 0.0 || max_chord_error.is_nan() || max_chord_error <= 0.0 {
+        return vec![point(a0), point(a1)];
+    }
+    let max_step = 2.0 * (1.0 - max_chord_error / radius).max(-1.0).acos();
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let n = ((sweep.abs() / max_step.min(TAU)).ceil() as usize).max(1);
+    #[allow(clippy::cast_precision_loss)]
+    (0..=n)
+        .map(|i| {
+            if i == n {
+                point(a1)
+            } else {
+                point(a0 + sweep * i as f64 / n as f64)
+            }
+        })
+        .collect()
+}
+
 /// Computes the unit tangent direction on an arc at parameter `t` in `[0, 1]`.
 ///
 /// The tangent points in the direction of increasing `t`.
@@ -291,4 +333,31 @@ mod tests {
         assert!(sw.abs() < 0.01, "sweep={sw}");
         let _ = (cx, cy); // just checking it doesn't panic
     }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn chord_sampling_meets_error_bound() {
+        let center = Point2::new(1.0, -2.0);
+        let (radius, a0, a1) = (10.0, 0.3, 0.3 + 1.5 * PI);
+        let coarse = sample_arc_chord(center, radius, a0, a1, 1e-4);
+        let fine = sample_arc_chord(center, radius, a0, a1, 5e-5);
+        #[allow(clippy::cast_precision_loss)]
+        let ratio = (fine.len() - 1) as f64 / (coarse.len() - 1) as f64;
+        assert!((ratio - 2.0_f64.sqrt()).abs() < 0.01, "ratio={ratio}");
+
+        for (points, error) in [(&coarse, 1e-4), (&fine, 5e-5)] {
+            assert!(
+                (points[0] - Point2::new(1.0 + 10.0 * a0.cos(), -2.0 + 10.0 * a0.sin())).norm()
+                    < TOL
+            );
+            for w in points.windows(2) {
+                let mid = Point2::from((w[0].coords + w[1].coords) * 0.5);
+                let sagitta = radius - (mid - center).norm();
+                assert!(sagitta <= error + 1e-12, "sagitta={sagitta}");
+            }
+            // One fewer point would break the bound.
+            let step = (a1 - a0) / (points.len() - 2) as f64;
+            assert!(radius * (1.0 - (step / 2.0).cos()) > error);
+        }
+    }
 }
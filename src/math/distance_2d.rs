@@ -1,4 +1,8 @@
-use std::f64::consts::PI;
+use std::f64::consts::{PI, TAU};
+
+use super::arc_2d::arc_from_bulge;
+use super::Point2;
+use crate::geometry::pline::Pline;
 
 /// Returns the minimum distance from point `(px, py)` to the line segment
 /// from `(ax, ay)` to `(bx, by)`.
@@ -23,6 +27,28 @@ pub fn point_to_segment_dist(px: f64, py: f64, ax: f64, ay: f64, bx: f64, by: f6
     ((px - closest_x).powi(2) + (py - closest_y).powi(2)).sqrt()
 }
 
+/// Returns the minimum distance between segments `a0 → a1` and `b0 → b1`,
+/// or `0.0` if they cross or touch.
+///
+/// Segments that do not cross are closest at an endpoint of one of them,
+/// so the distance is the smallest endpoint-to-segment distance; this also
+/// covers parallel and collinear pairs.
+#[must_use]
+pub fn segment_segment(a0: Point2, a1: Point2, b0: Point2, b1: Point2) -> f64 {
+    let orient = |p: Point2, q: Point2, r: Point2| (q - p).perp(&(r - p));
+    let (oa0, oa1) = (orient(b0, b1, a0), orient(b0, b1, a1));
+    let (ob0, ob1) = (orient(a0, a1, b0), orient(a0, a1, b1));
+    if oa0 * oa1 < 0.0 && ob0 * ob1 < 0.0 {
+        return 0.0;
+    }
+    let to_segment =
+        |p: Point2, s0: Point2, s1: Point2| point_to_segment_dist(p.x, p.y, s0.x, s0.y, s1.x, s1.y);
+    to_segment(a0, b0, b1)
+        .min(to_segment(a1, b0, b1))
+        .min(to_segment(b0, a0, a1))
+        .min(to_segment(b1, a0, a1))
+}
+
 /// Returns the minimum distance from point `(px, py)` to a circular arc.
 ///
 /// The arc is defined by center `(cx, cy)`, `radius`, `start_angle`, and `sweep`.
@@ -63,6 +89,77 @@ pub fn point_to_arc_dist(
     d0.min(d1)
 }
 
+/// Returns the minimum distance from `p` to a polyline, together with the
+/// index of the closest segment and the normalized parameter `t ∈ [0, 1]`
+/// of the closest point along it (fraction of the sweep for arcs).
+///
+/// Ties keep the lowest segment index. A single-vertex pline reports the
+/// distance to that vertex as segment `0`; an empty one reports infinity.
+#[must_use]
+pub fn point_to_pline(p: Point2, pline: &Pline) -> (f64, usize, f64) {
+    let verts = &pline.vertices;
+    let n = verts.len();
+    match n {
+        0 => return (f64::INFINITY, 0, 0.0),
+        1 => return ((p.x - verts[0].x).hypot(p.y - verts[0].y), 0, 0.0),
+        _ => {}
+    }
+
+    let mut best = (f64::INFINITY, 0, 0.0);
+    for i in 0..pline.segment_count() {
+        let v0 = &verts[i];
+        let v1 = &verts[(i + 1) % n];
+        let (d, t) = if v0.bulge.abs() < 1e-12 {
+            closest_on_segment(p, v0.x, v0.y, v1.x, v1.y)
+        } else {
+            let (cx, cy, r, start_angle, sweep) = arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge);
+            if r < 1e-12 {
+                closest_on_segment(p, v0.x, v0.y, v1.x, v1.y)
+            } else {
+                closest_on_arc(p, cx, cy, r, start_angle, sweep)
+            }
+        };
+        if d < best.0 {
+            best = (d, i, t);
+        }
+    }
+    best
+}
+
+/// Distance and normalized parameter of the closest point on a line segment.
+fn closest_on_segment(p: Point2, ax: f64, ay: f64, bx: f64, by: f64) -> (f64, f64) {
+    let (dx, dy) = (bx - ax, by - ay);
+    let len_sq = dx * dx + dy * dy;
+    let t = if len_sq < 1e-20 {
+        0.0
+    } else {
+        (((p.x - ax) * dx + (p.y - ay) * dy) / len_sq).clamp(0.0, 1.0)
+    };
+    ((p.x - ax - t * dx).hypot(p.y - ay - t * dy), t)
+}
+
+/// Distance and normalized parameter of the closest point on an arc.
+fn closest_on_arc(p: Point2, cx: f64, cy: f64, r: f64, start_angle: f64, sweep: f64) -> (f64, f64) {
+    let (dx, dy) = (p.x - cx, p.y - cy);
+    let angle = dy.atan2(dx);
+    let delta = if sweep >= 0.0 {
+        (angle - start_angle).rem_euclid(TAU)
+    } else {
+        (start_angle - angle).rem_euclid(TAU)
+    };
+    if delta <= sweep.abs() {
+        return ((dx.hypot(dy) - r).abs(), delta / sweep.abs());
+    }
+    let end_angle = start_angle + sweep;
+    let d0 = (dx - r * start_angle.cos()).hypot(dy - r * start_angle.sin());
+    let d1 = (dx - r * end_angle.cos()).hypot(dy - r * end_angle.sin());
+    if d0 <= d1 {
+        (d0, 0.0)
+    } else {
+        (d1, 1.0)
+    }
+}
+
 /// Checks if an angle falls within an arc's angular range.
 fn angle_in_arc_range(angle: f64, start_angle: f64, sweep: f64) -> bool {
     let eps = 1e-10;
@@ -123,6 +220,42 @@ mod tests {
         assert!((d - 5.0).abs() < TOL, "d={d}");
     }
 
+    // ── segment_segment tests ──
+
+    #[test]
+    fn segment_segment_parallel_gap() {
+        let d = segment_segment(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(1.0, 1.5),
+            Point2::new(3.0, 1.5),
+        );
+        assert!((d - 1.5).abs() < TOL, "d={d}");
+    }
+
+    #[test]
+    fn segment_segment_crossing_is_zero() {
+        let d = segment_segment(
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+            Point2::new(2.0, 0.0),
+        );
+        assert!(d.abs() < TOL, "d={d}");
+    }
+
+    #[test]
+    fn segment_segment_skew_endpoint_closest() {
+        // b points at a's interior but stops 0.5 short of it.
+        let d = segment_segment(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(2.0, 0.5),
+            Point2::new(3.0, 3.0),
+        );
+        assert!((d - 0.5).abs() < TOL, "d={d}");
+    }
+
     // ── point_to_arc_dist tests ──
 
     #[test]
@@ -157,4 +290,52 @@ mod tests {
         let d = point_to_arc_dist(0.0, 0.0, 0.0, 0.0, 1.0, 0.0, PI);
         assert!((d - 1.0).abs() < TOL, "d={d}");
     }
+
+    // ── point_to_pline tests ──
+
+    fn unit_square() -> Pline {
+        use crate::math::Point3;
+        Pline::from_points(
+            &[
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            true,
+        )
+    }
+
+    #[test]
+    fn pline_dist_reports_nearest_edge() {
+        let sq = unit_square();
+        // Right of the square: nearest edge is (1,0)→(1,1), segment 1.
+        let (d, seg, t) = point_to_pline(Point2::new(3.0, 0.25), &sq);
+        assert!((d - 2.0).abs() < TOL, "d={d}");
+        assert_eq!(seg, 1);
+        assert!((t - 0.25).abs() < TOL, "t={t}");
+
+        // Left of the square: closing edge (0,1)→(0,0), segment 3.
+        let (d, seg, t) = point_to_pline(Point2::new(-0.5, 0.75), &sq);
+        assert!((d - 0.5).abs() < TOL, "d={d}");
+        assert_eq!(seg, 3);
+        assert!((t - 0.25).abs() < TOL, "t={t}");
+    }
+
+    #[test]
+    fn pline_dist_on_arc_segment() {
+        use crate::geometry::pline::PlineVertex;
+        // Upper unit semicircle (CCW) from (1,0) to (-1,0).
+        let semi = Pline {
+            vertices: vec![
+                PlineVertex::new(1.0, 0.0, 1.0),
+                PlineVertex::line(-1.0, 0.0),
+            ],
+            closed: false,
+        };
+        let (d, seg, t) = point_to_pline(Point2::new(0.0, 3.0), &semi);
+        assert!((d - 2.0).abs() < TOL, "d={d}");
+        assert_eq!(seg, 0);
+        assert!((t - 0.5).abs() < TOL, "t={t}");
+    }
 }
@@ -0,0 +1,119 @@
+//! Orthonormal frame construction.
+
+use super::{Vector3, TOLERANCE};
+
+/// Returns two unit vectors perpendicular to `axis` and to each other.
+///
+/// `(u, v, axis)` forms a right-handed frame. Uses the branchless basis of
+/// Duff et al. (2017), which stays well-conditioned for every direction,
+/// including axes at or near ±Z. `axis` need not be normalized; a zero
+/// vector yields the X/Y basis.
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn ortho_basis(axis: Vector3) -> (Vector3, Vector3) {
+    let len = axis.norm();
+    if len < TOLERANCE {
+        return (Vector3::x(), Vector3::y());
+    }
+    let n = axis / len;
+    let sign = 1.0_f64.copysign(n.z);
+    let a = -1.0 / (sign + n.z);
+    let b = n.x * n.y * a;
+    let u = Vector3::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x);
+    let v = Vector3::new(b, sign + n.y * n.y * a, -n.y);
+    (u, v)
+}
+
+/// Returns a unit vector perpendicular to the unit vector `axis`.
+///
+/// The result is `axis × X`, or `axis × Y` when `axis` is close to X, so
+/// it changes only when the axis does. Revolved primitives use it as their
+/// reference direction, which places their seam.
+#[must_use]
+pub fn perpendicular_dir(axis: &Vector3) -> Vector3 {
+    let candidate = if axis.x.abs() < 0.9 {
+        Vector3::x()
+    } else {
+        Vector3::y()
+    };
+    let perp = axis.cross(&candidate);
+    perp / perp.norm()
+}
+
+/// Gram-Schmidt orthonormalization of the pair `(a, b)`.
+///
+/// Returns `a` normalized and the component of `b` perpendicular to `a`,
+/// normalized. Returns `None` if `a` is zero-length or `b` is parallel to
+/// it.
+#[must_use]
+pub fn orthonormalize(a: Vector3, b: Vector3) -> Option<(Vector3, Vector3)> {
+    let a_len = a.norm();
+    if a_len < TOLERANCE {
+        return None;
+    }
+    let a = a / a_len;
+    let perp = b - a * b.dot(&a);
+    let perp_len = perp.norm();
+    if perp_len < TOLERANCE * b.norm().max(1.0) {
+        return None;
+    }
+    Some((a, perp / perp_len))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn assert_orthonormal(axis: Vector3) {
+        let (u, v) = ortho_basis(axis);
+        let n = axis.normalize();
+        assert!((u.norm() - 1.0).abs() < 1e-12, "|u| for {axis:?}");
+        assert!((v.norm() - 1.0).abs() < 1e-12, "|v| for {axis:?}");
+        assert!(u.dot(&v).abs() < 1e-12, "u·v for {axis:?}");
+        assert!(u.dot(&n).abs() < 1e-12, "u·n for {axis:?}");
+        assert!(v.dot(&n).abs() < 1e-12, "v·n for {axis:?}");
+        assert!((u.cross(&v) - n).norm() < 1e-12, "handedness for {axis:?}");
+    }
+
+    #[test]
+    fn ortho_basis_near_poles() {
+        for eps in [0.0, 1e-15, 1e-9, 1e-4] {
+            assert_orthonormal(Vector3::new(eps, -eps, 1.0));
+            assert_orthonormal(Vector3::new(eps, eps, -1.0));
+            assert_orthonormal(Vector3::new(-eps, 0.0, -1.0));
+        }
+    }
+
+    #[test]
+    fn ortho_basis_general_axes() {
+        for axis in [
+            Vector3::x(),
+            Vector3::y(),
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(-0.3, 0.7, -0.01),
+        ] {
+            assert_orthonormal(axis);
+        }
+    }
+
+    #[test]
+    fn perpendicular_dir_is_unit_and_orthogonal() {
+        assert!((perpendicular_dir(&Vector3::z()) - Vector3::y()).norm() < 1e-12);
+        assert!((perpendicular_dir(&Vector3::x()) - Vector3::z()).norm() < 1e-12);
+        let axis = Vector3::new(1.0, 2.0, 3.0).normalize();
+        let perp = perpendicular_dir(&axis);
+        assert!((perp.norm() - 1.0).abs() < 1e-12);
+        assert!(perp.dot(&axis).abs() < 1e-12);
+    }
+
+    #[test]
+    fn orthonormalize_removes_parallel_component() {
+        let (a, b) =
+            orthonormalize(Vector3::new(0.0, 0.0, 2.0), Vector3::new(3.0, 0.0, 5.0)).unwrap();
+        assert!((a - Vector3::z()).norm() < 1e-12);
+        assert!((b - Vector3::x()).norm() < 1e-12);
+        assert!(orthonormalize(Vector3::z(), Vector3::new(0.0, 0.0, -4.0)).is_none());
+        assert!(orthonormalize(Vector3::zeros(), Vector3::x()).is_none());
+    }
+}
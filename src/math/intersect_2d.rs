@@ -1,6 +1,6 @@
 use std::f64::consts::PI;
 
-use super::{Point3, Vector3, TOLERANCE};
+use super::{Point2, Point3, Vector2, Vector3, TOLERANCE};
 
 /// Parametric 2D line-line intersection.
 ///
@@ -57,6 +57,36 @@ pub fn segment_segment_intersect_2d(
     }
 }
 
+/// Collinear overlap of two 2D segments.
+///
+/// Returns the parameter range `(t0, t1)` with `t0 < t1` of the part of
+/// `a0 → a1` that `b0 → b1` covers, when the segments lie on a common line
+/// (within [`TOLERANCE`]) and share more than a single point. Crossing,
+/// parallel-offset and end-to-end touching segments return `None`.
+#[must_use]
+pub fn segment_segment_overlap_2d(
+    a0: &Point3,
+    a1: &Point3,
+    b0: &Point3,
+    b1: &Point3,
+) -> Option<(f64, f64)> {
+    let da = Vector2::new(a1.x - a0.x, a1.y - a0.y);
+    let len_sq = da.norm_squared();
+    if len_sq < TOLERANCE * TOLERANCE {
+        return None;
+    }
+    let len = len_sq.sqrt();
+    let off_line = |p: &Point3| (da.x * (p.y - a0.y) - da.y * (p.x - a0.x)).abs() / len;
+    if off_line(b0) > TOLERANCE || off_line(b1) > TOLERANCE {
+        return None;
+    }
+    let param = |p: &Point3| (da.x * (p.x - a0.x) + da.y * (p.y - a0.y)) / len_sq;
+    let (u0, u1) = (param(b0), param(b1));
+    let t0 = u0.min(u1).max(0.0);
+    let t1 = u0.max(u1).min(1.0);
+    ((t1 - t0) * len > TOLERANCE).then_some((t0, t1))
+}
+
 /// Linear interpolation: `origin + dir * t`.
 #[must_use]
 pub fn point_at(origin: &Point3, dir: &Vector3, t: f64) -> Point3 {
@@ -223,6 +253,54 @@ pub fn arc_arc_intersect_2d(
     results
 }
 
+/// Result of intersecting two full circles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CircleIntersection {
+    /// The circles are disjoint, one inside the other, or concentric with
+    /// different radii.
+    None,
+    /// The circles touch at a single point (internal or external tangency).
+    One(Point2),
+    /// The circles cross at two points; the first lies to the left of the
+    /// line from `c0` to `c1`.
+    Two(Point2, Point2),
+    /// The circles are identical, so every point is shared.
+    Coincident,
+}
+
+/// Intersects the circle `(c0, r0)` with the circle `(c1, r1)`.
+///
+/// Uses the radical-line construction: the intersection points lie on the
+/// line perpendicular to `c0 → c1` at distance `a = (r0² − r1² + d²) / 2d`
+/// from `c0`, offset either side by `h = √(r0² − a²)`. Tangency is
+/// detected within [`TOLERANCE`] of the centre distance.
+#[must_use]
+pub fn circle_circle(c0: &Point2, r0: f64, c1: &Point2, r1: f64) -> CircleIntersection {
+    let delta = c1 - c0;
+    let d = delta.norm();
+    if d < TOLERANCE {
+        return if (r0 - r1).abs() < TOLERANCE {
+            CircleIntersection::Coincident
+        } else {
+            CircleIntersection::None
+        };
+    }
+    if d > r0 + r1 + TOLERANCE || d < (r0 - r1).abs() - TOLERANCE {
+        return CircleIntersection::None;
+    }
+
+    let dir = delta / d;
+    let a = (r0 * r0 - r1 * r1 + d * d) / (2.0 * d);
+    let mid = c0 + dir * a;
+    let h_sq = r0 * r0 - a * a;
+    // Tangent within tolerance: the radical line touches both circles once.
+    if h_sq <= 0.0 || r0 + r1 - d < TOLERANCE || d - (r0 - r1).abs() < TOLERANCE {
+        return CircleIntersection::One(mid);
+    }
+    let offset = Vector2::new(-dir.y, dir.x) * h_sq.sqrt();
+    CircleIntersection::Two(mid + offset, mid - offset)
+}
+
 /// Converts an absolute angle to an arc parameter `t` in `[0, 1]`.
 ///
 /// Returns `None` if the angle is not within the arc's angular range.
@@ -282,6 +360,28 @@ mod tests {
         assert!(line_line_intersect_2d(&p1, &d1, &p2, &d2).is_none());
     }
 
+    #[test]
+    fn segment_segment_overlap_cases() {
+        let p = |x: f64, y: f64| Point3::new(x, y, 0.0);
+        let (t0, t1) =
+            segment_segment_overlap_2d(&p(0.0, 0.0), &p(4.0, 0.0), &p(3.0, 0.0), &p(1.0, 0.0))
+                .unwrap();
+        assert!((t0 - 0.25).abs() < TOLERANCE && (t1 - 0.75).abs() < TOLERANCE);
+        // Retracing past the start clamps to the segment.
+        let (t0, t1) =
+            segment_segment_overlap_2d(&p(0.0, 0.0), &p(4.0, 0.0), &p(4.0, 0.0), &p(-2.0, 0.0))
+                .unwrap();
+        assert!(t0.abs() < TOLERANCE && (t1 - 1.0).abs() < TOLERANCE);
+        // End-to-end, parallel offset and crossing segments do not overlap.
+        for (b0, b1) in [
+            (p(4.0, 0.0), p(6.0, 0.0)),
+            (p(0.0, 1.0), p(4.0, 1.0)),
+            (p(2.0, -1.0), p(2.0, 1.0)),
+        ] {
+            assert!(segment_segment_overlap_2d(&p(0.0, 0.0), &p(4.0, 0.0), &b0, &b1).is_none());
+        }
+    }
+
     #[test]
     fn segment_segment_crossing() {
         let a0 = Point3::new(0.0, 0.0, 0.0);
@@ -372,6 +472,48 @@ mod tests {
         assert!(hits.is_empty(), "hits={hits:?}");
     }
 
+    // ── circle-circle intersection tests ──
+
+    #[test]
+    fn circle_circle_external_tangency() {
+        let hit = circle_circle(&Point2::new(0.0, 0.0), 1.0, &Point2::new(3.0, 0.0), 2.0);
+        let CircleIntersection::One(p) = hit else {
+            panic!("expected tangency, got {hit:?}");
+        };
+        assert!((p - Point2::new(1.0, 0.0)).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn circle_circle_two_points() {
+        let hit = circle_circle(&Point2::new(0.0, 0.0), 1.0, &Point2::new(1.0, 0.0), 1.0);
+        let CircleIntersection::Two(a, b) = hit else {
+            panic!("expected two points, got {hit:?}");
+        };
+        let h = 3.0_f64.sqrt() / 2.0;
+        assert!((a - Point2::new(0.5, h)).norm() < TOLERANCE);
+        assert!((b - Point2::new(0.5, -h)).norm() < TOLERANCE);
+    }
+
+    #[test]
+    fn circle_circle_concentric_is_none() {
+        let c = Point2::new(2.0, -1.0);
+        assert_eq!(circle_circle(&c, 1.0, &c, 2.0), CircleIntersection::None);
+        // Nested but off-centre circles do not meet either.
+        assert_eq!(
+            circle_circle(&c, 3.0, &Point2::new(2.5, -1.0), 1.0),
+            CircleIntersection::None
+        );
+    }
+
+    #[test]
+    fn circle_circle_identical_is_coincident() {
+        let c = Point2::new(1.0, 1.0);
+        assert_eq!(
+            circle_circle(&c, 2.0, &c, 2.0),
+            CircleIntersection::Coincident
+        );
+    }
+
     // ── arc-arc intersection tests ──
 
     #[test]
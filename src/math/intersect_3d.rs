@@ -132,6 +132,39 @@ pub fn signed_distance_to_plane(point: &Point3, plane: &Plane) -> f64 {
     plane.plane_normal().dot(&diff)
 }
 
+/// Möller–Trumbore intersection of the line `origin + t * dir` with the
+/// triangle `tri`, returning `t` and the barycentric coordinates of the hit
+/// (weights of `tri[0]`, `tri[1]`, `tri[2]`).
+///
+/// The hit may lie outside the triangle or behind `origin`; callers bound
+/// `t` and the coordinates as they need. Returns `None` when the
+/// determinant `(dir × e2) · e1` (with `e1`, `e2` the edges from `tri[0]`)
+/// is at most `min_det` in magnitude: the line is parallel to the
+/// triangle's plane or the triangle is degenerate. The cutoff is the
+/// caller's, since its scale depends on how `dir` and `tri` are sized.
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn line_triangle_intersect(
+    origin: &Point3,
+    dir: &Vector3,
+    tri: &[Point3; 3],
+    min_det: f64,
+) -> Option<(f64, [f64; 3])> {
+    let e1 = tri[1] - tri[0];
+    let e2 = tri[2] - tri[0];
+    let p = dir.cross(&e2);
+    let det = e1.dot(&p);
+    if det.abs() <= min_det {
+        return None;
+    }
+    let s = origin - tri[0];
+    let u = s.dot(&p) / det;
+    let q = s.cross(&e1);
+    let v = dir.dot(&q) / det;
+    let t = e2.dot(&q) / det;
+    Some((t, [1.0 - u - v, u, v]))
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -319,4 +352,32 @@ mod tests {
             PointPlaneClassification::On
         );
     }
+
+    // ── line_triangle_intersect ──
+
+    #[test]
+    fn line_hits_triangle_with_barycentrics() {
+        let tri = [p(0.0, 0.0, 1.0), p(4.0, 0.0, 1.0), p(0.0, 4.0, 1.0)];
+        let (t, bary) =
+            line_triangle_intersect(&p(1.0, 2.0, -1.0), &v(0.0, 0.0, 4.0), &tri, 0.0).unwrap();
+        assert!((t - 0.5).abs() < 1e-12);
+        let expected = [0.25, 0.25, 0.5];
+        assert!(bary
+            .iter()
+            .zip(expected)
+            .all(|(b, e)| (b - e).abs() < 1e-12));
+
+        // Outside the triangle and behind the origin is still reported.
+        let (t, bary) =
+            line_triangle_intersect(&p(5.0, 5.0, 3.0), &v(0.0, 0.0, 1.0), &tri, 0.0).unwrap();
+        assert!((t + 2.0).abs() < 1e-12 && bary[0] < 0.0);
+
+        // Parallel to the plane: no hit.
+        assert!(line_triangle_intersect(&p(1.0, 1.0, 0.0), &v(1.0, 0.0, 0.0), &tri, 0.0).is_none());
+
+        // Nearly parallel (det = 16e-9): kept or dropped by the cutoff.
+        let grazing = v(1.0, 0.0, 1e-9);
+        assert!(line_triangle_intersect(&p(1.0, 1.0, 0.0), &grazing, &tri, 1e-12).is_some());
+        assert!(line_triangle_intersect(&p(1.0, 1.0, 0.0), &grazing, &tri, 1e-6).is_none());
+    }
 }
@@ -1,10 +1,19 @@
+pub mod angle;
 pub mod arc_2d;
 pub mod distance_2d;
+pub mod frame;
 pub mod intersect_2d;
 pub mod intersect_3d;
 pub mod polygon_2d;
 pub mod polygon_3d;
+pub mod predicates;
 pub mod straight_skeleton;
+pub mod transform;
+
+pub use angle::{angle_between, planar_angle_between, planar_signed_angle, signed_angle};
+pub use frame::{ortho_basis, orthonormalize};
+pub use predicates::orient2d;
+pub use transform::{transform_normal, transform_point, transform_vector};
 
 /// 2D point type.
 pub type Point2 = nalgebra::Point2<f64>;
@@ -1,4 +1,4 @@
-use super::{Point3, Vector3, TOLERANCE};
+use super::{orient2d, Point2, Point3, Vector3, TOLERANCE};
 use crate::error::{OperationError, Result};
 
 /// Computes the signed area of a polygon in the XY plane (shoelace formula).
@@ -22,9 +22,21 @@ pub fn signed_area_2d(points: &[Point3]) -> f64 {
 /// breaking ties by smallest y. Ensures deterministic output for tests.
 #[must_use]
 pub fn rotate_to_canonical_start(points: &[Point3]) -> Vec<Point3> {
-    if points.len() < 2 {
+    let best = canonical_start_index(points);
+    if best == 0 {
         return points.to_vec();
     }
+    let mut rotated = Vec::with_capacity(points.len());
+    rotated.extend_from_slice(&points[best..]);
+    rotated.extend_from_slice(&points[..best]);
+    rotated
+}
+
+/// Index of the vertex [`rotate_to_canonical_start`] starts from: the
+/// leftmost (smallest x), ties broken by smallest y. `0` for an empty
+/// polygon.
+#[must_use]
+pub fn canonical_start_index(points: &[Point3]) -> usize {
     let mut best = 0;
     for (i, pt) in points.iter().enumerate().skip(1) {
         let b = &points[best];
@@ -32,13 +44,7 @@ pub fn rotate_to_canonical_start(points: &[Point3]) -> Vec<Point3> {
             best = i;
         }
     }
-    if best == 0 {
-        return points.to_vec();
-    }
-    let mut rotated = Vec::with_capacity(points.len());
-    rotated.extend_from_slice(&points[best..]);
-    rotated.extend_from_slice(&points[..best]);
-    rotated
+    best
 }
 
 /// Returns the leftmost-bottommost vertex of a polygon (for tie-breaking in sort).
@@ -77,6 +83,90 @@ pub fn left_normal(dir: Vector3) -> Vector3 {
     Vector3::new(-dir.y, dir.x, 0.0)
 }
 
+/// Tests whether `p` lies inside `polygon` under the even-odd rule: a
+/// point is inside when a ray from it crosses the boundary an odd number of
+/// times, so regions a self-intersecting polygon covers twice are outside.
+///
+/// Points within [`TOLERANCE`] of an edge are inside iff `include_boundary`.
+#[must_use]
+pub fn point_in_polygon_evenodd(polygon: &[Point2], p: Point2, include_boundary: bool) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+    if on_polygon_boundary(polygon, p) {
+        return include_boundary;
+    }
+    let mut inside = false;
+    for (a, b) in polygon_edges(polygon) {
+        if (a.y > p.y) != (b.y > p.y) {
+            let x = a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if p.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Tests whether `p` lies inside `polygon` under the nonzero rule: a point
+/// is inside when the boundary winds around it a nonzero number of times,
+/// so regions a self-intersecting polygon covers twice are inside.
+///
+/// Points within [`TOLERANCE`] of an edge are inside iff `include_boundary`.
+#[must_use]
+pub fn point_in_polygon_nonzero(polygon: &[Point2], p: Point2, include_boundary: bool) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+    if on_polygon_boundary(polygon, p) {
+        return include_boundary;
+    }
+    winding_number_2d(polygon_edges(polygon), p) != 0
+}
+
+/// Winding number of the closed ring given by `edges` around `p`: the
+/// signed count of upward (+1) and downward (-1) edge crossings of the +X
+/// ray from `p`, using the exact [`orient2d`] predicate.
+///
+/// Positive for counter-clockwise rings. Points on the boundary may
+/// report either side.
+#[must_use]
+pub fn winding_number_2d(edges: impl IntoIterator<Item = (Point2, Point2)>, p: Point2) -> i32 {
+    let mut winding = 0;
+    for (a, b) in edges {
+        if a.y <= p.y {
+            if b.y > p.y && orient2d(a, b, p) > 0 {
+                winding += 1;
+            }
+        } else if b.y <= p.y && orient2d(a, b, p) < 0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Iterates the closed polygon's edges, including the closing one.
+fn polygon_edges(polygon: &[Point2]) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Returns `true` if `p` is within [`TOLERANCE`] of an edge of `polygon`.
+fn on_polygon_boundary(polygon: &[Point2], p: Point2) -> bool {
+    polygon_edges(polygon).any(|(a, b)| {
+        let ab = b - a;
+        let len2 = ab.norm_squared();
+        let t = if len2 > 0.0 {
+            ((p - a).dot(&ab) / len2).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (p - (a + ab * t)).norm() < TOLERANCE
+    })
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -173,4 +263,49 @@ mod tests {
         assert!((n.x).abs() < TOLERANCE);
         assert!((n.y - 1.0).abs() < TOLERANCE);
     }
+
+    /// Pentagram traced by joining every second vertex of a pentagon; its
+    /// boundary winds twice around the central pentagon.
+    fn pentagram() -> Vec<Point2> {
+        (0..5)
+            .map(|i| {
+                let a =
+                    std::f64::consts::FRAC_PI_2 + f64::from(i) * 4.0 * std::f64::consts::PI / 5.0;
+                Point2::new(a.cos(), a.sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pentagram_center_differs_between_rules() {
+        let star = pentagram();
+        let center = Point2::origin();
+        assert!(!point_in_polygon_evenodd(&star, center, false));
+        assert!(point_in_polygon_nonzero(&star, center, false));
+
+        // A star point is covered once: inside under both rules.
+        let tip = Point2::new(0.0, 0.8);
+        assert!(point_in_polygon_evenodd(&star, tip, false));
+        assert!(point_in_polygon_nonzero(&star, tip, false));
+
+        let far = Point2::new(2.0, 0.0);
+        assert!(!point_in_polygon_evenodd(&star, far, true));
+        assert!(!point_in_polygon_nonzero(&star, far, true));
+    }
+
+    #[test]
+    fn boundary_points_follow_flag() {
+        let square = [
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 1.0),
+        ];
+        for p in [Point2::new(0.5, 0.0), Point2::new(1.0, 1.0)] {
+            assert!(point_in_polygon_evenodd(&square, p, true));
+            assert!(!point_in_polygon_evenodd(&square, p, false));
+            assert!(point_in_polygon_nonzero(&square, p, true));
+            assert!(!point_in_polygon_nonzero(&square, p, false));
+        }
+    }
 }
@@ -50,6 +50,24 @@ fn winding_number_2d(px: f64, py: f64, verts: &[(f64, f64)]) -> i32 {
     winding
 }
 
+/// Whether `(px, py)` lies within `TOLERANCE` of an edge of polygon `verts`.
+fn on_boundary_2d(px: f64, py: f64, verts: &[(f64, f64)]) -> bool {
+    let n = verts.len();
+    (0..n).any(|i| {
+        let (x0, y0) = verts[i];
+        let (x1, y1) = verts[(i + 1) % n];
+        let (ex, ey) = (x1 - x0, y1 - y0);
+        let len_sq = ex * ex + ey * ey;
+        let t = if len_sq > 0.0 {
+            (((px - x0) * ex + (py - y0) * ey) / len_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (dx, dy) = (x0 + ex * t - px, y0 + ey * t - py);
+        dx * dx + dy * dy <= TOLERANCE * TOLERANCE
+    })
+}
+
 /// 2D cross product: `(ax * by - ay * bx)`.
 #[inline]
 fn cross_2d(ax: f64, ay: f64, bx: f64, by: f64) -> f64 {
@@ -59,8 +77,9 @@ fn cross_2d(ax: f64, ay: f64, bx: f64, by: f64) -> f64 {
 /// Clips a line segment `(seg_start, seg_end)` to a convex polygon boundary.
 ///
 /// Both the segment and polygon must be coplanar with the given plane.
-/// Returns the sub-segments that lie inside the polygon as `(t_start, t_end)`
-/// pairs where `t` is the parameter along the original segment `[0, 1]`.
+/// Returns the sub-segments that lie inside the polygon or along its
+/// boundary as `(t_start, t_end)` pairs where `t` is the parameter along the
+/// original segment `[0, 1]`.
 ///
 /// For non-convex polygons, multiple sub-segments may be returned.
 #[must_use]
@@ -128,7 +147,7 @@ pub fn clip_segment_to_polygon(
         let mid_t = (t0 + t1) * 0.5;
         let mid_u = su + du * mid_t;
         let mid_v = sv + dv * mid_t;
-        if winding_number_2d(mid_u, mid_v, &uvs) != 0 {
+        if winding_number_2d(mid_u, mid_v, &uvs) != 0 || on_boundary_2d(mid_u, mid_v, &uvs) {
             // Merge with previous interval if contiguous
             if let Some(last) = result.last_mut() {
                 let (_, ref mut last_end): (f64, f64) = *last;
@@ -288,6 +307,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn segment_along_any_edge_is_kept() {
+        let plane = xy_plane();
+        let sq = unit_square();
+        // The winding number alone counts only some edges as inside.
+        for (a, b) in [
+            (p(0.0, -1.0, 0.0), p(0.0, 2.0, 0.0)),
+            (p(1.0, -1.0, 0.0), p(1.0, 2.0, 0.0)),
+            (p(-1.0, 0.0, 0.0), p(2.0, 0.0, 0.0)),
+            (p(-1.0, 1.0, 0.0), p(2.0, 1.0, 0.0)),
+        ] {
+            let result = clip_segment_to_polygon(&a, &b, &sq, &plane);
+            assert_eq!(result.len(), 1, "{a:?} -> {b:?}");
+            assert!((result[0].0 - 1.0 / 3.0).abs() < 0.01);
+            assert!((result[0].1 - 2.0 / 3.0).abs() < 0.01);
+        }
+    }
+
     // ── polygon_area_3d ──
 
     #[test]
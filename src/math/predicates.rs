@@ -0,0 +1,119 @@
+//! Robust geometric predicates.
+//!
+//! The sign of a 2D cross product decides which side of a line a point lies
+//! on. Evaluated naively in floating point, rounding can flip that sign for
+//! nearly collinear points. [`orient2d`] first tries a cheap error-bounded
+//! evaluation (Shewchuk's "stage A" filter) and only falls back to exact
+//! expansion arithmetic when the result is too close to zero to trust.
+
+use super::Point2;
+
+/// Relative error bound of the filtered determinant: `(3 + 16ε) ε` for the
+/// unit roundoff `ε = 2⁻⁵³`.
+const CCW_ERR_BOUND: f64 = (3.0 + 16.0 * f64::EPSILON * 0.5) * f64::EPSILON * 0.5;
+
+/// Returns the orientation of the triangle `a, b, c`: `1` when `c` lies to
+/// the left of the directed line `a → b` (counter-clockwise), `-1` when it
+/// lies to the right, and `0` when the three points are exactly collinear.
+///
+/// The sign is exact for all finite inputs whose products neither overflow
+/// nor underflow.
+#[must_use]
+pub fn orient2d(a: Point2, b: Point2, c: Point2) -> i8 {
+    let det_left = (a.x - c.x) * (b.y - c.y);
+    let det_right = (a.y - c.y) * (b.x - c.x);
+    let det = det_left - det_right;
+    let bound = CCW_ERR_BOUND * (det_left.abs() + det_right.abs());
+    if det > bound {
+        return 1;
+    }
+    if -det > bound {
+        return -1;
+    }
+    orient2d_exact(a, b, c)
+}
+
+/// Exact sign of `ax·by − ax·cy − ay·bx + ay·cx + bx·cy − by·cx`, summing
+/// the exact products as a non-overlapping floating-point expansion.
+fn orient2d_exact(a: Point2, b: Point2, c: Point2) -> i8 {
+    let terms = [
+        (a.x, b.y),
+        (-a.x, c.y),
+        (-a.y, b.x),
+        (a.y, c.x),
+        (b.x, c.y),
+        (-b.y, c.x),
+    ];
+    let mut expansion: Vec<f64> = Vec::with_capacity(2 * terms.len());
+    for (p, q) in terms {
+        let (hi, lo) = two_product(p, q);
+        grow_expansion(&mut expansion, lo);
+        grow_expansion(&mut expansion, hi);
+    }
+    // Components grow in magnitude without overlapping, so the largest
+    // non-zero one carries the sign of the sum.
+    match expansion.iter().rev().find(|&&e| e != 0.0) {
+        Some(&e) if e > 0.0 => 1,
+        Some(_) => -1,
+        None => 0,
+    }
+}
+
+/// Adds `value` to a non-overlapping expansion, keeping it non-overlapping
+/// and ordered by increasing magnitude. Zero components are dropped.
+fn grow_expansion(expansion: &mut Vec<f64>, value: f64) {
+    let mut q = value;
+    let mut out = Vec::with_capacity(expansion.len() + 1);
+    for &e in expansion.iter() {
+        let (sum, err) = two_sum(q, e);
+        if err != 0.0 {
+            out.push(err);
+        }
+        q = sum;
+    }
+    out.push(q);
+    *expansion = out;
+}
+
+/// Error-free sum: `a + b == sum + err` exactly.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let bv = sum - a;
+    let av = sum - bv;
+    (sum, (a - av) + (b - bv))
+}
+
+/// Error-free product: `a * b == hi + lo` exactly.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let hi = a * b;
+    (hi, a.mul_add(b, -hi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearly_collinear_sign_is_exact() {
+        // `a` sits a few ulps above the line y = x; the naive cross
+        // product rounds to the wrong side.
+        let ulp = f64::EPSILON * 0.5;
+        let a = Point2::new(0.5 + 41.0 * ulp, 0.5 + 48.0 * ulp);
+        let b = Point2::new(12.0, 12.0);
+        let c = Point2::new(24.0, 24.0);
+        let naive = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+        assert!(naive < 0.0, "naive={naive}");
+        assert_eq!(orient2d(a, b, c), 1);
+        assert_eq!(orient2d(b, a, c), -1);
+    }
+
+    #[test]
+    fn simple_orientations() {
+        let (a, b) = (Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+        assert_eq!(orient2d(a, b, Point2::new(0.5, 1.0)), 1);
+        assert_eq!(orient2d(a, b, Point2::new(0.5, -1.0)), -1);
+        assert_eq!(orient2d(a, b, Point2::new(3.0, 0.0)), 0);
+        let (p, q) = (Point2::new(0.1, 0.1), Point2::new(0.3, 0.3));
+        assert_eq!(orient2d(p, q, Point2::new(0.7, 0.7)), 0);
+    }
+}
@@ -0,0 +1,70 @@
+//! Applying 4x4 homogeneous matrices to points, vectors and normals.
+
+use super::{Matrix4, Point3, Vector3};
+
+/// Transforms a point by `m`, dividing by the homogeneous `w` when the
+/// matrix is projective (`w` is `1` for affine matrices).
+///
+/// A point mapped to infinity (`w = 0`) is returned undivided.
+#[must_use]
+#[allow(clippy::float_cmp)]
+pub fn transform_point(m: &Matrix4, p: Point3) -> Point3 {
+    let h = m * p.to_homogeneous();
+    if h.w == 0.0 || h.w == 1.0 {
+        Point3::new(h.x, h.y, h.z)
+    } else {
+        Point3::new(h.x / h.w, h.y / h.w, h.z / h.w)
+    }
+}
+
+/// Transforms a direction by the linear part of `m`, ignoring translation.
+#[must_use]
+pub fn transform_vector(m: &Matrix4, v: Vector3) -> Vector3 {
+    m.fixed_view::<3, 3>(0, 0) * v
+}
+
+/// Transforms a surface normal by the inverse transpose of the linear part
+/// of `m` and renormalizes it, so it stays perpendicular to transformed
+/// tangents under non-uniform scaling.
+///
+/// A singular linear part falls back to the plain linear map, and a normal
+/// that degenerates to zero is returned unchanged.
+#[must_use]
+pub fn transform_normal(m: &Matrix4, n: Vector3) -> Vector3 {
+    let linear = m.fixed_view::<3, 3>(0, 0).into_owned();
+    let normal_matrix = linear.try_inverse().map_or(linear, |inv| inv.transpose());
+    (normal_matrix * n).try_normalize(0.0).unwrap_or(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translation_moves_points_not_vectors() {
+        let m = Matrix4::new_translation(&Vector3::new(1.0, -2.0, 3.0));
+        let p = transform_point(&m, Point3::new(1.0, 1.0, 1.0));
+        assert!((p - Point3::new(2.0, -1.0, 4.0)).norm() < 1e-12);
+        let v = Vector3::new(0.5, 0.25, -1.0);
+        assert!((transform_vector(&m, v) - v).norm() < 1e-12);
+        assert!((transform_normal(&m, Vector3::z()) - Vector3::z()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn non_uniform_scale_keeps_normal_unit_and_perpendicular() {
+        let m = Matrix4::new_nonuniform_scaling(&Vector3::new(4.0, 1.0, 1.0));
+        // The plane x + y = 0: tangent (1, -1, 0), normal (1, 1, 0).
+        let tangent = transform_vector(&m, Vector3::new(1.0, -1.0, 0.0));
+        let normal = transform_normal(&m, Vector3::new(1.0, 1.0, 0.0).normalize());
+        assert!((normal.norm() - 1.0).abs() < 1e-12);
+        assert!(normal.dot(&tangent).abs() < 1e-12);
+    }
+
+    #[test]
+    fn projective_matrix_divides_by_w() {
+        let mut m = Matrix4::identity();
+        m[(3, 3)] = 2.0;
+        let p = transform_point(&m, Point3::new(2.0, 4.0, 6.0));
+        assert!((p - Point3::new(1.0, 2.0, 3.0)).norm() < 1e-12);
+    }
+}
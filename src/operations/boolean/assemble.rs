@@ -5,8 +5,8 @@ use crate::geometry::curve::Line;
 use crate::math::{Point3, TOLERANCE};
 use crate::operations::creation::{MakeFace, MakeSolid};
 use crate::topology::{
-    EdgeCurve, EdgeData, FaceId, OrientedEdge, ShellData, SolidId, TopologyStore, VertexData,
-    VertexId, WireData,
+    EdgeCurve, EdgeData, FaceId, OrientedEdge, ShellData, ShellId, SolidId, TopologyStore,
+    VertexData, VertexId, WireData,
 };
 
 use super::select::KeepDecision;
@@ -14,13 +14,52 @@ use super::split::{newell_normal_3d, FaceFragment};
 
 /// Assembles a `BRep` solid from a set of face fragments.
 ///
-/// Creates new topology (vertices, edges, wires, faces, shell, solid) from
+/// Creates new topology (vertices, edges, wires, faces, shells, solid) from
 /// the polygon boundaries of the fragments. Uses spatial hashing to merge
-/// coincident vertices.
+/// coincident vertices. Connected groups of faces enclosing a negative
+/// volume (inward-facing boundaries, i.e. voids) become inner shells; the
+/// rest form the outer shell.
 pub fn assemble_result(
     store: &mut TopologyStore,
     fragments: &[(FaceFragment, KeepDecision)],
 ) -> Result<SolidId> {
+    let faces = assemble_faces(store, fragments)?;
+    let (outer, voids) = separate_voids(store, faces)?;
+    let outer_shell = store.add_shell(ShellData {
+        faces: outer,
+        is_closed: true,
+    });
+    let inner_shells = voids
+        .into_iter()
+        .map(|faces| {
+            store.add_shell(ShellData {
+                faces,
+                is_closed: true,
+            })
+        })
+        .collect();
+    MakeSolid::new(outer_shell, inner_shells).execute(store)
+}
+
+/// Assembles a closed shell from a set of face fragments, as
+/// [`assemble_result`] does, without wrapping it in a solid. All faces go
+/// into the one shell.
+pub fn assemble_shell(
+    store: &mut TopologyStore,
+    fragments: &[(FaceFragment, KeepDecision)],
+) -> Result<ShellId> {
+    let faces = assemble_faces(store, fragments)?;
+    Ok(store.add_shell(ShellData {
+        faces,
+        is_closed: true,
+    }))
+}
+
+/// Creates the faces of every kept fragment, sharing merged vertices.
+fn assemble_faces(
+    store: &mut TopologyStore,
+    fragments: &[(FaceFragment, KeepDecision)],
+) -> Result<Vec<FaceId>> {
     let kept: Vec<&FaceFragment> = fragments
         .iter()
         .filter(|(_, decision)| *decision != KeepDecision::Discard)
@@ -72,14 +111,81 @@ pub fn assemble_result(
         .into());
     }
 
-    // Create shell
-    let shell_id = store.add_shell(ShellData {
-        faces: all_faces,
-        is_closed: true,
-    });
+    Ok(all_faces)
+}
 
-    // Create solid
-    MakeSolid::new(shell_id, vec![]).execute(store)
+/// Splits assembled faces into the outer boundary and any voids.
+///
+/// Faces sharing a vertex form one connected component. A component whose
+/// boundary encloses a negative signed volume faces inward and is returned
+/// as a void; every other component stays with the outer faces. When all
+/// faces are connected, or no component is positive, nothing is split off.
+fn separate_voids(
+    store: &TopologyStore,
+    faces: Vec<FaceId>,
+) -> Result<(Vec<FaceId>, Vec<Vec<FaceId>>)> {
+    // Union-find over face indices, joined through shared vertices.
+    let mut parent: Vec<usize> = (0..faces.len()).collect();
+
+    let mut first_face_of: HashMap<VertexId, usize> = HashMap::new();
+    let mut volumes = vec![0.0; faces.len()];
+    for (i, &face_id) in faces.iter().enumerate() {
+        let face = store.face(face_id)?;
+        for wire_id in std::iter::once(face.outer_wire).chain(face.inner_wires.iter().copied()) {
+            let mut ring = Vec::new();
+            for oe in &store.wire(wire_id)?.edges {
+                let edge = store.edge(oe.edge)?;
+                let vid = if oe.forward { edge.start } else { edge.end };
+                ring.push(store.vertex(vid)?.point.coords);
+                let j = *first_face_of.entry(vid).or_insert(i);
+                let (ri, rj) = (find_root(&mut parent, i), find_root(&mut parent, j));
+                parent[ri] = rj;
+            }
+            // Volume of the cone from the origin over this ring.
+            for k in 1..ring.len().saturating_sub(1) {
+                volumes[i] += ring[0].dot(&ring[k].cross(&ring[k + 1])) / 6.0;
+            }
+        }
+    }
+
+    let mut components: HashMap<usize, (Vec<FaceId>, f64)> = HashMap::new();
+    let mut order = Vec::new();
+    for (i, &face_id) in faces.iter().enumerate() {
+        let root = find_root(&mut parent, i);
+        let entry = components.entry(root).or_insert_with(|| {
+            order.push(root);
+            (Vec::new(), 0.0)
+        });
+        entry.0.push(face_id);
+        entry.1 += volumes[i];
+    }
+
+    if order.len() < 2 || !components.values().any(|(_, volume)| *volume > 0.0) {
+        return Ok((faces, Vec::new()));
+    }
+
+    let mut outer = Vec::new();
+    let mut voids = Vec::new();
+    for root in order {
+        let Some((component, volume)) = components.remove(&root) else {
+            continue;
+        };
+        if volume < 0.0 {
+            voids.push(component);
+        } else {
+            outer.extend(component);
+        }
+    }
+    Ok((outer, voids))
+}
+
+/// Union-find root of `i`, halving the path on the way.
+fn find_root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
 }
 
 /// Creates one or more faces from a polygon boundary with optional
@@ -488,6 +594,7 @@ fn create_line_edge(
         curve: EdgeCurve::Line(line),
         t_start: 0.0,
         t_end,
+        tessellation_tolerance: None,
     }))
 }
 
@@ -14,8 +14,9 @@ pub enum PointClassification {
 
 /// Classifies a point as inside, outside, or on the boundary of a solid.
 ///
-/// Uses ray casting: shoots a ray from the point and counts face crossings.
-/// Odd crossings = inside, even = outside. If the ray is degenerate
+/// Uses ray casting: shoots a ray from the point and counts face crossings
+/// over the outer shell and any void shells. Odd crossings = inside, even =
+/// outside. If the ray is degenerate
 /// (hits an edge/vertex), retries with alternative directions.
 ///
 /// # Errors
@@ -27,10 +28,13 @@ pub fn classify_point_in_solid(
     store: &TopologyStore,
 ) -> Result<PointClassification> {
     let solid = store.solid(solid_id)?;
-    let shell = store.shell(solid.outer_shell)?;
 
-    // Collect face polygons and planes
-    let faces = collect_face_data(store, &shell.faces)?;
+    // Collect face polygons and planes. Void shells take part in the
+    // crossing count, so a point inside a cavity sees an even number.
+    let mut faces = Vec::new();
+    for shell_id in std::iter::once(solid.outer_shell).chain(solid.inner_shells.iter().copied()) {
+        faces.extend(collect_face_data(store, &store.shell(shell_id)?.faces)?);
+    }
 
     // Try up to 3 ray directions
     let directions = [
@@ -1,37 +1,54 @@
 use std::collections::HashMap;
 
 use crate::error::{OperationError, Result};
-use crate::math::{Point3, TOLERANCE};
+use crate::geometry::surface::Plane;
+use crate::math::polygon_3d::point_in_polygon_3d;
+use crate::math::{Point3, Vector3, TOLERANCE};
+use crate::operations::query::IsValid;
 use crate::topology::{FaceId, FaceSurface, SolidId, TopologyStore};
 
-use super::assemble::assemble_result;
+use super::assemble::{assemble_result, assemble_shell};
 use super::classify::{classify_point_in_solid, PointClassification};
-use super::face_intersection::intersect_face_face;
-use super::select::{should_keep_fragment, BooleanOp, KeepDecision};
+use super::face_intersection::{
+    collect_face_polygon, collect_inner_wire_polygons, intersect_face_face,
+};
+use super::select::{should_keep_coincident, should_keep_fragment, BooleanOp, KeepDecision};
+use super::snap::snap_to_planes;
 use super::split::{split_face, FaceFragment, SolidSource};
 
-/// Executes a boolean operation on two solids.
-///
-/// Orchestrates the full pipeline: face-face intersection, splitting,
-/// classification, selection, and assembly.
-pub fn boolean_execute(
-    store: &mut TopologyStore,
-    solid_a: SolidId,
-    solid_b: SolidId,
-    op: BooleanOp,
-) -> Result<SolidId> {
-    boolean_execute_named(store, solid_a, solid_b, op, None)
+/// Fails with `OperationError::InvalidInput` if the boundary of either
+/// operand crosses itself (see [`IsValid::find_self_intersection`]).
+pub(super) fn reject_self_intersecting(store: &TopologyStore, solids: [SolidId; 2]) -> Result<()> {
+    for (name, solid) in ["first", "second"].into_iter().zip(solids) {
+        if let Some(p) = IsValid::find_self_intersection(store, solid)? {
+            return Err(OperationError::InvalidInput(format!(
+                "{name} boolean operand is self-intersecting near ({:.6}, {:.6}, {:.6})",
+                p.x, p.y, p.z
+            ))
+            .into());
+        }
+    }
+    Ok(())
 }
 
-/// [`boolean_execute`] with a caller-supplied operation identity for
-/// persistent-name evolution (NURBS path only; the planar pipeline does not
-/// name its entities yet).
+/// Executes a boolean operation on two solids.
+///
+/// Orchestrates the full pipeline: snap-rounding, face-face intersection,
+/// splitting, classification, selection, and assembly. `op_id` is the
+/// caller-supplied operation identity for persistent-name evolution (NURBS
+/// path only; the planar pipeline does not name its entities yet).
+///
+/// Vertices of `solid_b` within `merge_tol` of a face plane of `solid_a`
+/// are snapped onto it before splitting (see [`snap_to_planes`]), so
+/// near-coincident faces split as exactly coincident ones instead of
+/// leaving sliver fragments.
 pub fn boolean_execute_named(
     store: &mut TopologyStore,
     solid_a: SolidId,
     solid_b: SolidId,
     op: BooleanOp,
     op_id: Option<&crate::topology::OpId>,
+    merge_tol: f64,
 ) -> Result<SolidId> {
     // NURBS routing: if either solid has a NURBS face, the planar pipeline does
     // not apply. The through-cut subtract handles it; everything else returns an
@@ -40,7 +57,10 @@ pub fn boolean_execute_named(
         return super::nurbs::try_boolean(store, solid_a, solid_b, op, op_id);
     }
 
-    // Step 1: AABB early-out
+    // Step 1: Snap near-coincident vertices of B onto A's planes
+    let solid_b = snap_to_planes(store, solid_b, solid_a, merge_tol)?.unwrap_or(solid_b);
+
+    // Step 2: AABB early-out
     let aabb_a = compute_solid_aabb(store, solid_a)?;
     let aabb_b = compute_solid_aabb(store, solid_b)?;
 
@@ -48,11 +68,21 @@ pub fn boolean_execute_named(
         return handle_disjoint(store, solid_a, solid_b, op);
     }
 
-    // Step 2: Collect faces from both solids
+    // Overlapping solids must be planar from here on: face intersection and
+    // point classification only handle planes. (Snapping above already left
+    // a curved solid untouched.)
+    if !solid_is_planar(store, solid_a)? || !solid_is_planar(store, solid_b)? {
+        return Err(OperationError::Failed(
+            "boolean of overlapping solids with curved analytic faces is not supported".into(),
+        )
+        .into());
+    }
+
+    // Step 3: Collect faces from both solids
     let faces_a = collect_solid_faces(store, solid_a)?;
     let faces_b = collect_solid_faces(store, solid_b)?;
 
-    // Step 3: Compute all face-face intersections
+    // Step 4: Compute all face-face intersections
     let mut cuts_by_face: HashMap<FaceId, Vec<(Point3, Point3)>> = HashMap::new();
 
     for &fa in &faces_a {
@@ -76,32 +106,32 @@ pub fn boolean_execute_named(
         return handle_no_intersection(store, solid_a, solid_b, op);
     }
 
-    // Step 4: Split faces into fragments
+    // Step 5: Split faces into fragments
     let mut all_fragments: Vec<(FaceFragment, KeepDecision)> = Vec::new();
 
-    // Split faces from solid A
-    for &face_id in &faces_a {
-        let cuts = cuts_by_face.get(&face_id).map_or(&[][..], |v| v.as_slice());
-        let fragments = split_face(store, face_id, cuts, SolidSource::A)?;
-        for frag in fragments {
-            let classification = classify_fragment_centroid(store, &frag, solid_b)?;
-            let decision = should_keep_fragment(frag.source, classification, op);
-            all_fragments.push((frag, decision));
-        }
-    }
-
-    // Split faces from solid B
-    for &face_id in &faces_b {
-        let cuts = cuts_by_face.get(&face_id).map_or(&[][..], |v| v.as_slice());
-        let fragments = split_face(store, face_id, cuts, SolidSource::B)?;
-        for frag in fragments {
-            let classification = classify_fragment_centroid(store, &frag, solid_a)?;
-            let decision = should_keep_fragment(frag.source, classification, op);
-            all_fragments.push((frag, decision));
+    // Split faces from both solids; a fragment covered by a coincident face
+    // of the other solid is decided by orientation, the rest by position.
+    let planar_a = collect_planar_faces(store, &faces_a)?;
+    let planar_b = collect_planar_faces(store, &faces_b)?;
+    for (faces, source, other, other_planar) in [
+        (&faces_a, SolidSource::A, solid_b, &planar_b),
+        (&faces_b, SolidSource::B, solid_a, &planar_a),
+    ] {
+        for &face_id in faces {
+            let cuts = cuts_by_face.get(&face_id).map_or(&[][..], |v| v.as_slice());
+            for frag in split_face(store, face_id, cuts, source)? {
+                let decision = if let Some(same) = coincident_direction(&frag, other_planar) {
+                    should_keep_coincident(source, same, op)
+                } else {
+                    let classification = classify_fragment_centroid(store, &frag, other)?;
+                    should_keep_fragment(source, classification, op)
+                };
+                all_fragments.push((frag, decision));
+            }
         }
     }
 
-    // Step 5: Check if we have any kept fragments
+    // Step 6: Check if we have any kept fragments
     let kept_count = all_fragments
         .iter()
         .filter(|(_, d)| *d != KeepDecision::Discard)
@@ -113,13 +143,54 @@ pub fn boolean_execute_named(
         );
     }
 
-    // Step 6: Assemble the result
+    // Step 7: Assemble the result
     let assembled = assemble_result(store, &all_fragments)?;
 
-    // Step 7: Merge coplanar adjacent faces
+    // Step 8: Merge coplanar adjacent faces
     super::merge::merge_coplanar_faces(store, assembled)
 }
 
+/// Returns whether `solid_a` and `solid_b` might overlap: `false` only
+/// when their conservative bounding boxes are disjoint.
+pub(super) fn solids_may_overlap(
+    store: &TopologyStore,
+    solid_a: SolidId,
+    solid_b: SolidId,
+) -> Result<bool> {
+    match (
+        conservative_aabb(store, solid_a)?,
+        conservative_aabb(store, solid_b)?,
+    ) {
+        (Some(a), Some(b)) => Ok(aabb_overlap(&a, &b)),
+        _ => Ok(true),
+    }
+}
+
+/// A box containing the whole solid: its vertices plus the control points
+/// of NURBS faces (which bound the surface by the convex-hull property).
+/// `None` when an analytic curved face may bulge past both.
+fn conservative_aabb(store: &TopologyStore, solid_id: SolidId) -> Result<Option<Aabb>> {
+    let mut aabb = compute_solid_aabb(store, solid_id)?;
+    let shell = store.shell(store.solid(solid_id)?.outer_shell)?;
+    for &fid in &shell.faces {
+        match &store.face(fid)?.surface {
+            FaceSurface::Plane(_) => {}
+            FaceSurface::Nurbs(surface) => {
+                let (nu, nv) = surface.grid_size();
+                for i in 0..nu {
+                    for j in 0..nv {
+                        let p = surface.control_point(i, j);
+                        aabb.min = aabb.min.inf(p);
+                        aabb.max = aabb.max.sup(p);
+                    }
+                }
+            }
+            _ => return Ok(None),
+        }
+    }
+    Ok(Some(aabb))
+}
+
 /// Classifies a fragment's centroid against the other solid.
 fn classify_fragment_centroid(
     store: &TopologyStore,
@@ -139,6 +210,90 @@ fn classify_fragment_centroid(
     classify_point_in_solid(&test_point, other_solid, store)
 }
 
+/// A planar face's boundary and outward normal, for coincidence tests.
+pub(super) struct PlanarFace {
+    pub(super) polygon: Vec<Point3>,
+    pub(super) holes: Vec<Vec<Point3>>,
+    pub(super) plane: Plane,
+    pub(super) normal: Vector3,
+}
+
+/// Collects the planar faces among `faces` with their outward normals.
+pub(super) fn collect_planar_faces(
+    store: &TopologyStore,
+    faces: &[FaceId],
+) -> Result<Vec<PlanarFace>> {
+    let mut planar = Vec::with_capacity(faces.len());
+    for &face_id in faces {
+        let face = store.face(face_id)?;
+        let FaceSurface::Plane(plane) = &face.surface else {
+            continue;
+        };
+        let normal = if face.same_sense {
+            *plane.plane_normal()
+        } else {
+            -plane.plane_normal()
+        };
+        planar.push(PlanarFace {
+            polygon: collect_face_polygon(store, face_id)?,
+            holes: collect_inner_wire_polygons(store, face_id)?,
+            plane: plane.clone(),
+            normal,
+        });
+    }
+    Ok(planar)
+}
+
+/// If the fragment lies on a face of `others` that covers it entirely,
+/// returns whether the two faces point the same way.
+///
+/// Coplanar faces do not cut each other, so a fragment is either wholly
+/// on such a face (shared walls, flush caps) or only partly overlaps it;
+/// the latter falls back to centroid classification.
+fn coincident_direction(fragment: &FaceFragment, others: &[PlanarFace]) -> Option<bool> {
+    let tol = TOLERANCE * 100.0;
+    let normal = if fragment.same_sense {
+        *fragment.plane.plane_normal()
+    } else {
+        -fragment.plane.plane_normal()
+    };
+    let centroid = polygon_centroid(&fragment.boundary);
+    others.iter().find_map(|face| {
+        let alignment = face.normal.dot(&normal);
+        if alignment.abs() < 1.0 - tol {
+            return None;
+        }
+        let on_plane = |p: &Point3| (p - face.plane.origin()).dot(&face.normal).abs() <= tol;
+        let covered = fragment.boundary.iter().all(|p| {
+            on_plane(p)
+                && (point_in_polygon_3d(p, &face.polygon, &face.plane)
+                    || on_polygon_edge(p, &face.polygon, tol))
+        }) && point_in_polygon_3d(&centroid, &face.polygon, &face.plane)
+            && !face
+                .holes
+                .iter()
+                .any(|hole| point_in_polygon_3d(&centroid, hole, &face.plane));
+        covered.then_some(alignment > 0.0)
+    })
+}
+
+/// Whether `p` lies within `tol` of an edge of the closed `polygon`.
+pub(super) fn on_polygon_edge(p: &Point3, polygon: &[Point3], tol: f64) -> bool {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .any(|(a, b)| {
+            let ab = b - a;
+            let len_sq = ab.norm_squared();
+            let t = if len_sq > 0.0 {
+                ((p - a).dot(&ab) / len_sq).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            (a + ab * t - p).norm() <= tol
+        })
+}
+
 /// Computes the centroid of a polygon.
 fn polygon_centroid(points: &[Point3]) -> Point3 {
     let n = points.len();
@@ -165,11 +320,28 @@ fn solid_has_nurbs_face(store: &TopologyStore, solid_id: SolidId) -> Result<bool
     Ok(false)
 }
 
-/// Collects all face IDs from a solid's outer shell.
-fn collect_solid_faces(store: &TopologyStore, solid_id: SolidId) -> Result<Vec<FaceId>> {
+/// Whether every face of the solid's shells is planar.
+fn solid_is_planar(store: &TopologyStore, solid_id: SolidId) -> Result<bool> {
     let solid = store.solid(solid_id)?;
-    let shell = store.shell(solid.outer_shell)?;
-    Ok(shell.faces.clone())
+    for shell_id in std::iter::once(solid.outer_shell).chain(solid.inner_shells.iter().copied()) {
+        for &fid in &store.shell(shell_id)?.faces {
+            if !matches!(store.face(fid)?.surface, FaceSurface::Plane(_)) {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Collects all face IDs from a solid's shells, outer shell first, so
+/// that the faces bounding its voids take part in the boolean too.
+pub(super) fn collect_solid_faces(store: &TopologyStore, solid_id: SolidId) -> Result<Vec<FaceId>> {
+    let solid = store.solid(solid_id)?;
+    let mut faces = Vec::new();
+    for shell_id in std::iter::once(solid.outer_shell).chain(solid.inner_shells.iter().copied()) {
+        faces.extend_from_slice(&store.shell(shell_id)?.faces);
+    }
+    Ok(faces)
 }
 
 /// Axis-Aligned Bounding Box.
@@ -181,9 +353,6 @@ struct Aabb {
 
 /// Computes the AABB of a solid.
 fn compute_solid_aabb(store: &TopologyStore, solid_id: SolidId) -> Result<Aabb> {
-    let solid = store.solid(solid_id)?;
-    let shell = store.shell(solid.outer_shell)?;
-
     let mut min_x = f64::INFINITY;
     let mut min_y = f64::INFINITY;
     let mut min_z = f64::INFINITY;
@@ -191,7 +360,7 @@ fn compute_solid_aabb(store: &TopologyStore, solid_id: SolidId) -> Result<Aabb>
     let mut max_y = f64::NEG_INFINITY;
     let mut max_z = f64::NEG_INFINITY;
 
-    for &face_id in &shell.faces {
+    for face_id in collect_solid_faces(store, solid_id)? {
         let face = store.face(face_id)?;
         let wire = store.wire(face.outer_wire)?;
         for oe in &wire.edges {
@@ -259,64 +428,88 @@ fn handle_no_intersection(
     solid_b: SolidId,
     op: BooleanOp,
 ) -> Result<SolidId> {
-    // Sample a point from solid B to see if it's inside A
-    let b_sample = get_solid_vertex(store, solid_b)?;
-    let b_in_a = classify_point_in_solid(&b_sample, solid_a, store)?;
-
-    // Sample a point from solid A to see if it's inside B
-    let a_sample = get_solid_vertex(store, solid_a)?;
-    let a_in_b = classify_point_in_solid(&a_sample, solid_b, store)?;
-
-    match (a_in_b, b_in_a) {
-        (PointClassification::Inside, _) => {
-            // A is inside B
-            match op {
-                BooleanOp::Union => copy_solid(store, solid_b),
-                BooleanOp::Subtract => Err(OperationError::Failed(
-                    "subtraction where A is inside B produces empty result".into(),
-                )
-                .into()),
-                BooleanOp::Intersect => copy_solid(store, solid_a),
+    if solid_contained_in(store, solid_a, solid_b)? {
+        // A is inside B
+        return match op {
+            BooleanOp::Union => copy_solid(store, solid_b),
+            BooleanOp::Subtract => Err(OperationError::Failed(
+                "subtraction where A is inside B produces empty result".into(),
+            )
+            .into()),
+            BooleanOp::Intersect => copy_solid(store, solid_a),
+        };
+    }
+    if solid_contained_in(store, solid_b, solid_a)? {
+        // B is inside A
+        return match op {
+            BooleanOp::Union => copy_solid(store, solid_a),
+            // A - B where B is inside A => A with a void bounded by B
+            BooleanOp::Subtract => {
+                let result = copy_solid(store, solid_a)?;
+                let void = assemble_shell(
+                    store,
+                    &solid_fragments(store, solid_b, KeepDecision::KeepFlipped)?,
+                )?;
+                store.solid_mut(result)?.inner_shells.push(void);
+                Ok(result)
             }
+            BooleanOp::Intersect => copy_solid(store, solid_b),
+        };
+    }
+    // No clear containment — treat as disjoint
+    handle_disjoint(store, solid_a, solid_b, op)
+}
+
+/// Whether every vertex of `inner` lies strictly inside
+/// `outer`. Only meaningful once the solids are known not to cross: then
+/// the boundaries are disjoint and one inside vertex implies the rest, but
+/// sampling them all keeps a touching or mis-classified vertex from
+/// turning a face-sharing pair into a containment.
+fn solid_contained_in(store: &TopologyStore, inner: SolidId, outer: SolidId) -> Result<bool> {
+    for point in solid_vertices(store, inner)? {
+        if classify_point_in_solid(&point, outer, store)? != PointClassification::Inside {
+            return Ok(false);
         }
-        (_, PointClassification::Inside) => {
-            // B is inside A
-            match op {
-                BooleanOp::Union => copy_solid(store, solid_a),
-                BooleanOp::Subtract => {
-                    // A - B where B is inside A => A with a void
-                    // For now, return error — inner shells need more work
-                    Err(OperationError::Failed(
-                        "subtraction where B is fully inside A (void creation) is not yet supported".into(),
-                    )
-                    .into())
+    }
+    Ok(true)
+}
+
+/// Collects the distinct vertex positions of a solid's shells.
+fn solid_vertices(store: &TopologyStore, solid_id: SolidId) -> Result<Vec<Point3>> {
+    let mut seen = std::collections::HashSet::new();
+    let mut points = Vec::new();
+    for face_id in collect_solid_faces(store, solid_id)? {
+        let face = store.face(face_id)?;
+        for wire_id in std::iter::once(face.outer_wire).chain(face.inner_wires.iter().copied()) {
+            for oe in &store.wire(wire_id)?.edges {
+                let edge = store.edge(oe.edge)?;
+                for vid in [edge.start, edge.end] {
+                    if seen.insert(vid) {
+                        points.push(store.vertex(vid)?.point);
+                    }
                 }
-                BooleanOp::Intersect => copy_solid(store, solid_b),
             }
         }
-        _ => {
-            // No clear containment — treat as disjoint
-            handle_disjoint(store, solid_a, solid_b, op)
-        }
     }
+    Ok(points)
 }
 
-/// Gets a vertex position from a solid (for containment testing).
-fn get_solid_vertex(store: &TopologyStore, solid_id: SolidId) -> Result<Point3> {
-    let solid = store.solid(solid_id)?;
-    let shell = store.shell(solid.outer_shell)?;
-    let face = store.face(shell.faces[0])?;
-    let wire = store.wire(face.outer_wire)?;
-    let oe = &wire.edges[0];
-    let edge = store.edge(oe.edge)?;
-    let vertex = store.vertex(edge.start)?;
-    Ok(vertex.point)
+/// Creates a copy of a solid by duplicating all its topology, voids
+/// included.
+fn copy_solid(store: &mut TopologyStore, solid_id: SolidId) -> Result<SolidId> {
+    let fragments = solid_fragments(store, solid_id, KeepDecision::Keep)?;
+    assemble_result(store, &fragments)
 }
 
-/// Creates a copy of a solid by duplicating all its topology.
-fn copy_solid(store: &mut TopologyStore, solid_id: SolidId) -> Result<SolidId> {
+/// Converts every face of a solid's shells into a fragment carrying
+/// `decision`, for reassembly as a copy (`Keep`) or an inside-out copy
+/// (`KeepFlipped`).
+fn solid_fragments(
+    store: &TopologyStore,
+    solid_id: SolidId,
+    decision: KeepDecision,
+) -> Result<Vec<(FaceFragment, KeepDecision)>> {
     use super::face_intersection::{collect_face_polygon, collect_inner_wire_polygons};
-    use super::split::SolidSource;
 
     let faces = collect_solid_faces(store, solid_id)?;
     let mut fragments = Vec::new();
@@ -344,16 +537,17 @@ fn copy_solid(store: &mut TopologyStore, solid_id: SolidId) -> Result<SolidId> {
                 source_face: *face_id,
                 source: SolidSource::A,
             },
-            KeepDecision::Keep,
+            decision,
         ));
     }
 
-    assemble_result(store, &fragments)
+    Ok(fragments)
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
+    use super::super::snap::DEFAULT_MERGE_TOL;
     use super::*;
     use crate::math::Vector3;
     use crate::operations::creation::{MakeFace, MakeWire};
@@ -363,6 +557,15 @@ mod tests {
         Point3::new(x, y, z)
     }
 
+    fn boolean_execute(
+        store: &mut TopologyStore,
+        solid_a: SolidId,
+        solid_b: SolidId,
+        op: BooleanOp,
+    ) -> Result<SolidId> {
+        boolean_execute_named(store, solid_a, solid_b, op, None, DEFAULT_MERGE_TOL)
+    }
+
     fn make_box(
         store: &mut TopologyStore,
         x: f64,
@@ -548,6 +751,44 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn union_with_contained_box_is_outer_box() {
+        use crate::operations::query::Volume;
+
+        let mut store = TopologyStore::new();
+        let a = make_box(&mut store, 0.0, 0.0, 0.0, 10.0, 10.0, 10.0);
+        let b = make_box(&mut store, 4.0, 4.0, 4.0, 2.0, 2.0, 2.0);
+
+        let result = boolean_execute(&mut store, a, b, BooleanOp::Union).unwrap();
+        let solid = store.solid(result).unwrap();
+        assert!(solid.inner_shells.is_empty());
+        assert_eq!(store.shell(solid.outer_shell).unwrap().faces.len(), 6);
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 1000.0).abs() < 1e-6, "volume {volume}");
+    }
+
+    #[test]
+    fn subtract_contained_box_leaves_void() {
+        use crate::operations::query::Volume;
+
+        let mut store = TopologyStore::new();
+        let a = make_box(&mut store, 0.0, 0.0, 0.0, 10.0, 10.0, 10.0);
+        let b = make_box(&mut store, 4.0, 4.0, 4.0, 2.0, 2.0, 2.0);
+
+        let result = boolean_execute(&mut store, a, b, BooleanOp::Subtract).unwrap();
+        let solid = store.solid(result).unwrap();
+        assert_eq!(solid.inner_shells.len(), 1);
+        assert_eq!(store.shell(solid.inner_shells[0]).unwrap().faces.len(), 6);
+
+        let classify = |q: Point3| classify_point_in_solid(&q, result, &store).unwrap();
+        assert_eq!(classify(p(5.0, 5.0, 5.0)), PointClassification::Outside);
+        assert_eq!(classify(p(2.0, 5.0, 5.0)), PointClassification::Inside);
+        assert_eq!(classify(p(12.0, 5.0, 5.0)), PointClassification::Outside);
+
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 992.0).abs() < 1e-6, "volume {volume}");
+    }
+
     /// Builds an axis-aligned-footprint box rotated about the Z axis by `angle`
     /// (radians) around its planar center. Mirrors how revion authors a wall
     /// solid: an oriented rectangular footprint extruded along +Z.
@@ -952,4 +1193,81 @@ mod tests {
             "result should still have 2 faces with holes, got {result_holes}"
         );
     }
+
+    #[test]
+    fn subtract_near_coincident_box_leaves_no_slivers() {
+        let mut store = TopologyStore::new();
+        let a = make_box(&mut store, 0.0, 0.0, 0.0, 4.0, 4.0, 4.0);
+        // A through-notch whose top lands 1e-12 below A's top face.
+        let b = make_box(&mut store, 2.0, -1.0, -1.0 - 1e-12, 4.0, 6.0, 5.0);
+
+        let result = boolean_execute(&mut store, a, b, BooleanOp::Subtract).unwrap();
+        let shell = store
+            .shell(store.solid(result).unwrap().outer_shell)
+            .unwrap();
+        // The 0..2 half of A: a plain box, no skin or overlapping top faces.
+        assert_eq!(shell.faces.len(), 6);
+
+        let mesh = crate::tessellation::TessellateSolid::new(
+            result,
+            crate::tessellation::TessellationParams::default(),
+        )
+        .execute(&store)
+        .unwrap();
+        mesh.validate().unwrap();
+        assert!((mesh.signed_volume(Point3::origin()) - 32.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn snap_to_planes_respects_merge_tol() {
+        let mut store = TopologyStore::new();
+        let a = make_box(&mut store, 0.0, 0.0, 0.0, 4.0, 4.0, 4.0);
+        let b = make_box(&mut store, 2.0, -1.0, -1.0 - 1e-12, 4.0, 6.0, 5.0);
+        let snapped = snap_to_planes(&mut store, b, a, 0.0).unwrap();
+        assert!(snapped.is_none());
+        let snapped = snap_to_planes(&mut store, b, a, DEFAULT_MERGE_TOL)
+            .unwrap()
+            .unwrap();
+        let max_z = collect_solid_faces(&store, snapped)
+            .unwrap()
+            .iter()
+            .flat_map(|&f| {
+                super::super::face_intersection::collect_face_polygon(&store, f).unwrap()
+            })
+            .map(|p| p.z)
+            .fold(f64::NEG_INFINITY, f64::max);
+        assert!((max_z - 4.0).abs() < f64::EPSILON * 4.0);
+    }
+
+    #[test]
+    fn snap_to_planes_ignores_far_extensions_of_faces() {
+        let mut store = TopologyStore::new();
+        let a = make_box(&mut store, 0.0, 0.0, 0.0, 4.0, 4.0, 4.0);
+        // Level with A's top to within 1e-12, but beside A rather than on it.
+        let b = make_box(&mut store, 10.0, 0.0, -1e-12, 2.0, 2.0, 4.0);
+        assert!(snap_to_planes(&mut store, b, a, DEFAULT_MERGE_TOL)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn analytic_curved_solids_fail_cleanly_unless_disjoint() {
+        use crate::operations::creation::MakeCylinder;
+        let mut store = TopologyStore::new();
+        let a = make_box(&mut store, 0.0, 0.0, 0.0, 4.0, 4.0, 4.0);
+        let axis = Vector3::new(0.0, 0.0, 1.0);
+        let through = MakeCylinder::new(p(2.0, 2.0, -1.0), 1.0, axis, 6.0)
+            .execute(&mut store)
+            .unwrap();
+        assert!(boolean_execute(&mut store, a, through, BooleanOp::Subtract).is_err());
+
+        let apart = MakeCylinder::new(p(10.0, 2.0, 0.0), 1.0, axis, 4.0)
+            .execute(&mut store)
+            .unwrap();
+        let kept = boolean_execute(&mut store, a, apart, BooleanOp::Subtract).unwrap();
+        let volume = crate::operations::query::Volume::new(kept)
+            .execute(&store)
+            .unwrap();
+        assert!((volume - 64.0).abs() < 1e-9);
+    }
 }
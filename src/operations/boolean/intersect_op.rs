@@ -8,6 +8,8 @@ pub struct Intersect {
     solid_a: SolidId,
     solid_b: SolidId,
     op_id: Option<crate::topology::OpId>,
+    merge_tol: f64,
+    validate_inputs: bool,
 }
 
 impl Intersect {
@@ -18,6 +20,8 @@ impl Intersect {
             solid_a,
             solid_b,
             op_id: None,
+            merge_tol: super::DEFAULT_MERGE_TOL,
+            validate_inputs: false,
         }
     }
 
@@ -29,18 +33,80 @@ impl Intersect {
         self
     }
 
+    /// Sets the distance within which near-coincident vertices are snapped
+    /// together before splitting (default [`super::DEFAULT_MERGE_TOL`]).
+    #[must_use]
+    pub fn with_merge_tol(mut self, merge_tol: f64) -> Self {
+        self.merge_tol = merge_tol;
+        self
+    }
+
+    /// Rejects operands whose boundary crosses itself before running the
+    /// boolean (off by default). The check tessellates both solids, so it
+    /// costs roughly as much as meshing them.
+    #[must_use]
+    pub fn with_validate_inputs(mut self, validate_inputs: bool) -> Self {
+        self.validate_inputs = validate_inputs;
+        self
+    }
+
     /// Executes the intersection, creating the result solid in the topology store.
     ///
     /// # Errors
     ///
     /// Returns an error if the solids don't overlap or the operation fails.
     pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
+        if self.validate_inputs {
+            super::engine::reject_self_intersecting(store, [self.solid_a, self.solid_b])?;
+        }
         crate::operations::boolean::engine::boolean_execute_named(
             store,
             self.solid_a,
             self.solid_b,
             BooleanOp::Intersect,
             self.op_id.as_ref(),
+            self.merge_tol,
         )
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::Point3;
+    use crate::operations::creation::MakeBox;
+    use crate::operations::query::Volume;
+
+    #[test]
+    fn intersect_flush_boxes_gives_the_smaller_box() {
+        let mut store = TopologyStore::new();
+        let a = MakeBox::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+        // Shares A's -X, ±Y and ±Z face planes.
+        let b = MakeBox::new(Point3::origin(), Point3::new(1.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+
+        let result = Intersect::new(a, b).execute(&mut store).unwrap();
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 4.0).abs() < 1e-9, "volume {volume}");
+        let solid = store.solid(result).unwrap();
+        assert_eq!(store.shell(solid.outer_shell).unwrap().faces.len(), 6);
+    }
+
+    #[test]
+    fn intersect_face_adjacent_boxes_is_empty() {
+        let mut store = TopologyStore::new();
+        let a = MakeBox::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+        // Touches A's +X face over a patch without entering it.
+        let b = MakeBox::new(Point3::new(2.0, 0.5, 0.5), Point3::new(3.0, 1.5, 1.5))
+            .execute(&mut store)
+            .unwrap();
+
+        assert!(Intersect::new(a, b).execute(&mut store).is_err());
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use crate::error::{OperationError, Result};
 use crate::math::{Point3, Vector3, TOLERANCE};
 use crate::operations::creation::{MakeFace, MakeSolid, MakeWire};
-use crate::topology::{FaceId, FaceSurface, ShellData, SolidId, TopologyStore};
+use crate::topology::{FaceId, FaceSurface, ShellData, ShellId, SolidId, TopologyStore};
 
 /// Tolerance for grouping coplanar faces (plane distance comparison).
 const COPLANAR_DISTANCE_TOL: f64 = 1e-6;
@@ -28,14 +28,47 @@ struct FaceInfo {
 ///
 /// Groups faces that share the same plane, finds connected components
 /// (faces sharing edges), and merges each component into a single face
-/// with potential inner wires (holes).
+/// with potential inner wires (holes). Each shell is merged on its own,
+/// so the solid's voids survive as inner shells.
 pub fn merge_coplanar_faces(store: &mut TopologyStore, solid_id: SolidId) -> Result<SolidId> {
-    let face_infos = collect_all_face_info(store, solid_id)?;
+    let solid = store.solid(solid_id)?;
+    let shell_ids: Vec<ShellId> = std::iter::once(solid.outer_shell)
+        .chain(solid.inner_shells.iter().copied())
+        .collect();
+
+    let mut shells = Vec::with_capacity(shell_ids.len());
+    let mut changed = false;
+    for shell_id in shell_ids {
+        let faces = store.shell(shell_id)?.faces.clone();
+        if let Some(merged) = merge_shell_faces(store, &faces)? {
+            changed = true;
+            shells.push(store.add_shell(ShellData {
+                faces: merged,
+                is_closed: true,
+            }));
+        } else {
+            shells.push(shell_id);
+        }
+    }
 
-    if face_infos.is_empty() {
+    // If nothing was merged, return the original solid
+    if !changed {
         return Ok(solid_id);
     }
 
+    let outer_shell = shells.remove(0);
+    MakeSolid::new(outer_shell, shells).execute(store)
+}
+
+/// Merges coplanar adjacent faces among one shell's `faces`, returning the
+/// new face list, or `None` when nothing merged.
+fn merge_shell_faces(store: &mut TopologyStore, faces: &[FaceId]) -> Result<Option<Vec<FaceId>>> {
+    let face_infos = collect_all_face_info(store, faces)?;
+
+    if face_infos.is_empty() {
+        return Ok(None);
+    }
+
     let groups = group_coplanar(&face_infos);
 
     let mut merged_face_ids: Vec<FaceId> = Vec::new();
@@ -69,9 +102,8 @@ pub fn merge_coplanar_faces(store: &mut TopologyStore, solid_id: SolidId) -> Res
         }
     }
 
-    // If nothing was merged, return the original solid
     if consumed.is_empty() {
-        return Ok(solid_id);
+        return Ok(None);
     }
 
     // Collect non-merged faces
@@ -81,22 +113,13 @@ pub fn merge_coplanar_faces(store: &mut TopologyStore, solid_id: SolidId) -> Res
         }
     }
 
-    // Build new shell and solid
-    let shell_id = store.add_shell(ShellData {
-        faces: merged_face_ids,
-        is_closed: true,
-    });
-    MakeSolid::new(shell_id, vec![]).execute(store)
+    Ok(Some(merged_face_ids))
 }
 
-/// Collects face info for all faces of a solid.
-fn collect_all_face_info(store: &TopologyStore, solid_id: SolidId) -> Result<Vec<FaceInfo>> {
-    let solid = store.solid(solid_id)?;
-    let shell = store.shell(solid.outer_shell)?;
-    let face_ids = shell.faces.clone();
-
+/// Collects face info for each of `face_ids`.
+fn collect_all_face_info(store: &TopologyStore, face_ids: &[FaceId]) -> Result<Vec<FaceInfo>> {
     let mut infos = Vec::with_capacity(face_ids.len());
-    for face_id in face_ids {
+    for &face_id in face_ids {
         infos.push(collect_face_info(store, face_id)?);
     }
     Ok(infos)
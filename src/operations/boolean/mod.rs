@@ -6,14 +6,20 @@ mod intersect_op;
 mod merge;
 mod nurbs;
 mod select;
+mod snap;
+mod solid_classifier;
 mod split;
 mod subtract;
+mod trim_shell;
 mod union;
 
 pub use classify::{classify_point_in_solid, PointClassification};
 pub use face_intersection::{intersect_face_face, FaceFaceIntersection};
 pub use intersect_op::Intersect;
 pub use select::BooleanOp;
+pub use snap::DEFAULT_MERGE_TOL;
+pub use solid_classifier::SolidClassifier;
 pub use split::{FaceFragment, SolidSource};
-pub use subtract::Subtract;
-pub use union::Union;
+pub use subtract::{Subtract, SubtractMany};
+pub use trim_shell::{KeepSide, TrimShell};
+pub use union::{Union, UnionMany};
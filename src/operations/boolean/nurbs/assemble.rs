@@ -730,6 +730,7 @@ pub(crate) fn copy_face(store: &mut TopologyStore, face: FaceId) -> Result<FaceI
         // The copy references the same shared boundary edges, so the per-edge
         // UV images remain valid on the copy.
         pcurves: src.pcurves.clone(),
+        u_seam: src.u_seam,
     };
     Ok(store.add_face(data))
 }
@@ -138,6 +138,7 @@ pub(crate) fn build_band_face_oriented(
         same_sense,
         trim: Some(trim),
         pcurves: Vec::new(),
+        u_seam: None,
     }))
 }
 
@@ -184,6 +185,7 @@ pub(crate) fn build_pocket_band_face(
         same_sense: false,
         trim: Some(trim),
         pcurves: Vec::new(),
+        u_seam: None,
     }))
 }
 
@@ -393,6 +395,7 @@ pub(crate) fn build_band_fragments(
             same_sense: false,
             trim: Some(trim),
             pcurves,
+            u_seam: None,
         });
         fragments.push(BandFragment { tool_face, face });
     }
@@ -518,6 +521,7 @@ pub(crate) fn build_open_band_fragments(
             same_sense: false,
             trim: Some(trim),
             pcurves,
+            u_seam: None,
         });
         fragments.push(BandFragment { tool_face, face });
     }
@@ -631,6 +635,7 @@ pub(crate) fn build_pocket_band_fragments(
             same_sense: false,
             trim: Some(trim),
             pcurves: Vec::new(),
+            u_seam: None,
         });
         fragments.push(BandFragment { tool_face, face });
     }
@@ -859,6 +864,7 @@ fn straight_edge(
         curve: EdgeCurve::Nurbs(curve),
         t_start: t0,
         t_end: t1,
+        tessellation_tolerance: None,
     }))
 }
 
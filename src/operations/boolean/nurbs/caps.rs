@@ -149,6 +149,7 @@ fn rebuild_cap(
             same_sense: face.same_sense,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         }));
     }
 
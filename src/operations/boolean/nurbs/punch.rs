@@ -309,6 +309,7 @@ pub(crate) fn build_ring_wire(store: &mut TopologyStore, points: &[Point3]) -> R
         curve: EdgeCurve::Nurbs(curve),
         t_start: t0,
         t_end: t1,
+        tessellation_tolerance: None,
     });
     Ok(store.add_wire(WireData {
         edges: vec![OrientedEdge::new(edge, true)],
@@ -182,6 +182,7 @@ pub(crate) fn trace_edge(
         curve: EdgeCurve::Nurbs(curve),
         t_start: t0,
         t_end: t1,
+        tessellation_tolerance: None,
     });
     Ok((edge, pcurve, tool_pcurve))
 }
@@ -1066,6 +1067,7 @@ impl EdgeSplitter {
                     curve: parent.curve.clone(),
                     t_start: t0,
                     t_end: t1,
+                    tessellation_tolerance: parent.tessellation_tolerance,
                 });
                 subs.push(SubEdge {
                     id,
@@ -1556,6 +1558,7 @@ fn build_fragments(
             same_sense: parent.same_sense,
             trim: Some(trim),
             pcurves: build.pcurves,
+            u_seam: None,
         });
         fragments.push(Fragment {
             face,
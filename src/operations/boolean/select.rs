@@ -74,6 +74,36 @@ pub fn should_keep_fragment(
     }
 }
 
+/// Determines whether a fragment lying on a coincident face of the other
+/// solid should be kept, from whether the two faces point the same way.
+///
+/// | Fragment | Coincident face | Union | Subtract(A-B) | Intersect |
+/// |----------|-----------------|-------|----------------|-----------|
+/// | from A   | same direction  | keep  | discard        | keep      |
+/// | from A   | opposite        | discard | keep         | discard   |
+/// | from B   | either          | discard | discard      | discard   |
+///
+/// A shared face keeps a single copy (A's) where the result still has a
+/// boundary there; faces where the two solids merely touch or meet inside
+/// the result are dropped.
+#[must_use]
+pub fn should_keep_coincident(
+    source: SolidSource,
+    same_direction: bool,
+    op: BooleanOp,
+) -> KeepDecision {
+    let keep = match (source, op) {
+        (SolidSource::B, _) => false,
+        (SolidSource::A, BooleanOp::Union | BooleanOp::Intersect) => same_direction,
+        (SolidSource::A, BooleanOp::Subtract) => !same_direction,
+    };
+    if keep {
+        KeepDecision::Keep
+    } else {
+        KeepDecision::Discard
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +199,39 @@ mod tests {
             KeepDecision::Keep
         );
     }
+
+    #[test]
+    fn coincident_faces_keep_one_copy_of_outer_boundary() {
+        use BooleanOp::{Intersect, Subtract, Union};
+        let a = SolidSource::A;
+        assert_eq!(should_keep_coincident(a, true, Union), KeepDecision::Keep);
+        assert_eq!(
+            should_keep_coincident(a, false, Union),
+            KeepDecision::Discard
+        );
+        assert_eq!(
+            should_keep_coincident(a, true, Subtract),
+            KeepDecision::Discard
+        );
+        assert_eq!(
+            should_keep_coincident(a, false, Subtract),
+            KeepDecision::Keep
+        );
+        assert_eq!(
+            should_keep_coincident(a, true, Intersect),
+            KeepDecision::Keep
+        );
+        assert_eq!(
+            should_keep_coincident(a, false, Intersect),
+            KeepDecision::Discard
+        );
+        for op in [Union, Subtract, Intersect] {
+            for same in [true, false] {
+                assert_eq!(
+                    should_keep_coincident(SolidSource::B, same, op),
+                    KeepDecision::Discard
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,198 @@
+//! Snap-rounding of near-coincident input geometry.
+//!
+//! When a face of one solid lies microscopically off a face of the other,
+//! their intersection vertices land a hair apart: cut lines narrowly miss
+//! the faces they should split, and the fragments between them become
+//! slivers that vertex merging later collapses into overlapping faces.
+//! Snapping such vertices exactly onto the nearby face before splitting
+//! turns the near-coincidence into an exact one.
+
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::geometry::surface::Plane;
+use crate::math::intersect_3d::signed_distance_to_plane;
+use crate::math::polygon_3d::point_in_polygon_3d;
+use crate::math::{Point3, Vector3, TOLERANCE};
+use crate::topology::{FaceSurface, SolidId, TopologyStore};
+
+use super::assemble::assemble_result;
+use super::engine::{collect_planar_faces, collect_solid_faces, on_polygon_edge, PlanarFace};
+use super::face_intersection::{collect_face_polygon, collect_inner_wire_polygons};
+use super::select::KeepDecision;
+use super::split::{FaceFragment, SolidSource};
+
+/// Default distance within which vertices are snapped onto faces of the
+/// other solid.
+pub const DEFAULT_MERGE_TOL: f64 = crate::math::TOLERANCE * 100.0;
+
+/// Projection passes per vertex, so a vertex near an edge or corner of
+/// `target` converges onto every plane it is close to.
+const SNAP_PASSES: usize = 3;
+
+/// Returns a copy of `solid` with its near-coincident vertices snapped onto
+/// faces of `target`, or `None` when no vertex needs to move.
+///
+/// A vertex within `merge_tol` of a planar face of `target` is projected
+/// onto that face's plane when the projection lands on the bounded face,
+/// and every vertex of a face lying within `merge_tol` of a parallel,
+/// overlapping face of `target` is projected onto it. Vertices that are
+/// merely close to the infinite extension of a face stay put. Snapped
+/// faces get their planes refitted to the moved boundary; if a snap would
+/// bend a face out of plane, `solid` is left unsnapped and `None` is
+/// returned. Solids with curved faces are never snapped: they cannot be
+/// rebuilt from vertex loops, and the boolean rejects them anyway unless
+/// they turn out to be disjoint.
+///
+/// # Errors
+///
+/// Returns an error if the topology cannot be read or the snapped copy
+/// cannot be assembled.
+pub fn snap_to_planes(
+    store: &mut TopologyStore,
+    solid: SolidId,
+    target: SolidId,
+    merge_tol: f64,
+) -> Result<Option<SolidId>> {
+    if merge_tol <= 0.0 {
+        return Ok(None);
+    }
+    let targets = collect_planar_faces(store, &collect_solid_faces(store, target)?)?;
+
+    let mut faces = Vec::new();
+    for face_id in collect_solid_faces(store, solid)? {
+        let face = store.face(face_id)?;
+        let FaceSurface::Plane(ref plane) = face.surface else {
+            // Curved faces cannot be rebuilt from their vertex loops.
+            return Ok(None);
+        };
+        faces.push(FaceFragment {
+            boundary: collect_face_polygon(store, face_id)?,
+            inner_boundaries: collect_inner_wire_polygons(store, face_id)?,
+            plane: plane.clone(),
+            same_sense: face.same_sense,
+            source_face: face_id,
+            source: SolidSource::B,
+        });
+    }
+
+    // Target faces each vertex (keyed by its exact coordinates) snaps onto.
+    let key = |p: &Point3| [p.x.to_bits(), p.y.to_bits(), p.z.to_bits()];
+    let mut snaps: HashMap<[u64; 3], Vec<usize>> = HashMap::new();
+    for face in &faces {
+        let loops = || std::iter::once(&face.boundary).chain(&face.inner_boundaries);
+        for (t, target_face) in targets.iter().enumerate() {
+            let whole_face = face_near_coplanar(face, target_face, merge_tol);
+            for p in loops().flatten() {
+                if whole_face || vertex_near_face(p, target_face, merge_tol) {
+                    let entry = snaps.entry(key(p)).or_default();
+                    if !entry.contains(&t) {
+                        entry.push(t);
+                    }
+                }
+            }
+        }
+    }
+    if snaps.is_empty() {
+        return Ok(None);
+    }
+    let snap = |p: &Point3| -> Point3 {
+        let Some(onto) = snaps.get(&key(p)) else {
+            return *p;
+        };
+        let mut q = *p;
+        for _ in 0..SNAP_PASSES {
+            for &t in onto {
+                let face = &targets[t];
+                q -= face.plane.plane_normal() * signed_distance_to_plane(&q, &face.plane);
+            }
+        }
+        q
+    };
+
+    let mut moved = false;
+    let mut fragments = Vec::with_capacity(faces.len());
+    for mut face in faces {
+        let boundary: Vec<Point3> = face.boundary.iter().map(&snap).collect();
+        let inner_boundaries: Vec<Vec<Point3>> = face
+            .inner_boundaries
+            .iter()
+            .map(|hole| hole.iter().map(&snap).collect())
+            .collect();
+        if boundary != face.boundary || inner_boundaries != face.inner_boundaries {
+            let Some(plane) = refit_plane(&face.plane, &boundary, &inner_boundaries) else {
+                return Ok(None);
+            };
+            face.plane = plane;
+            face.boundary = boundary;
+            face.inner_boundaries = inner_boundaries;
+            moved = true;
+        }
+        fragments.push((face, KeepDecision::Keep));
+    }
+
+    if !moved {
+        return Ok(None);
+    }
+    assemble_result(store, &fragments).map(Some)
+}
+
+/// Whether `p` lies within `tol` of `face`'s plane with its projection on
+/// the bounded face (inside it or within `tol` of its boundary).
+fn vertex_near_face(p: &Point3, face: &PlanarFace, tol: f64) -> bool {
+    let d = signed_distance_to_plane(p, &face.plane);
+    if d == 0.0 || d.abs() >= tol {
+        return false;
+    }
+    let q = p - face.plane.plane_normal() * d;
+    point_in_polygon_3d(&q, &face.polygon, &face.plane) || on_polygon_edge(&q, &face.polygon, tol)
+}
+
+/// Whether the whole boundary of `face` lies within `tol` of the parallel
+/// `target` face's plane, with bounding boxes that overlap.
+fn face_near_coplanar(face: &FaceFragment, target: &PlanarFace, tol: f64) -> bool {
+    let normal = face.plane.plane_normal();
+    if normal.dot(&target.normal).abs() < 1.0 - TOLERANCE {
+        return false;
+    }
+    let near = face
+        .boundary
+        .iter()
+        .all(|p| signed_distance_to_plane(p, &target.plane).abs() < tol);
+    let bounds = |points: &[Point3]| {
+        points
+            .iter()
+            .fold((points[0], points[0]), |(lo, hi), p| (lo.inf(p), hi.sup(p)))
+    };
+    if !near || face.boundary.is_empty() || target.polygon.is_empty() {
+        return false;
+    }
+    let ((a_lo, a_hi), (b_lo, b_hi)) = (bounds(&face.boundary), bounds(&target.polygon));
+    (0..3).all(|d| a_lo[d] <= b_hi[d] + tol && b_lo[d] <= a_hi[d] + tol)
+}
+
+/// Refits `plane` to a snapped outer boundary, keeping its orientation and
+/// as much of its `u` direction as possible, or `None` if the snapped loops
+/// are no longer planar.
+fn refit_plane(plane: &Plane, boundary: &[Point3], holes: &[Vec<Point3>]) -> Option<Plane> {
+    let origin = *boundary.first()?;
+    // Newell's normal of the outer boundary.
+    let newell: Vector3 = boundary
+        .iter()
+        .zip(boundary.iter().cycle().skip(1))
+        .map(|(a, b)| (a - origin).cross(&(b - origin)))
+        .sum();
+    let mut normal = newell.try_normalize(TOLERANCE)?;
+    if normal.dot(plane.plane_normal()) < 0.0 {
+        normal = -normal;
+    }
+    let planar = boundary
+        .iter()
+        .chain(holes.iter().flatten())
+        .all(|p| (p - origin).dot(&normal).abs() <= TOLERANCE);
+    if !planar {
+        return None;
+    }
+    let u = (plane.u_dir() - normal * plane.u_dir().dot(&normal)).try_normalize(TOLERANCE)?;
+    Plane::new(origin, u, normal.cross(&u)).ok()
+}
@@ -0,0 +1,301 @@
+//! Batch point classification against a solid's triangle mesh.
+//!
+//! [`classify_point_in_solid`](super::classify_point_in_solid) walks every
+//! face for every query. [`SolidClassifier`] tessellates the solid once and
+//! indexes the triangles in a bounding-volume hierarchy, so each query only
+//! tests the triangles near its ray.
+
+use crate::error::{OperationError, Result};
+use crate::math::intersect_3d::line_triangle_intersect;
+use crate::math::{Point3, Vector3, TOLERANCE};
+use crate::tessellation::{TessellateSolid, TessellationParams};
+use crate::topology::{SolidId, TopologyStore};
+
+use super::PointClassification;
+
+/// Maximum number of triangles in a BVH leaf.
+const LEAF_SIZE: usize = 4;
+
+/// Distance below which a point counts as lying on a triangle.
+const BOUNDARY_TOL: f64 = TOLERANCE * 10.0;
+
+/// Barycentric margin below which a ray hit counts as grazing an edge.
+const EDGE_EPS: f64 = 1e-9;
+
+/// Möller–Trumbore determinant below which a ray counts as parallel to a
+/// triangle. Absolute: the rays are unit length.
+const MIN_DET: f64 = TOLERANCE * TOLERANCE;
+
+/// Ray directions tried in turn until one avoids every triangle edge.
+/// Skewed so that axis-aligned faces and their diagonals are not grazed.
+const RAY_DIRECTIONS: [[f64; 3]; 4] = [
+    [0.857_142_9, 0.428_571_4, 0.285_714_3],
+    [-0.267_261_2, 0.801_783_7, 0.534_522_5],
+    [0.301_511_3, -0.301_511_3, 0.904_534_0],
+    [1.0, 0.0, 0.0],
+];
+
+/// A solid prepared for fast repeated point classification.
+///
+/// Classification is against the solid's tessellation, so it is exact for
+/// planar solids and accurate to the tessellation tolerance for curved
+/// ones. Void shells are included: a point inside a cavity is outside.
+#[derive(Debug, Clone)]
+pub struct SolidClassifier {
+    triangles: Vec<[Point3; 3]>,
+    nodes: Vec<BvhNode>,
+}
+
+#[derive(Debug, Clone)]
+struct BvhNode {
+    min: Point3,
+    max: Point3,
+    content: NodeContent,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NodeContent {
+    /// Triangles `start..end` of the reordered triangle list.
+    Leaf { start: usize, end: usize },
+    /// Child node indices.
+    Split { left: usize, right: usize },
+}
+
+enum RayCastResult {
+    Clear(PointClassification),
+    Degenerate,
+}
+
+impl SolidClassifier {
+    /// Tessellates `solid` with default parameters and builds the triangle
+    /// hierarchy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the solid cannot be tessellated.
+    pub fn build(store: &TopologyStore, solid: SolidId) -> Result<Self> {
+        let mesh = TessellateSolid::new(solid, TessellationParams::default()).execute(store)?;
+        let mut triangles: Vec<[Point3; 3]> = mesh
+            .indices
+            .iter()
+            .map(|tri| tri.map(|i| mesh.vertices[i as usize]))
+            .collect();
+        let mut nodes = Vec::new();
+        if !triangles.is_empty() {
+            let len = triangles.len();
+            build_node(&mut triangles, 0, len, &mut nodes);
+        }
+        Ok(Self { triangles, nodes })
+    }
+
+    /// Classifies `point` as inside, outside, or on the boundary of the
+    /// solid.
+    ///
+    /// Rays are cast along skewed directions, then the x axis, until one
+    /// avoids every triangle edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::Failed` if every ray grazes an edge or
+    /// vertex, so the point cannot be classified. (The face-walking
+    /// [`classify_point_in_solid`](super::classify_point_in_solid) answers
+    /// `Outside` in that case.)
+    pub fn classify(&self, point: &Point3) -> Result<PointClassification> {
+        self.classify_along(point, &RAY_DIRECTIONS)
+    }
+
+    fn classify_along<'a>(
+        &self,
+        point: &Point3,
+        directions: impl IntoIterator<Item = &'a [f64; 3]>,
+    ) -> Result<PointClassification> {
+        for d in directions {
+            let dir = Vector3::new(d[0], d[1], d[2]);
+            if let RayCastResult::Clear(c) = self.ray_cast(point, &dir) {
+                return Ok(c);
+            }
+        }
+        Err(OperationError::Failed(format!(
+            "cannot classify point ({}, {}, {}): every ray grazes an edge or vertex",
+            point.x, point.y, point.z
+        ))
+        .into())
+    }
+
+    /// Counts the triangles crossed by the ray from `origin` along `dir`.
+    fn ray_cast(&self, origin: &Point3, dir: &Vector3) -> RayCastResult {
+        if self.nodes.is_empty() {
+            return RayCastResult::Clear(PointClassification::Outside);
+        }
+        let mut crossings = 0u32;
+        let mut stack = vec![0];
+        while let Some(i) = stack.pop() {
+            let node = &self.nodes[i];
+            if !ray_hits_box(origin, dir, &node.min, &node.max) {
+                continue;
+            }
+            match node.content {
+                NodeContent::Split { left, right } => stack.extend([left, right]),
+                NodeContent::Leaf { start, end } => {
+                    for tri in &self.triangles[start..end] {
+                        let Some((t, bary)) = line_triangle_intersect(origin, dir, tri, MIN_DET)
+                        else {
+                            continue;
+                        };
+                        let inside = bary.iter().all(|&b| b >= -EDGE_EPS);
+                        if !inside {
+                            continue;
+                        }
+                        if t.abs() <= BOUNDARY_TOL {
+                            return RayCastResult::Clear(PointClassification::OnBoundary);
+                        }
+                        if t < 0.0 {
+                            continue;
+                        }
+                        if bary.iter().any(|&b| b <= EDGE_EPS) {
+                            return RayCastResult::Degenerate;
+                        }
+                        crossings += 1;
+                    }
+                }
+            }
+        }
+        RayCastResult::Clear(if crossings % 2 == 1 {
+            PointClassification::Inside
+        } else {
+            PointClassification::Outside
+        })
+    }
+}
+
+/// Builds the subtree over `triangles[start..end]`, returning its index.
+fn build_node(
+    triangles: &mut [[Point3; 3]],
+    start: usize,
+    end: usize,
+    nodes: &mut Vec<BvhNode>,
+) -> usize {
+    let (mut min, mut max) = (triangles[start][0], triangles[start][0]);
+    for tri in &triangles[start..end] {
+        for p in tri {
+            min = min.inf(p);
+            max = max.sup(p);
+        }
+    }
+    let pad = Vector3::repeat(BOUNDARY_TOL);
+    let (min, max) = (min - pad, max + pad);
+
+    let index = nodes.len();
+    nodes.push(BvhNode {
+        min,
+        max,
+        content: NodeContent::Leaf { start, end },
+    });
+    if end - start <= LEAF_SIZE {
+        return index;
+    }
+
+    // Median split on the longest axis of the box.
+    let extent = max - min;
+    let axis = extent.imax();
+    let centroid = |tri: &[Point3; 3]| tri[0][axis] + tri[1][axis] + tri[2][axis];
+    let mid = usize::midpoint(start, end);
+    triangles[start..end]
+        .select_nth_unstable_by(mid - start, |a, b| centroid(a).total_cmp(&centroid(b)));
+
+    let left = build_node(triangles, start, mid, nodes);
+    let right = build_node(triangles, mid, end, nodes);
+    nodes[index].content = NodeContent::Split { left, right };
+    index
+}
+
+/// Slab test of the ray `origin + t * dir`, `t >= -BOUNDARY_TOL`, against
+/// an axis-aligned box.
+fn ray_hits_box(origin: &Point3, dir: &Vector3, min: &Point3, max: &Point3) -> bool {
+    let (mut t_near, mut t_far) = (-BOUNDARY_TOL, f64::INFINITY);
+    for i in 0..3 {
+        if dir[i].abs() < TOLERANCE {
+            if origin[i] < min[i] || origin[i] > max[i] {
+                return false;
+            }
+            continue;
+        }
+        let t1 = (min[i] - origin[i]) / dir[i];
+        let t2 = (max[i] - origin[i]) / dir[i];
+        t_near = t_near.max(t1.min(t2));
+        t_far = t_far.min(t1.max(t2));
+        if t_near > t_far {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::operations::boolean::classify_point_in_solid;
+    use crate::operations::creation::MakeBox;
+
+    #[test]
+    fn grid_matches_per_point_classification() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+        let classifier = SolidClassifier::build(&store, solid).unwrap();
+
+        let coords: Vec<f64> = (0..8).map(|i| -0.75 + 0.5 * f64::from(i)).collect();
+        let (mut inside, mut outside) = (0, 0);
+        for &x in &coords {
+            for &y in &coords {
+                for &z in &coords {
+                    let p = Point3::new(x, y, z);
+                    let expected = classify_point_in_solid(&p, solid, &store).unwrap();
+                    assert_eq!(classifier.classify(&p).unwrap(), expected, "at {p:?}");
+                    match expected {
+                        PointClassification::Inside => inside += 1,
+                        PointClassification::Outside => outside += 1,
+                        PointClassification::OnBoundary => {}
+                    }
+                }
+            }
+        }
+        assert_eq!(inside, 64);
+        assert_eq!(outside, 512 - 64);
+    }
+
+    #[test]
+    fn point_on_face_is_boundary() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+        let classifier = SolidClassifier::build(&store, solid).unwrap();
+        assert_eq!(
+            classifier.classify(&Point3::new(1.0, 2.0, 0.5)).unwrap(),
+            PointClassification::OnBoundary
+        );
+    }
+
+    #[test]
+    fn all_grazing_rays_are_an_error() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+        let classifier = SolidClassifier::build(&store, solid).unwrap();
+
+        // From (-1, 0, 0) these rays pass through the corner (0, 0, 0) and
+        // the edge points (0, 1, 0) and (0, 0, 1): no direction is clean.
+        let point = Point3::new(-1.0, 0.0, 0.0);
+        let grazing = [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 0.0, 1.0]];
+        assert!(classifier.classify_along(&point, &grazing).is_err());
+        // The default skewed directions miss the box cleanly.
+        assert_eq!(
+            classifier.classify(&point).unwrap(),
+            PointClassification::Outside
+        );
+    }
+}
@@ -159,7 +159,7 @@ fn associate_inner_fragments(
 }
 
 /// Computes the centroid of a polygon.
-fn polygon_centroid(points: &[Point3]) -> Point3 {
+pub(super) fn polygon_centroid(points: &[Point3]) -> Point3 {
     let n = points.len();
     if n == 0 {
         return Point3::new(0.0, 0.0, 0.0);
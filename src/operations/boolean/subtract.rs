@@ -1,6 +1,7 @@
 use crate::error::Result;
 use crate::topology::{SolidId, TopologyStore};
 
+use super::engine::solids_may_overlap;
 use super::select::BooleanOp;
 
 /// Computes the boolean subtraction of one solid from another.
@@ -8,6 +9,8 @@ pub struct Subtract {
     solid_a: SolidId,
     solid_b: SolidId,
     op_id: Option<crate::topology::OpId>,
+    merge_tol: f64,
+    validate_inputs: bool,
 }
 
 impl Subtract {
@@ -18,6 +21,8 @@ impl Subtract {
             solid_a,
             solid_b,
             op_id: None,
+            merge_tol: super::DEFAULT_MERGE_TOL,
+            validate_inputs: false,
         }
     }
 
@@ -29,18 +34,311 @@ impl Subtract {
         self
     }
 
+    /// Sets the distance within which near-coincident vertices are snapped
+    /// together before splitting (default [`super::DEFAULT_MERGE_TOL`]).
+    #[must_use]
+    pub fn with_merge_tol(mut self, merge_tol: f64) -> Self {
+        self.merge_tol = merge_tol;
+        self
+    }
+
+    /// Rejects operands whose boundary crosses itself before running the
+    /// boolean (off by default). The check tessellates both solids, so it
+    /// costs roughly as much as meshing them.
+    #[must_use]
+    pub fn with_validate_inputs(mut self, validate_inputs: bool) -> Self {
+        self.validate_inputs = validate_inputs;
+        self
+    }
+
+    /// Starts subtracting every solid in `tools` from `target` in turn.
+    ///
+    /// Configure the returned [`SubtractMany`] like a pairwise
+    /// [`Subtract`] and call [`SubtractMany::execute`] to run it.
+    #[must_use]
+    pub fn many(target: SolidId, tools: Vec<SolidId>) -> SubtractMany {
+        SubtractMany {
+            target,
+            tools,
+            op_id: None,
+            merge_tol: super::DEFAULT_MERGE_TOL,
+            validate_inputs: false,
+        }
+    }
+
     /// Executes the subtraction, creating the result solid in the topology store.
     ///
     /// # Errors
     ///
     /// Returns an error if the operation fails.
     pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
+        if self.validate_inputs {
+            super::engine::reject_self_intersecting(store, [self.solid_a, self.solid_b])?;
+        }
         crate::operations::boolean::engine::boolean_execute_named(
             store,
             self.solid_a,
             self.solid_b,
             BooleanOp::Subtract,
             self.op_id.as_ref(),
+            self.merge_tol,
         )
     }
 }
+
+/// Subtracts a list of tool solids from a target, as built by
+/// [`Subtract::many`].
+pub struct SubtractMany {
+    target: SolidId,
+    tools: Vec<SolidId>,
+    op_id: Option<crate::topology::OpId>,
+    merge_tol: f64,
+    validate_inputs: bool,
+}
+
+impl SubtractMany {
+    /// Evolves persistent names through every step under the
+    /// caller-supplied operation identity (see [`Subtract::with_op_id`]).
+    #[must_use]
+    pub fn with_op_id(mut self, op: crate::topology::OpId) -> Self {
+        self.op_id = Some(op);
+        self
+    }
+
+    /// Sets the merge tolerance passed to every step
+    /// (see [`Subtract::with_merge_tol`]).
+    #[must_use]
+    pub fn with_merge_tol(mut self, merge_tol: f64) -> Self {
+        self.merge_tol = merge_tol;
+        self
+    }
+
+    /// Enables operand validation on every step
+    /// (see [`Subtract::with_validate_inputs`]).
+    #[must_use]
+    pub fn with_validate_inputs(mut self, validate_inputs: bool) -> Self {
+        self.validate_inputs = validate_inputs;
+        self
+    }
+
+    /// Subtracts the tools in turn, returning the cumulative result.
+    ///
+    /// Each step runs a regular [`Subtract`] with these settings on the
+    /// previous step's result. Tools whose bounding box misses the current
+    /// result are skipped without copying it, so when no tool overlaps,
+    /// the target itself is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any individual subtraction fails.
+    pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
+        let mut result = self.target;
+        for &tool in &self.tools {
+            if solids_may_overlap(store, result, tool)? {
+                let mut step = Subtract::new(result, tool)
+                    .with_merge_tol(self.merge_tol)
+                    .with_validate_inputs(self.validate_inputs);
+                if let Some(op) = &self.op_id {
+                    step = step.with_op_id(op.clone());
+                }
+                result = step.execute(store)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::intersect_3d::line_triangle_intersect;
+    use crate::math::{Point3, Vector3};
+    use crate::operations::boolean::classify::{classify_point_in_solid, PointClassification};
+    use crate::operations::boolean::nurbs::test_support::welded_boundary_edges;
+    use crate::operations::creation::{
+        MakeBox, MakeFace, MakeSegmentedPrism, MakeWire, ProfileSegment,
+    };
+    use crate::operations::query::Volume;
+    use crate::operations::shaping::Extrude;
+    use crate::tessellation::{TessellateSolid, TessellationParams, TriangleMesh};
+
+    /// Ray-parity containment test against a closed mesh (the ray is
+    /// skewed so it avoids mesh edges on axis-aligned fixtures).
+    fn mesh_contains(mesh: &TriangleMesh, p: Point3) -> bool {
+        let dir = Vector3::new(1.0, 0.0123, 0.0371);
+        let hits = mesh
+            .indices
+            .iter()
+            .filter(|tri| {
+                let tri = tri.map(|i| mesh.vertices[i as usize]);
+                line_triangle_intersect(&p, &dir, &tri, 0.0)
+                    .is_some_and(|(t, bary)| t > 0.0 && bary.iter().all(|&b| b >= 0.0))
+            })
+            .count();
+        hits % 2 == 1
+    }
+
+    /// A NURBS cylinder of `radius` whose base circle is centred at
+    /// `center` in the XZ plane, extruded by `length` along +Y.
+    fn cylinder_tool(
+        store: &mut TopologyStore,
+        center: Point3,
+        radius: f64,
+        length: f64,
+    ) -> SolidId {
+        let third = std::f64::consts::TAU / 3.0;
+        let profile = (0..3)
+            .map(|i| ProfileSegment::Arc {
+                center,
+                radius,
+                normal: Vector3::y(),
+                ref_dir: Vector3::x(),
+                start_angle: third * f64::from(i),
+                end_angle: third * f64::from(i + 1),
+            })
+            .collect();
+        MakeSegmentedPrism::new(profile, Vector3::y() * length)
+            .execute(store)
+            .unwrap()
+    }
+
+    #[test]
+    fn many_keeps_every_interior_void() {
+        let mut store = TopologyStore::new();
+        let stock = MakeBox::new(Point3::origin(), Point3::new(10.0, 10.0, 10.0))
+            .execute(&mut store)
+            .unwrap();
+        let cavities = [Point3::new(3.0, 3.0, 3.0), Point3::new(7.0, 7.0, 7.0)];
+        let tools = cavities
+            .iter()
+            .map(|c| {
+                MakeBox::new(c - Vector3::repeat(1.0), c + Vector3::repeat(1.0))
+                    .execute(&mut store)
+                    .unwrap()
+            })
+            .collect();
+
+        let result = Subtract::many(stock, tools).execute(&mut store).unwrap();
+        assert_eq!(store.solid(result).unwrap().inner_shells.len(), 2);
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 984.0).abs() < 1e-6, "volume {volume}");
+        for c in cavities {
+            assert_eq!(
+                classify_point_in_solid(&c, result, &store).unwrap(),
+                PointClassification::Outside
+            );
+        }
+    }
+
+    #[test]
+    fn many_drills_three_holes() {
+        let mut store = TopologyStore::new();
+        // The curved-tool subtraction runs on NURBS faces, so the stock is a
+        // segmented-prism wall (10 long, 1 thick, 3 high) rather than a
+        // planar box.
+        let corners =
+            [(0.0, 0.0), (10.0, 0.0), (10.0, 1.0), (0.0, 1.0)].map(|(x, y)| Point3::new(x, y, 0.0));
+        let outline = (0..4)
+            .map(|i| ProfileSegment::Line {
+                start: corners[i],
+                end: corners[(i + 1) % 4],
+            })
+            .collect();
+        let stock = MakeSegmentedPrism::new(outline, Vector3::z() * 3.0)
+            .execute(&mut store)
+            .unwrap();
+        let centers = [2.0, 5.0, 8.0].map(|x| Point3::new(x, -1.0, 1.5));
+        let mut tools: Vec<SolidId> = centers
+            .iter()
+            .map(|&c| cylinder_tool(&mut store, c, 0.5, 3.0))
+            .collect();
+        // A tool far from the stock is skipped.
+        tools.push(
+            MakeBox::new(Point3::new(20.0, 0.0, 0.0), Point3::new(21.0, 1.0, 1.0))
+                .execute(&mut store)
+                .unwrap(),
+        );
+
+        let result = Subtract::many(stock, tools).execute(&mut store).unwrap();
+        assert_eq!(welded_boundary_edges(&store, result), 0);
+        let mesh = TessellateSolid::new(result, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        for c in centers {
+            assert!(
+                !mesh_contains(&mesh, Point3::new(c.x, 0.5, c.z)),
+                "no hole at x = {}",
+                c.x
+            );
+        }
+        assert!(mesh_contains(&mesh, Point3::new(3.5, 0.5, 1.5)));
+    }
+
+    #[test]
+    fn subtract_face_adjacent_tool_leaves_stock_whole() {
+        let mut store = TopologyStore::new();
+        let stock = MakeBox::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+        // Touches the stock's +X face over a patch without entering it.
+        let tool = MakeBox::new(Point3::new(2.0, 0.5, 0.5), Point3::new(3.0, 1.5, 1.5))
+            .execute(&mut store)
+            .unwrap();
+
+        let result = Subtract::new(stock, tool).execute(&mut store).unwrap();
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 8.0).abs() < 1e-9, "volume {volume}");
+        assert_eq!(
+            classify_point_in_solid(&Point3::new(1.9, 1.0, 1.0), result, &store).unwrap(),
+            PointClassification::Inside
+        );
+    }
+
+    #[test]
+    fn subtract_flush_tool_removes_half_without_slivers() {
+        let mut store = TopologyStore::new();
+        let stock = MakeBox::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+        // Shares the stock's -X, ±Y and ±Z face planes.
+        let tool = MakeBox::new(Point3::origin(), Point3::new(1.0, 2.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+
+        let result = Subtract::new(stock, tool).execute(&mut store).unwrap();
+        assert_eq!(welded_boundary_edges(&store, result), 0);
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 4.0).abs() < 1e-9, "volume {volume}");
+        let solid = store.solid(result).unwrap();
+        assert_eq!(store.shell(solid.outer_shell).unwrap().faces.len(), 6);
+    }
+
+    #[test]
+    fn many_applies_settings_to_every_step() {
+        let mut store = TopologyStore::new();
+        let stock = MakeBox::new(Point3::origin(), Point3::new(10.0, 10.0, 10.0))
+            .execute(&mut store)
+            .unwrap();
+        let cube = MakeBox::new(Point3::new(2.0, 2.0, 2.0), Point3::new(3.0, 3.0, 3.0))
+            .execute(&mut store)
+            .unwrap();
+        let pts = vec![
+            Point3::new(5.0, 5.0, 5.0),
+            Point3::new(8.0, 8.0, 5.0),
+            Point3::new(8.0, 5.0, 5.0),
+            Point3::new(5.0, 7.0, 5.0),
+        ];
+        let wire = MakeWire::new(pts, true).execute(&mut store).unwrap();
+        let face = MakeFace::new(wire, vec![]).execute(&mut store).unwrap();
+        let bowtie = Extrude::new(face, Vector3::z())
+            .execute(&mut store)
+            .unwrap();
+
+        let err = Subtract::many(stock, vec![cube, bowtie])
+            .with_validate_inputs(true)
+            .execute(&mut store)
+            .unwrap_err();
+        assert!(err.to_string().contains("self-intersecting"), "{err}");
+    }
+}
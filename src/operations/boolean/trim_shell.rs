@@ -0,0 +1,178 @@
+//! Trimming of open (surface) shells by a solid tool.
+
+use std::collections::HashMap;
+
+use crate::error::{OperationError, Result};
+use crate::math::Point3;
+use crate::topology::{FaceId, FaceSurface, ShellId, SolidId, TopologyStore};
+
+use super::assemble::assemble_shell;
+use super::classify::{classify_point_in_solid, PointClassification};
+use super::face_intersection::intersect_face_face;
+use super::select::KeepDecision;
+use super::split::{polygon_centroid, split_face, SolidSource};
+
+/// Which part of a trimmed shell to keep, relative to the tool solid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeepSide {
+    /// Keep the portion inside the tool.
+    Inside,
+    /// Keep the portion outside the tool.
+    Outside,
+}
+
+/// Trims a shell (typically an open surface model) by a solid tool.
+///
+/// Each shell face is split along its intersections with the tool's faces;
+/// fragments whose centroid falls on the unwanted side are discarded.
+/// Fragments lying on the tool's boundary (coplanar with a tool face) count
+/// as inside. Only planar faces are supported.
+pub struct TrimShell {
+    shell: ShellId,
+    tool: SolidId,
+    keep: KeepSide,
+}
+
+impl TrimShell {
+    /// Creates a new `TrimShell` operation.
+    #[must_use]
+    pub fn new(shell: ShellId, tool: SolidId, keep: KeepSide) -> Self {
+        Self { shell, tool, keep }
+    }
+
+    /// Executes the trim, creating the trimmed shell in the topology store.
+    ///
+    /// The result is a new open shell; the input shell is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OperationError::InvalidInput`] if the shell or tool has a
+    /// non-planar face, and [`OperationError::Failed`] if nothing of the
+    /// shell remains.
+    pub fn execute(&self, store: &mut TopologyStore) -> Result<ShellId> {
+        let shell_faces = store.shell(self.shell)?.faces.clone();
+        let tool_faces = store
+            .shell(store.solid(self.tool)?.outer_shell)?
+            .faces
+            .clone();
+        for &face_id in shell_faces.iter().chain(&tool_faces) {
+            if !matches!(store.face(face_id)?.surface, FaceSurface::Plane(_)) {
+                return Err(OperationError::InvalidInput(
+                    "shell trimming supports planar faces only".into(),
+                )
+                .into());
+            }
+        }
+
+        let mut cuts_by_face: HashMap<FaceId, Vec<(Point3, Point3)>> = HashMap::new();
+        for &face in &shell_faces {
+            for &tool_face in &tool_faces {
+                for isect in intersect_face_face(store, face, tool_face)? {
+                    cuts_by_face
+                        .entry(face)
+                        .or_default()
+                        .push((isect.start, isect.end));
+                }
+            }
+        }
+
+        let mut fragments = Vec::new();
+        for &face in &shell_faces {
+            let cuts = cuts_by_face.get(&face).map_or(&[][..], |v| v.as_slice());
+            for frag in split_face(store, face, cuts, SolidSource::A)? {
+                let centroid = polygon_centroid(&frag.boundary);
+                let inside = !matches!(
+                    classify_point_in_solid(&centroid, self.tool, store)?,
+                    PointClassification::Outside
+                );
+                let decision = if inside == (self.keep == KeepSide::Inside) {
+                    KeepDecision::Keep
+                } else {
+                    KeepDecision::Discard
+                };
+                fragments.push((frag, decision));
+            }
+        }
+
+        if fragments.iter().all(|(_, d)| *d == KeepDecision::Discard) {
+            return Err(OperationError::Failed("shell trim removed every face".into()).into());
+        }
+        let trimmed = assemble_shell(store, &fragments)?;
+        store.shell_mut(trimmed)?.is_closed = false;
+        Ok(trimmed)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::polygon_3d::polygon_area_3d;
+    use crate::operations::boolean::face_intersection::collect_face_polygon;
+    use crate::operations::creation::{MakeBox, MakeFace, MakeWire};
+    use crate::topology::ShellData;
+
+    /// A 10 × 10 sheet at `z = 1` and a box covering `[2, 5] × [3, 7]` of it.
+    fn sheet_and_box(store: &mut TopologyStore) -> (ShellId, SolidId) {
+        let pts = vec![
+            Point3::new(0.0, 0.0, 1.0),
+            Point3::new(10.0, 0.0, 1.0),
+            Point3::new(10.0, 10.0, 1.0),
+            Point3::new(0.0, 10.0, 1.0),
+        ];
+        let wire = MakeWire::new(pts, true).execute(store).unwrap();
+        let face = MakeFace::new(wire, vec![]).execute(store).unwrap();
+        let sheet = store.add_shell(ShellData {
+            faces: vec![face],
+            is_closed: false,
+        });
+        let tool = MakeBox::new(Point3::new(2.0, 3.0, 0.0), Point3::new(5.0, 7.0, 2.0))
+            .execute(store)
+            .unwrap();
+        (sheet, tool)
+    }
+
+    fn shell_area(store: &TopologyStore, shell: ShellId) -> f64 {
+        store
+            .shell(shell)
+            .unwrap()
+            .faces
+            .iter()
+            .map(|&f| {
+                polygon_area_3d(
+                    &collect_face_polygon(store, f).unwrap(),
+                    &crate::math::Vector3::z(),
+                )
+            })
+            .sum()
+    }
+
+    #[test]
+    fn trim_keeps_covered_portion() {
+        let mut store = TopologyStore::new();
+        let (sheet, tool) = sheet_and_box(&mut store);
+        let trimmed = TrimShell::new(sheet, tool, KeepSide::Inside)
+            .execute(&mut store)
+            .unwrap();
+        let shell = store.shell(trimmed).unwrap();
+        assert!(!shell.is_closed);
+        assert!((shell_area(&store, trimmed) - 12.0).abs() < 1e-9);
+        for &face in &store.shell(trimmed).unwrap().faces {
+            for p in collect_face_polygon(&store, face).unwrap() {
+                assert!((2.0 - 1e-9..=5.0 + 1e-9).contains(&p.x), "{p:?}");
+                assert!((3.0 - 1e-9..=7.0 + 1e-9).contains(&p.y), "{p:?}");
+                assert!((p.z - 1.0).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn trim_outside_keeps_the_rest() {
+        let mut store = TopologyStore::new();
+        let (sheet, tool) = sheet_and_box(&mut store);
+        let trimmed = TrimShell::new(sheet, tool, KeepSide::Outside)
+            .execute(&mut store)
+            .unwrap();
+        assert!((shell_area(&store, trimmed) - 88.0).abs() < 1e-9);
+    }
+}
@@ -1,20 +1,44 @@
-use crate::error::Result;
+use crate::error::{OperationError, Result};
 use crate::topology::{SolidId, TopologyStore};
 
-use super::engine::boolean_execute;
+use super::engine::boolean_execute_named;
 use super::select::BooleanOp;
 
 /// Computes the boolean union of two solids.
 pub struct Union {
     solid_a: SolidId,
     solid_b: SolidId,
+    merge_tol: f64,
+    validate_inputs: bool,
 }
 
 impl Union {
     /// Creates a new `Union` operation.
     #[must_use]
     pub fn new(solid_a: SolidId, solid_b: SolidId) -> Self {
-        Self { solid_a, solid_b }
+        Self {
+            solid_a,
+            solid_b,
+            merge_tol: super::DEFAULT_MERGE_TOL,
+            validate_inputs: false,
+        }
+    }
+
+    /// Sets the distance within which near-coincident vertices are snapped
+    /// together before splitting (default [`super::DEFAULT_MERGE_TOL`]).
+    #[must_use]
+    pub fn with_merge_tol(mut self, merge_tol: f64) -> Self {
+        self.merge_tol = merge_tol;
+        self
+    }
+
+    /// Rejects operands whose boundary crosses itself before running the
+    /// boolean (off by default). The check tessellates both solids, so it
+    /// costs roughly as much as meshing them.
+    #[must_use]
+    pub fn with_validate_inputs(mut self, validate_inputs: bool) -> Self {
+        self.validate_inputs = validate_inputs;
+        self
     }
 
     /// Executes the union, creating the result solid in the topology store.
@@ -23,6 +47,234 @@ impl Union {
     ///
     /// Returns an error if the operation fails.
     pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
-        boolean_execute(store, self.solid_a, self.solid_b, BooleanOp::Union)
+        if self.validate_inputs {
+            super::engine::reject_self_intersecting(store, [self.solid_a, self.solid_b])?;
+        }
+        boolean_execute_named(
+            store,
+            self.solid_a,
+            self.solid_b,
+            BooleanOp::Union,
+            None,
+            self.merge_tol,
+        )
+    }
+
+    /// Starts an n-ary union of any number of solids.
+    ///
+    /// Configure the returned [`UnionMany`] like a pairwise [`Union`] and
+    /// call [`UnionMany::execute`] to run it.
+    #[must_use]
+    pub fn many(solids: Vec<SolidId>) -> UnionMany {
+        UnionMany {
+            solids,
+            merge_tol: super::DEFAULT_MERGE_TOL,
+            validate_inputs: false,
+        }
+    }
+}
+
+/// Computes the boolean union of any number of solids, as built by
+/// [`Union::many`].
+pub struct UnionMany {
+    solids: Vec<SolidId>,
+    merge_tol: f64,
+    validate_inputs: bool,
+}
+
+impl UnionMany {
+    /// Sets the merge tolerance passed to every pairwise union
+    /// (see [`Union::with_merge_tol`]).
+    #[must_use]
+    pub fn with_merge_tol(mut self, merge_tol: f64) -> Self {
+        self.merge_tol = merge_tol;
+        self
+    }
+
+    /// Enables operand validation on every pairwise union
+    /// (see [`Union::with_validate_inputs`]).
+    #[must_use]
+    pub fn with_validate_inputs(mut self, validate_inputs: bool) -> Self {
+        self.validate_inputs = validate_inputs;
+        self
+    }
+
+    /// Unions the solids into one.
+    ///
+    /// Uses a balanced reduction — adjacent pairs are unioned, then the
+    /// results are paired again — so each input passes through
+    /// `O(log n)` booleans instead of `n - 1`, limiting both numerical drift
+    /// and the size of intermediate solids. A single input is returned
+    /// unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the solid list is empty or any pairwise union
+    /// fails.
+    pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
+        if self.solids.is_empty() {
+            return Err(OperationError::InvalidInput("union of an empty solid list".into()).into());
+        }
+        let mut level = self.solids.clone();
+        while level.len() > 1 {
+            let mut next = Vec::with_capacity(level.len().div_ceil(2));
+            for pair in level.chunks(2) {
+                next.push(match (pair[0], pair.get(1)) {
+                    (a, None) => a,
+                    (a, Some(&b)) => Union::new(a, b)
+                        .with_merge_tol(self.merge_tol)
+                        .with_validate_inputs(self.validate_inputs)
+                        .execute(store)?,
+                });
+            }
+            level = next;
+        }
+        Ok(level[0])
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::{Point3, Vector3};
+    use crate::operations::creation::{MakeFace, MakeWire};
+    use crate::operations::query::Volume;
+    use crate::operations::shaping::Extrude;
+
+    /// Axis-aligned `size × size × 1` box with its minimum corner at `(x, y, z)`.
+    fn make_box(store: &mut TopologyStore, x: f64, y: f64, z: f64, size: f64) -> SolidId {
+        let pts = vec![
+            Point3::new(x, y, z),
+            Point3::new(x + size, y, z),
+            Point3::new(x + size, y + size, z),
+            Point3::new(x, y + size, z),
+        ];
+        let wire = MakeWire::new(pts, true).execute(store).unwrap();
+        let face = MakeFace::new(wire, vec![]).execute(store).unwrap();
+        Extrude::new(face, Vector3::new(0.0, 0.0, 1.0))
+            .execute(store)
+            .unwrap()
+    }
+
+    #[test]
+    fn many_unions_two_by_two_block() {
+        let mut store = TopologyStore::new();
+        // Four 2×2×1 boxes in a 2×2 block, each overlapping its neighbours.
+        // The corners are staggered slightly so no two boxes share a face
+        // plane, which the planar pipeline does not merge reliably.
+        let boxes = vec![
+            make_box(&mut store, 0.0, 0.0, 0.0, 2.0),
+            make_box(&mut store, 1.5, 0.2, 0.1, 2.0),
+            make_box(&mut store, 0.2, 1.5, 0.2, 2.0),
+            make_box(&mut store, 1.7, 1.7, 0.3, 2.0),
+        ];
+        let result = Union::many(boxes).execute(&mut store).unwrap();
+        let volume = Volume::new(result).execute(&store).unwrap();
+
+        // Inclusion-exclusion over the box intersections.
+        let pairwise = 0.5 * 1.8 * 0.9 // 0 ∩ 1
+            + 1.8 * 0.5 * 0.8 // 0 ∩ 2
+            + 0.3 * 0.3 * 0.7 // 0 ∩ 3
+            + 0.7 * 0.7 * 0.9 // 1 ∩ 2
+            + 1.8 * 0.5 * 0.8 // 1 ∩ 3
+            + 0.5 * 1.8 * 0.9; // 2 ∩ 3
+        let triple = 0.5 * 0.5 * 0.8 // 0 ∩ 1 ∩ 2
+            + 0.3 * 0.3 * 0.7 // 0 ∩ 1 ∩ 3
+            + 0.3 * 0.3 * 0.7 // 0 ∩ 2 ∩ 3
+            + 0.5 * 0.5 * 0.8; // 1 ∩ 2 ∩ 3
+        let quadruple = 0.3 * 0.3 * 0.7;
+        let expected = 4.0 * 4.0 - pairwise + triple - quadruple;
+        assert!(
+            (volume - expected).abs() < 1e-6,
+            "volume={volume}, expected={expected}"
+        );
+    }
+
+    #[test]
+    fn many_fuses_face_adjacent_two_by_two_block() {
+        let mut store = TopologyStore::new();
+        // Four unit boxes sharing faces: the shared walls must vanish and
+        // the flush tops, bottoms and sides merge into one 2×2×1 box.
+        let boxes = vec![
+            make_box(&mut store, 0.0, 0.0, 0.0, 1.0),
+            make_box(&mut store, 1.0, 0.0, 0.0, 1.0),
+            make_box(&mut store, 0.0, 1.0, 0.0, 1.0),
+            make_box(&mut store, 1.0, 1.0, 0.0, 1.0),
+        ];
+        let result = Union::many(boxes).execute(&mut store).unwrap();
+
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 4.0).abs() < 1e-9, "volume={volume}");
+        let solid = store.solid(result).unwrap();
+        assert!(solid.inner_shells.is_empty());
+        assert_eq!(store.shell(solid.outer_shell).unwrap().faces.len(), 6);
+    }
+
+    #[test]
+    fn union_fuses_face_adjacent_boxes() {
+        let mut store = TopologyStore::new();
+        // The shared wall faces point opposite ways: both copies go.
+        let a = make_box(&mut store, 0.0, 0.0, 0.0, 1.0);
+        let b = make_box(&mut store, 1.0, 0.0, 0.0, 1.0);
+        let result = Union::new(a, b).execute(&mut store).unwrap();
+
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 2.0).abs() < 1e-9, "volume={volume}");
+        let solid = store.solid(result).unwrap();
+        assert_eq!(store.shell(solid.outer_shell).unwrap().faces.len(), 6);
+    }
+
+    #[test]
+    fn union_absorbs_flush_box() {
+        let mut store = TopologyStore::new();
+        // B lies inside A flush with A's top, bottom and two sides: the faces
+        // there point the same way and one copy is kept.
+        let a = make_box(&mut store, 0.0, 0.0, 0.0, 2.0);
+        let b = make_box(&mut store, 0.0, 0.0, 0.0, 1.0);
+        let result = Union::new(a, b).execute(&mut store).unwrap();
+
+        let volume = Volume::new(result).execute(&store).unwrap();
+        assert!((volume - 4.0).abs() < 1e-9, "volume={volume}");
+        let solid = store.solid(result).unwrap();
+        assert_eq!(store.shell(solid.outer_shell).unwrap().faces.len(), 6);
+    }
+
+    #[test]
+    fn many_rejects_empty_and_passes_single_through() {
+        let mut store = TopologyStore::new();
+        assert!(Union::many(Vec::new()).execute(&mut store).is_err());
+        let b = make_box(&mut store, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(Union::many(vec![b]).execute(&mut store).unwrap(), b);
+    }
+
+    #[test]
+    fn validate_inputs_rejects_self_intersecting_operand() {
+        let mut store = TopologyStore::new();
+        let pts = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(3.0, 3.0, 0.0),
+            Point3::new(3.0, 0.0, 0.0),
+            Point3::new(0.0, 2.0, 0.0),
+        ];
+        let wire = MakeWire::new(pts, true).execute(&mut store).unwrap();
+        let face = MakeFace::new(wire, vec![]).execute(&mut store).unwrap();
+        let bowtie = Extrude::new(face, Vector3::new(0.0, 0.0, 1.0))
+            .execute(&mut store)
+            .unwrap();
+        let cube = make_box(&mut store, 5.0, 0.0, 0.0, 1.0);
+
+        let err = Union::new(bowtie, cube)
+            .with_validate_inputs(true)
+            .execute(&mut store)
+            .unwrap_err();
+        assert!(err.to_string().contains("self-intersecting"), "{err}");
+
+        let other = make_box(&mut store, 8.0, 0.0, 0.0, 1.0);
+        let err = Union::many(vec![bowtie, cube, other])
+            .with_validate_inputs(true)
+            .execute(&mut store)
+            .unwrap_err();
+        assert!(err.to_string().contains("self-intersecting"), "{err}");
     }
 }
@@ -5,6 +5,8 @@
 //! [`WALL_EPS`], and the same low-level point-in-polygon classifier.
 
 use crate::math::distance_2d::point_to_segment_dist;
+use crate::math::polygon_2d::winding_number_2d;
+use crate::math::Point2;
 
 /// Single epsilon for all geometric decisions in the 2D boolean pipeline.
 ///
@@ -81,18 +83,9 @@ pub fn point_in_polygon_class(p: (f64, f64), poly: &Polygon) -> PointClass {
             return PointClass::Boundary;
         }
     }
-    let mut winding = 0i32;
-    for i in 0..n {
-        let a = poly[i];
-        let b = poly[(i + 1) % n];
-        if a.1 <= p.1 {
-            if b.1 > p.1 && cross_2d(a, b, p) > 0.0 {
-                winding += 1;
-            }
-        } else if b.1 <= p.1 && cross_2d(a, b, p) < 0.0 {
-            winding -= 1;
-        }
-    }
+    let pt = |q: (f64, f64)| Point2::new(q.0, q.1);
+    let edges = (0..n).map(|i| (pt(poly[i]), pt(poly[(i + 1) % n])));
+    let winding = winding_number_2d(edges, pt(p));
     if winding != 0 {
         PointClass::Inside
     } else {
@@ -100,10 +93,6 @@ pub fn point_in_polygon_class(p: (f64, f64), poly: &Polygon) -> PointClass {
     }
 }
 
-fn cross_2d(a: (f64, f64), b: (f64, f64), p: (f64, f64)) -> f64 {
-    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
-}
-
 /// Shoelace signed area. CCW > 0, CW < 0.
 #[must_use]
 pub fn signed_area(poly: &Polygon) -> f64 {
@@ -1,4 +1,5 @@
 use crate::error::{OperationError, Result};
+use crate::math::frame::perpendicular_dir;
 use crate::math::{Point3, Vector3, TOLERANCE};
 use crate::operations::shaping::Revolve;
 use crate::topology::{SolidId, TopologyStore};
@@ -102,17 +103,6 @@ impl MakeCone {
     }
 }
 
-/// Finds a direction perpendicular to the given unit vector.
-fn perpendicular_dir(axis: &Vector3) -> Vector3 {
-    let candidate = if axis.x.abs() < 0.9 {
-        Vector3::x()
-    } else {
-        Vector3::y()
-    };
-    let perp = axis.cross(&candidate);
-    perp / perp.norm()
-}
-
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
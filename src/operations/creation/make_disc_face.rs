@@ -0,0 +1,211 @@
+use std::f64::consts::TAU;
+
+use crate::error::{OperationError, Result};
+use crate::geometry::curve::Circle;
+use crate::geometry::surface::Plane;
+use crate::math::{Point3, Vector3, TOLERANCE};
+use crate::topology::{
+    EdgeCurve, EdgeData, FaceData, FaceId, FaceSurface, OrientedEdge, TopologyStore, VertexData,
+    WireData, WireId,
+};
+
+/// Creates a planar disc face bounded by a single full-circle edge.
+///
+/// The face matches the caps produced by `Revolve`, so it tessellates
+/// through the annular-disc polar grid.
+pub struct MakeDiscFace {
+    center: Point3,
+    radius: f64,
+    normal: Vector3,
+}
+
+impl MakeDiscFace {
+    /// Creates a new `MakeDiscFace` operation.
+    #[must_use]
+    pub fn new(center: Point3, radius: f64, normal: Vector3) -> Self {
+        Self {
+            center,
+            radius,
+            normal,
+        }
+    }
+
+    /// Executes the operation, creating the face in the topology store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the radius is near zero or the normal is
+    /// degenerate.
+    pub fn execute(&self, store: &mut TopologyStore) -> Result<FaceId> {
+        if self.radius < TOLERANCE {
+            return Err(OperationError::InvalidInput("disc radius must be positive".into()).into());
+        }
+        let plane = disc_plane(&self.center, &self.normal)?;
+        let outer_wire = add_circle_wire(store, &plane, self.radius, true)?;
+        Ok(add_disc_face(store, plane, outer_wire, Vec::new()))
+    }
+}
+
+/// Creates a planar annulus face: a disc with a concentric circular hole.
+pub struct MakeAnnulusFace {
+    center: Point3,
+    r_inner: f64,
+    r_outer: f64,
+    normal: Vector3,
+}
+
+impl MakeAnnulusFace {
+    /// Creates a new `MakeAnnulusFace` operation.
+    #[must_use]
+    pub fn new(center: Point3, r_inner: f64, r_outer: f64, normal: Vector3) -> Self {
+        Self {
+            center,
+            r_inner,
+            r_outer,
+            normal,
+        }
+    }
+
+    /// Executes the operation, creating the face in the topology store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the inner radius is near zero, the outer radius
+    /// does not exceed it, or the normal is degenerate.
+    pub fn execute(&self, store: &mut TopologyStore) -> Result<FaceId> {
+        if self.r_inner < TOLERANCE {
+            return Err(OperationError::InvalidInput(
+                "annulus inner radius must be positive".into(),
+            )
+            .into());
+        }
+        if self.r_outer - self.r_inner < TOLERANCE {
+            return Err(OperationError::InvalidInput(
+                "annulus outer radius must exceed the inner radius".into(),
+            )
+            .into());
+        }
+        let plane = disc_plane(&self.center, &self.normal)?;
+        let outer_wire = add_circle_wire(store, &plane, self.r_outer, true)?;
+        let hole = add_circle_wire(store, &plane, self.r_inner, false)?;
+        Ok(add_disc_face(store, plane, outer_wire, vec![hole]))
+    }
+}
+
+/// Builds the face plane, rejecting a degenerate normal.
+fn disc_plane(center: &Point3, normal: &Vector3) -> Result<Plane> {
+    if normal.norm() < TOLERANCE {
+        return Err(OperationError::InvalidInput("disc normal must be non-zero".into()).into());
+    }
+    Plane::from_normal(*center, *normal)
+}
+
+/// Adds a closed wire holding one full-circle edge centred on the plane
+/// origin. The edge starts and ends on the same seam vertex along `u_dir`;
+/// `forward = false` traverses it clockwise, as a hole.
+fn add_circle_wire(
+    store: &mut TopologyStore,
+    plane: &Plane,
+    radius: f64,
+    forward: bool,
+) -> Result<WireId> {
+    let circle = Circle::new(
+        *plane.origin(),
+        radius,
+        *plane.plane_normal(),
+        *plane.u_dir(),
+    )?;
+    let seam = store.add_vertex(VertexData::new(plane.origin() + plane.u_dir() * radius));
+    let edge = store.add_edge(EdgeData {
+        start: seam,
+        end: seam,
+        curve: EdgeCurve::Circle(circle),
+        t_start: 0.0,
+        t_end: TAU,
+        tessellation_tolerance: None,
+    });
+    Ok(store.add_wire(WireData {
+        edges: vec![OrientedEdge::new(edge, forward)],
+        is_closed: true,
+    }))
+}
+
+/// Adds a planar face over `plane` bounded by the given wires.
+fn add_disc_face(
+    store: &mut TopologyStore,
+    plane: Plane,
+    outer_wire: WireId,
+    inner_wires: Vec<WireId>,
+) -> FaceId {
+    store.add_face(FaceData {
+        surface: FaceSurface::Plane(plane),
+        outer_wire,
+        inner_wires,
+        same_sense: true,
+        trim: None,
+        pcurves: Vec::new(),
+        u_seam: None,
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::tessellation::{TessellateFace, TessellationParams};
+
+    #[test]
+    fn disc_tessellates_to_polar_grid() {
+        let mut store = TopologyStore::new();
+        let center = Point3::new(1.0, 2.0, 3.0);
+        let face = MakeDiscFace::new(center, 2.0, Vector3::z())
+            .execute(&mut store)
+            .unwrap();
+        let mesh = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        assert!(!mesh.indices.is_empty());
+        for v in &mesh.vertices {
+            assert!((v - center).norm() <= 2.0 + 1e-9);
+            assert!((v.z - 3.0).abs() < 1e-12);
+        }
+        for n in &mesh.normals {
+            assert!((n - Vector3::z()).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn annulus_excludes_inner_hole() {
+        let mut store = TopologyStore::new();
+        let face = MakeAnnulusFace::new(Point3::origin(), 1.0, 3.0, Vector3::x())
+            .execute(&mut store)
+            .unwrap();
+        let mesh = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        assert!(!mesh.indices.is_empty());
+        for v in &mesh.vertices {
+            let r = v.coords.norm();
+            assert!((1.0 - 1e-9..=3.0 + 1e-9).contains(&r), "r={r}");
+        }
+        for tri in &mesh.indices {
+            let c = tri
+                .iter()
+                .map(|&i| mesh.vertices[i as usize].coords)
+                .sum::<Vector3>()
+                / 3.0;
+            // Chords of the inner circle sit slightly inside it.
+            assert!(c.norm() > 0.9, "centroid at r={}", c.norm());
+        }
+    }
+
+    #[test]
+    fn annulus_rejects_inverted_radii() {
+        let mut store = TopologyStore::new();
+        assert!(
+            MakeAnnulusFace::new(Point3::origin(), 2.0, 1.0, Vector3::z())
+                .execute(&mut store)
+                .is_err()
+        );
+    }
+}
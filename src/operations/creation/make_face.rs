@@ -67,6 +67,7 @@ impl MakeFace {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         });
 
         Ok(face_id)
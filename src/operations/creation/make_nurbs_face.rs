@@ -83,6 +83,7 @@ impl MakeNurbsFace {
             same_sense: true,
             trim: self.trim.clone(),
             pcurves,
+            u_seam: None,
         });
         Ok(face_id)
     }
@@ -149,6 +150,7 @@ impl MakeNurbsFace {
                 curve: EdgeCurve::Nurbs(curve),
                 t_start: t_min,
                 t_end: t_max,
+                tessellation_tolerance: None,
             });
             oriented_edges.push(OrientedEdge::new(edge_id, forward));
         }
@@ -215,6 +217,7 @@ impl MakeNurbsFace {
                 curve: EdgeCurve::Nurbs(curve),
                 t_start: t_min,
                 t_end: t_max,
+                tessellation_tolerance: None,
             });
             oriented_edges.push(OrientedEdge::new(edge_id, true));
             prev_vertex = Some(end_v);
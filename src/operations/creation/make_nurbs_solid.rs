@@ -155,6 +155,7 @@ fn closed_ring_edge(store: &mut TopologyStore, curve: NurbsCurve3D) -> Result<Ed
         curve: EdgeCurve::Nurbs(curve),
         t_start: t0,
         t_end: t1,
+        tessellation_tolerance: None,
     }))
 }
 
@@ -520,6 +520,7 @@ fn build_shared_edges(
             curve: EdgeCurve::Nurbs(curves[k].clone()),
             t_start: t0,
             t_end: t1,
+            tessellation_tolerance: None,
         }));
         // The surface's exact v = 1 isocurve is the segment curve translated
         // by `direction`, with identical knots.
@@ -532,6 +533,7 @@ fn build_shared_edges(
             curve: EdgeCurve::Nurbs(top_curve),
             t_start: tt0,
             t_end: tt1,
+            tessellation_tolerance: None,
         }));
         let kink_curve = NurbsCurve3D::polyline(&[joint_points[k], joint_points[k] + direction])?;
         let (kt0, kt1) = kink_curve.parameter_domain();
@@ -541,6 +543,7 @@ fn build_shared_edges(
             curve: EdgeCurve::Nurbs(kink_curve),
             t_start: kt0,
             t_end: kt1,
+            tessellation_tolerance: None,
         }));
     }
     Ok(edges)
@@ -1,5 +1,7 @@
-use crate::error::Result;
-use crate::topology::{ShellId, SolidData, SolidId, TopologyStore};
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::error::{Result, TopologyError};
+use crate::topology::{EdgeId, FaceId, ShellData, ShellId, SolidData, SolidId, TopologyStore};
 
 /// Creates a solid from shells.
 pub struct MakeSolid {
@@ -17,6 +19,13 @@ impl MakeSolid {
         }
     }
 
+    /// Assembles `faces` into a closed shell wrapped in a new solid; see
+    /// [`MakeSolidFromFaces::execute`].
+    #[must_use]
+    pub fn from_faces(faces: Vec<FaceId>) -> MakeSolidFromFaces {
+        MakeSolidFromFaces { faces }
+    }
+
     /// Executes the operation, creating the solid in the topology store.
     ///
     /// # Errors
@@ -37,3 +46,236 @@ impl MakeSolid {
         Ok(solid_id)
     }
 }
+
+/// Assembles faces into a closed shell and wraps it in a new solid, as
+/// built by [`MakeSolid::from_faces`].
+pub struct MakeSolidFromFaces {
+    faces: Vec<FaceId>,
+}
+
+impl MakeSolidFromFaces {
+    /// Executes the operation, creating the shell and solid in the
+    /// topology store.
+    ///
+    /// Every edge of the faces' wires must be used exactly twice (a closed
+    /// 2-manifold). Faces are then made consistent by flipping
+    /// `same_sense` so that neighbours traverse each shared edge in
+    /// opposite directions, and the whole shell is flipped if that makes
+    /// [`TopologyStore::shell_is_outward`] hold.
+    ///
+    /// The faces are modified in place. Closure and orientability are
+    /// checked before any face is flipped, but flips already made are not
+    /// undone if a later step fails, and faces shared with other shells
+    /// are re-oriented there too.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TopologyError::OpenShell`] listing (in ascending order)
+    /// every edge not used exactly twice, `TopologyError::InvalidTopology`
+    /// if the face list is empty or the shell cannot be oriented
+    /// consistently, or [`TopologyError::EntityNotFound`] for a dangling
+    /// reference.
+    pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
+        if self.faces.is_empty() {
+            return Err(TopologyError::InvalidTopology("no faces to assemble".into()).into());
+        }
+
+        // Each use of an edge: the face index and its traversal direction
+        // relative to the face's outward side.
+        let mut uses: BTreeMap<EdgeId, Vec<(usize, bool)>> = BTreeMap::new();
+        for (i, &face_id) in self.faces.iter().enumerate() {
+            let face = store.face(face_id)?;
+            for wire in std::iter::once(face.outer_wire).chain(face.inner_wires.iter().copied()) {
+                for oe in &store.wire(wire)?.edges {
+                    uses.entry(oe.edge)
+                        .or_default()
+                        .push((i, oe.forward == face.same_sense));
+                }
+            }
+        }
+        let open: Vec<EdgeId> = uses
+            .iter()
+            .filter(|(_, u)| u.len() != 2)
+            .map(|(&e, _)| e)
+            .collect();
+        if !open.is_empty() {
+            return Err(TopologyError::OpenShell(open).into());
+        }
+
+        // Neighbours across each shared edge, with whether the two uses
+        // currently agree in direction (and so need one side flipped).
+        let mut adjacent: Vec<Vec<(usize, bool)>> = vec![Vec::new(); self.faces.len()];
+        for u in uses.values() {
+            let ((a, da), (b, db)) = (u[0], u[1]);
+            if a != b {
+                adjacent[a].push((b, da == db));
+                adjacent[b].push((a, da == db));
+            }
+        }
+        let mut flip: Vec<Option<bool>> = vec![None; self.faces.len()];
+        for root in 0..self.faces.len() {
+            if flip[root].is_some() {
+                continue;
+            }
+            flip[root] = Some(false);
+            let mut queue = VecDeque::from([root]);
+            while let Some(i) = queue.pop_front() {
+                let fi = flip[i].unwrap_or(false);
+                for &(j, agree) in &adjacent[i] {
+                    let fj = fi ^ agree;
+                    match flip[j] {
+                        None => {
+                            flip[j] = Some(fj);
+                            queue.push_back(j);
+                        }
+                        Some(existing) if existing != fj => {
+                            return Err(TopologyError::InvalidTopology(
+                                "faces cannot be oriented consistently".into(),
+                            )
+                            .into());
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        for (&face_id, f) in self.faces.iter().zip(&flip) {
+            if *f == Some(true) {
+                let face = store.face_mut(face_id)?;
+                face.same_sense = !face.same_sense;
+            }
+        }
+
+        let shell = store.add_shell(ShellData {
+            faces: self.faces.clone(),
+            is_closed: true,
+        });
+        if !store.shell_is_outward(shell) {
+            flip_all(store, shell)?;
+            // Undecidable (e.g. no planar face): keep the input orientation.
+            if !store.shell_is_outward(shell) {
+                flip_all(store, shell)?;
+            }
+        }
+        MakeSolid::new(shell, Vec::new()).execute(store)
+    }
+}
+
+/// Toggles `same_sense` on every face of `shell`.
+fn flip_all(store: &mut TopologyStore, shell: ShellId) -> Result<()> {
+    for face_id in store.shell(shell)?.faces.clone() {
+        let face = store.face_mut(face_id)?;
+        face.same_sense = !face.same_sense;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::Point3;
+    use crate::operations::creation::MakeFace;
+    use crate::operations::query::{IsValid, Volume};
+    use crate::topology::{EdgeCurve, EdgeData, OrientedEdge, VertexData, WireData};
+
+    /// Six quads of the unit cube sharing twelve edges. One face is wound
+    /// the wrong way round to exercise re-orientation.
+    fn cube_faces(store: &mut TopologyStore) -> (Vec<FaceId>, Vec<EdgeId>) {
+        let corners: Vec<_> = (0..8)
+            .map(|i| {
+                let p = Point3::new(f64::from(i & 1), f64::from((i >> 1) & 1), f64::from(i >> 2));
+                store.add_vertex(VertexData::new(p))
+            })
+            .collect();
+        let mut edges = BTreeMap::new();
+        let mut edge = |store: &mut TopologyStore, a: usize, b: usize| {
+            let key = (a.min(b), a.max(b));
+            let id = *edges.entry(key).or_insert_with(|| {
+                let (p, q) = (
+                    store.vertex(corners[key.0]).unwrap().point,
+                    store.vertex(corners[key.1]).unwrap().point,
+                );
+                store.add_edge(EdgeData {
+                    start: corners[key.0],
+                    end: corners[key.1],
+                    curve: EdgeCurve::Line(crate::geometry::curve::Line::new(p, q - p).unwrap()),
+                    t_start: 0.0,
+                    t_end: (q - p).norm(),
+                    tessellation_tolerance: None,
+                })
+            });
+            OrientedEdge::new(id, a < b)
+        };
+        // Outward-facing (counter-clockwise seen from outside), except the
+        // last, which is reversed.
+        let quads = [
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 1, 5, 4],
+            [2, 6, 7, 3],
+            [0, 4, 6, 2],
+            [1, 5, 7, 3],
+        ];
+        let mut faces = Vec::new();
+        for quad in quads {
+            let oriented: Vec<_> = (0..4)
+                .map(|k| edge(store, quad[k], quad[(k + 1) % 4]))
+                .collect();
+            let wire = store.add_wire(WireData {
+                edges: oriented,
+                is_closed: true,
+            });
+            faces.push(MakeFace::new(wire, vec![]).execute(store).unwrap());
+        }
+        let edge_ids = edges.into_values().collect();
+        (faces, edge_ids)
+    }
+
+    #[test]
+    fn six_quads_make_a_closed_box() {
+        let mut store = TopologyStore::new();
+        let (faces, _) = cube_faces(&mut store);
+        let solid = MakeSolid::from_faces(faces).execute(&mut store).unwrap();
+        let shell = store.solid(solid).unwrap().outer_shell;
+        assert!(store.shell_is_outward(shell));
+        assert!(IsValid::new(solid).execute(&store));
+        let volume = Volume::new(solid).execute(&store).unwrap();
+        assert!((volume - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn open_face_set_lists_boundary_edges() {
+        let mut store = TopologyStore::new();
+        let (mut faces, _) = cube_faces(&mut store);
+        let top = faces.remove(1);
+        let mut expected: Vec<EdgeId> = store
+            .wire(store.face(top).unwrap().outer_wire)
+            .unwrap()
+            .edges
+            .iter()
+            .map(|oe| oe.edge)
+            .collect();
+        expected.sort_unstable();
+
+        let senses = |store: &TopologyStore, faces: &[FaceId]| -> Vec<bool> {
+            faces
+                .iter()
+                .map(|&f| store.face(f).unwrap().same_sense)
+                .collect()
+        };
+        let before = senses(&store, &faces);
+
+        let err = MakeSolid::from_faces(faces.clone())
+            .execute(&mut store)
+            .unwrap_err();
+        // Closure is checked before any face is flipped.
+        assert_eq!(senses(&store, &faces), before);
+        match err {
+            crate::error::GeolisError::Topology(TopologyError::OpenShell(edges)) => {
+                assert_eq!(edges, expected);
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::error::{OperationError, Result};
+use crate::math::{Point3, Vector3, TOLERANCE};
+use crate::operations::shaping::Extrude;
+use crate::topology::{SolidId, TopologyStore};
+
+use super::{MakeFace, MakeWire};
+
+/// Creates a wedge: a box `[0, dx] × [0, dy] × [0, dz]` whose `+X`/`+Z`
+/// edge is cut away by a sloped top face.
+///
+/// The slope runs from `(taper_x, dz)` on the top face down to
+/// `(dx, taper_z)` on the `+X` face, constant along Y. With both tapers 0
+/// the result is a right-angled wedge whose slope spans the full box.
+pub struct MakeWedge {
+    dx: f64,
+    dy: f64,
+    dz: f64,
+    taper_x: f64,
+    taper_z: f64,
+}
+
+impl MakeWedge {
+    /// Creates a new `MakeWedge` operation.
+    ///
+    /// `taper_x` is the X length of the flat top face and `taper_z` the
+    /// height of the `+X` face.
+    #[must_use]
+    pub fn new(dx: f64, dy: f64, dz: f64, taper_x: f64, taper_z: f64) -> Self {
+        Self {
+            dx,
+            dy,
+            dz,
+            taper_x,
+            taper_z,
+        }
+    }
+
+    /// Executes the operation, creating the wedge in the topology store.
+    ///
+    /// Creates the XZ profile at `y = 0`, then extrudes it along `+Y`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any dimension is not positive, or a taper is
+    /// negative or not less than its dimension (which would leave no slope).
+    pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
+        let (dx, dy, dz) = (self.dx, self.dy, self.dz);
+        if dx < TOLERANCE || dy < TOLERANCE || dz < TOLERANCE {
+            return Err(
+                OperationError::InvalidInput("wedge dimensions must be positive".into()).into(),
+            );
+        }
+        let (tx, tz) = (self.taper_x, self.taper_z);
+        if !(0.0..dx - TOLERANCE).contains(&tx) || !(0.0..dz - TOLERANCE).contains(&tz) {
+            return Err(OperationError::InvalidInput(
+                "wedge tapers must lie in [0, dx) and [0, dz)".into(),
+            )
+            .into());
+        }
+
+        // A zero taper collapses its profile corner onto the neighbour.
+        let mut profile = vec![Point3::origin(), Point3::new(dx, 0.0, 0.0)];
+        if tz >= TOLERANCE {
+            profile.push(Point3::new(dx, 0.0, tz));
+        }
+        if tx >= TOLERANCE {
+            profile.push(Point3::new(tx, 0.0, dz));
+        }
+        profile.push(Point3::new(0.0, 0.0, dz));
+
+        let wire = MakeWire::new(profile, true).execute(store)?;
+        let face = MakeFace::new(wire, vec![]).execute(store)?;
+        Extrude::new(face, Vector3::new(0.0, dy, 0.0)).execute(store)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::operations::query::IsValid;
+    use crate::tessellation::{TessellateSolid, TessellationParams};
+
+    fn mesh_volume(store: &TopologyStore, solid: SolidId) -> f64 {
+        TessellateSolid::new(solid, TessellationParams::default())
+            .execute(store)
+            .unwrap()
+            .signed_volume(Point3::new(0.1, 0.1, 0.1))
+    }
+
+    #[test]
+    fn wedge_volume_is_box_minus_corner() {
+        for (tx, tz) in [(0.0, 0.0), (1.0, 0.0), (0.0, 2.0), (1.5, 0.5)] {
+            let (dx, dy, dz) = (4.0, 3.0, 5.0);
+            let mut store = TopologyStore::new();
+            let solid = MakeWedge::new(dx, dy, dz, tx, tz)
+                .execute(&mut store)
+                .unwrap();
+            assert!(IsValid::new(solid).execute(&store));
+            let expected = dy * (dx * dz - 0.5 * (dx - tx) * (dz - tz));
+            let volume = mesh_volume(&store, solid);
+            assert!(
+                (volume - expected).abs() < 1e-9,
+                "tapers ({tx}, {tz}): {volume} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn wedge_rejects_bad_dimensions() {
+        let mut store = TopologyStore::new();
+        assert!(MakeWedge::new(0.0, 1.0, 1.0, 0.0, 0.0)
+            .execute(&mut store)
+            .is_err());
+        assert!(MakeWedge::new(1.0, 1.0, 1.0, 1.0, 0.0)
+            .execute(&mut store)
+            .is_err());
+        assert!(MakeWedge::new(1.0, 1.0, 1.0, 0.0, -0.5)
+            .execute(&mut store)
+            .is_err());
+    }
+}
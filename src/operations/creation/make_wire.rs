@@ -84,6 +84,7 @@ impl MakeWire {
                 curve: EdgeCurve::Line(line),
                 t_start: 0.0,
                 t_end,
+                tessellation_tolerance: None,
             });
 
             oriented_edges.push(OrientedEdge::new(edge_id, true));
@@ -1,23 +1,28 @@
 mod make_box;
 mod make_cone;
 mod make_cylinder;
+mod make_disc_face;
 mod make_face;
 mod make_nurbs_face;
 mod make_nurbs_solid;
 mod make_segmented_prism;
 mod make_solid;
 mod make_sphere;
+mod make_wedge;
 mod make_wire;
+pub(crate) mod pline_face;
 
 pub use make_box::MakeBox;
 pub use make_cone::MakeCone;
 pub use make_cylinder::MakeCylinder;
+pub use make_disc_face::{MakeAnnulusFace, MakeDiscFace};
 pub use make_face::MakeFace;
 pub use make_nurbs_face::MakeNurbsFace;
 pub use make_nurbs_solid::{
     MakeCurvedSlab, MakeCurvedWall, MakeNurbsPrism, MakeNurbsTube, MakeRevolvedSolid,
 };
 pub use make_segmented_prism::{MakeSegmentedPrism, ProfileSegment};
-pub use make_solid::MakeSolid;
+pub use make_solid::{MakeSolid, MakeSolidFromFaces};
 pub use make_sphere::MakeSphere;
+pub use make_wedge::MakeWedge;
 pub use make_wire::MakeWire;
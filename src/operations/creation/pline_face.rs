@@ -0,0 +1,206 @@
+//! Materializing bulge polylines in a plane as topology wires and faces.
+
+use crate::error::{OperationError, Result};
+use crate::geometry::curve::Line;
+use crate::geometry::pline::{Pline, PlineRegion};
+use crate::geometry::surface::Plane;
+use crate::math::arc_2d::arc_from_bulge;
+use crate::math::{Point3, Vector3, TOLERANCE};
+use crate::topology::{
+    EdgeCurve, EdgeData, FaceId, OrientedEdge, TopologyStore, VertexData, VertexId, WireData,
+    WireId,
+};
+
+use super::MakeFace;
+
+impl Pline {
+    /// Materializes this polyline as a wire of `Line` and `Arc` edges lying
+    /// in `plane`.
+    ///
+    /// Polyline coordinates are taken in the orthonormal frame of the plane:
+    /// `(x, y)` maps to `origin + x * u_dir + y * (normal × u_dir)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line segment has zero length.
+    pub fn to_wire_on_plane(&self, store: &mut TopologyStore, plane: &Plane) -> Result<WireId> {
+        pline_to_wire(store, self, &PlaneFrame::new(plane))
+    }
+
+    /// Builds one planar face per region, its outer loop and holes
+    /// materialized with [`Self::to_wire_on_plane`] (arcs preserved) and
+    /// joined by [`MakeFace`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a loop is open or cannot be materialized, or if
+    /// [`MakeFace`] rejects a region.
+    pub fn regions_to_faces(
+        regions: &[PlineRegion],
+        store: &mut TopologyStore,
+        plane: &Plane,
+    ) -> Result<Vec<FaceId>> {
+        let frame = PlaneFrame::new(plane);
+        regions
+            .iter()
+            .map(|region| {
+                let outer = pline_to_wire(store, &region.outer, &frame)?;
+                let holes = region
+                    .holes
+                    .iter()
+                    .map(|hole| pline_to_wire(store, hole, &frame))
+                    .collect::<Result<Vec<_>>>()?;
+                MakeFace::new(outer, holes).execute(store)
+            })
+            .collect()
+    }
+}
+
+/// Orthonormal 2D frame of a plane.
+pub(crate) struct PlaneFrame {
+    origin: Point3,
+    u: Vector3,
+    v: Vector3,
+    pub(crate) normal: Vector3,
+}
+
+impl PlaneFrame {
+    pub(crate) fn new(plane: &Plane) -> Self {
+        let normal = *plane.plane_normal();
+        let u = *plane.u_dir();
+        Self {
+            origin: *plane.origin(),
+            u,
+            v: normal.cross(&u),
+            normal,
+        }
+    }
+
+    /// Returns in-plane coordinates of `p`, or an error if it lies off the plane.
+    pub(crate) fn to_2d(&self, p: &Point3) -> Result<(f64, f64)> {
+        let d = p - self.origin;
+        if d.dot(&self.normal).abs() > TOLERANCE * 1e3 {
+            return Err(
+                OperationError::InvalidInput("wire does not lie in the plane".into()).into(),
+            );
+        }
+        Ok((d.dot(&self.u), d.dot(&self.v)))
+    }
+
+    pub(crate) fn to_3d(&self, x: f64, y: f64) -> Point3 {
+        self.origin + self.u * x + self.v * y
+    }
+}
+
+/// Materializes a bulge polyline in `frame`'s coordinates as a wire of
+/// `Line` and `Arc` edges.
+pub(crate) fn pline_to_wire(
+    store: &mut TopologyStore,
+    pline: &Pline,
+    frame: &PlaneFrame,
+) -> Result<WireId> {
+    let n = pline.vertices.len();
+    let vertex_ids: Vec<VertexId> = pline
+        .vertices
+        .iter()
+        .map(|v| store.add_vertex(VertexData::new(frame.to_3d(v.x, v.y))))
+        .collect();
+
+    let mut edges = Vec::with_capacity(pline.segment_count());
+    for i in 0..pline.segment_count() {
+        let (v0, v1) = (&pline.vertices[i], &pline.vertices[(i + 1) % n]);
+        let (a, b) = (frame.to_3d(v0.x, v0.y), frame.to_3d(v1.x, v1.y));
+        let (cx, cy, radius, _, sweep) = arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge);
+        let (curve, t_end) = if v0.bulge.abs() < 1e-12 || radius < 1e-12 {
+            (EdgeCurve::Line(Line::new(a, b - a)?), (b - a).norm())
+        } else {
+            let center = frame.to_3d(cx, cy);
+            let normal = if sweep > 0.0 {
+                frame.normal
+            } else {
+                -frame.normal
+            };
+            let arc = crate::geometry::curve::Arc::new(
+                center,
+                radius,
+                normal,
+                (a - center) / radius,
+                0.0,
+                sweep.abs(),
+            )?;
+            (EdgeCurve::Arc(arc), sweep.abs())
+        };
+        let edge = store.add_edge(EdgeData {
+            start: vertex_ids[i],
+            end: vertex_ids[(i + 1) % n],
+            curve,
+            t_start: 0.0,
+            t_end,
+            tessellation_tolerance: None,
+        });
+        edges.push(OrientedEdge::new(edge, true));
+    }
+    Ok(store.add_wire(WireData {
+        edges,
+        is_closed: pline.closed,
+    }))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::pline::PlineVertex;
+    use crate::tessellation::{TessellateFace, TessellationParams};
+
+    /// Closed two-arc circle of `radius` about `(5, 5)`, counter-clockwise.
+    fn circle(radius: f64) -> Pline {
+        Pline {
+            vertices: vec![
+                PlineVertex::new(5.0 + radius, 5.0, 1.0),
+                PlineVertex::new(5.0 - radius, 5.0, 1.0),
+            ],
+            closed: true,
+        }
+    }
+
+    #[test]
+    fn annulus_region_becomes_face_with_hole() {
+        let mut store = TopologyStore::new();
+        let plane = Plane::new(Point3::new(0.0, 0.0, 1.0), Vector3::x(), Vector3::y()).unwrap();
+        let regions = [PlineRegion {
+            outer: circle(2.0),
+            holes: vec![circle(1.0).reversed()],
+        }];
+
+        let faces = Pline::regions_to_faces(&regions, &mut store, &plane).unwrap();
+        assert_eq!(faces.len(), 1);
+        let face = store.face(faces[0]).unwrap();
+        assert_eq!(face.inner_wires.len(), 1);
+        for wire in std::iter::once(face.outer_wire).chain(face.inner_wires.clone()) {
+            let wire = store.wire(wire).unwrap();
+            assert!(wire
+                .edges
+                .iter()
+                .all(|oe| matches!(store.edge(oe.edge).unwrap().curve, EdgeCurve::Arc(_))));
+        }
+
+        let params = TessellationParams {
+            tolerance: 1e-3,
+            ..TessellationParams::default()
+        };
+        let mesh = TessellateFace::new(faces[0], params)
+            .execute(&store)
+            .unwrap();
+        let center = Point3::new(5.0, 5.0, 1.0);
+        let mut area = 0.0;
+        for tri in &mesh.indices {
+            let [a, b, c] = tri.map(|i| mesh.vertices[i as usize]);
+            let centroid = Point3::from((a.coords + b.coords + c.coords) / 3.0);
+            assert!((centroid - center).norm() > 1.0 - 1e-3, "{centroid:?}");
+            area += (b - a).cross(&(c - a)).norm() / 2.0;
+        }
+        let expected = std::f64::consts::PI * 3.0;
+        assert!((area - expected).abs() < 0.01 * expected, "area={area}");
+    }
+}
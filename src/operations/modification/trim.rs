@@ -37,6 +37,7 @@ impl Trim {
         let curve = edge.curve.clone();
         let orig_start = edge.t_start;
         let orig_end = edge.t_end;
+        let tessellation_tolerance = edge.tessellation_tolerance;
 
         // Validate range
         if (self.t_end - self.t_start).abs() < TOLERANCE {
@@ -78,6 +79,7 @@ impl Trim {
                     curve: EdgeCurve::Line(line),
                     t_start: 0.0,
                     t_end,
+                    tessellation_tolerance,
                 }
             }
             EdgeCurve::Arc(arc) => {
@@ -88,6 +90,7 @@ impl Trim {
                     curve: EdgeCurve::Arc(arc.clone()),
                     t_start: self.t_start,
                     t_end: self.t_end,
+                    tessellation_tolerance,
                 }
             }
             EdgeCurve::Circle(circle) => {
@@ -98,6 +101,7 @@ impl Trim {
                     curve: EdgeCurve::Circle(circle.clone()),
                     t_start: self.t_start,
                     t_end: self.t_end,
+                    tessellation_tolerance,
                 }
             }
             EdgeCurve::Ellipse(ellipse) => {
@@ -108,15 +112,29 @@ impl Trim {
                     curve: EdgeCurve::Ellipse(ellipse.clone()),
                     t_start: self.t_start,
                     t_end: self.t_end,
+                    tessellation_tolerance,
                 }
             }
-            EdgeCurve::Nurbs(_) => {
-                // Trimming a NURBS edge to an arbitrary sub-interval requires two
-                // knot-insertion splits; not yet supported.
-                return Err(OperationError::Failed(
-                    "trimming NURBS edges is not yet supported".into(),
-                )
-                .into());
+            EdgeCurve::Nurbs(nurbs) => {
+                // Cut the curve down to the trimmed interval with up to two
+                // splits; the pieces keep the original parameterization.
+                let (lo, hi) = (self.t_start.min(self.t_end), self.t_start.max(self.t_end));
+                let (t_min, t_max) = nurbs.parameter_domain();
+                let mut piece = nurbs.clone();
+                if lo > t_min + TOLERANCE {
+                    piece = piece.split(lo)?.1;
+                }
+                if hi < t_max - TOLERANCE {
+                    piece = piece.split(hi)?.0;
+                }
+                EdgeData {
+                    start: start_vertex,
+                    end: end_vertex,
+                    curve: EdgeCurve::Nurbs(piece),
+                    t_start: self.t_start,
+                    t_end: self.t_end,
+                    tessellation_tolerance,
+                }
             }
         };
 
@@ -139,6 +157,7 @@ fn evaluate_curve(curve: &EdgeCurve, t: f64) -> Result<Point3> {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::geometry::nurbs::{KnotVector, NurbsCurve3D};
     use crate::math::Point3;
     use crate::operations::creation::MakeWire;
     use crate::topology::TopologyStore;
@@ -193,4 +212,44 @@ mod tests {
         let result = Trim::new(edge_id, -5.0, 5.0).execute(&mut store);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn trim_nurbs_keeps_parameterization() {
+        let curve = NurbsCurve3D::from_unweighted(
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 2.0, 0.0),
+                Point3::new(3.0, 2.0, 1.0),
+                Point3::new(4.0, 0.0, 0.0),
+            ],
+            KnotVector::new(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]).unwrap(),
+            2,
+        )
+        .unwrap();
+        let mut store = TopologyStore::new();
+        let start = store.add_vertex(VertexData::new(curve.point_at(0.0).unwrap()));
+        let end = store.add_vertex(VertexData::new(curve.point_at(1.0).unwrap()));
+        let edge_id = store.add_edge(EdgeData {
+            start,
+            end,
+            curve: EdgeCurve::Nurbs(curve.clone()),
+            t_start: 0.0,
+            t_end: 1.0,
+            tessellation_tolerance: None,
+        });
+
+        let trimmed = Trim::new(edge_id, 0.2, 0.7).execute(&mut store).unwrap();
+        let edge = store.edge(trimmed).unwrap();
+        let EdgeCurve::Nurbs(piece) = &edge.curve else {
+            panic!("expected a NURBS edge");
+        };
+        let (t_min, t_max) = piece.parameter_domain();
+        assert!((t_min - 0.2).abs() < 1e-12 && (t_max - 0.7).abs() < 1e-12);
+        for t in [0.2, 0.45, 0.5, 0.7] {
+            let p = piece.point_at(t).unwrap();
+            assert!((p - curve.point_at(t).unwrap()).norm() < 1e-12);
+        }
+        let end = store.vertex(edge.end).unwrap().point;
+        assert!((end - curve.point_at(0.7).unwrap()).norm() < 1e-12);
+    }
 }
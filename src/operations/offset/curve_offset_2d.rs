@@ -1,7 +1,12 @@
 use crate::error::{OperationError, Result};
 use crate::geometry::curve::{Curve, Line};
+use crate::geometry::pline::{Pline, PlineVertex};
+use crate::geometry::surface::Plane;
 use crate::math::{Point3, Vector3};
-use crate::topology::{EdgeCurve, EdgeData, EdgeId, TopologyStore, VertexData};
+use crate::operations::creation::pline_face::{pline_to_wire, PlaneFrame};
+use crate::topology::{EdgeCurve, EdgeData, EdgeId, TopologyStore, VertexData, WireId};
+
+use super::PlineOffset2D;
 
 /// Offsets a 2D curve (edge) by a given distance.
 ///
@@ -44,6 +49,93 @@ impl CurveOffset2D {
     }
 }
 
+impl CurveOffset2D {
+    /// Offsets a planar wire of line and arc edges within `plane`.
+    ///
+    /// The wire is expressed in an orthonormal frame of `plane` (`u_dir` and
+    /// `normal × u_dir`), offset with the arc-aware [`PlineOffset2D`]
+    /// (positive distance = left of the traversal direction seen from the
+    /// plane normal), and every resulting loop or run is materialized as a
+    /// new wire of `Line` and `Arc` edges lying in the plane. Arcs beyond a
+    /// semicircle (including full circles) are split at their midpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::InvalidInput` if the wire has ellipse or
+    /// NURBS edges or does not lie in `plane`, and propagates offset
+    /// failures (e.g. the offset collapses entirely).
+    pub fn offset_wire(
+        store: &mut TopologyStore,
+        wire: WireId,
+        distance: f64,
+        plane: &Plane,
+    ) -> Result<Vec<WireId>> {
+        let frame = PlaneFrame::new(plane);
+        let input = wire_to_pline(store, wire, &frame)?;
+        PlineOffset2D::new(input, distance)
+            .execute()?
+            .iter()
+            .map(|result| pline_to_wire(store, result, &frame))
+            .collect()
+    }
+}
+
+/// Converts a wire of line / arc / circle edges into a bulge polyline in
+/// `frame`'s coordinates.
+fn wire_to_pline(store: &TopologyStore, wire: WireId, frame: &PlaneFrame) -> Result<Pline> {
+    let wire = store.wire(wire)?;
+    let mut vertices = Vec::with_capacity(wire.edges.len() + 1);
+    let mut last_end = None;
+    for oe in &wire.edges {
+        let edge = store.edge(oe.edge)?;
+        let (t0, t1) = if oe.forward {
+            (edge.t_start, edge.t_end)
+        } else {
+            (edge.t_end, edge.t_start)
+        };
+        // Arc-like edges sweep CCW about their own normal with increasing t.
+        let (curve, normal): (&dyn Curve, Option<&Vector3>) = match &edge.curve {
+            EdgeCurve::Line(line) => (line, None),
+            EdgeCurve::Arc(arc) => (arc, Some(arc.normal())),
+            EdgeCurve::Circle(circle) => (circle, Some(circle.normal())),
+            EdgeCurve::Ellipse(_) | EdgeCurve::Nurbs(_) => {
+                return Err(OperationError::InvalidInput(
+                    "wire offset supports line, arc and circle edges only".into(),
+                )
+                .into())
+            }
+        };
+        let (x0, y0) = frame.to_2d(&curve.evaluate(t0)?)?;
+        match normal {
+            None => vertices.push(PlineVertex::line(x0, y0)),
+            Some(n) => {
+                let sweep = (t1 - t0) * n.dot(&frame.normal).signum();
+                if sweep.abs() <= std::f64::consts::PI + 1e-9 {
+                    vertices.push(PlineVertex::new(x0, y0, (sweep / 4.0).tan()));
+                } else {
+                    // Keep |bulge| <= 1 (and full circles encodable): split
+                    // arcs beyond a semicircle at their midpoint.
+                    let (xm, ym) = frame.to_2d(&curve.evaluate(0.5 * (t0 + t1))?)?;
+                    let half = (sweep / 8.0).tan();
+                    vertices.push(PlineVertex::new(x0, y0, half));
+                    vertices.push(PlineVertex::new(xm, ym, half));
+                }
+            }
+        }
+        last_end = Some(curve.evaluate(t1)?);
+    }
+    if !wire.is_closed {
+        if let Some(end) = last_end {
+            let (x, y) = frame.to_2d(&end)?;
+            vertices.push(PlineVertex::line(x, y));
+        }
+    }
+    Ok(Pline {
+        vertices,
+        closed: wire.is_closed,
+    })
+}
+
 /// Offsets a line edge by computing a perpendicular displacement.
 fn offset_line(
     store: &mut TopologyStore,
@@ -73,6 +165,7 @@ fn offset_line(
         curve: EdgeCurve::Line(new_line),
         t_start: 0.0,
         t_end: new_t_end,
+        tessellation_tolerance: None,
     }))
 }
 
@@ -124,6 +217,7 @@ fn offset_arc(
                 curve: EdgeCurve::Arc(new_arc),
                 t_start,
                 t_end,
+                tessellation_tolerance: None,
             }))
         }
         None => Err(OperationError::Failed("arc offset collapsed (radius <= 0)".into()).into()),
@@ -183,4 +277,46 @@ mod tests {
         // Right offset = downward (negative Y)
         assert!((start.y + 2.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn offset_wire_keeps_arc_edges_of_rounded_rectangle() {
+        let mut store = TopologyStore::new();
+        // Stadium in the z = 2 plane: 10 long, 4 wide, semicircular ends,
+        // built from a bulge polyline through the materializer.
+        let plane = Plane::new(Point3::new(0.0, 0.0, 2.0), Vector3::x(), Vector3::y()).unwrap();
+        let frame = PlaneFrame::new(&plane);
+        let stadium = Pline {
+            vertices: vec![
+                PlineVertex::line(0.0, 0.0),
+                PlineVertex::new(10.0, 0.0, 1.0),
+                PlineVertex::line(10.0, 4.0),
+                PlineVertex::new(0.0, 4.0, 1.0),
+            ],
+            closed: true,
+        };
+        let wire = pline_to_wire(&mut store, &stadium, &frame).unwrap();
+
+        let results = CurveOffset2D::offset_wire(&mut store, wire, 0.5, &plane).unwrap();
+        assert_eq!(results.len(), 1);
+        let result = store.wire(results[0]).unwrap();
+        assert!(result.is_closed);
+
+        let mut arcs = 0;
+        for oe in &result.edges {
+            let edge = store.edge(oe.edge).unwrap();
+            for v in [edge.start, edge.end] {
+                assert!((store.vertex(v).unwrap().point.z - 2.0).abs() < 1e-12);
+            }
+            if let EdgeCurve::Arc(arc) = &edge.curve {
+                arcs += 1;
+                assert!((arc.radius() - 1.5).abs() < 1e-9, "r={}", arc.radius());
+                // The arc's own endpoints meet the edge's vertices.
+                let start = store.vertex(edge.start).unwrap().point;
+                let end = store.vertex(edge.end).unwrap().point;
+                assert!((arc.evaluate(edge.t_start).unwrap() - start).norm() < 1e-9);
+                assert!((arc.evaluate(edge.t_end).unwrap() - end).norm() < 1e-9);
+            }
+        }
+        assert_eq!(arcs, 2);
+    }
 }
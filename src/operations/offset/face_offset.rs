@@ -125,6 +125,10 @@ impl FaceOffset {
                 "offsetting NURBS faces is not yet supported".into(),
             )
             .into()),
+            FaceSurface::Ruled(_) | FaceSurface::Extruded(_) => Err(OperationError::Failed(
+                "offsetting ruled and extruded faces is not yet supported".into(),
+            )
+            .into()),
         }
     }
 }
@@ -1,16 +1,18 @@
 mod curve_offset_2d;
 mod face_offset;
 pub mod pline_offset;
+mod polygon_offset;
 mod thicken_face;
 pub mod wall_outline;
 mod wire_offset_2d;
 
 pub use curve_offset_2d::CurveOffset2D;
 pub use face_offset::FaceOffset;
-pub use pline_offset::PlineOffset2D;
+pub use pline_offset::{PlineOffset2D, PlineOffsetSide, PlineRegion};
+pub use polygon_offset::offset_polygon;
 pub use thicken_face::ThickenFace;
 pub use wall_outline::{
-    CapEnd, FootprintProvenance, OffsetSide, SegmentOrigin, SegmentProvenance, WallFootprint2D,
-    WallOutline2D,
+    CapEnd, CapStyle, FootprintProvenance, OffsetSide, SegmentOrigin, SegmentProvenance,
+    WallFootprint2D, WallOutline2D,
 };
 pub use wire_offset_2d::WireOffset2D;
@@ -1,14 +1,18 @@
-use crate::geometry::pline::Pline;
+use crate::geometry::pline::{Pline, PlineVertex};
 use crate::math::arc_2d::arc_from_bulge;
 use crate::math::distance_2d::{point_to_arc_dist, point_to_segment_dist};
 
 use super::slice::PlineSlice;
 
-/// Filters slices, keeping only those whose midpoints are at least `|distance| - eps`
+/// Filters slices, keeping only those lying at least `|distance| - eps`
 /// from the original polyline.
 ///
 /// This removes self-intersection loops that are "too close" to the original,
-/// which are artifacts of the offset rather than valid geometry.
+/// which are artifacts of the offset rather than valid geometry. Every
+/// vertex of a slice and the midpoint of every segment are probed, so a
+/// loop that only touches the offset distance at its middle vertex (such
+/// as the pocket a narrow slit leaves in an outward offset) is still
+/// rejected.
 #[must_use]
 pub fn apply<'a>(slices: &'a [PlineSlice], original: &Pline, distance: f64) -> Vec<&'a PlineSlice> {
     let abs_d = distance.abs();
@@ -16,19 +20,31 @@ pub fn apply<'a>(slices: &'a [PlineSlice], original: &Pline, distance: f64) -> V
 
     slices
         .iter()
-        .filter(|s| {
-            if s.vertices.len() < 2 {
-                return false;
-            }
-            // Check the midpoint of the slice.
-            let mid_idx = s.vertices.len() / 2;
-            let mid = &s.vertices[mid_idx];
-            let dist = min_dist_to_pline(mid.x, mid.y, original);
-            dist >= threshold
-        })
+        .filter(|s| s.vertices.len() >= 2 && stays_clear(&s.vertices, original, threshold))
         .collect()
 }
 
+/// Whether every vertex of the path `vertices` and the midpoint of every
+/// segment between them lie at least `min_dist` from `original`.
+#[must_use]
+pub fn stays_clear(vertices: &[PlineVertex], original: &Pline, min_dist: f64) -> bool {
+    let midpoints = vertices.windows(2).map(|w| {
+        // The arc midpoint sits `bulge * chord / 2` right of the
+        // chord midpoint.
+        let (dx, dy) = (w[1].x - w[0].x, w[1].y - w[0].y);
+        let half = w[0].bulge * 0.5;
+        (
+            0.5 * (w[0].x + w[1].x) + dy * half,
+            0.5 * (w[0].y + w[1].y) - dx * half,
+        )
+    });
+    vertices
+        .iter()
+        .map(|v| (v.x, v.y))
+        .chain(midpoints)
+        .all(|(x, y)| min_dist_to_pline(x, y, original) >= min_dist)
+}
+
 /// Computes the minimum distance from a point to a polyline.
 ///
 /// Handles both line segments (bulge=0) and arc segments (bulge≠0).
@@ -1,38 +1,147 @@
 mod filter;
+mod overlap;
 mod raw_offset;
 mod self_intersect;
 mod slice;
 mod stitch;
+#[cfg(feature = "debug-dump")]
+mod trace;
 
-use crate::error::{OperationError, Result};
-use crate::geometry::pline::Pline;
+use crate::error::{GeolisError, OffsetError, OperationError, Result};
+use crate::geometry::pline::{Pline, PlineVertex};
+use crate::math::polygon_2d::canonical_start_index;
+use crate::math::Point3;
+
+use super::wall_outline::OffsetSide;
+
+pub use crate::geometry::pline::PlineRegion;
+#[cfg(feature = "debug-dump")]
+pub use trace::{OffsetTrace, TracedSlice};
+
+/// An intermediate result of the slice-and-filter pipeline, reported to
+/// the observer passed to [`PlineOffset2D::execute_observed`].
+#[cfg_attr(not(feature = "debug-dump"), allow(dead_code))]
+enum Stage<'a> {
+    /// The untrimmed offset polyline.
+    Raw(&'a Pline),
+    /// Self-intersections of the raw offset.
+    Intersections(&'a [self_intersect::Intersection]),
+    /// All slices, and the ones the distance filter kept.
+    Filtered {
+        slices: &'a [slice::PlineSlice],
+        kept: &'a [&'a slice::PlineSlice],
+    },
+}
+
+/// Which side of a polyline an offset is taken on, for
+/// [`PlineOffset2D::new_sided`].
+///
+/// `Left` and `Right` are relative to the traversal direction (`Left` is
+/// +90° from it). `Inward` and `Outward` apply to closed polylines only and
+/// are resolved against the polyline's actual winding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlineOffsetSide {
+    /// +90° from the traversal direction.
+    Left,
+    /// −90° from the traversal direction.
+    Right,
+    /// Into the region a closed polyline bounds.
+    Inward,
+    /// Away from the region a closed polyline bounds.
+    Outward,
+}
 
 /// Offsets a polyline (with potential arc segments) using the slice-and-filter
 /// algorithm.
 ///
-/// For closed polylines: positive distance = inward, negative = outward.
-/// For open polylines: positive distance = left side, negative = right side.
+/// A positive distance offsets to the left of the traversal direction, a
+/// negative one to the right. For a closed polyline left is inward when it
+/// is wound counter-clockwise and outward when it is wound clockwise; use
+/// [`PlineOffset2D::new_sided`] to request a side without tracking the sign.
 /// Returns offset curve(s) without endpoint caps.
+///
+/// Input with large coordinates should be moved near the origin with
+/// [`Pline::recenter`] first and the results shifted back, since the
+/// algorithm's tolerance comparisons lose precision far from the origin.
 #[derive(Debug)]
 pub struct PlineOffset2D {
     pline: Pline,
     distance: f64,
+    round_joins: bool,
 }
 
 impl PlineOffset2D {
-    /// Creates a new polyline offset operation.
+    /// Creates a new polyline offset operation with a signed distance
+    /// (positive = left of the traversal direction).
     #[must_use]
     pub fn new(pline: Pline, distance: f64) -> Self {
-        Self { pline, distance }
+        Self {
+            pline,
+            distance,
+            round_joins: false,
+        }
+    }
+
+    /// Joins convex corners with an arc about the original vertex instead
+    /// of a miter, so the offset stays exactly `|distance|` from the input.
+    #[must_use]
+    pub fn with_round_joins(mut self, round_joins: bool) -> Self {
+        self.round_joins = round_joins;
+        self
+    }
+
+    /// Creates a polyline offset by `distance` (taken as a magnitude) on the
+    /// named `side`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::InvalidInput` if `side` is `Inward` or
+    /// `Outward` and the polyline is open or has no well-defined winding
+    /// (zero signed area).
+    pub fn new_sided(pline: Pline, distance: f64, side: PlineOffsetSide) -> Result<Self> {
+        let left = match side {
+            PlineOffsetSide::Left => true,
+            PlineOffsetSide::Right => false,
+            PlineOffsetSide::Inward | PlineOffsetSide::Outward => {
+                if !pline.closed {
+                    return Err(OperationError::InvalidInput(
+                        "inward/outward offset requires a closed pline".to_owned(),
+                    )
+                    .into());
+                }
+                let area = pline.signed_area();
+                if area.abs() < crate::math::TOLERANCE {
+                    return Err(OperationError::InvalidInput(
+                        "pline winding is undefined (zero area)".to_owned(),
+                    )
+                    .into());
+                }
+                // Left of a counter-clockwise loop is its interior.
+                (area > 0.0) == (side == PlineOffsetSide::Inward)
+            }
+        };
+        let magnitude = distance.abs();
+        Ok(Self::new(pline, if left { magnitude } else { -magnitude }))
     }
 
     /// Executes the offset, returning one or more result polylines.
     ///
+    /// Results of a closed polyline are ordered deterministically: by
+    /// descending absolute area, each loop starting at its leftmost vertex
+    /// (lowest on ties). Loop windings follow the input.
+    ///
     /// # Errors
     ///
     /// Returns `OperationError::InvalidInput` if the polyline has fewer than
-    /// 2 vertices, or `OperationError::Failed` if the offset collapses entirely.
+    /// 2 vertices, or [`OffsetError::Collapsed`] if the offset collapses
+    /// entirely.
     pub fn execute(&self) -> Result<Vec<Pline>> {
+        self.execute_observed(&mut |_| {})
+    }
+
+    /// [`execute`](Self::execute), reporting each pipeline stage to
+    /// `observe`.
+    fn execute_observed(&self, observe: &mut dyn FnMut(Stage<'_>)) -> Result<Vec<Pline>> {
         if self.pline.vertices.len() < 2 {
             return Err(OperationError::InvalidInput(
                 "at least 2 vertices required for pline offset".to_owned(),
@@ -45,22 +154,143 @@ impl PlineOffset2D {
         }
 
         if self.pline.closed {
-            self.execute_closed()
+            self.execute_closed(observe)
         } else {
-            self.execute_open()
+            self.execute_open(observe)
+        }
+    }
+
+    /// Offsets an open polyline by the magnitude of this operation's distance
+    /// on `side`, returning the single-sided offset curve(s).
+    ///
+    /// The raw offset is trimmed at its self-intersections but never closed
+    /// into a buffer outline, so the results stay open.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::InvalidInput` if the polyline is closed,
+    /// plus the errors of [`execute`](Self::execute).
+    pub fn offset_open_side(&self, side: OffsetSide) -> Result<Vec<Pline>> {
+        if self.pline.closed {
+            return Err(OperationError::InvalidInput(
+                "single-side offset requires an open pline".to_owned(),
+            )
+            .into());
+        }
+        let side = match side {
+            OffsetSide::Left => PlineOffsetSide::Left,
+            OffsetSide::Right => PlineOffsetSide::Right,
+        };
+        Self::new_sided(self.pline.clone(), self.distance, side)?.execute()
+    }
+
+    /// Builds the closed outline of an open polyline buffered by the
+    /// magnitude of this operation's distance on both sides.
+    ///
+    /// Each side is offset like [`execute`](Self::execute) does, so arc
+    /// segments stay concentric arcs and the outline keeps the path's
+    /// curvature. The two sides are joined by straight (butt) caps across
+    /// the path's ends into a counter-clockwise loop; where the outline
+    /// crosses itself it is trimmed with the closed slice-and-filter
+    /// pipeline. Results are ordered as in [`execute`](Self::execute).
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::InvalidInput` if the polyline is closed,
+    /// has fewer than 2 vertices or the distance is zero, the errors of the
+    /// per-side offsets (e.g. an arc collapsing on its inner side), or
+    /// [`OffsetError::Collapsed`] if nothing of the outline survives.
+    pub fn execute_buffer(&self) -> Result<Vec<Pline>> {
+        if self.pline.closed || self.pline.vertices.len() < 2 {
+            return Err(OperationError::InvalidInput(
+                "buffer outline requires an open pline with at least 2 vertices".to_owned(),
+            )
+            .into());
+        }
+        let distance = self.distance.abs();
+        if distance < crate::math::TOLERANCE {
+            return Err(OperationError::InvalidInput(
+                "buffer distance must be non-zero".to_owned(),
+            )
+            .into());
+        }
+
+        // Right side forward, then left side backward: counter-clockwise.
+        let right = overlap::remove_retraced(&raw_offset::build(
+            &self.pline,
+            -distance,
+            self.round_joins,
+        )?);
+        let left =
+            overlap::remove_retraced(&raw_offset::build(&self.pline, distance, self.round_joins)?)
+                .reversed();
+        // The segment leaving each side's last vertex is a straight cap.
+        let cap = |mut side: Vec<PlineVertex>| {
+            if let Some(last) = side.last_mut() {
+                last.bulge = 0.0;
+            }
+            side
+        };
+        let mut vertices = cap(right.vertices);
+        vertices.extend(cap(left.vertices));
+        let outline = Pline {
+            vertices,
+            closed: true,
+        };
+
+        let intersections = self_intersect::find_all(&outline);
+        if intersections.is_empty() {
+            return Ok(vec![outline]);
+        }
+        let slices = slice::build(&outline.vertices, outline.segment_count(), &intersections);
+        let valid = filter::apply(&slices, &self.pline, distance);
+        let result = stitch::connect(&valid, true);
+        if result.is_empty() {
+            return Err(collapsed(0.0));
         }
+        Ok(canonical_order(result))
+    }
+
+    /// Executes the offset of a closed polyline and groups the resulting
+    /// loops into islands.
+    ///
+    /// Collapsing concavities (inward) or closing gaps (outward) can turn a
+    /// single input loop into several loops; each returned [`PlineRegion`]
+    /// pairs an outer loop with the loops it directly contains, determined
+    /// by containment depth (see [`Pline::classify_nesting`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::InvalidInput` if the polyline is open or has
+    /// fewer than 2 vertices, or [`OffsetError::Collapsed`] if the offset
+    /// collapses entirely.
+    pub fn execute_islands(&self) -> Result<Vec<PlineRegion>> {
+        if !self.pline.closed {
+            return Err(OperationError::InvalidInput(
+                "island classification requires a closed pline".to_owned(),
+            )
+            .into());
+        }
+        Ok(Pline::classify_nesting(&self.execute()?))
     }
 
     /// Executes offset for closed polylines using the standard slice-and-filter
     /// pipeline.
-    fn execute_closed(&self) -> Result<Vec<Pline>> {
+    fn execute_closed(&self, observe: &mut dyn FnMut(Stage<'_>)) -> Result<Vec<Pline>> {
         // Step 1: Build raw offset polyline.
-        let raw = raw_offset::build(&self.pline, self.distance)?;
+        let raw = raw_offset::build(&self.pline, self.distance, self.round_joins)?;
+        observe(Stage::Raw(&raw));
 
         // Step 2: Find all self-intersections.
         let intersections = self_intersect::find_all(&raw);
+        observe(Stage::Intersections(&intersections));
         if intersections.is_empty() {
-            return Ok(vec![raw]);
+            // An inward offset past the inradius turns the loop inside out
+            // without self-intersecting.
+            if raw.signed_area() * self.pline.signed_area() < 0.0 {
+                return Err(collapsed(0.0));
+            }
+            return Ok(canonical_order(vec![raw]));
         }
 
         // Step 3: Slice at intersection points.
@@ -69,15 +299,25 @@ impl PlineOffset2D {
 
         // Step 4: Filter slices by distance to original.
         let valid = filter::apply(&slices, &self.pline, self.distance);
+        observe(Stage::Filtered {
+            slices: &slices,
+            kept: &valid,
+        });
 
         // Step 5: Stitch valid slices into result polylines.
         let result = stitch::connect(&valid, true);
 
         if result.is_empty() {
-            return Err(OperationError::Failed("offset collapsed completely".to_owned()).into());
+            let all: Vec<&slice::PlineSlice> = slices.iter().collect();
+            let winding = self.pline.signed_area().signum();
+            let largest = stitch::connect(&all, true)
+                .iter()
+                .map(|l| l.signed_area() * winding)
+                .fold(0.0, f64::max);
+            return Err(collapsed(largest));
         }
 
-        Ok(result)
+        Ok(canonical_order(result))
     }
 
     /// Executes offset for open polylines using the slice-and-filter pipeline.
@@ -85,12 +325,22 @@ impl PlineOffset2D {
     /// Positive distance offsets to the left (when facing along the polyline
     /// direction), negative distance offsets to the right.  Returns open
     /// polyline(s) without endpoint caps.
-    fn execute_open(&self) -> Result<Vec<Pline>> {
-        // Step 1: Build raw offset polyline.
-        let raw = raw_offset::build(&self.pline, self.distance)?;
+    fn execute_open(&self, observe: &mut dyn FnMut(Stage<'_>)) -> Result<Vec<Pline>> {
+        // Step 1: Build raw offset polyline, folding away the spikes left
+        // where the offsets of a hairpin's arms retrace each other.
+        let raw = overlap::remove_retraced(&raw_offset::build(
+            &self.pline,
+            self.distance,
+            self.round_joins,
+        )?);
+        observe(Stage::Raw(&raw));
+        if raw.vertices.len() < 2 {
+            return Err(collapsed(0.0));
+        }
 
         // Step 2: Find all self-intersections.
         let intersections = self_intersect::find_all(&raw);
+        observe(Stage::Intersections(&intersections));
         if intersections.is_empty() {
             return Ok(vec![raw]);
         }
@@ -101,23 +351,267 @@ impl PlineOffset2D {
 
         // Step 4: Filter slices by distance to original.
         let valid = filter::apply(&slices, &self.pline, self.distance);
+        observe(Stage::Filtered {
+            slices: &slices,
+            kept: &valid,
+        });
 
         // Step 5: Stitch valid slices into result polylines.
         let result = stitch::connect(&valid, false);
 
         if result.is_empty() {
-            return Err(OperationError::Failed("offset collapsed completely".to_owned()).into());
+            return Err(collapsed(0.0));
         }
 
         Ok(result)
     }
 }
 
+/// Puts closed offset loops in a deterministic order: each loop is rotated
+/// to start where [`rotate_to_canonical_start`] would start it (leftmost
+/// vertex, ties broken by smallest y), keeping every bulge on its segment,
+/// and loops are sorted by descending absolute area, then by start vertex.
+/// Windings are left as they are.
+///
+/// [`rotate_to_canonical_start`]: crate::math::polygon_2d::rotate_to_canonical_start
+fn canonical_order(mut loops: Vec<Pline>) -> Vec<Pline> {
+    for pline in &mut loops {
+        let points: Vec<Point3> = pline
+            .vertices
+            .iter()
+            .map(|v| Point3::new(v.x, v.y, 0.0))
+            .collect();
+        pline.vertices.rotate_left(canonical_start_index(&points));
+    }
+    let start = |p: &Pline| p.vertices.first().map_or((0.0, 0.0), |v| (v.x, v.y));
+    loops.sort_by(|a, b| {
+        b.signed_area()
+            .abs()
+            .total_cmp(&a.signed_area().abs())
+            .then_with(|| start(a).0.total_cmp(&start(b).0))
+            .then_with(|| start(a).1.total_cmp(&start(b).1))
+    });
+    loops
+}
+
+/// Builds the error reported when no part of an offset survives.
+fn collapsed(largest_remaining_area: f64) -> crate::error::GeolisError {
+    OperationError::from(OffsetError::Collapsed {
+        largest_remaining_area,
+    })
+    .into()
+}
+
+impl Pline {
+    /// Offsets this polyline by a signed `distance` (positive = left of the
+    /// traversal direction), shorthand for
+    /// `PlineOffset2D::new(self.clone(), distance).execute()`.
+    ///
+    /// Use [`offset_islands`](Self::offset_islands) for island results and
+    /// [`PlineOffset2D`] directly for sided offsets.
+    ///
+    /// ```
+    /// use geolis::geometry::pline::{Pline, PlineVertex};
+    ///
+    /// let square = Pline {
+    ///     vertices: vec![
+    ///         PlineVertex::line(0.0, 0.0),
+    ///         PlineVertex::line(10.0, 0.0),
+    ///         PlineVertex::line(10.0, 10.0),
+    ///         PlineVertex::line(0.0, 10.0),
+    ///     ],
+    ///     closed: true,
+    /// };
+    /// // Counter-clockwise, so a positive distance offsets inward: 8 × 8.
+    /// let inset = square.offset(1.0)?;
+    /// assert_eq!(inset.len(), 1);
+    /// assert!((inset[0].signed_area() - 64.0).abs() < 1e-9);
+    /// # Ok::<(), geolis::GeolisError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`PlineOffset2D::execute`].
+    pub fn offset(&self, distance: f64) -> Result<Vec<Pline>> {
+        PlineOffset2D::new(self.clone(), distance).execute()
+    }
+
+    /// Offsets this closed polyline by a signed `distance` and groups the
+    /// loops into islands, shorthand for
+    /// `PlineOffset2D::new(self.clone(), distance).execute_islands()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`PlineOffset2D::execute_islands`].
+    pub fn offset_islands(&self, distance: f64) -> Result<Vec<PlineRegion>> {
+        PlineOffset2D::new(self.clone(), distance).execute_islands()
+    }
+
+    /// Offsets this closed polyline toward its interior by `distance`,
+    /// whichever way it is wound; shorthand for a
+    /// [`PlineOffset2D::new_sided`] offset on [`PlineOffsetSide::Inward`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`PlineOffset2D::new_sided`] (open or
+    /// zero-area polyline) and [`PlineOffset2D::execute`].
+    pub fn shrink(&self, distance: f64) -> Result<Vec<Pline>> {
+        PlineOffset2D::new_sided(self.clone(), distance, PlineOffsetSide::Inward)?.execute()
+    }
+
+    /// Offsets this closed polyline away from its interior by `distance`,
+    /// whichever way it is wound; the outward counterpart of
+    /// [`shrink`](Self::shrink).
+    ///
+    /// # Errors
+    ///
+    /// As for [`shrink`](Self::shrink).
+    pub fn grow(&self, distance: f64) -> Result<Vec<Pline>> {
+        PlineOffset2D::new_sided(self.clone(), distance, PlineOffsetSide::Outward)?.execute()
+    }
+
+    /// Morphological closing: offsets this closed polyline outward by
+    /// `radius` and the result back inward, both with round joins.
+    ///
+    /// Notches and gaps narrower than `2 * radius` are filled; features
+    /// that survive both passes keep their shape. Loops are returned
+    /// grouped as by [`Pline::classify_nesting`]: each outer
+    /// (counter-clockwise) followed by its holes (clockwise).
+    ///
+    /// # Errors
+    ///
+    /// Returns `OperationError::InvalidInput` if the polyline is open or
+    /// `radius` is not positive, plus the errors of
+    /// [`PlineOffset2D::execute`] other than a collapse.
+    pub fn morph_close(&self, radius: f64) -> Result<Vec<Pline>> {
+        morph(self, radius, -1.0)
+    }
+
+    /// Morphological opening: offsets this closed polyline inward by
+    /// `radius` and the result back outward, both with round joins.
+    ///
+    /// Spurs and necks narrower than `2 * radius` are removed and convex
+    /// corners are rounded to `radius`. Results are grouped as in
+    /// [`morph_close`](Self::morph_close); a polyline that vanishes
+    /// entirely yields no loops.
+    ///
+    /// # Errors
+    ///
+    /// As for [`morph_close`](Self::morph_close).
+    pub fn morph_open(&self, radius: f64) -> Result<Vec<Pline>> {
+        morph(self, radius, 1.0)
+    }
+}
+
+/// Offsets `pline` by `first_sign * radius` and then back by the opposite
+/// amount, with round joins. Loops are re-oriented by nesting before each
+/// pass so that one signed distance erodes (positive) or dilates
+/// (negative) every outer and hole alike; loops that collapse are dropped,
+/// and loops that grow into each other are merged (see [`offset_rings`]).
+fn morph(pline: &Pline, radius: f64, first_sign: f64) -> Result<Vec<Pline>> {
+    if !pline.closed {
+        return Err(
+            OperationError::InvalidInput("morphology requires a closed pline".to_owned()).into(),
+        );
+    }
+    if radius.is_nan() || radius <= 0.0 {
+        return Err(OperationError::InvalidInput(format!(
+            "morphology radius must be positive, got {radius}"
+        ))
+        .into());
+    }
+
+    let mut loops = vec![pline.clone()];
+    for sign in [first_sign, -first_sign] {
+        let rings: Vec<Pline> = Pline::classify_nesting(&loops)
+            .into_iter()
+            .flat_map(|region| std::iter::once(region.outer).chain(region.holes))
+            .collect();
+        loops = offset_rings(&rings, sign * radius)?;
+    }
+
+    Ok(Pline::classify_nesting(&loops)
+        .into_iter()
+        .flat_map(|region| std::iter::once(region.outer).chain(region.holes))
+        .collect())
+}
+
+/// Offsets every ring of a region by `distance` with round joins, as one
+/// boundary.
+///
+/// Each ring is offset on its own first; rings that collapse are dropped.
+/// Where the loops of different rings cross, they are split at the
+/// crossings, the pieces that come closer than `|distance|` to another
+/// ring are discarded, and the rest are stitched back together. A loop
+/// that crosses nothing is kept only if it stays clear of every other
+/// ring, so a loop swallowed by another's offset disappears.
+fn offset_rings(rings: &[Pline], distance: f64) -> Result<Vec<Pline>> {
+    let mut loops: Vec<(usize, Pline)> = Vec::new();
+    for (source, ring) in rings.iter().enumerate() {
+        match PlineOffset2D::new(ring.clone(), distance)
+            .with_round_joins(true)
+            .execute()
+        {
+            Ok(result) => loops.extend(result.into_iter().map(|l| (source, l))),
+            Err(GeolisError::Operation(OperationError::Offset(OffsetError::Collapsed {
+                ..
+            }))) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    if rings.len() < 2 {
+        return Ok(loops.into_iter().map(|(_, l)| l).collect());
+    }
+
+    let mut splits: Vec<Vec<(usize, f64)>> = vec![Vec::new(); loops.len()];
+    for a in 0..loops.len() {
+        for b in (a + 1)..loops.len() {
+            if loops[a].0 == loops[b].0 {
+                continue;
+            }
+            for (seg_a, t_a, seg_b, t_b) in self_intersect::find_between(&loops[a].1, &loops[b].1) {
+                splits[a].push((seg_a, t_a));
+                splits[b].push((seg_b, t_b));
+            }
+        }
+    }
+
+    // Crossing points sit exactly `|distance|` from both rings, so allow
+    // for rounding there.
+    let min_dist = distance.abs() * (1.0 - 1e-9) - crate::math::TOLERANCE;
+    let clear_of_others = |vertices: &[PlineVertex], source: usize| {
+        rings
+            .iter()
+            .enumerate()
+            .all(|(i, ring)| i == source || filter::stays_clear(vertices, ring, min_dist))
+    };
+
+    let mut result = Vec::new();
+    let mut pieces = Vec::new();
+    for ((source, pline), at) in loops.iter().zip(&splits) {
+        if at.len() < 2 {
+            if clear_of_others(&pline.vertices, *source) {
+                result.push(pline.clone());
+            }
+        } else {
+            pieces.extend(
+                slice::build_at(&pline.vertices, pline.segment_count(), at)
+                    .into_iter()
+                    .filter(|piece| clear_of_others(&piece.vertices, *source)),
+            );
+        }
+    }
+    let pieces: Vec<&slice::PlineSlice> = pieces.iter().collect();
+    result.extend(stitch::connect(&pieces, true));
+    Ok(result)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-    use crate::geometry::pline::PlineVertex;
+    use crate::geometry::pline::PlineSegment;
+    use crate::math::{angle_between, Point2, Point3, Vector2};
 
     fn square_pline() -> Pline {
         Pline {
@@ -141,6 +635,61 @@ mod tests {
         assert_eq!(poly.vertices.len(), 4, "expected 4 vertices");
     }
 
+    #[test]
+    fn pline_offset_shrinks_square_to_8x8() {
+        let result = square_pline().offset(1.0).unwrap();
+        assert_eq!(result.len(), 1);
+        let (mut min, mut max) = ([f64::MAX; 2], [f64::MIN; 2]);
+        for v in &result[0].vertices {
+            min = [min[0].min(v.x), min[1].min(v.y)];
+            max = [max[0].max(v.x), max[1].max(v.y)];
+        }
+        assert!((max[0] - min[0] - 8.0).abs() < 1e-9);
+        assert!((max[1] - min[1] - 8.0).abs() < 1e-9);
+        assert!((result[0].signed_area() - 64.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn shrink_and_grow_ignore_winding() {
+        for pline in [square_pline(), square_pline().reversed()] {
+            let shrunk = pline.shrink(1.0).unwrap();
+            assert_eq!(shrunk.len(), 1);
+            assert!((shrunk[0].signed_area().abs() - 64.0).abs() < 1e-9);
+            let (mut min, mut max) = ([f64::MAX; 2], [f64::MIN; 2]);
+            for v in &shrunk[0].vertices {
+                min = [min[0].min(v.x), min[1].min(v.y)];
+                max = [max[0].max(v.x), max[1].max(v.y)];
+            }
+            assert!((min[0] - 1.0).abs() < 1e-9 && (max[0] - 9.0).abs() < 1e-9);
+            assert!((min[1] - 1.0).abs() < 1e-9 && (max[1] - 9.0).abs() < 1e-9);
+
+            let grown = pline.grow(1.0).unwrap();
+            assert_eq!(grown.len(), 1);
+            assert!((grown[0].signed_area().abs() - 144.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn recentered_offset_matches_offset_at_origin() {
+        use crate::math::Vector2;
+
+        let far = square_pline().translated(Vector2::new(1e6 - 5.0, 1e6 - 5.0));
+        let (near, shift) = far.recenter();
+        assert!((shift - Vector2::new(-1e6, -1e6)).norm() < 1e-9);
+        let result: Vec<Pline> = near
+            .offset(1.0)
+            .unwrap()
+            .iter()
+            .map(|p| p.translated(-shift))
+            .collect();
+
+        let expected = square_pline().offset(1.0).unwrap();
+        assert_eq!(result.len(), expected.len());
+        let expected = expected[0].translated(Vector2::new(1e6 - 5.0, 1e6 - 5.0));
+        assert!(result[0].approx_eq(&expected, 1e-9));
+        assert!((result[0].signed_area() - 64.0).abs() < 1e-6);
+    }
+
     #[test]
     fn square_outward_offset() {
         let op = PlineOffset2D::new(square_pline(), -1.0);
@@ -150,6 +699,32 @@ mod tests {
         assert_eq!(poly.vertices.len(), 4, "expected 4 vertices");
     }
 
+    #[test]
+    fn sided_inward_shrinks_both_windings() {
+        let ccw = square_pline();
+        let cw = ccw.reversed();
+        for pline in [ccw, cw] {
+            let op = PlineOffset2D::new_sided(pline, 1.0, PlineOffsetSide::Inward).unwrap();
+            let result = op.execute().unwrap();
+            assert_eq!(result.len(), 1);
+            let area = result[0].signed_area().abs();
+            assert!((area - 64.0).abs() < 1e-9, "area={area}");
+
+            let op = PlineOffset2D::new_sided(result[0].clone(), 1.0, PlineOffsetSide::Outward);
+            let grown = op.unwrap().execute().unwrap();
+            let area = grown[0].signed_area().abs();
+            assert!((area - 100.0).abs() < 1e-9, "area={area}");
+        }
+    }
+
+    #[test]
+    fn sided_inward_rejects_open_pline() {
+        let mut open = square_pline();
+        open.closed = false;
+        assert!(PlineOffset2D::new_sided(open.clone(), 1.0, PlineOffsetSide::Inward).is_err());
+        assert!(PlineOffset2D::new_sided(open, 1.0, PlineOffsetSide::Left).is_ok());
+    }
+
     #[test]
     fn no_self_intersection_passthrough() {
         // A simple triangle offset inward — no self-intersections expected.
@@ -253,6 +828,34 @@ mod tests {
         assert!(!result[0].closed, "result should be open");
     }
 
+    #[test]
+    fn open_l_shape_offset_open_side_left() {
+        let pline = Pline {
+            vertices: vec![
+                PlineVertex::line(0.0, 0.0),
+                PlineVertex::line(5.0, 0.0),
+                PlineVertex::line(5.0, 5.0),
+            ],
+            closed: false,
+        };
+        // The sign of the stored distance is ignored; the side decides.
+        let result = PlineOffset2D::new(pline, -0.5)
+            .offset_open_side(OffsetSide::Left)
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        let curve = &result[0];
+        assert!(!curve.closed, "single-side offset should stay open");
+        let points: Vec<(f64, f64)> = curve.vertices.iter().map(|v| (v.x, v.y)).collect();
+        let expected = [(0.0, 0.5), (4.5, 0.5), (4.5, 5.0)];
+        assert_eq!(points.len(), expected.len(), "{points:?}");
+        for (p, e) in points.iter().zip(expected) {
+            assert!(
+                (p.0 - e.0).abs() < 1e-9 && (p.1 - e.1).abs() < 1e-9,
+                "{points:?}"
+            );
+        }
+    }
+
     #[test]
     fn open_arc_segment_offset() {
         let pline = Pline {
@@ -417,6 +1020,111 @@ mod tests {
         }
     }
 
+    /// Thick C-shape (10×10 block with a 6×6 void) whose mouth is a
+    /// narrow V-shaped slot on the right edge (0.4 wide at the void).
+    fn c_shape_pline() -> Pline {
+        let pts = [
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 4.0),
+            (8.0, 4.8),
+            (6.0, 2.0),
+            (2.0, 2.0),
+            (2.0, 8.0),
+            (6.0, 8.0),
+            (8.0, 5.2),
+            (10.0, 6.0),
+            (10.0, 10.0),
+            (0.0, 10.0),
+        ];
+        Pline {
+            vertices: pts.iter().map(|&(x, y)| PlineVertex::line(x, y)).collect(),
+            closed: true,
+        }
+    }
+
+    #[test]
+    fn islands_open_mouth_single_region_without_holes() {
+        let regions = PlineOffset2D::new(c_shape_pline(), -0.1)
+            .execute_islands()
+            .unwrap();
+        assert_eq!(regions.len(), 1);
+        assert!(regions[0].holes.is_empty());
+        assert!(regions[0].outer.signed_area() > 0.0, "outer must be CCW");
+    }
+
+    #[test]
+    fn islands_closed_mouth_nests_void_as_hole() {
+        // Growing the C by 0.4 seals the slot: the void becomes a hole
+        // of the single outer region.
+        let regions = PlineOffset2D::new(c_shape_pline(), -0.4)
+            .execute_islands()
+            .unwrap();
+        assert_eq!(regions.len(), 1, "one region");
+        let region = &regions[0];
+        assert_eq!(region.holes.len(), 1, "void must be a hole");
+        assert!(region.outer.signed_area() > 0.0, "outer must be CCW");
+        let hole = &region.holes[0];
+        assert!(hole.signed_area() < 0.0, "hole must be CW");
+        assert!(hole.signed_area().abs() < region.outer.signed_area());
+        for v in &hole.vertices {
+            assert!(
+                region.outer.contains_point(v.x, v.y),
+                "hole vertex ({}, {}) outside outer",
+                v.x,
+                v.y
+            );
+        }
+        // Void center is inside the hole, the block body is not.
+        assert!(hole.contains_point(4.0, 5.0));
+        assert!(!hole.contains_point(1.0, 1.0));
+    }
+
+    #[test]
+    fn offset_islands_splits_pinched_bar_inward() {
+        // A 10x4 bar pinched to a 1-wide waist by facing V notches;
+        // shrinking by 1 cuts the waist and leaves one island per lobe.
+        let pts = [
+            (0.0, 0.0),
+            (3.5, 0.0),
+            (5.0, 1.5),
+            (6.5, 0.0),
+            (10.0, 0.0),
+            (10.0, 4.0),
+            (6.5, 4.0),
+            (5.0, 2.5),
+            (3.5, 4.0),
+            (0.0, 4.0),
+        ];
+        let pinched = Pline {
+            vertices: pts.iter().map(|&(x, y)| PlineVertex::line(x, y)).collect(),
+            closed: true,
+        };
+        let regions = pinched.offset_islands(1.0).unwrap();
+        assert_eq!(regions.len(), 2);
+        for (x, y) in [(2.0, 2.0), (8.0, 2.0)] {
+            let region = regions
+                .iter()
+                .find(|r| r.outer.contains_point(x, y))
+                .unwrap();
+            assert!(region.holes.is_empty());
+            assert!(region.outer.signed_area() > 0.0, "outer must be CCW");
+            assert!(!region.outer.contains_point(5.0, 2.0));
+            for v in &region.outer.vertices {
+                assert!(v.y > 0.99 && v.y < 3.01, "({}, {})", v.x, v.y);
+            }
+        }
+    }
+
+    #[test]
+    fn islands_reject_open_pline() {
+        let pline = Pline {
+            vertices: vec![PlineVertex::line(0.0, 0.0), PlineVertex::line(10.0, 0.0)],
+            closed: false,
+        };
+        assert!(PlineOffset2D::new(pline, 1.0).execute_islands().is_err());
+    }
+
     #[test]
     fn mixed_line_arc_square_with_rounded_corner() {
         // Square with one rounded corner (quarter-circle arc).
@@ -434,4 +1142,359 @@ mod tests {
         let result = op.execute().unwrap();
         assert!(!result.is_empty(), "should produce at least one result");
     }
+
+    #[test]
+    fn over_offset_thin_triangle_reports_collapse() {
+        // Inradius is just under 0.5.
+        let tri = Pline {
+            vertices: vec![
+                PlineVertex::line(0.0, 0.0),
+                PlineVertex::line(10.0, 0.0),
+                PlineVertex::line(5.0, 1.0),
+            ],
+            closed: true,
+        };
+        let ok = PlineOffset2D::new_sided(tri.clone(), 0.3, PlineOffsetSide::Inward).unwrap();
+        assert_eq!(ok.execute().unwrap().len(), 1);
+
+        for d in [0.5, 1.0, 3.0] {
+            let err = PlineOffset2D::new_sided(tri.clone(), d, PlineOffsetSide::Inward)
+                .unwrap()
+                .execute()
+                .unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    crate::error::GeolisError::Operation(OperationError::Offset(
+                        OffsetError::Collapsed { largest_remaining_area }
+                    )) if largest_remaining_area.abs() < 1e-12
+                ),
+                "d={d}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn partial_collapse_reports_largest_remaining_area() {
+        // Inward by 3.8 the raw offset of this hexagon still keeps a loop
+        // with the input's winding, but every slice of it lies too close
+        // to the input, so the collapse carries that loop's area.
+        let hexagon = Pline {
+            vertices: [
+                (7.6, 0.4),
+                (0.5, 1.0),
+                (-3.5, 3.7),
+                (-5.5, -0.7),
+                (-0.9, -2.1),
+                (2.3, -2.7),
+            ]
+            .iter()
+            .map(|&(x, y)| PlineVertex::line(x, y))
+            .collect(),
+            closed: true,
+        };
+        let err = PlineOffset2D::new_sided(hexagon.clone(), 3.8, PlineOffsetSide::Inward)
+            .unwrap()
+            .execute()
+            .unwrap_err();
+        let crate::error::GeolisError::Operation(OperationError::Offset(OffsetError::Collapsed {
+            largest_remaining_area,
+        })) = err
+        else {
+            panic!("expected a collapse, got {err:?}");
+        };
+        assert!(
+            largest_remaining_area > 1.0 && largest_remaining_area < hexagon.signed_area().abs(),
+            "{largest_remaining_area}"
+        );
+    }
+
+    #[test]
+    fn hairpin_open_offset_has_no_retraced_edges() {
+        // Arms 1 apart: offsetting 0.5 inward makes their offsets coincide.
+        let hairpin = Pline {
+            vertices: vec![
+                PlineVertex::line(0.0, 0.0),
+                PlineVertex::line(10.0, 0.0),
+                PlineVertex::line(10.0, 1.0),
+                PlineVertex::line(4.0, 1.0),
+            ],
+            closed: false,
+        };
+        let result = PlineOffset2D::new(hairpin.clone(), 0.5).execute().unwrap();
+        assert_eq!(result.len(), 1);
+        let points: Vec<(f64, f64)> = result[0].vertices.iter().map(|v| (v.x, v.y)).collect();
+        assert_eq!(points.len(), 2, "{points:?}");
+        assert!((points[0].0).abs() < 1e-9 && (points[0].1 - 0.5).abs() < 1e-9);
+        assert!((points[1].0 - 4.0).abs() < 1e-9 && (points[1].1 - 0.5).abs() < 1e-9);
+
+        // A full-length return retraces completely: nothing is left.
+        let mut full = hairpin;
+        full.vertices[3] = PlineVertex::line(0.0, 1.0);
+        assert!(PlineOffset2D::new(full, 0.5).execute().is_err());
+    }
+
+    #[test]
+    fn hairpin_buffer_outline_has_no_retraced_edges() {
+        // Arms 1 apart buffered by 0.5: the inner offsets coincide along
+        // y = 0.5, which must not leave a zero-width slit in the outline.
+        let hairpin = Pline {
+            vertices: vec![
+                PlineVertex::line(0.0, 0.0),
+                PlineVertex::line(10.0, 0.0),
+                PlineVertex::line(10.0, 1.0),
+                PlineVertex::line(4.0, 1.0),
+            ],
+            closed: false,
+        };
+        let result = PlineOffset2D::new(hairpin, 0.5).execute_buffer().unwrap();
+        assert_eq!(result.len(), 1);
+        let outline = &result[0];
+        // [0, 10.5] × [-0.5, 0.5] plus [4, 10.5] × [0.5, 1.5].
+        assert!((outline.signed_area() - 17.0).abs() < 1e-9, "{outline:?}");
+        let n = outline.vertices.len();
+        for k in 0..n {
+            let [prev, at, next] = [k, (k + 1) % n, (k + 2) % n].map(|i| outline.vertices[i]);
+            let incoming = Vector2::new(at.x - prev.x, at.y - prev.y);
+            let outgoing = Vector2::new(next.x - at.x, next.y - at.y);
+            assert!(
+                angle_between(incoming, outgoing) < std::f64::consts::PI - 1e-9,
+                "outline doubles back at {at:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn closed_offset_order_is_deterministic() {
+        // Two blocks of different size pinched to a narrow neck: shrinking
+        // past the neck's half-width splits the result into two loops.
+        let pts = [
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (5.0, 1.5),
+            (6.0, 0.0),
+            (11.0, 0.0),
+            (11.0, 5.0),
+            (6.0, 5.0),
+            (5.0, 2.5),
+            (4.0, 4.0),
+            (0.0, 4.0),
+        ];
+        let pline = Pline {
+            vertices: pts.iter().map(|&(x, y)| PlineVertex::line(x, y)).collect(),
+            closed: true,
+        };
+        let first = PlineOffset2D::new(pline.clone(), 0.75).execute().unwrap();
+        let second = PlineOffset2D::new(pline, 0.75).execute().unwrap();
+        assert_eq!(first.len(), second.len());
+        for (a, b) in first.iter().zip(&second) {
+            assert_eq!(a.vertices, b.vertices);
+        }
+
+        assert_eq!(first.len(), 2);
+        assert!(first[0].signed_area().abs() > first[1].signed_area().abs());
+        for loop_ in &first {
+            let start = loop_.vertices[0];
+            for v in &loop_.vertices {
+                assert!(v.x > start.x - 1e-9, "{loop_:?}");
+                if (v.x - start.x).abs() < 1e-9 {
+                    assert!(v.y >= start.y - 1e-9, "{loop_:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn arc_centerline_buffer_is_crescent() {
+        // Quarter circle of radius 10 about the origin, counter-clockwise.
+        let bulge = (std::f64::consts::FRAC_PI_2 / 4.0).tan();
+        let centerline = Pline {
+            vertices: vec![
+                PlineVertex::new(10.0, 0.0, bulge),
+                PlineVertex::line(0.0, 10.0),
+            ],
+            closed: false,
+        };
+        let result = PlineOffset2D::new(centerline, 1.0)
+            .execute_buffer()
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        let outline = &result[0];
+        assert!(outline.closed);
+
+        let arcs: Vec<(f64, f64, f64)> = outline
+            .vertices
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.bulge.abs() > 1e-12)
+            .map(|(i, v)| {
+                let next = outline.vertices[(i + 1) % outline.vertices.len()];
+                let (cx, cy, r, _, _) =
+                    crate::math::arc_2d::arc_from_bulge(v.x, v.y, next.x, next.y, v.bulge);
+                (cx, cy, r)
+            })
+            .collect();
+        assert_eq!(arcs.len(), 2, "{outline:?}");
+        let mut radii: Vec<f64> = arcs.iter().map(|a| a.2).collect();
+        radii.sort_by(f64::total_cmp);
+        assert!((radii[0] - 9.0).abs() < 1e-9 && (radii[1] - 11.0).abs() < 1e-9);
+        for (cx, cy, _) in arcs {
+            assert!(cx.abs() < 1e-9 && cy.abs() < 1e-9);
+        }
+
+        // Quarter annulus between radii 9 and 11.
+        let expected = std::f64::consts::FRAC_PI_4 * (121.0 - 81.0);
+        assert!((outline.signed_area() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn morph_close_fills_slit_notch() {
+        // A 10x10 square with a 0.4 wide, 5 deep slit cut down from the top.
+        let notched = Pline::from_points(
+            &[
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(10.0, 0.0, 0.0),
+                Point3::new(10.0, 10.0, 0.0),
+                Point3::new(5.2, 10.0, 0.0),
+                Point3::new(5.2, 5.0, 0.0),
+                Point3::new(4.8, 5.0, 0.0),
+                Point3::new(4.8, 10.0, 0.0),
+                Point3::new(0.0, 10.0, 0.0),
+            ],
+            true,
+        );
+        assert!((notched.signed_area() - 98.0).abs() < 1e-9);
+
+        let closed = notched.morph_close(1.0).unwrap();
+        assert_eq!(closed.len(), 1);
+        let area = closed[0].signed_area();
+        // Only a shallow dip (~0.02 deep) remains at the slit mouth.
+        assert!(area > 99.99 && area < 100.0 + 1e-9, "area={area}");
+        assert!(closed[0].contains_point(5.0, 7.0));
+        assert!(closed[0].contains_point(5.0, 9.9));
+
+        // Opening a plain square rounds its corners to the radius.
+        let opened = square_pline().morph_open(1.0).unwrap();
+        assert_eq!(opened.len(), 1);
+        let expected = 100.0 - (4.0 - std::f64::consts::PI);
+        assert!((opened[0].signed_area() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn morph_open_merges_lobes_that_regrow_into_each_other() {
+        // A 10x4 bar pinched to a 1 wide waist at x = 5 by right-angled V
+        // notches in both long edges. Eroding by 1 cuts it at the waist
+        // into two nearby lobes whose regrown outlines overlap there.
+        let pinched = Pline::from_points(
+            &[
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(3.5, 0.0, 0.0),
+                Point3::new(5.0, 1.5, 0.0),
+                Point3::new(6.5, 0.0, 0.0),
+                Point3::new(10.0, 0.0, 0.0),
+                Point3::new(10.0, 4.0, 0.0),
+                Point3::new(6.5, 4.0, 0.0),
+                Point3::new(5.0, 2.5, 0.0),
+                Point3::new(3.5, 4.0, 0.0),
+                Point3::new(0.0, 4.0, 0.0),
+            ],
+            true,
+        );
+        let eroded = PlineOffset2D::new(pinched.clone(), 1.0)
+            .with_round_joins(true)
+            .execute()
+            .unwrap();
+        assert_eq!(eroded.len(), 2);
+
+        let opened = pinched.morph_open(1.0).unwrap();
+        assert_eq!(opened.len(), 1);
+        assert!(self_intersect::find_all(&opened[0]).is_empty());
+        let area = opened[0].signed_area();
+        assert!(area > 0.0 && area < pinched.signed_area(), "area={area}");
+        assert!(opened[0].contains_point(5.0, 2.0));
+    }
+
+    /// A 32x10 bar pinched twice by facing semicircular notches of radius
+    /// 4 centred at x = 8 and x = 24; open, it lacks the left edge.
+    fn double_notched_bar(closed: bool) -> Pline {
+        Pline {
+            vertices: vec![
+                PlineVertex::line(0.0, 0.0),
+                PlineVertex::new(4.0, 0.0, -1.0),
+                PlineVertex::line(12.0, 0.0),
+                PlineVertex::new(20.0, 0.0, -1.0),
+                PlineVertex::line(28.0, 0.0),
+                PlineVertex::line(32.0, 0.0),
+                PlineVertex::line(32.0, 10.0),
+                PlineVertex::new(28.0, 10.0, -1.0),
+                PlineVertex::line(20.0, 10.0),
+                PlineVertex::new(12.0, 10.0, -1.0),
+                PlineVertex::line(4.0, 10.0),
+                PlineVertex::line(0.0, 10.0),
+            ],
+            closed,
+        }
+    }
+
+    #[test]
+    fn filter_drops_slices_through_closed_waists() {
+        // Probing only each slice's middle vertex kept the raw loop that
+        // runs through both 2-wide waists, 1 unit from the notches.
+        let bar = double_notched_bar(true);
+        let loops = PlineOffset2D::new(bar.clone(), 1.5).execute().unwrap();
+        assert_eq!(loops.len(), 3);
+        for l in &loops {
+            assert!(filter::stays_clear(&l.vertices, &bar, 1.5 - 1e-6), "{l:?}");
+        }
+    }
+
+    #[test]
+    fn filter_drops_slices_through_open_waists() {
+        // As above for the open bar, whose middle-vertex filter returned
+        // one path crossing both waists.
+        let bar = double_notched_bar(false);
+        let paths = PlineOffset2D::new(bar.clone(), 1.5).execute().unwrap();
+        assert_eq!(paths.len(), 3);
+        for p in &paths {
+            assert!(filter::stays_clear(&p.vertices, &bar, 1.5 - 1e-6), "{p:?}");
+        }
+    }
+
+    #[test]
+    fn stitched_arcs_keep_their_bow() {
+        let bar = double_notched_bar(true);
+        let notch_centers = [(8.0, 0.0), (24.0, 0.0), (8.0, 10.0), (24.0, 10.0)];
+
+        // The offset notches overlap at both waists, so the middle lobe is
+        // stitched from two slices that each start on an arc.
+        let loops = bar.offset(1.5).unwrap();
+        assert_eq!(loops.len(), 3);
+        let middle = loops.iter().find(|l| l.contains_point(16.0, 5.0)).unwrap();
+        let mut arcs = 0;
+        for seg in middle.segments() {
+            let PlineSegment::Arc {
+                a,
+                b,
+                bulge,
+                center,
+                radius,
+                ..
+            } = seg
+            else {
+                continue;
+            };
+            arcs += 1;
+            assert!((radius - 5.5).abs() < 1e-9, "radius={radius}");
+            assert!(notch_centers
+                .iter()
+                .any(|&(x, y)| (center - Point2::new(x, y)).norm() < 1e-9));
+            // The sagitta bows away from the notch centre, onto the circle.
+            let chord = b - a;
+            let right = Vector2::new(chord.y, -chord.x).normalize();
+            let mid =
+                Point2::from((a.coords + b.coords) / 2.0) + right * (bulge * chord.norm() / 2.0);
+            assert!(((mid - center).norm() - 5.5).abs() < 1e-9);
+        }
+        assert_eq!(arcs, 4);
+    }
 }
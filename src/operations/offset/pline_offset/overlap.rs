@@ -0,0 +1,45 @@
+use crate::geometry::pline::{Pline, PlineVertex};
+use crate::math::intersect_2d::segment_segment_overlap_2d;
+use crate::math::{Point3, TOLERANCE};
+
+/// Removes the zero-width spikes an open raw offset picks up where the
+/// input doubles back on itself.
+///
+/// When the two arms of a hairpin are closer than twice the offset
+/// distance, their offsets coincide or pass each other, and the raw
+/// offset retraces its own line. Such a spike has no self-intersection
+/// for slice-and-filter to cut at, so it is folded here instead:
+/// consecutive line segments that overlap collinearly are merged into
+/// their net segment, and coincident vertices are dropped. Arc segments
+/// are left untouched.
+#[must_use]
+pub fn remove_retraced(pline: &Pline) -> Pline {
+    let pt = |v: &PlineVertex| Point3::new(v.x, v.y, 0.0);
+    let mut out: Vec<PlineVertex> = Vec::with_capacity(pline.vertices.len());
+    for &v in &pline.vertices {
+        loop {
+            let n = out.len();
+            if let Some(last) = out.last_mut() {
+                if (last.x - v.x).hypot(last.y - v.y) < TOLERANCE {
+                    // The segment leaving the duplicate continues from `last`.
+                    last.bulge = v.bulge;
+                    break;
+                }
+            }
+            if n >= 2 && out[n - 2].bulge == 0.0 && out[n - 1].bulge == 0.0 {
+                let (p, q) = (pt(&out[n - 2]), pt(&out[n - 1]));
+                if segment_segment_overlap_2d(&p, &q, &q, &pt(&v)).is_some() {
+                    // `q` is the tip of a spike; `p → v` is the net segment.
+                    out.pop();
+                    continue;
+                }
+            }
+            out.push(v);
+            break;
+        }
+    }
+    Pline {
+        vertices: out,
+        closed: pline.closed,
+    }
+}
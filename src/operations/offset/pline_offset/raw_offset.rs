@@ -3,7 +3,7 @@ use crate::geometry::pline::{Pline, PlineVertex};
 use crate::math::arc_2d::{arc_from_bulge, arc_tangent_at, bulge_from_arc, offset_arc_segment};
 use crate::math::intersect_2d::line_line_intersect_2d;
 use crate::math::polygon_2d::{left_normal, segment_direction};
-use crate::math::{Point3, TOLERANCE};
+use crate::math::{angle_between, Point3, Vector2, TOLERANCE};
 
 /// Maximum miter distance as a multiple of `|distance|`.
 const MITER_LIMIT: f64 = 4.0;
@@ -11,6 +11,10 @@ const MITER_LIMIT: f64 = 4.0;
 /// Threshold for flat cap: `cos(angle) < this` → near-180° reversal.
 const FLAT_CAP_COS: f64 = -0.98;
 
+/// Offset radius, relative to the source radius, below which an arc is
+/// taken to shrink exactly onto its centre.
+const POINT_ARC_TOL: f64 = 1e-9;
+
 /// The carrier curve an offset segment lies on — the infinite line /
 /// full circle used for EXACT corner joins. Intersecting carriers (not
 /// tangent-line approximations) keeps every joined arc endpoint ON its
@@ -19,7 +23,15 @@ const FLAT_CAP_COS: f64 = -0.98;
 #[derive(Clone, Copy)]
 enum Carrier {
     Line,
-    Circle { cx: f64, cy: f64, r: f64, ccw: bool },
+    Circle {
+        cx: f64,
+        cy: f64,
+        r: f64,
+        ccw: bool,
+    },
+    /// An arc offset exactly onto its centre: the segment is a single
+    /// point and contributes no span of its own.
+    Point,
 }
 
 /// An offset segment with endpoints, carrier, and tangent directions.
@@ -41,20 +53,32 @@ enum Join {
     /// segment's own start — connected by a straight bevel span
     /// (flat cap, miter-limit bevel, or disjoint-carrier fallback).
     Bevel((f64, f64), (f64, f64)),
+    /// Like `Bevel`, but the span is the arc of radius `|distance|` about
+    /// the original vertex, with the given bulge.
+    Round((f64, f64), (f64, f64), f64),
 }
 
 impl Join {
     /// The point the PREVIOUS segment ends at.
     fn prev_end(&self) -> (f64, f64) {
         match self {
-            Self::Miter(p) | Self::Bevel(p, _) => *p,
+            Self::Miter(p) | Self::Bevel(p, _) | Self::Round(p, _, _) => *p,
         }
     }
 
     /// The point the NEXT segment starts at.
     fn next_start(&self) -> (f64, f64) {
         match self {
-            Self::Miter(p) | Self::Bevel(_, p) => *p,
+            Self::Miter(p) | Self::Bevel(_, p) | Self::Round(_, p, _) => *p,
+        }
+    }
+
+    /// The vertex opening the span between the two segments, if any.
+    fn span_vertex(&self) -> Option<PlineVertex> {
+        match self {
+            Self::Miter(_) => None,
+            Self::Bevel(a, _) => Some(PlineVertex::line(a.0, a.1)),
+            Self::Round(a, _, bulge) => Some(PlineVertex::new(a.0, a.1, *bulge)),
         }
     }
 }
@@ -69,11 +93,16 @@ impl Join {
 /// offset circle — the joined arc stays concentric with its source at
 /// `r ± distance`.
 ///
+/// With `round_joins`, convex corners (where the offset segments pull
+/// apart) are bridged by an arc about the original vertex instead of a
+/// miter. An arc that shrinks exactly onto its centre becomes a point
+/// that its neighbours join to.
+///
 /// # Errors
 ///
 /// Returns `OperationError::InvalidInput` for zero-length segments or
 /// `OperationError::Failed` if no valid segments exist.
-pub fn build(pline: &Pline, distance: f64) -> Result<Pline> {
+pub fn build(pline: &Pline, distance: f64, round_joins: bool) -> Result<Pline> {
     let n = pline.vertices.len();
     let seg_count = pline.segment_count();
     if seg_count == 0 {
@@ -99,14 +128,16 @@ pub fn build(pline: &Pline, distance: f64) -> Result<Pline> {
                     pline.vertices[i].x,
                     pline.vertices[i].y,
                     distance,
+                    round_joins,
                 )
             })
             .collect();
         for i in 0..seg_count {
             let start = joins[i].next_start();
             let end = joins[(i + 1) % seg_count].prev_end();
-            if let Join::Bevel(a, _) = &joins[i] {
-                verts.push(PlineVertex::line(a.0, a.1));
+            verts.extend(joins[i].span_vertex());
+            if matches!(offset_segs[i].carrier, Carrier::Point) {
+                continue;
             }
             verts.push(PlineVertex::new(
                 start.0,
@@ -125,6 +156,7 @@ pub fn build(pline: &Pline, distance: f64) -> Result<Pline> {
                     pline.vertices[i].x,
                     pline.vertices[i].y,
                     distance,
+                    round_joins,
                 )
             })
             .collect();
@@ -140,9 +172,10 @@ pub fn build(pline: &Pline, distance: f64) -> Result<Pline> {
                 offset_segs[seg_count - 1].end
             };
             if i > 0 {
-                if let Join::Bevel(a, _) = &joins[i - 1] {
-                    verts.push(PlineVertex::line(a.0, a.1));
-                }
+                verts.extend(joins[i - 1].span_vertex());
+            }
+            if matches!(offset_segs[i].carrier, Carrier::Point) {
+                continue;
             }
             verts.push(PlineVertex::new(
                 start.0,
@@ -162,7 +195,9 @@ pub fn build(pline: &Pline, distance: f64) -> Result<Pline> {
 
 /// Offsets every segment of `pline` individually: lines shift along
 /// their left normal, arcs stay concentric with the source arc at the
-/// left-offset radius (`offset_arc_segment`).
+/// left-offset radius (`offset_arc_segment`). An arc whose offset
+/// radius is zero (within [`POINT_ARC_TOL`]) becomes a [`Carrier::Point`]
+/// at its centre.
 ///
 /// # Errors
 ///
@@ -196,6 +231,19 @@ fn offset_segments(pline: &Pline, distance: f64) -> Result<Vec<OffsetSeg>> {
                 end_dir: d,
             });
         } else {
+            let (cx, cy, r, sa, sw) = arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge);
+            let shrink = if v0.bulge > 0.0 { -distance } else { distance };
+            if (r + shrink).abs() <= POINT_ARC_TOL * r.max(1.0) {
+                offset_segs.push(OffsetSeg {
+                    start: (cx, cy),
+                    end: (cx, cy),
+                    carrier: Carrier::Point,
+                    start_dir: arc_tangent_at(sa, sw, 0.0),
+                    end_dir: arc_tangent_at(sa, sw, 1.0),
+                });
+                continue;
+            }
+
             // Arc segment: change radius, preserve sweep — concentric
             // with the source arc.
             let seg = offset_arc_segment(v0.x, v0.y, v1.x, v1.y, v0.bulge, distance).ok_or_else(
@@ -230,7 +278,7 @@ fn offset_segments(pline: &Pline, distance: f64) -> Result<Vec<OffsetSeg>> {
 /// circle in the source arc's winding.
 fn seg_bulge(seg: &OffsetSeg, start: (f64, f64), end: (f64, f64)) -> f64 {
     match seg.carrier {
-        Carrier::Line => 0.0,
+        Carrier::Line | Carrier::Point => 0.0,
         Carrier::Circle { cx, cy, ccw, .. } => {
             bulge_from_arc(start.0, start.1, end.0, end.1, cx, cy, ccw)
         }
@@ -240,10 +288,13 @@ fn seg_bulge(seg: &OffsetSeg, start: (f64, f64), end: (f64, f64)) -> f64 {
 /// Resolves the corner between two consecutive offset segments at the
 /// original vertex `(orig_x, orig_y)`.
 ///
-/// Handles three cases:
-/// 1. Near-antiparallel (>~169°): flat cap (bevel).
-/// 2. Miter too long: bevel.
-/// 3. Normal corner: single exact corner point — line × line miter for
+/// Handles these cases:
+/// 1. `round` and a convex corner: round join about the vertex.
+/// 2. Near-antiparallel (>~169°): flat cap (bevel).
+/// 3. A point segment on either side: the endpoints themselves, bevelled
+///    when they differ.
+/// 4. Miter too long: bevel.
+/// 5. Normal corner: single exact corner point — line × line miter for
 ///    two straight segments (unchanged legacy math), carrier
 ///    intersection (line × circle / circle × circle) when an arc is
 ///    involved so the joined point lies exactly on the offset circle.
@@ -253,24 +304,46 @@ fn corner_join(
     orig_x: f64,
     orig_y: f64,
     distance: f64,
+    round: bool,
 ) -> Join {
-    let dir_prev = &seg_prev.end_dir;
-    let dir_next = &seg_next.start_dir;
-    let cos_angle = dir_prev.0 * dir_next.0 + dir_prev.1 * dir_next.1;
+    let dir_prev = Vector2::new(seg_prev.end_dir.0, seg_prev.end_dir.1);
+    let dir_next = Vector2::new(seg_next.start_dir.0, seg_next.start_dir.1);
+    let cos_angle = dir_prev.dot(&dir_next);
+    let sin_angle = dir_prev.perp(&dir_next);
+    let (gap_x, gap_y) = (
+        seg_next.start.0 - seg_prev.end.0,
+        seg_next.start.1 - seg_prev.end.1,
+    );
+    let apart = gap_x.hypot(gap_y) > TOLERANCE;
+
+    // Turning away from the offset side opens a gap between the segments.
+    if round && apart && (sin_angle * distance < 0.0 || cos_angle < FLAT_CAP_COS) {
+        let sweep = angle_between(dir_prev, dir_next);
+        let bulge = -(sweep / 4.0).tan() * distance.signum();
+        return Join::Round(seg_prev.end, seg_next.start, bulge);
+    }
 
     if cos_angle < FLAT_CAP_COS {
         // Near-antiparallel: flat cap.
         return Join::Bevel(seg_prev.end, seg_next.start);
     }
 
+    if matches!(seg_prev.carrier, Carrier::Point) || matches!(seg_next.carrier, Carrier::Point) {
+        return if apart {
+            Join::Bevel(seg_prev.end, seg_next.start)
+        } else {
+            Join::Miter(seg_prev.end)
+        };
+    }
+
     let corner = match (&seg_prev.carrier, &seg_next.carrier) {
         (Carrier::Line, Carrier::Line) => {
             // Legacy line-line miter via tangent intersection (exact for
             // straight carriers).
             let p_prev = Point3::new(seg_prev.end.0, seg_prev.end.1, 0.0);
-            let d_prev = crate::math::Vector3::new(dir_prev.0, dir_prev.1, 0.0);
+            let d_prev = crate::math::Vector3::new(dir_prev.x, dir_prev.y, 0.0);
             let p_next = Point3::new(seg_next.start.0, seg_next.start.1, 0.0);
-            let d_next = crate::math::Vector3::new(dir_next.0, dir_next.1, 0.0);
+            let d_next = crate::math::Vector3::new(dir_next.x, dir_next.y, 0.0);
             let Some((t, _)) = line_line_intersect_2d(&p_prev, &d_prev, &p_next, &d_next) else {
                 // Parallel: use offset of the original corner point
                 // (no miter-limit check — matches the legacy path).
@@ -292,10 +365,10 @@ fn corner_join(
             // carriers (arc curving away) get a straight bevel.
             let candidates = match pair {
                 (Carrier::Line, Carrier::Circle { cx, cy, r, .. }) => {
-                    line_circle_intersections(seg_prev.start, *dir_prev, (*cx, *cy), *r)
+                    line_circle_intersections(seg_prev.start, seg_prev.end_dir, (*cx, *cy), *r)
                 }
                 (Carrier::Circle { cx, cy, r, .. }, Carrier::Line) => {
-                    line_circle_intersections(seg_next.start, *dir_next, (*cx, *cy), *r)
+                    line_circle_intersections(seg_next.start, seg_next.start_dir, (*cx, *cy), *r)
                 }
                 (
                     Carrier::Circle { cx, cy, r, .. },
@@ -325,7 +398,9 @@ fn corner_join(
                         circle_circle_intersections((*cx, *cy), *r, (*bx, *by), *br)
                     }
                 }
-                (Carrier::Line, Carrier::Line) => unreachable!("handled above"),
+                (Carrier::Line, Carrier::Line) | (Carrier::Point, _) | (_, Carrier::Point) => {
+                    unreachable!("handled above")
+                }
             };
             let nearest = candidates.into_iter().min_by(|a, b| {
                 let score = |p: &(f64, f64)| {
@@ -1,4 +1,4 @@
-use crate::geometry::pline::Pline;
+use crate::geometry::pline::{Pline, PlineVertex};
 use crate::math::arc_2d::arc_from_bulge;
 use crate::math::intersect_2d::{
     arc_arc_intersect_2d, line_arc_intersect_2d, segment_segment_intersect_2d,
@@ -51,45 +51,7 @@ pub fn find_all(pline: &Pline) -> Vec<Intersection> {
             let vj0 = &pline.vertices[j];
             let vj1 = &pline.vertices[j_next];
 
-            let i_is_arc = vi0.bulge.abs() >= 1e-12;
-            let j_is_arc = vj0.bulge.abs() >= 1e-12;
-
-            let hits: Vec<((f64, f64), f64, f64)> = match (i_is_arc, j_is_arc) {
-                (false, false) => {
-                    // Line-line.
-                    let a0 = Point3::new(vi0.x, vi0.y, 0.0);
-                    let a1 = Point3::new(vi1.x, vi1.y, 0.0);
-                    let b0 = Point3::new(vj0.x, vj0.y, 0.0);
-                    let b1 = Point3::new(vj1.x, vj1.y, 0.0);
-                    segment_segment_intersect_2d(&a0, &a1, &b0, &b1)
-                        .map(|(pt, t, u)| vec![((pt.x, pt.y), t, u)])
-                        .unwrap_or_default()
-                }
-                (false, true) => {
-                    // Line-arc: segment i is line, segment j is arc.
-                    let (cx, cy, r, sa, sw) = arc_from_bulge(vj0.x, vj0.y, vj1.x, vj1.y, vj0.bulge);
-                    line_arc_intersect_2d(vi0.x, vi0.y, vi1.x, vi1.y, cx, cy, r, sa, sw)
-                }
-                (true, false) => {
-                    // Arc-line: segment i is arc, segment j is line.
-                    let (cx, cy, r, sa, sw) = arc_from_bulge(vi0.x, vi0.y, vi1.x, vi1.y, vi0.bulge);
-                    // line_arc returns (point, t_line, t_arc); we need (point, t_arc, t_line).
-                    line_arc_intersect_2d(vj0.x, vj0.y, vj1.x, vj1.y, cx, cy, r, sa, sw)
-                        .into_iter()
-                        .map(|(pt, t_line, t_arc)| (pt, t_arc, t_line))
-                        .collect()
-                }
-                (true, true) => {
-                    // Arc-arc.
-                    let (c1x, c1y, r1, s1, sw1) =
-                        arc_from_bulge(vi0.x, vi0.y, vi1.x, vi1.y, vi0.bulge);
-                    let (c2x, c2y, r2, s2, sw2) =
-                        arc_from_bulge(vj0.x, vj0.y, vj1.x, vj1.y, vj0.bulge);
-                    arc_arc_intersect_2d(c1x, c1y, r1, s1, sw1, c2x, c2y, r2, s2, sw2)
-                }
-            };
-
-            for (pt, t, u) in hits {
+            for (pt, t, u) in segment_hits(vi0, vi1, vj0, vj1) {
                 // Skip vertex touches: any intersection where either parameter
                 // is at a segment endpoint is a vertex-on-segment touch, not a
                 // genuine crossing.
@@ -121,3 +83,73 @@ pub fn find_all(pline: &Pline) -> Vec<Intersection> {
 
     results
 }
+
+/// Finds the crossings between the segments of two different polylines,
+/// each as `(segment of a, t on it, segment of b, t on it)`.
+///
+/// Vertex touches are skipped as in [`find_all`].
+#[must_use]
+pub fn find_between(a: &Pline, b: &Pline) -> Vec<(usize, f64, usize, f64)> {
+    let eps = TOLERANCE * 100.0;
+    let (na, nb) = (a.vertices.len(), b.vertices.len());
+    let mut results = Vec::new();
+    for i in 0..a.segment_count() {
+        let (vi0, vi1) = (&a.vertices[i], &a.vertices[(i + 1) % na]);
+        for j in 0..b.segment_count() {
+            let (vj0, vj1) = (&b.vertices[j], &b.vertices[(j + 1) % nb]);
+            for (_, t, u) in segment_hits(vi0, vi1, vj0, vj1) {
+                let t_at_end = t < eps || t > 1.0 - eps;
+                let u_at_end = u < eps || u > 1.0 - eps;
+                if !t_at_end && !u_at_end {
+                    results.push((i, t, j, u));
+                }
+            }
+        }
+    }
+    results
+}
+
+/// Crossings between segment `vi0 → vi1` and segment `vj0 → vj1`, each as
+/// `(point, t on the first, t on the second)`.
+fn segment_hits(
+    vi0: &PlineVertex,
+    vi1: &PlineVertex,
+    vj0: &PlineVertex,
+    vj1: &PlineVertex,
+) -> Vec<((f64, f64), f64, f64)> {
+    let i_is_arc = vi0.bulge.abs() >= 1e-12;
+    let j_is_arc = vj0.bulge.abs() >= 1e-12;
+
+    match (i_is_arc, j_is_arc) {
+        (false, false) => {
+            // Line-line.
+            let a0 = Point3::new(vi0.x, vi0.y, 0.0);
+            let a1 = Point3::new(vi1.x, vi1.y, 0.0);
+            let b0 = Point3::new(vj0.x, vj0.y, 0.0);
+            let b1 = Point3::new(vj1.x, vj1.y, 0.0);
+            segment_segment_intersect_2d(&a0, &a1, &b0, &b1)
+                .map(|(pt, t, u)| vec![((pt.x, pt.y), t, u)])
+                .unwrap_or_default()
+        }
+        (false, true) => {
+            // Line-arc: segment i is line, segment j is arc.
+            let (cx, cy, r, sa, sw) = arc_from_bulge(vj0.x, vj0.y, vj1.x, vj1.y, vj0.bulge);
+            line_arc_intersect_2d(vi0.x, vi0.y, vi1.x, vi1.y, cx, cy, r, sa, sw)
+        }
+        (true, false) => {
+            // Arc-line: segment i is arc, segment j is line.
+            let (cx, cy, r, sa, sw) = arc_from_bulge(vi0.x, vi0.y, vi1.x, vi1.y, vi0.bulge);
+            // line_arc returns (point, t_line, t_arc); we need (point, t_arc, t_line).
+            line_arc_intersect_2d(vj0.x, vj0.y, vj1.x, vj1.y, cx, cy, r, sa, sw)
+                .into_iter()
+                .map(|(pt, t_line, t_arc)| (pt, t_arc, t_line))
+                .collect()
+        }
+        (true, true) => {
+            // Arc-arc.
+            let (c1x, c1y, r1, s1, sw1) = arc_from_bulge(vi0.x, vi0.y, vi1.x, vi1.y, vi0.bulge);
+            let (c2x, c2y, r2, s2, sw2) = arc_from_bulge(vj0.x, vj0.y, vj1.x, vj1.y, vj0.bulge);
+            arc_arc_intersect_2d(c1x, c1y, r1, s1, sw1, c2x, c2y, r2, s2, sw2)
+        }
+    }
+}
@@ -32,14 +32,42 @@ pub fn build(
         return Vec::new();
     }
 
-    let n = vertices.len();
-
     // Build a list of split points per segment: (segment_index, t, intersection_index).
     let mut splits: Vec<(usize, f64, usize)> = Vec::new();
     for (idx, ix) in intersections.iter().enumerate() {
         splits.push((ix.seg_i, ix.t_i, idx));
         splits.push((ix.seg_j, ix.t_j, idx));
     }
+    build_from_splits(vertices, n_segs, splits)
+}
+
+/// Slices a closed polyline at the given `(segment, t)` points, as
+/// [`build`] does at its intersections. Slice indices refer to `points`.
+#[must_use]
+pub fn build_at(
+    vertices: &[PlineVertex],
+    n_segs: usize,
+    points: &[(usize, f64)],
+) -> Vec<PlineSlice> {
+    if points.is_empty() || vertices.is_empty() {
+        return Vec::new();
+    }
+    let splits = points
+        .iter()
+        .enumerate()
+        .map(|(idx, &(seg, t))| (seg, t, idx))
+        .collect();
+    build_from_splits(vertices, n_segs, splits)
+}
+
+/// Walks the closed polyline once, emitting the slice between each pair
+/// of consecutive `(segment, t, index)` split points.
+fn build_from_splits(
+    vertices: &[PlineVertex],
+    n_segs: usize,
+    mut splits: Vec<(usize, f64, usize)>,
+) -> Vec<PlineSlice> {
+    let n = vertices.len();
     splits.sort_by(|a, b| {
         a.0.cmp(&b.0)
             .then(a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
@@ -85,8 +113,9 @@ fn build_slice_verts(
 ) -> Vec<PlineVertex> {
     let mut verts = Vec::new();
 
-    if seg_start == seg_end {
-        // Both split points on the same segment: single sub-segment.
+    if seg_start == seg_end && t_start <= t_end {
+        // Both split points on the same segment, in order: single
+        // sub-segment. (Out of order, the slice wraps around the loop.)
         let start_pos = point_on_segment(vertices, n, seg_start, t_start);
         let end_pos = point_on_segment(vertices, n, seg_end, t_end);
         let bulge = sub_bulge(vertices[seg_start].bulge, t_start, t_end);
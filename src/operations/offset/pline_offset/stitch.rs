@@ -8,6 +8,11 @@ use super::slice::PlineSlice;
 /// unconnected slice whose start point matches, preferring forward index
 /// distance (`cavalier_contours` style).
 ///
+/// Slices are only ever appended forward. A slice's last vertex is a bare
+/// endpoint (bulge `0`), so at each junction the chain's end vertex takes
+/// over the bulge of the appended slice's first vertex; otherwise a slice
+/// starting with an arc would be flattened to its chord.
+///
 /// When `input_closed` is true, all results are marked closed (original
 /// behavior for closed-polyline offsets).  When false, each chain is
 /// checked: if the first and last vertices coincide the result is closed,
@@ -57,8 +62,7 @@ pub fn connect(slices: &[&PlineSlice], input_closed: bool) -> Vec<Pline> {
 
             if let Some(next) = best {
                 used[next] = true;
-                // Append the next slice's vertices (skip the first since it overlaps).
-                chain_verts.extend_from_slice(&slices[next].vertices[1..]);
+                append_slice(&mut chain_verts, slices[next]);
                 current = next;
             } else {
                 break;
@@ -99,3 +103,16 @@ pub fn connect(slices: &[&PlineSlice], input_closed: bool) -> Vec<Pline> {
 
     results
 }
+
+/// Appends `slice` to `chain`, whose last vertex coincides with the slice's
+/// first: the shared vertex keeps its position and adopts the bulge of the
+/// slice's first segment.
+fn append_slice(chain: &mut Vec<PlineVertex>, slice: &PlineSlice) {
+    let Some((first, rest)) = slice.vertices.split_first() else {
+        return;
+    };
+    if let Some(last) = chain.last_mut() {
+        last.bulge = first.bulge;
+    }
+    chain.extend_from_slice(rest);
+}
@@ -0,0 +1,150 @@
+//! Structured trace of a pline offset for bug reports (`debug-dump`
+//! feature).
+
+use std::fmt::Write as _;
+
+use super::{PlineOffset2D, Stage};
+use crate::error::Result;
+use crate::geometry::pline::dump::json_f64;
+use crate::geometry::pline::Pline;
+
+/// A slice of the raw offset and the distance filter's verdict on it.
+#[derive(Debug, Clone)]
+pub struct TracedSlice {
+    /// The slice as an open polyline.
+    pub pline: Pline,
+    /// Whether the slice survived the filter and was stitched.
+    pub kept: bool,
+}
+
+/// The intermediate data of one [`PlineOffset2D::execute_traced`] run.
+///
+/// Stages the run did not reach (for example slicing, when the raw
+/// offset has no self-intersections) are left empty.
+#[derive(Debug, Clone)]
+pub struct OffsetTrace {
+    /// The polyline being offset.
+    pub input: Pline,
+    /// The signed offset distance.
+    pub distance: f64,
+    /// The untrimmed offset polyline.
+    pub raw: Option<Pline>,
+    /// Self-intersection points of the raw offset.
+    pub intersections: Vec<(f64, f64)>,
+    /// Slices of the raw offset between intersections, in order.
+    pub slices: Vec<TracedSlice>,
+}
+
+impl OffsetTrace {
+    /// Formats the trace as JSON lines, one record per line, each tagged
+    /// with a `"kind"`: `input`, `raw`, `intersection` and `slice`.
+    #[must_use]
+    pub fn to_json_lines(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{{\"kind\":\"input\",\"distance\":{},\"pline\":{}}}",
+            json_f64(self.distance),
+            self.input.dump_json()
+        );
+        if let Some(raw) = &self.raw {
+            let _ = writeln!(out, "{{\"kind\":\"raw\",\"pline\":{}}}", raw.dump_json());
+        }
+        for &(x, y) in &self.intersections {
+            let _ = writeln!(
+                out,
+                "{{\"kind\":\"intersection\",\"point\":[{},{}]}}",
+                json_f64(x),
+                json_f64(y)
+            );
+        }
+        for (i, slice) in self.slices.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "{{\"kind\":\"slice\",\"index\":{i},\"kept\":{},\"pline\":{}}}",
+                slice.kept,
+                slice.pline.dump_json()
+            );
+        }
+        out
+    }
+}
+
+impl PlineOffset2D {
+    /// Runs [`execute`](Self::execute) while recording the raw offset,
+    /// its self-intersections, the slices and the filter's decisions.
+    ///
+    /// The trace is returned whether or not the offset succeeds, so a
+    /// failing case can be attached to a bug report via
+    /// [`OffsetTrace::to_json_lines`].
+    pub fn execute_traced(&self) -> (Result<Vec<Pline>>, OffsetTrace) {
+        let mut trace = OffsetTrace {
+            input: self.pline.clone(),
+            distance: self.distance,
+            raw: None,
+            intersections: Vec::new(),
+            slices: Vec::new(),
+        };
+        let result = self.execute_observed(&mut |stage| match stage {
+            Stage::Raw(raw) => trace.raw = Some(raw.clone()),
+            Stage::Intersections(found) => {
+                trace.intersections = found.iter().map(|x| x.point).collect();
+            }
+            Stage::Filtered { slices, kept } => {
+                trace.slices = slices
+                    .iter()
+                    .map(|s| TracedSlice {
+                        pline: Pline {
+                            vertices: s.vertices.clone(),
+                            closed: false,
+                        },
+                        kept: kept.iter().any(|k| std::ptr::eq(*k, s)),
+                    })
+                    .collect();
+            }
+        });
+        (result, trace)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::pline::PlineVertex;
+
+    #[test]
+    fn traced_outward_offset_records_intersections() {
+        // A square with a deep V notch: offsetting outward closes the
+        // notch, so the offsets of its walls cross.
+        let pline = Pline {
+            vertices: [
+                (0.0, 0.0),
+                (10.0, 0.0),
+                (10.0, 10.0),
+                (6.0, 10.0),
+                (5.0, 3.0),
+                (4.0, 10.0),
+                (0.0, 10.0),
+            ]
+            .iter()
+            .map(|&(x, y)| PlineVertex::line(x, y))
+            .collect(),
+            closed: true,
+        };
+        let (result, trace) = PlineOffset2D::new(pline, -1.0).execute_traced();
+        let result = result.unwrap();
+        assert!(!result.is_empty());
+        assert!(trace.raw.is_some());
+        assert!(!trace.intersections.is_empty());
+        assert!(trace.slices.iter().any(|s| s.kept));
+        assert!(trace.slices.iter().any(|s| !s.kept));
+
+        let text = trace.to_json_lines();
+        assert_eq!(
+            text.lines().count(),
+            2 + trace.intersections.len() + trace.slices.len()
+        );
+        assert!(text.lines().all(|l| l.starts_with("{\"kind\":")));
+    }
+}
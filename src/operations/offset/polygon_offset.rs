@@ -0,0 +1,80 @@
+//! Offsetting of plain point-list polygons.
+
+use crate::error::{GeolisError, OffsetError, OperationError, Result};
+use crate::geometry::pline::Pline;
+use crate::math::{Point2, Point3, TOLERANCE};
+
+use super::PlineOffset2D;
+
+/// Offsets a closed polygon by a signed `distance` (positive = left of the
+/// traversal direction, i.e. inward for a counter-clockwise polygon) and
+/// returns the resulting loops as point lists.
+///
+/// A convenience bridge over [`PlineOffset2D`] for callers without a
+/// [`Pline`]. Round corners of the offset are flattened to chords
+/// deviating at most `|distance| / 1000` from the true arc. Returns no
+/// loops when the polygon has fewer than 3 points or the offset collapses.
+///
+/// # Errors
+///
+/// Returns any [`PlineOffset2D`] error other than
+/// [`OffsetError::Collapsed`].
+pub fn offset_polygon(polygon: &[Point2], distance: f64) -> Result<Vec<Vec<Point2>>> {
+    if polygon.len() < 3 {
+        return Ok(Vec::new());
+    }
+    let points: Vec<Point3> = polygon.iter().map(|p| Point3::new(p.x, p.y, 0.0)).collect();
+    let loops = match PlineOffset2D::new(Pline::from_points(&points, true), distance).execute() {
+        Ok(loops) => loops,
+        Err(GeolisError::Operation(OperationError::Offset(OffsetError::Collapsed { .. }))) => {
+            return Ok(Vec::new());
+        }
+        Err(e) => return Err(e),
+    };
+    let chord_tol = (distance.abs() * 1e-3).max(TOLERANCE);
+    Ok(loops
+        .iter()
+        .map(|pline| {
+            pline
+                .flatten(chord_tol)
+                .vertices
+                .iter()
+                .map(|v| Point2::new(v.x, v.y))
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_square_inward() {
+        let square = [
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(0.0, 10.0),
+        ];
+        let loops = offset_polygon(&square, 1.0).unwrap();
+        assert_eq!(loops.len(), 1);
+        assert_eq!(loops[0].len(), 4);
+        for p in &loops[0] {
+            assert!(((p.x - 5.0).abs() - 4.0).abs() < 1e-9, "{p:?}");
+            assert!(((p.y - 5.0).abs() - 4.0).abs() < 1e-9, "{p:?}");
+        }
+    }
+
+    #[test]
+    fn collapsed_offset_yields_no_loops() {
+        let strip = [
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ];
+        assert!(offset_polygon(&strip, 3.0).unwrap().is_empty());
+    }
+}
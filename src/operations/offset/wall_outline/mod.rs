@@ -1,3 +1,4 @@
+mod openings;
 pub(crate) mod polygon_union;
 mod provenance;
 mod stroke;
@@ -9,6 +10,8 @@ use polygon_union::{point_in_polygon_class, seg_seg_intersect, PointClass, WALL_
 use provenance::{footprint_provenances, EdgeSource, InputEdgeSources};
 use stroke::{StrokeLabels, StrokeOrigin};
 
+pub use stroke::CapStyle;
+
 pub use provenance::{CapEnd, FootprintProvenance, OffsetSide, SegmentOrigin, SegmentProvenance};
 
 /// A planar wall face described by an outer boundary and zero or more holes,
@@ -316,6 +319,8 @@ pub struct WallOutline2D {
     plines: Vec<Pline>,
     left_width: f64,
     right_width: f64,
+    openings: Vec<openings::Opening>,
+    cap_style: CapStyle,
 }
 
 impl WallOutline2D {
@@ -326,6 +331,8 @@ impl WallOutline2D {
             plines,
             left_width: half_width,
             right_width: half_width,
+            openings: Vec::new(),
+            cap_style: CapStyle::Square,
         }
     }
 
@@ -341,9 +348,37 @@ impl WallOutline2D {
             plines,
             left_width,
             right_width,
+            openings: Vec::new(),
+            cap_style: CapStyle::Square,
         }
     }
 
+    /// Punches openings (doors, gaps) into the walls.
+    ///
+    /// Each opening is `(pline, start_s, end_s)`: an arc-length interval
+    /// along centerline `pline` (measured from its first vertex) where
+    /// the wall is absent. The traced boundary jumps across the opening
+    /// and closes the wall on each side with a flat jamb, reported as
+    /// [`SegmentOrigin::Jamb`]. An opening must lie within a single
+    /// centerline segment and be strictly narrower than it; openings on
+    /// one polyline must not overlap. Violations surface as
+    /// `OperationError::InvalidInput` from the execute methods.
+    #[must_use]
+    pub fn with_openings(mut self, openings: Vec<(usize, f64, f64)>) -> Self {
+        self.openings = openings;
+        self
+    }
+
+    /// Sets how the free ends of open centerlines are capped (square by
+    /// default). A round cap is a semicircle across the wall thickness,
+    /// tessellated into line chords and reported as
+    /// [`SegmentOrigin::Cap`]. Jambs at openings stay flat.
+    #[must_use]
+    pub fn with_cap_style(mut self, cap_style: CapStyle) -> Self {
+        self.cap_style = cap_style;
+        self
+    }
+
     /// Executes the wall outline generation, returning typed face topology.
     ///
     /// Each returned [`WallFootprint2D`] represents one connected wall-material
@@ -367,9 +402,13 @@ impl WallOutline2D {
     /// # Errors
     ///
     /// - `OperationError::InvalidInput` — no polyline has at least 2
-    ///   vertices, or both `left_width` and `right_width` are within
+    ///   vertices, both `left_width` and `right_width` are within
     ///   `crate::math::TOLERANCE` of zero (zero-width input has no
-    ///   footprint to extrude).
+    ///   footprint to extrude), or an opening from
+    ///   [`Self::with_openings`] is invalid (unknown polyline, empty or
+    ///   non-finite interval, not within a single centerline segment or
+    ///   not strictly narrower than it, or overlapping another opening on
+    ///   the same polyline).
     /// - `OperationError::Failed` — no outline can be generated, or the
     ///   `polygon_union` arrangement / face-assembly stage detected
     ///   broken topology (ambiguous half-edge classification, witness on
@@ -445,63 +484,57 @@ impl WallOutline2D {
             .into());
         }
 
+        openings::validate(&self.plines, &self.openings)?;
+
         // Step 1: Stroke-expand each polyline into a wall polygon,
         // recording per-edge origins for provenance.
         let mut wall_polys: Vec<polygon_union::PolygonWithHoles> = Vec::new();
         let mut wall_sources: Vec<InputEdgeSources> = Vec::new();
 
         for &(pline_idx, pline) in &valid {
-            // Tessellate arc segments into line segments.
-            // Tolerance scales with wall width for consistent arc resolution.
-            let has_arcs = pline.vertices.iter().any(|v| v.bulge.abs() > 1e-12);
-            let arc_tolerance = self.left_width.max(self.right_width) * 0.1;
-            // `seg_src[k]` = original pline segment index of stroke segment
-            // `k` (identity for line-only inputs; each tessellated arc
-            // chord maps back to its arc segment).
-            let (mut verts, mut seg_src): (Vec<(f64, f64)>, Vec<usize>) = if has_arcs {
-                let (pts, src) =
-                    pline.to_points_with_sources(arc_tolerance.max(polygon_union::WALL_EPS));
-                (pts.iter().map(|p| (p.x, p.y)).collect(), src)
+            let (verts, seg_src) = self.centerline_path(pline);
+            let cuts: Vec<(usize, f64, f64)> = self
+                .openings
+                .iter()
+                .enumerate()
+                .filter(|(_, o)| o.0 == pline_idx)
+                .map(|(k, o)| (k, o.1, o.2))
+                .collect();
+            let pieces = if cuts.is_empty() {
+                let tess_ord = (0..seg_src.len()).collect();
+                vec![openings::PathPiece {
+                    verts,
+                    seg_src,
+                    tess_ord,
+                    start_jamb: None,
+                    end_jamb: None,
+                }]
             } else {
-                let v: Vec<(f64, f64)> = pline.vertices.iter().map(|v| (v.x, v.y)).collect();
-                let seg_count = if pline.closed {
-                    v.len()
-                } else {
-                    v.len().saturating_sub(1)
-                };
-                (v, (0..seg_count).collect())
+                openings::cut_path(pline, &verts, &seg_src, &cuts)
             };
-            // For closed polylines, to_points() may duplicate the start point at
-            // the end. Strip trailing duplicate to avoid a zero-length segment.
-            // The stripped path edge count then equals the closed ring's edge
-            // count, so `seg_src` stays aligned with the ring edges.
-            if pline.closed && verts.len() >= 2 {
-                let first = verts[0];
-                let last = verts[verts.len() - 1];
-                if (first.0 - last.0).powi(2) + (first.1 - last.1).powi(2)
-                    < polygon_union::WALL_EPS * polygon_union::WALL_EPS
-                {
-                    verts.pop();
-                }
-            }
-            if pline.closed {
-                // Ring edge count is verts.len(); pad defensively in case the
-                // duplicate strip above did not fire (closing edge keeps the
-                // last path edge's source).
-                while seg_src.len() < verts.len() {
-                    seg_src.push(seg_src.last().copied().unwrap_or(0));
+            // A closed centerline stays closed only when nothing cut it.
+            let closed = pline.closed && cuts.is_empty();
+            for piece in &pieces {
+                let cap = |jamb: Option<usize>| {
+                    if jamb.is_some() {
+                        CapStyle::Square
+                    } else {
+                        self.cap_style
+                    }
+                };
+                let (pwh, labels) = stroke::stroke_expand_labeled(
+                    &piece.verts,
+                    closed,
+                    self.left_width,
+                    self.right_width,
+                    cap(piece.start_jamb),
+                    cap(piece.end_jamb),
+                );
+                if pwh.outer.len() >= 3 {
+                    wall_sources.push(build_edge_sources(pline_idx, &pwh, &labels, piece));
+                    wall_polys.push(pwh);
                 }
             }
-            let (pwh, labels) = stroke::stroke_expand_labeled(
-                &verts,
-                pline.closed,
-                self.left_width,
-                self.right_width,
-            );
-            if pwh.outer.len() >= 3 {
-                wall_sources.push(build_edge_sources(pline_idx, &pwh, &labels, &seg_src));
-                wall_polys.push(pwh);
-            }
         }
 
         if wall_polys.is_empty() {
@@ -534,16 +567,63 @@ impl WallOutline2D {
             })
             .collect())
     }
+
+    /// Stroke path of one centerline: its vertices with arcs tessellated,
+    /// plus `seg_src[k]`, the original pline segment index of stroke
+    /// segment `k` (identity for line-only inputs; each tessellated arc
+    /// chord maps back to its arc segment).
+    fn centerline_path(&self, pline: &Pline) -> (Vec<(f64, f64)>, Vec<usize>) {
+        // Tessellate arc segments into line segments.
+        // Tolerance scales with wall width for consistent arc resolution.
+        let has_arcs = pline.vertices.iter().any(|v| v.bulge.abs() > 1e-12);
+        let arc_tolerance = self.left_width.max(self.right_width) * 0.1;
+        let (mut verts, mut seg_src): (Vec<(f64, f64)>, Vec<usize>) = if has_arcs {
+            let (pts, src) =
+                pline.to_points_with_sources(arc_tolerance.max(polygon_union::WALL_EPS));
+            (pts.iter().map(|p| (p.x, p.y)).collect(), src)
+        } else {
+            let v: Vec<(f64, f64)> = pline.vertices.iter().map(|v| (v.x, v.y)).collect();
+            let seg_count = if pline.closed {
+                v.len()
+            } else {
+                v.len().saturating_sub(1)
+            };
+            (v, (0..seg_count).collect())
+        };
+        // For closed polylines, to_points() may duplicate the start point at
+        // the end. Strip trailing duplicate to avoid a zero-length segment.
+        // The stripped path edge count then equals the closed ring's edge
+        // count, so `seg_src` stays aligned with the ring edges.
+        if pline.closed && verts.len() >= 2 {
+            let first = verts[0];
+            let last = verts[verts.len() - 1];
+            if (first.0 - last.0).powi(2) + (first.1 - last.1).powi(2)
+                < polygon_union::WALL_EPS * polygon_union::WALL_EPS
+            {
+                verts.pop();
+            }
+        }
+        if pline.closed {
+            // Ring edge count is verts.len(); pad defensively in case the
+            // duplicate strip above did not fire (closing edge keeps the
+            // last path edge's source).
+            while seg_src.len() < verts.len() {
+                seg_src.push(seg_src.last().copied().unwrap_or(0));
+            }
+        }
+        (verts, seg_src)
+    }
 }
 
 /// Build the per-edge source table for one stroke-expanded input,
 /// composing the stroke's local origins with the tessellation map
-/// (`seg_src`: stroke segment → original pline segment).
+/// (`piece.seg_src`: stroke segment → original pline segment). End caps
+/// of a piece cut at an opening become that opening's jambs.
 fn build_edge_sources(
     pline: usize,
     pwh: &polygon_union::PolygonWithHoles,
     labels: &StrokeLabels,
-    seg_src: &[usize],
+    piece: &openings::PathPiece,
 ) -> InputEdgeSources {
     let ring = |pts: &[(f64, f64)], origins: &[StrokeOrigin]| -> Vec<EdgeSource> {
         debug_assert_eq!(pts.len(), origins.len());
@@ -557,16 +637,26 @@ fn build_edge_sources(
                     StrokeOrigin::Side { seg, side } => EdgeSource {
                         pline,
                         origin: SegmentOrigin::Side {
-                            edge: seg_src[seg],
+                            edge: piece.seg_src[seg],
                             side,
                         },
-                        tess_ord: seg,
+                        tess_ord: piece.tess_ord[seg],
                         a,
                         b,
                     },
                     StrokeOrigin::Cap { end } => EdgeSource {
                         pline,
-                        origin: SegmentOrigin::Cap { end },
+                        origin: match (end, piece.start_jamb, piece.end_jamb) {
+                            (CapEnd::Start, Some(opening), _) => SegmentOrigin::Jamb {
+                                opening,
+                                end: CapEnd::End,
+                            },
+                            (CapEnd::End, _, Some(opening)) => SegmentOrigin::Jamb {
+                                opening,
+                                end: CapEnd::Start,
+                            },
+                            _ => SegmentOrigin::Cap { end },
+                        },
                         tess_ord: 0,
                         a,
                         b,
@@ -672,6 +762,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn round_caps_close_stub_with_semicircles() {
+        let pline = Pline::from_points(
+            &[Point3::new(0.0, 0.0, 0.0), Point3::new(4.0, 0.0, 0.0)],
+            false,
+        );
+        let result = WallOutline2D::new(vec![pline], 0.5)
+            .with_cap_style(CapStyle::Round)
+            .execute_faces_with_provenance()
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        let (face, prov) = &result[0];
+        let outer = &face.outer().vertices;
+        assert_eq!(outer.len(), prov.outer().len());
+
+        // Beyond either end vertex the outline is a semicircle of radius
+        // 0.5 about that vertex, reaching out to its apex.
+        let mut apexes = 0;
+        for v in outer {
+            let center = if v.x < -1e-9 {
+                0.0
+            } else if v.x > 4.0 + 1e-9 {
+                4.0
+            } else {
+                continue;
+            };
+            let r = (v.x - center).hypot(v.y);
+            assert!((r - 0.5).abs() < 1e-9, "({}, {}) r={r}", v.x, v.y);
+            if v.y.abs() < 1e-9 {
+                apexes += 1;
+            }
+        }
+        assert_eq!(apexes, 2);
+        for (k, sp) in prov.outer().iter().enumerate() {
+            let (a, b) = (&outer[k], &outer[(k + 1) % outer.len()]);
+            if a.x.min(b.x) < -1e-9 || a.x.max(b.x) > 4.0 + 1e-9 {
+                assert!(matches!(sp.origin, SegmentOrigin::Cap { .. }), "{sp:?}");
+            }
+        }
+
+        // Body plus one full disc, short of π r² by the chord sagitta.
+        let area = total_area(&[face.outer().clone()]);
+        let disc = std::f64::consts::PI * 0.25;
+        assert!(area > 4.0 + disc - 0.01 && area < 4.0 + disc, "area={area}");
+    }
+
     #[test]
     fn l_shape() {
         let pline = Pline::from_points(
@@ -1279,9 +1415,15 @@ mod tests {
             .iter()
             .filter_map(|p| {
                 let verts: Vec<(f64, f64)> = p.vertices.iter().map(|v| (v.x, v.y)).collect();
-                let pwh =
-                    super::stroke::stroke_expand_labeled(&verts, p.closed, half_width, half_width)
-                        .0;
+                let pwh = super::stroke::stroke_expand_labeled(
+                    &verts,
+                    p.closed,
+                    half_width,
+                    half_width,
+                    CapStyle::Square,
+                    CapStyle::Square,
+                )
+                .0;
                 if pwh.outer.len() >= 3 {
                     Some(pwh)
                 } else {
@@ -1932,4 +2074,129 @@ mod tests {
         let err = WallFootprint2D::try_from_parts(outer, vec![hole]).expect_err("must err");
         assert!(format!("{err}").contains("zero-length"), "{err}");
     }
+
+    type Jamb = (SegmentOrigin, (f64, f64), (f64, f64));
+
+    /// Outer-ring jamb segments of every footprint as
+    /// `(origin, midpoint, outward normal)`.
+    fn outer_jambs(faces: &[(WallFootprint2D, FootprintProvenance)]) -> Vec<Jamb> {
+        let mut jambs = Vec::new();
+        for (footprint, prov) in faces {
+            let vs = &footprint.outer().vertices;
+            for (k, seg) in prov.outer().iter().enumerate() {
+                if let SegmentOrigin::Jamb { .. } = seg.origin {
+                    let a = &vs[k];
+                    let b = &vs[(k + 1) % vs.len()];
+                    // CCW outer: outward normal is the edge direction
+                    // rotated by -90°.
+                    jambs.push((
+                        seg.origin,
+                        (0.5 * (a.x + b.x), 0.5 * (a.y + b.y)),
+                        (b.y - a.y, a.x - b.x),
+                    ));
+                }
+            }
+        }
+        jambs.sort_by(|x, y| x.1 .0.total_cmp(&y.1 .0));
+        jambs
+    }
+
+    #[test]
+    fn opening_on_straight_wall_leaves_two_jambs_facing_gap() {
+        let pline = Pline::from_points(
+            &[Point3::new(0.0, 0.0, 0.0), Point3::new(10.0, 0.0, 0.0)],
+            false,
+        );
+        let faces = WallOutline2D::new(vec![pline], 0.1)
+            .with_openings(vec![(0, 4.0, 5.0)])
+            .execute_faces_with_provenance()
+            .unwrap();
+        assert_eq!(faces.len(), 2, "the opening splits the wall in two");
+
+        let jambs = outer_jambs(&faces);
+        assert_eq!(jambs.len(), 2, "{jambs:?}");
+        let (left, right) = (jambs[0], jambs[1]);
+        assert_eq!(
+            left.0,
+            SegmentOrigin::Jamb {
+                opening: 0,
+                end: CapEnd::Start
+            }
+        );
+        assert_eq!(
+            right.0,
+            SegmentOrigin::Jamb {
+                opening: 0,
+                end: CapEnd::End
+            }
+        );
+        assert!((left.1 .0 - 4.0).abs() < 1e-9 && left.1 .1.abs() < 1e-9);
+        assert!((right.1 .0 - 5.0).abs() < 1e-9 && right.1 .1.abs() < 1e-9);
+        // Both jambs face into the gap.
+        assert!(left.2 .0 > 0.0, "{left:?}");
+        assert!(right.2 .0 < 0.0, "{right:?}");
+    }
+
+    #[test]
+    fn opening_on_closed_room_opens_the_ring() {
+        let pline = Pline::from_points(
+            &[
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(10.0, 0.0, 0.0),
+                Point3::new(10.0, 8.0, 0.0),
+                Point3::new(0.0, 8.0, 0.0),
+            ],
+            true,
+        );
+        let faces = WallOutline2D::new(vec![pline], 0.1)
+            .with_openings(vec![(0, 4.0, 5.0)])
+            .execute_faces_with_provenance()
+            .unwrap();
+        assert_eq!(faces.len(), 1);
+        assert!(
+            faces[0].0.holes().is_empty(),
+            "the room is no longer closed"
+        );
+
+        let jambs = outer_jambs(&faces);
+        assert_eq!(jambs.len(), 2, "{jambs:?}");
+        assert!((jambs[0].1 .0 - 4.0).abs() < 1e-9 && jambs[0].2 .0 > 0.0);
+        assert!((jambs[1].1 .0 - 5.0).abs() < 1e-9 && jambs[1].2 .0 < 0.0);
+
+        // The cut bottom edge survives as two fragments per side, numbered
+        // along the centerline.
+        let bottom_right: Vec<(u32, f64)> = faces[0]
+            .1
+            .outer()
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| {
+                p.origin
+                    == SegmentOrigin::Side {
+                        edge: 0,
+                        side: OffsetSide::Right,
+                    }
+            })
+            .map(|(k, p)| (p.fragment, faces[0].0.outer().vertices[k].x))
+            .collect();
+        assert_eq!(bottom_right.len(), 2, "{bottom_right:?}");
+        let first = bottom_right.iter().find(|f| f.0 == 0).unwrap();
+        assert!(
+            first.1 < 4.5,
+            "fragment 0 precedes the opening: {bottom_right:?}"
+        );
+    }
+
+    #[test]
+    fn opening_as_wide_as_segment_is_rejected() {
+        let pline = Pline::from_points(
+            &[Point3::new(0.0, 0.0, 0.0), Point3::new(3.0, 0.0, 0.0)],
+            false,
+        );
+        let err = WallOutline2D::new(vec![pline], 0.1)
+            .with_openings(vec![(0, 0.0, 3.0)])
+            .execute_faces()
+            .expect_err("must err");
+        assert!(format!("{err}").contains("narrower"), "{err}");
+    }
 }
@@ -0,0 +1,224 @@
+//! Door / gap openings: arc-length intervals along a centerline where
+//! the wall is absent.
+//!
+//! An opening cuts the (tessellated) centerline path into open pieces
+//! that are stroked independently, so the traced boundary jumps across
+//! the opening and closes each side with a flat jamb.
+
+use crate::error::{OperationError, Result};
+use crate::geometry::pline::Pline;
+use crate::math::arc_2d::arc_from_bulge;
+
+use super::polygon_union::WALL_EPS;
+
+/// One opening on a specific polyline, as passed to
+/// [`super::WallOutline2D::with_openings`]: `(pline, start_s, end_s)`.
+pub(super) type Opening = (usize, f64, f64);
+
+/// An open stroke path produced by cutting a centerline at its openings.
+pub(super) struct PathPiece {
+    pub verts: Vec<(f64, f64)>,
+    /// Source pline segment of each piece segment.
+    pub seg_src: Vec<usize>,
+    /// Fragment-ordering key of each piece segment (see
+    /// [`super::provenance`]): increases along the source centerline even
+    /// when an opening splits one tessellated segment across pieces.
+    pub tess_ord: Vec<usize>,
+    /// Opening whose `end_s` jamb closes the start of this piece.
+    pub start_jamb: Option<usize>,
+    /// Opening whose `start_s` jamb closes the end of this piece.
+    pub end_jamb: Option<usize>,
+}
+
+/// Arc length of every segment of `pline`.
+fn segment_lengths(pline: &Pline) -> Vec<f64> {
+    let n = pline.vertices.len();
+    (0..pline.segment_count())
+        .map(|i| {
+            let v0 = &pline.vertices[i];
+            let v1 = &pline.vertices[(i + 1) % n];
+            if v0.bulge.abs() < 1e-12 {
+                (v1.x - v0.x).hypot(v1.y - v0.y)
+            } else {
+                let (_, _, r, _, sweep) = arc_from_bulge(v0.x, v0.y, v1.x, v1.y, v0.bulge);
+                r * sweep.abs()
+            }
+        })
+        .collect()
+}
+
+/// Validates every opening against its polyline.
+///
+/// Each opening must reference an existing polyline, have finite
+/// `start_s < end_s`, and lie within a single centerline segment while
+/// being strictly narrower than it. Openings on the same polyline must
+/// not overlap.
+pub(super) fn validate(plines: &[Pline], openings: &[Opening]) -> Result<()> {
+    let invalid = |msg: String| -> Result<()> { Err(OperationError::InvalidInput(msg).into()) };
+    for (k, &(pi, s0, s1)) in openings.iter().enumerate() {
+        let Some(pline) = plines.get(pi) else {
+            return invalid(format!(
+                "WallOutline2D: opening {k} references pline {pi}, but only {} were given",
+                plines.len()
+            ));
+        };
+        if !s0.is_finite() || !s1.is_finite() || s1 - s0 < WALL_EPS {
+            return invalid(format!(
+                "WallOutline2D: opening {k} needs finite start_s < end_s; got [{s0}, {s1}]"
+            ));
+        }
+        let lengths = segment_lengths(pline);
+        let mut seg_start = 0.0;
+        let mut host = None;
+        for (i, &len) in lengths.iter().enumerate() {
+            if s0 >= seg_start - WALL_EPS && s0 < seg_start + len {
+                host = Some((i, seg_start, len));
+                break;
+            }
+            seg_start += len;
+        }
+        let Some((seg, seg_start, len)) = host else {
+            return invalid(format!(
+                "WallOutline2D: opening {k} start_s {s0} is outside pline {pi}"
+            ));
+        };
+        if s1 > seg_start + len + WALL_EPS || s1 - s0 >= len - WALL_EPS {
+            return invalid(format!(
+                "WallOutline2D: opening {k} [{s0}, {s1}] must be narrower than \
+                 and lie within centerline segment {seg} of pline {pi}"
+            ));
+        }
+        for (j, &(pj, t0, t1)) in openings.iter().enumerate().take(k) {
+            if pj == pi && s0 < t1 && t0 < s1 {
+                return invalid(format!(
+                    "WallOutline2D: openings {j} and {k} overlap on pline {pi}"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Cuts the tessellated path of `pline` (`verts` with per-edge
+/// `seg_src`, as prepared for stroking) at the given openings.
+///
+/// `openings` holds `(opening index, start_s, end_s)` for this polyline
+/// only, already validated. A closed path becomes open pieces; the piece
+/// spanning the original start vertex wraps around it.
+pub(super) fn cut_path(
+    pline: &Pline,
+    verts: &[(f64, f64)],
+    seg_src: &[usize],
+    openings: &[(usize, f64, f64)],
+) -> Vec<PathPiece> {
+    let closed = pline.closed;
+    let n_edges = if closed {
+        verts.len()
+    } else {
+        verts.len().saturating_sub(1)
+    };
+    let edge = |g: usize| (verts[g], verts[(g + 1) % verts.len()]);
+    let chord = |g: usize| {
+        let (a, b) = edge(g);
+        (b.0 - a.0).hypot(b.1 - a.1)
+    };
+
+    // Arc-length parameter at both ends of every path edge. Chords of a
+    // tessellated arc are equal-angle, so a proportional split of the
+    // source segment's arc length is exact along the arc.
+    let lengths = segment_lengths(pline);
+    let mut chord_total = vec![0.0; lengths.len()];
+    for g in 0..n_edges {
+        chord_total[seg_src[g]] += chord(g);
+    }
+    let mut seg_start = Vec::with_capacity(lengths.len());
+    let mut acc = 0.0;
+    for &len in &lengths {
+        seg_start.push(acc);
+        acc += len;
+    }
+    let total = acc;
+    let mut edge_s = Vec::with_capacity(n_edges);
+    let mut chord_acc = vec![0.0; lengths.len()];
+    for (g, &i) in seg_src.iter().enumerate().take(n_edges) {
+        let scale = if chord_total[i] > 0.0 {
+            lengths[i] / chord_total[i]
+        } else {
+            0.0
+        };
+        let s0 = seg_start[i] + chord_acc[i] * scale;
+        chord_acc[i] += chord(g);
+        edge_s.push((s0, seg_start[i] + chord_acc[i] * scale));
+    }
+
+    // Surviving intervals `(s0, s1, start_jamb, end_jamb)`; `s1` may
+    // exceed `total` for the wrapping piece of a closed path.
+    let mut sorted: Vec<(usize, f64, f64)> = openings.to_vec();
+    sorted.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let mut intervals: Vec<(f64, f64, Option<usize>, Option<usize>)> = Vec::new();
+    if let (true, Some(first), Some(last)) = (closed, sorted.first(), sorted.last()) {
+        intervals.push((last.2, first.1 + total, Some(last.0), Some(first.0)));
+    } else {
+        let first_end = sorted.first().map_or((total, None), |o| (o.1, Some(o.0)));
+        intervals.push((0.0, first_end.0, None, first_end.1));
+        if let Some(last) = sorted.last() {
+            intervals.push((last.2, total, Some(last.0), None));
+        }
+    }
+    for w in sorted.windows(2) {
+        intervals.push((w[0].2, w[1].1, Some(w[0].0), Some(w[1].0)));
+    }
+
+    let stride = openings.len() + 1;
+    let lerp = |g: usize, s: f64| -> (f64, f64) {
+        let (a, b) = edge(g);
+        let (e0, e1) = edge_s[g];
+        let t = if e1 - e0 > 0.0 {
+            ((s - e0) / (e1 - e0)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1))
+    };
+
+    let mut pieces = Vec::new();
+    for (s0, s1, start_jamb, end_jamb) in intervals {
+        if s1 - s0 < WALL_EPS {
+            continue;
+        }
+        let mut piece = PathPiece {
+            verts: Vec::new(),
+            seg_src: Vec::new(),
+            tess_ord: Vec::new(),
+            start_jamb,
+            end_jamb,
+        };
+        let laps = if closed { 2 } else { 1 };
+        for lap in 0..laps {
+            let shift = f64::from(lap) * total;
+            for g in 0..n_edges {
+                let (e0, e1) = (edge_s[g].0 + shift, edge_s[g].1 + shift);
+                let (a, b) = (s0.max(e0), s1.min(e1));
+                if b - a < WALL_EPS {
+                    continue;
+                }
+                if piece.verts.is_empty() {
+                    piece.verts.push(lerp(g, a - shift));
+                }
+                piece.verts.push(lerp(g, b - shift));
+                piece.seg_src.push(seg_src[g]);
+                // Fragments of one path edge that begin after an opening
+                // inside that edge must sort after those before it.
+                let after = openings
+                    .iter()
+                    .filter(|o| o.2 + shift >= e0 && o.2 + shift <= a + WALL_EPS)
+                    .count();
+                piece.tess_ord.push(g * stride + after);
+            }
+        }
+        if piece.verts.len() >= 2 {
+            pieces.push(piece);
+        }
+    }
+    pieces
+}
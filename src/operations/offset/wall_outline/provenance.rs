@@ -60,9 +60,13 @@ pub enum CapEnd {
 ///
 /// There is no join variant: wall joins are miters, so a join
 /// contributes a single shared vertex to the stroke polygon, never a
-/// segment of its own. Every boundary segment is either a side offset
-/// or an end cap.
+/// segment of its own. Every boundary segment is either a side offset,
+/// an end cap, or the jamb of an opening.
+///
+/// New origins may be added as walls gain features (`Jamb` arrived with
+/// openings), so matches outside this crate need a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum SegmentOrigin {
     /// Offset of centerline edge `edge` (connecting centerline vertex
     /// `edge` to vertex `edge + 1`, wrapping for closed polylines) on
@@ -71,6 +75,10 @@ pub enum SegmentOrigin {
     Side { edge: usize, side: OffsetSide },
     /// Flat end cap of an open centerline.
     Cap { end: CapEnd },
+    /// Flat jamb closing the wall at one end of opening `opening` (its
+    /// index in [`super::WallOutline2D::with_openings`]): `Start` is the
+    /// jamb at `start_s`, `End` the jamb at `end_s`.
+    Jamb { opening: usize, end: CapEnd },
 }
 
 /// Provenance of one output boundary segment.
@@ -419,6 +427,8 @@ mod tests {
                         };
                         ((seg_a.x + width * nn.0, seg_a.y + width * nn.1), seg_dir)
                     }
+                    // Jamb geometry is covered by the opening tests.
+                    SegmentOrigin::Jamb { .. } => continue,
                     SegmentOrigin::Cap { end } => {
                         let (cap_v, prev, next) = match end {
                             CapEnd::Start => (&pl.vertices[0], &pl.vertices[0], &pl.vertices[1]),
@@ -662,6 +672,7 @@ mod tests {
                     }
                 }
                 SegmentOrigin::Cap { .. } => caps += 1,
+                SegmentOrigin::Jamb { .. } => unreachable!("no openings were given"),
             }
         }
         assert!(left >= 2, "curved left offset must span several chords");
@@ -1,6 +1,20 @@
 use super::polygon_union::{Polygon, PolygonWithHoles, WALL_EPS};
 use super::provenance::{CapEnd, OffsetSide};
 
+/// Number of chords a round end cap is tessellated into.
+const ROUND_CAP_SEGMENTS: usize = 16;
+
+/// How the free ends of an open wall are closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CapStyle {
+    /// A flat end perpendicular to the end segment.
+    #[default]
+    Square,
+    /// A semicircle spanning the wall thickness, bulging past the end
+    /// vertex and tessellated into line chords.
+    Round,
+}
+
 /// Structural origin of one stroke-polygon edge, expressed in the
 /// **caller's** vertex frame: `seg` indexes the segments of the
 /// `vertices` slice exactly as passed to [`stroke_expand_labeled`]
@@ -10,8 +24,9 @@ use super::provenance::{CapEnd, OffsetSide};
 /// transparently remapped back to the caller frame.
 ///
 /// Joins are miters (each join contributes a single shared point, never
-/// its own edge), so every stroke edge is either a `Side` offset or a
-/// flat end `Cap` — there is no join-arc origin.
+/// its own edge), so every stroke edge is either a `Side` offset or an
+/// end `Cap` — there is no join-arc origin. Every chord of a round cap
+/// carries the same `Cap` label.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StrokeOrigin {
     Side { seg: usize, side: OffsetSide },
@@ -45,12 +60,16 @@ pub struct StrokeLabels {
 /// every winding.
 ///
 /// For a closed polyline the result is an annulus: outer ring + one hole.
-/// For an open polyline it is a single ring (left side forward, right side back).
+/// For an open polyline it is a single ring (left side forward, right side
+/// back) whose ends are closed as `start_cap` / `end_cap` direct; closed
+/// inputs ignore the cap styles.
 pub fn stroke_expand_labeled(
     vertices: &[(f64, f64)],
     closed: bool,
     left_w: f64,
     right_w: f64,
+    start_cap: CapStyle,
+    end_cap: CapStyle,
 ) -> (PolygonWithHoles, StrokeLabels) {
     let n = vertices.len();
     if n < 2 {
@@ -121,7 +140,8 @@ pub fn stroke_expand_labeled(
     if closed {
         assemble_closed_annulus(left_pts, right_pts, seg_count, reversed)
     } else {
-        assemble_open_ring(&left_pts, right_pts, seg_count)
+        let caps = [(start_cap, dirs[0]), (end_cap, dirs[seg_count - 1])];
+        assemble_open_ring(&left_pts, right_pts, seg_count, caps)
     }
 }
 
@@ -207,20 +227,36 @@ fn assemble_closed_annulus(
 
 /// Assembles the open-input single ring: left side forward, end cap,
 /// right side backward, start cap — with matching per-edge labels.
+///
+/// `caps` holds the style and end-segment direction of the start and
+/// end cap.
 fn assemble_open_ring(
     left_pts: &[(f64, f64)],
     mut right_pts: Vec<(f64, f64)>,
     seg_count: usize,
+    caps: [(CapStyle, (f64, f64)); 2],
 ) -> (PolygonWithHoles, StrokeLabels) {
     // Each side has seg_count + 1 points (start cap point, one miter per
     // interior vertex, end point); side edge k lies on the offset line
     // of segment k.
     debug_assert_eq!(left_pts.len(), seg_count + 1);
     debug_assert_eq!(right_pts.len(), seg_count + 1);
+    let [(start_style, start_dir), (end_style, end_dir)] = caps;
+    let end_arc = cap_arc(
+        end_style,
+        left_pts[seg_count],
+        right_pts[seg_count],
+        end_dir,
+    );
+    let start_dir = (-start_dir.0, -start_dir.1);
+    let start_arc = cap_arc(start_style, right_pts[0], left_pts[0], start_dir);
+
     let mut poly: Polygon = Vec::new();
     poly.extend_from_slice(left_pts);
+    poly.extend_from_slice(&end_arc);
     right_pts.reverse();
     poly.extend_from_slice(&right_pts);
+    poly.extend_from_slice(&start_arc);
 
     let mut labels: Vec<StrokeOrigin> = Vec::with_capacity(poly.len());
     for k in 0..seg_count {
@@ -229,14 +265,20 @@ fn assemble_open_ring(
             side: OffsetSide::Left,
         });
     }
-    labels.push(StrokeOrigin::Cap { end: CapEnd::End });
+    labels.extend(std::iter::repeat_n(
+        StrokeOrigin::Cap { end: CapEnd::End },
+        end_arc.len() + 1,
+    ));
     for k in (0..seg_count).rev() {
         labels.push(StrokeOrigin::Side {
             seg: k,
             side: OffsetSide::Right,
         });
     }
-    labels.push(StrokeOrigin::Cap { end: CapEnd::Start });
+    labels.extend(std::iter::repeat_n(
+        StrokeOrigin::Cap { end: CapEnd::Start },
+        start_arc.len() + 1,
+    ));
     debug_assert_eq!(labels.len(), poly.len());
 
     (
@@ -251,6 +293,37 @@ fn assemble_open_ring(
     )
 }
 
+/// Interior points of the cap from `from` to `to`: none for a square cap,
+/// otherwise the semicircle on the segment `from → to` bulging towards
+/// `outward`, less its endpoints.
+fn cap_arc(
+    style: CapStyle,
+    from: (f64, f64),
+    to: (f64, f64),
+    outward: (f64, f64),
+) -> Vec<(f64, f64)> {
+    if style == CapStyle::Square {
+        return Vec::new();
+    }
+    let center = ((from.0 + to.0) * 0.5, (from.1 + to.1) * 0.5);
+    let radial = (from.0 - center.0, from.1 - center.1);
+    let r = radial.0.hypot(radial.1);
+    if r < WALL_EPS {
+        return Vec::new();
+    }
+    (1..ROUND_CAP_SEGMENTS)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let theta = std::f64::consts::PI * i as f64 / ROUND_CAP_SEGMENTS as f64;
+            let (sin, cos) = theta.sin_cos();
+            (
+                center.0 + cos * radial.0 + sin * r * outward.0,
+                center.1 + cos * radial.1 + sin * r * outward.1,
+            )
+        })
+        .collect()
+}
+
 /// Label remap for an in-place ring reversal `q[j] = p[m - 1 - j]`: the
 /// reversed ring's edge `j` retraces the original ring's edge
 /// `(m - 2 - j) mod m`, so its label is `l[(2m - 2 - j) % m]`.
@@ -432,7 +505,15 @@ mod tests {
         left_w: f64,
         right_w: f64,
     ) -> PolygonWithHoles {
-        stroke_expand_labeled(vertices, closed, left_w, right_w).0
+        stroke_expand_labeled(
+            vertices,
+            closed,
+            left_w,
+            right_w,
+            CapStyle::Square,
+            CapStyle::Square,
+        )
+        .0
     }
 
     #[test]
@@ -599,8 +680,14 @@ mod tests {
 
     #[test]
     fn labels_open_l_shape_sequence() {
-        let (pwh, labels) =
-            stroke_expand_labeled(&[(0.0, 0.0), (3.0, 0.0), (3.0, 3.0)], false, 0.3, 0.3);
+        let (pwh, labels) = stroke_expand_labeled(
+            &[(0.0, 0.0), (3.0, 0.0), (3.0, 3.0)],
+            false,
+            0.3,
+            0.3,
+            CapStyle::Square,
+            CapStyle::Square,
+        );
         assert!(labels.holes.is_empty());
         assert_eq!(labels.outer.len(), pwh.outer.len());
         let l = |seg| StrokeOrigin::Side {
@@ -661,7 +748,8 @@ mod tests {
     #[test]
     fn labels_closed_square_ccw_outer_right_hole_left() {
         let square = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
-        let (pwh, labels) = stroke_expand_labeled(&square, true, 0.3, 0.3);
+        let (pwh, labels) =
+            stroke_expand_labeled(&square, true, 0.3, 0.3, CapStyle::Square, CapStyle::Square);
         assert_eq!(labels.outer.len(), pwh.outer.len());
         assert_eq!(labels.holes.len(), 1);
         assert_eq!(labels.holes[0].len(), pwh.holes[0].len());
@@ -712,7 +800,14 @@ mod tests {
         // Same square traversed CW: sides must be reported relative to the
         // caller's (CW) traversal, so the outer ring is now the LEFT side.
         let square_cw = [(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)];
-        let (pwh, labels) = stroke_expand_labeled(&square_cw, true, 0.3, 0.3);
+        let (pwh, labels) = stroke_expand_labeled(
+            &square_cw,
+            true,
+            0.3,
+            0.3,
+            CapStyle::Square,
+            CapStyle::Square,
+        );
         for lab in &labels.outer {
             assert!(
                 matches!(
@@ -751,7 +846,14 @@ mod tests {
     #[test]
     fn labels_closed_square_cw_asymmetric_widths_follow_caller_sides() {
         let square_cw = [(0.0, 0.0), (0.0, 10.0), (10.0, 10.0), (10.0, 0.0)];
-        let (pwh, labels) = stroke_expand_labeled(&square_cw, true, 0.4, 0.1);
+        let (pwh, labels) = stroke_expand_labeled(
+            &square_cw,
+            true,
+            0.4,
+            0.1,
+            CapStyle::Square,
+            CapStyle::Square,
+        );
         assert_side_edges_on_offset_lines(&pwh.outer, &labels.outer, &square_cw, 0.4, 0.1);
         assert_side_edges_on_offset_lines(&pwh.holes[0], &labels.holes[0], &square_cw, 0.4, 0.1);
         // CW ring: caller-left is the ring exterior, so the 0.4 band
@@ -767,7 +869,8 @@ mod tests {
     #[test]
     fn labels_asymmetric_open_segment_on_offset_lines() {
         let line = [(0.0, 0.0), (5.0, 0.0)];
-        let (pwh, labels) = stroke_expand_labeled(&line, false, 0.0, 0.3);
+        let (pwh, labels) =
+            stroke_expand_labeled(&line, false, 0.0, 0.3, CapStyle::Square, CapStyle::Square);
         assert_eq!(labels.outer.len(), pwh.outer.len());
         assert_side_edges_on_offset_lines(&pwh.outer, &labels.outer, &line, 0.0, 0.3);
     }
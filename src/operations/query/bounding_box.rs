@@ -1,6 +1,6 @@
 use crate::error::Result;
 use crate::math::Point3;
-use crate::topology::{SolidId, TopologyStore};
+use crate::topology::{ShellId, SolidId, TopologyStore};
 
 /// An axis-aligned bounding box.
 #[derive(Debug, Clone, Copy)]
@@ -31,47 +31,60 @@ impl BoundingBox {
     ///
     /// Returns an error if the solid or any referenced entity is not found.
     pub fn execute(&self, store: &TopologyStore) -> Result<Aabb> {
-        let solid = store.solid(self.solid)?;
-        let outer_shell_id = solid.outer_shell;
-        let inner_shell_ids = solid.inner_shells.clone();
-
-        let mut min = Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
-        let mut max = Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
-
-        let mut process_shell = |shell_id: crate::topology::ShellId| -> Result<()> {
-            let shell = store.shell(shell_id)?;
-            for &face_id in &shell.faces {
-                let face = store.face(face_id)?;
-                let wire_ids: Vec<_> = std::iter::once(face.outer_wire)
-                    .chain(face.inner_wires.iter().copied())
-                    .collect();
-
-                for wire_id in wire_ids {
-                    let wire = store.wire(wire_id)?;
-                    for oe in &wire.edges {
-                        let edge = store.edge(oe.edge)?;
-                        for &vid in &[edge.start, edge.end] {
-                            let pt = store.vertex(vid)?.point;
-                            min.x = min.x.min(pt.x);
-                            min.y = min.y.min(pt.y);
-                            min.z = min.z.min(pt.z);
-                            max.x = max.x.max(pt.x);
-                            max.y = max.y.max(pt.y);
-                            max.z = max.z.max(pt.z);
-                        }
-                    }
-                }
-            }
-            Ok(())
-        };
+        Self::of_solids(store, &[self.solid])
+    }
 
-        process_shell(outer_shell_id)?;
-        for &shell_id in &inner_shell_ids {
-            process_shell(shell_id)?;
+    /// Computes the AABB enclosing all of `solids`.
+    ///
+    /// An empty slice yields an inverted box (`min` at `+∞`, `max` at `−∞`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a solid or any referenced entity is not found.
+    pub fn of_solids(store: &TopologyStore, solids: &[SolidId]) -> Result<Aabb> {
+        let mut aabb = Aabb {
+            min: Point3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            max: Point3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        };
+        for &solid_id in solids {
+            let solid = store.solid(solid_id)?;
+            for &shell_id in std::iter::once(&solid.outer_shell).chain(&solid.inner_shells) {
+                extend_with_shell(store, shell_id, &mut aabb)?;
+            }
         }
+        Ok(aabb)
+    }
+
+    /// Computes the AABB enclosing every solid in `store`, e.g. to frame a
+    /// whole scene. A store without solids yields an inverted box.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entity referenced by a solid is not found.
+    pub fn of_store(store: &TopologyStore) -> Result<Aabb> {
+        let solids: Vec<SolidId> = store.solid_ids().collect();
+        Self::of_solids(store, &solids)
+    }
+}
 
-        Ok(Aabb { min, max })
+/// Grows `aabb` to enclose every vertex on the faces of a shell.
+fn extend_with_shell(store: &TopologyStore, shell_id: ShellId, aabb: &mut Aabb) -> Result<()> {
+    let shell = store.shell(shell_id)?;
+    for &face_id in &shell.faces {
+        let face = store.face(face_id)?;
+        for &wire_id in std::iter::once(&face.outer_wire).chain(&face.inner_wires) {
+            let wire = store.wire(wire_id)?;
+            for oe in &wire.edges {
+                let edge = store.edge(oe.edge)?;
+                for &vid in &[edge.start, edge.end] {
+                    let pt = store.vertex(vid)?.point;
+                    aabb.min = aabb.min.inf(&pt);
+                    aabb.max = aabb.max.sup(&pt);
+                }
+            }
+        }
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -142,4 +155,28 @@ mod tests {
         assert!((aabb.max.y - 7.0).abs() < 1e-10);
         assert!((aabb.max.z - 4.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn union_over_solids_and_store() {
+        use crate::operations::creation::MakeBox;
+
+        let mut store = TopologyStore::new();
+        let empty = BoundingBox::of_store(&store).unwrap();
+        assert!(empty.min.x > empty.max.x);
+
+        let a = MakeBox::new(p(0.0, 0.0, 0.0), p(1.0, 1.0, 1.0))
+            .execute(&mut store)
+            .unwrap();
+        let b = MakeBox::new(p(4.0, -2.0, 3.0), p(6.0, 0.5, 5.0))
+            .execute(&mut store)
+            .unwrap();
+
+        let aabb = BoundingBox::of_solids(&store, &[a, b]).unwrap();
+        assert!((aabb.min - p(0.0, -2.0, 0.0)).norm() < 1e-10);
+        assert!((aabb.max - p(6.0, 1.0, 5.0)).norm() < 1e-10);
+
+        let scene = BoundingBox::of_store(&store).unwrap();
+        assert!((scene.min - aabb.min).norm() < 1e-10);
+        assert!((scene.max - aabb.max).norm() < 1e-10);
+    }
 }
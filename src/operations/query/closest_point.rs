@@ -1,6 +1,7 @@
 use crate::error::{OperationError, Result};
+use crate::geometry::curve::Curve;
 use crate::math::Point3;
-use crate::topology::{EdgeCurve, EdgeId, TopologyStore};
+use crate::topology::{EdgeCurve, EdgeId, TopologyStore, WireId};
 
 /// Result of a closest point query.
 #[derive(Debug, Clone, Copy)]
@@ -30,6 +31,8 @@ impl ClosestPointOnCurve {
     ///
     /// For `Line` edges, computes the analytical projection.
     /// For `Arc` edges, finds the closest angle and clamps to the arc domain.
+    /// `Circle` and `Ellipse` edges are searched numerically over the edge's
+    /// parameter range.
     ///
     /// # Errors
     ///
@@ -41,8 +44,11 @@ impl ClosestPointOnCurve {
                 closest_point_on_line(line, edge.t_start, edge.t_end, &self.point)
             }
             EdgeCurve::Arc(arc) => closest_point_on_arc(arc, edge.t_start, edge.t_end, &self.point),
-            EdgeCurve::Circle(_) | EdgeCurve::Ellipse(_) => {
-                todo!("ClosestPointOnCurve for Circle/Ellipse")
+            EdgeCurve::Circle(circle) => {
+                closest_point_sampled(circle, edge.t_start, edge.t_end, &self.point)
+            }
+            EdgeCurve::Ellipse(ellipse) => {
+                closest_point_sampled(ellipse, edge.t_start, edge.t_end, &self.point)
             }
             EdgeCurve::Nurbs(_) => Err(OperationError::Failed(
                 "closest point on NURBS edges is not yet supported".into(),
@@ -50,6 +56,32 @@ impl ClosestPointOnCurve {
             .into()),
         }
     }
+
+    /// Finds the closest point to `point` across every edge of `wire`.
+    ///
+    /// Each edge is searched within its own `t_start..t_end` range; ties go
+    /// to the earlier edge in wire order. Returns the nearest edge, the
+    /// parameter on it, and the footpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wire or one of its edges is not found, the
+    /// wire has no edges, or an edge's curve type is unsupported.
+    pub fn on_wire(
+        store: &TopologyStore,
+        wire: WireId,
+        point: Point3,
+    ) -> Result<(EdgeId, f64, Point3)> {
+        let mut best: Option<(EdgeId, ClosestPointResult)> = None;
+        for oe in &store.wire(wire)?.edges {
+            let result = Self::new(oe.edge, point).execute(store)?;
+            if best.is_none_or(|(_, b)| result.distance < b.distance) {
+                best = Some((oe.edge, result));
+            }
+        }
+        best.map(|(edge, r)| (edge, r.parameter, r.point))
+            .ok_or_else(|| OperationError::InvalidInput("wire has no edges".into()).into())
+    }
 }
 
 /// Finds the closest point on a bounded line segment.
@@ -59,8 +91,6 @@ fn closest_point_on_line(
     t_end: f64,
     point: &Point3,
 ) -> Result<ClosestPointResult> {
-    use crate::geometry::curve::Curve;
-
     let origin = line.origin();
     let dir = line.direction();
 
@@ -85,8 +115,6 @@ fn closest_point_on_arc(
     t_end: f64,
     point: &Point3,
 ) -> Result<ClosestPointResult> {
-    use crate::geometry::curve::Curve;
-
     let center = arc.center();
     let normal = arc.normal();
 
@@ -107,9 +135,18 @@ fn closest_point_on_arc(
         });
     }
 
-    // Find the angle of the projection
-    // Use atan2 in the arc's local coordinate system
-    // Sample the arc at multiple points to find the closest
+    closest_point_sampled(arc, t_start, t_end, point)
+}
+
+/// Finds the closest point on `curve` over `t_start..t_end` by coarse
+/// sampling followed by ternary-search refinement.
+fn closest_point_sampled(
+    curve: &dyn Curve,
+    t_start: f64,
+    t_end: f64,
+    point: &Point3,
+) -> Result<ClosestPointResult> {
+    // Sample the curve at multiple points to find the closest
     let n_samples = 64;
     let mut best_t = t_start;
     let mut best_dist = f64::INFINITY;
@@ -118,7 +155,7 @@ fn closest_point_on_arc(
         #[allow(clippy::cast_precision_loss)]
         let frac = f64::from(i) / f64::from(n_samples);
         let t = t_start + frac * (t_end - t_start);
-        let pt = arc.evaluate(t)?;
+        let pt = curve.evaluate(t)?;
         let d = (point - pt).norm();
         if d < best_dist {
             best_dist = d;
@@ -135,8 +172,8 @@ fn closest_point_on_arc(
     for _ in 0..50 {
         let mid1 = lo + (hi - lo) / 3.0;
         let mid2 = hi - (hi - lo) / 3.0;
-        let d1 = (point - arc.evaluate(mid1)?).norm();
-        let d2 = (point - arc.evaluate(mid2)?).norm();
+        let d1 = (point - curve.evaluate(mid1)?).norm();
+        let d2 = (point - curve.evaluate(mid2)?).norm();
         if d1 < d2 {
             hi = mid2;
         } else {
@@ -146,7 +183,7 @@ fn closest_point_on_arc(
 
     #[allow(clippy::manual_midpoint)]
     let best_t = (lo + hi) / 2.0;
-    let closest = arc.evaluate(best_t)?;
+    let closest = curve.evaluate(best_t)?;
     let distance = (point - closest).norm();
 
     Ok(ClosestPointResult {
@@ -221,4 +258,33 @@ mod tests {
         assert!((result.point.x - 10.0).abs() < 1e-10);
         assert!((result.distance - 5.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn on_wire_picks_nearest_edge() {
+        let mut store = TopologyStore::new();
+        let wire = MakeWire::new(
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(4.0, 0.0, 0.0),
+                Point3::new(4.0, 4.0, 0.0),
+                Point3::new(0.0, 4.0, 0.0),
+            ],
+            true,
+        )
+        .execute(&mut store)
+        .unwrap();
+        let right = store.wire(wire).unwrap().edges[1].edge;
+
+        let (edge, t, p) =
+            ClosestPointOnCurve::on_wire(&store, wire, Point3::new(4.5, 1.0, 0.0)).unwrap();
+        assert_eq!(edge, right);
+        assert!((p - Point3::new(4.0, 1.0, 0.0)).norm() < 1e-10);
+        let e = store.edge(edge).unwrap();
+        assert!((e.t_start..=e.t_end).contains(&t));
+
+        // Beyond a corner, the footpoint clamps to the shared vertex.
+        let (_, _, p) =
+            ClosestPointOnCurve::on_wire(&store, wire, Point3::new(5.0, 5.0, 0.0)).unwrap();
+        assert!((p - Point3::new(4.0, 4.0, 0.0)).norm() < 1e-10);
+    }
 }
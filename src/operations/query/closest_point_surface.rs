@@ -46,6 +46,12 @@ impl ClosestPointOnSurface {
             FaceSurface::Cone(cone) => Ok(closest_on_cone(cone, &self.query)),
             FaceSurface::Torus(torus) => closest_on_torus(torus, &self.query),
             FaceSurface::Nurbs(nurbs) => closest_on_nurbs(nurbs, &self.query),
+            FaceSurface::Ruled(ruled) => {
+                closest_via_inverse(ruled, ruled.inverse(&self.query), &self.query)
+            }
+            FaceSurface::Extruded(extruded) => {
+                closest_via_inverse(extruded, extruded.inverse(&self.query), &self.query)
+            }
         }
     }
 }
@@ -178,6 +184,23 @@ fn closest_on_torus(
     })
 }
 
+/// Evaluates the point at `(u, v)` found by a surface's own numeric
+/// inverse (ruled and extruded surfaces).
+fn closest_via_inverse(
+    surface: &dyn Surface,
+    (u, v): (f64, f64),
+    query: &Point3,
+) -> Result<SurfacePoint> {
+    let point = surface.evaluate(u, v)?;
+    let distance = (query - point).norm();
+    Ok(SurfacePoint {
+        u,
+        v,
+        point,
+        distance,
+    })
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -202,6 +225,7 @@ mod tests {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         })
     }
 
@@ -218,6 +242,7 @@ mod tests {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         })
     }
 
@@ -234,6 +259,7 @@ mod tests {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         })
     }
 
@@ -322,6 +348,7 @@ mod tests {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         })
     }
 
@@ -0,0 +1,277 @@
+use crate::geometry::curve::{Curve, Line};
+use crate::geometry::surface::Plane;
+use crate::math::{Point3, TOLERANCE};
+
+/// Sample intervals scanned for sign changes over a bounded domain.
+const SCAN_SAMPLES: u32 = 64;
+
+/// Bisection steps refining each bracketed crossing.
+const BISECTION_ITERATIONS: usize = 64;
+
+/// Finds where a curve crosses a plane, e.g. to section a wire.
+pub struct CurvePlaneIntersect {
+    plane: Plane,
+}
+
+impl CurvePlaneIntersect {
+    /// Creates a new `CurvePlaneIntersect` query against `plane`.
+    #[must_use]
+    pub fn new(plane: &Plane) -> Self {
+        Self {
+            plane: plane.clone(),
+        }
+    }
+
+    /// Returns `(t, point)` for every crossing, in increasing `t`.
+    ///
+    /// A [`Line`] (or a ray split from one) is solved exactly. Any other
+    /// curve must have a bounded domain, which is scanned in 64 intervals
+    /// for sign changes of the signed distance to the plane, each refined
+    /// by bisection. Where both ends of an
+    /// interval lie on one side but the curve turns back towards the plane
+    /// in between, the turning point is located from the tangent and any
+    /// pair of crossings around it is found as well. Samples lying on the
+    /// plane count as crossings, and a run of them (a curve lying in the
+    /// plane) is reported once. Parameters where the curve fails to
+    /// evaluate are skipped.
+    ///
+    /// Crossings are missed only when the distance to the plane turns more
+    /// than once within a single interval, and an unbounded curve other
+    /// than a line yields none.
+    #[must_use]
+    pub fn execute(&self, curve: &dyn Curve) -> Vec<(f64, Point3)> {
+        if let Some(line) = curve.as_line() {
+            return self.solve_line(line).into_iter().collect();
+        }
+        let domain = curve.domain();
+        if domain.t_min.is_finite() && domain.t_max.is_finite() {
+            self.scan(curve, domain.t_min, domain.t_max)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Solves `dist(t) = 0` on `line`, keeping the root only if it lies in
+    /// the line's domain.
+    fn solve_line(&self, line: &Line) -> Option<(f64, Point3)> {
+        let rate = line.direction().dot(self.plane.plane_normal());
+        if rate.abs() < TOLERANCE {
+            // Parallel to the plane (or lying in it): no single crossing.
+            return None;
+        }
+        let t = -self.plane.signed_distance(line.origin()) / rate;
+        let domain = line.domain();
+        (t >= domain.t_min - TOLERANCE && t <= domain.t_max + TOLERANCE)
+            .then(|| (t, line.origin() + line.direction() * t))
+    }
+
+    fn scan(&self, curve: &dyn Curve, t_min: f64, t_max: f64) -> Vec<(f64, Point3)> {
+        let dist = |t: f64| {
+            curve
+                .evaluate(t)
+                .ok()
+                .map(|p| (self.plane.signed_distance(&p), p))
+        };
+        let samples: Vec<(f64, Option<(f64, Point3)>)> = (0..=SCAN_SAMPLES)
+            .map(|i| {
+                let t = t_min + (t_max - t_min) * f64::from(i) / f64::from(SCAN_SAMPLES);
+                (t, dist(t))
+            })
+            .collect();
+
+        let mut hits: Vec<(f64, Point3)> = Vec::new();
+        let mut prev_on_plane = false;
+        for (i, &(t, sample)) in samples.iter().enumerate() {
+            let Some((d, p)) = sample else {
+                prev_on_plane = false;
+                continue;
+            };
+            let on_plane = d.abs() < TOLERANCE;
+            if on_plane && !prev_on_plane {
+                hits.push((t, p));
+            }
+            prev_on_plane = on_plane;
+            if on_plane {
+                continue;
+            }
+            let Some(&(t_next, Some((d_next, _)))) = samples.get(i + 1) else {
+                continue;
+            };
+            if d_next.abs() < TOLERANCE {
+                continue;
+            }
+            if d.signum() == d_next.signum() {
+                hits.extend(self.turning_crossings(curve, &dist, t, d, t_next, d_next));
+            } else if let Some(hit) = bisect(&dist, t, d, t_next) {
+                hits.push(hit);
+            }
+        }
+
+        // The end of a closed curve revisits its start.
+        if curve.is_closed() && hits.len() > 1 {
+            let (first, last) = (hits[0].1, hits[hits.len() - 1].1);
+            if (first - last).norm() < TOLERANCE {
+                hits.pop();
+            }
+        }
+        hits
+    }
+
+    /// Crossings between two samples on the same side of the plane (`da`
+    /// and `db` share a sign), found where the curve heads towards the
+    /// plane at `a` and away from it at `b`: the turning point between is
+    /// bisected from the tangent, and if it reaches the plane the crossings
+    /// on either side of it are refined.
+    fn turning_crossings(
+        &self,
+        curve: &dyn Curve,
+        dist: &impl Fn(f64) -> Option<(f64, Point3)>,
+        a: f64,
+        da: f64,
+        b: f64,
+        db: f64,
+    ) -> Vec<(f64, Point3)> {
+        let slope = |t: f64| {
+            curve
+                .tangent(t)
+                .ok()
+                .map(|v| v.dot(self.plane.plane_normal()))
+        };
+        let (Some(sa), Some(sb)) = (slope(a), slope(b)) else {
+            return Vec::new();
+        };
+        if sa * da >= 0.0 || sb * db <= 0.0 {
+            return Vec::new();
+        }
+        let (mut lo, mut hi) = (a, b);
+        for _ in 0..BISECTION_ITERATIONS {
+            let t = f64::midpoint(lo, hi);
+            let Some(s) = slope(t) else {
+                return Vec::new();
+            };
+            if s.signum() == sa.signum() {
+                lo = t;
+            } else {
+                hi = t;
+            }
+            if hi - lo < TOLERANCE * TOLERANCE {
+                break;
+            }
+        }
+        let turn = f64::midpoint(lo, hi);
+        let Some((d_turn, p_turn)) = dist(turn) else {
+            return Vec::new();
+        };
+        if d_turn.abs() < TOLERANCE {
+            // Touches the plane without crossing it.
+            return vec![(turn, p_turn)];
+        }
+        if d_turn.signum() == da.signum() {
+            return Vec::new();
+        }
+        [bisect(dist, a, da, turn), bisect(dist, turn, d_turn, b)]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+/// Narrows a sign change of `dist` on `[a, b]` (with `dist(a) = da`) to a
+/// crossing.
+#[allow(clippy::many_single_char_names)]
+fn bisect(
+    dist: &impl Fn(f64) -> Option<(f64, Point3)>,
+    mut a: f64,
+    mut da: f64,
+    mut b: f64,
+) -> Option<(f64, Point3)> {
+    let mut mid = None;
+    for _ in 0..BISECTION_ITERATIONS {
+        let t = 0.5 * (a + b);
+        let (d, p) = dist(t)?;
+        mid = Some((t, p));
+        if d.abs() < TOLERANCE * TOLERANCE || b - a < TOLERANCE * TOLERANCE {
+            break;
+        }
+        if d.signum() == da.signum() {
+            (a, da) = (t, d);
+        } else {
+            b = t;
+        }
+    }
+    mid
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::curve::Circle;
+    use crate::math::Vector3;
+
+    fn xy_plane() -> Plane {
+        Plane::from_normal(Point3::origin(), Vector3::z()).unwrap()
+    }
+
+    #[test]
+    fn vertical_line_hits_xy_plane_once() {
+        let line = Line::new(Point3::new(1.0, 2.0, 5.0), -Vector3::z()).unwrap();
+        let hits = CurvePlaneIntersect::new(&xy_plane()).execute(&line);
+        assert_eq!(hits.len(), 1);
+        let (t, p) = hits[0];
+        assert!((t - 5.0).abs() < 1e-12);
+        assert!((p - Point3::new(1.0, 2.0, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn parallel_line_misses() {
+        let line = Line::new(Point3::new(0.0, 0.0, 1.0), Vector3::x()).unwrap();
+        assert!(CurvePlaneIntersect::new(&xy_plane())
+            .execute(&line)
+            .is_empty());
+    }
+
+    #[test]
+    fn tilted_circle_crosses_twice() {
+        // Circle in the XZ plane, centred on the XY plane.
+        let circle =
+            Circle::new(Point3::new(1.0, 0.0, 0.0), 2.0, Vector3::y(), Vector3::x()).unwrap();
+        let hits = CurvePlaneIntersect::new(&xy_plane()).execute(&circle);
+        assert_eq!(hits.len(), 2, "{hits:?}");
+        for (t, p) in &hits {
+            assert!(p.z.abs() < 1e-9);
+            assert!((circle.evaluate(*t).unwrap() - p).norm() < 1e-12);
+            assert!(((p - Point3::new(1.0, 0.0, 0.0)).norm() - 2.0).abs() < 1e-9);
+        }
+        assert!((hits[0].1.x - hits[1].1.x).abs() > 3.9);
+    }
+
+    #[test]
+    fn close_pair_of_crossings_inside_one_scan_interval() {
+        // A circle dipping 1e-4 below the plane: its two crossings lie
+        // 0.028 rad apart, between two of the 64 scan samples.
+        let circle = Circle::new(
+            Point3::new(0.0, 0.0, 0.9999),
+            1.0,
+            Vector3::y(),
+            Vector3::new(1.0, 0.0, 0.5),
+        )
+        .unwrap();
+        let hits = CurvePlaneIntersect::new(&xy_plane()).execute(&circle);
+        assert_eq!(hits.len(), 2, "{hits:?}");
+        for (_, p) in &hits {
+            assert!(p.z.abs() < 1e-9, "{p:?}");
+        }
+    }
+
+    #[test]
+    fn ray_short_of_the_plane_misses() {
+        let line = Line::new(Point3::new(0.0, 0.0, 5.0), -Vector3::z()).unwrap();
+        let (before, after) = line.split_at(2.0).unwrap();
+        let query = CurvePlaneIntersect::new(&xy_plane());
+        assert!(query.execute(before.as_ref()).is_empty());
+        let hits = query.execute(after.as_ref());
+        assert_eq!(hits.len(), 1);
+        assert!((hits[0].0 - 5.0).abs() < 1e-12);
+    }
+}
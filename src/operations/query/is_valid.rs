@@ -1,4 +1,18 @@
-use crate::topology::{SolidId, TopologyStore};
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{OperationError, Result};
+use crate::math::intersect_3d::line_triangle_intersect;
+use crate::math::{Point3, TOLERANCE};
+use crate::tessellation::{TessellateSolid, TessellationParams};
+use crate::topology::{ShellId, SolidId, TopologyStore};
+
+/// Distance within which tessellated vertices are welded before the
+/// self-intersection test, so triangles meeting across face boundaries
+/// count as adjacent.
+const WELD_TOL: f64 = TOLERANCE * 100.0;
+
+/// Parametric margin below which a triangle crossing counts as touching.
+const CROSS_EPS: f64 = 1e-9;
 
 /// Validates the topological and geometric consistency of a solid.
 pub struct IsValid {
@@ -24,9 +38,73 @@ impl IsValid {
         self.validate(store).is_ok()
     }
 
-    fn validate(&self, store: &TopologyStore) -> Result<(), &'static str> {
-        use std::collections::HashMap;
+    /// Returns `true` if every shell of `solid` is a closed 2-manifold
+    /// surface; see [`Self::check_solid_closed`].
+    #[must_use]
+    pub fn solid_is_closed(store: &TopologyStore, solid: SolidId) -> bool {
+        Self::check_solid_closed(store, solid).is_ok()
+    }
+
+    /// Checks that every shell of `solid` is closed: each edge is used by
+    /// exactly two face loops, and the Euler–Poincaré characteristic
+    /// `χ = V − E + F − R` (with `R` the inner loops of the faces) equals
+    /// `2 − 2g` for some genus `g ≥ 0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the offending shell and its computed `χ` if
+    /// a check fails, or if the solid's topology cannot be read.
+    pub fn check_solid_closed(store: &TopologyStore, solid: SolidId) -> Result<()> {
+        let solid = store.solid(solid)?;
+        let shells = std::iter::once(solid.outer_shell).chain(solid.inner_shells.iter().copied());
+        for (index, shell) in shells.enumerate() {
+            let (chi, open_edges) = shell_euler_characteristic(store, shell)?;
+            if open_edges > 0 || chi > 2 || chi % 2 != 0 {
+                return Err(OperationError::Failed(format!(
+                    "shell {index} is not closed: V - E + F - R = {chi}, \
+                     {open_edges} edges not used exactly twice"
+                ))
+                .into());
+            }
+        }
+        Ok(())
+    }
 
+    /// Returns `true` if the boundary of `solid` crosses itself; see
+    /// [`Self::find_self_intersection`]. A solid that cannot be
+    /// tessellated reports `false`.
+    #[must_use]
+    pub fn solid_is_self_intersecting(store: &TopologyStore, solid: SolidId) -> bool {
+        matches!(Self::find_self_intersection(store, solid), Ok(Some(_)))
+    }
+
+    /// Searches the tessellated boundary of `solid` for two triangles that
+    /// share no vertex yet cross each other, returning a point on the
+    /// crossing.
+    ///
+    /// Triangles that merely touch, or overlap while coplanar, are not
+    /// reported. Faces are compared through their tessellation, so curved
+    /// faces are tested to the default tessellation tolerance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the solid cannot be tessellated.
+    pub fn find_self_intersection(store: &TopologyStore, solid: SolidId) -> Result<Option<Point3>> {
+        let mesh = TessellateSolid::new(solid, TessellationParams::default())
+            .with_vertex_dedup(WELD_TOL)
+            .execute(store)?;
+        let tris: Vec<[Point3; 3]> = mesh
+            .indices
+            .iter()
+            .map(|tri| tri.map(|i| mesh.vertices[i as usize]))
+            .collect();
+        // Triangles sharing a vertex meet there by construction.
+        let adjacent =
+            |i: usize, j: usize| mesh.indices[i].iter().any(|v| mesh.indices[j].contains(v));
+        Ok(crossing_point(&tris, adjacent, &mut 0))
+    }
+
+    fn validate(&self, store: &TopologyStore) -> std::result::Result<(), &'static str> {
         let solid = store.solid(self.solid).map_err(|_| "solid not found")?;
         let shell = store
             .shell(solid.outer_shell)
@@ -94,6 +172,88 @@ impl IsValid {
     }
 }
 
+/// Returns the Euler–Poincaré characteristic `V − E + F − R` of `shell` and
+/// the number of its edges not used by exactly two face loops.
+fn shell_euler_characteristic(store: &TopologyStore, shell: ShellId) -> Result<(i64, usize)> {
+    let shell = store.shell(shell)?;
+    let mut vertices = HashSet::new();
+    let mut edge_usage: HashMap<crate::topology::EdgeId, usize> = HashMap::new();
+    let mut rings = 0;
+    for &face_id in &shell.faces {
+        let face = store.face(face_id)?;
+        rings += face.inner_wires.len();
+        for wire_id in std::iter::once(face.outer_wire).chain(face.inner_wires.iter().copied()) {
+            for oe in &store.wire(wire_id)?.edges {
+                let edge = store.edge(oe.edge)?;
+                vertices.extend([edge.start, edge.end]);
+                *edge_usage.entry(oe.edge).or_insert(0) += 1;
+            }
+        }
+    }
+    let open_edges = edge_usage.values().filter(|&&n| n != 2).count();
+    let count = |n: usize| i64::try_from(n).unwrap_or(i64::MAX);
+    let chi =
+        count(vertices.len()) - count(edge_usage.len()) + count(shell.faces.len()) - count(rings);
+    Ok((chi, open_edges))
+}
+
+/// A point where two triangles of `tris` cross, found by sweeping the
+/// triangles' bounding boxes along x.
+///
+/// Pairs for which `skip(i, j)` holds are not tested; `pairs` counts the
+/// remaining pairs whose x-ranges overlap.
+pub(super) fn crossing_point(
+    tris: &[[Point3; 3]],
+    skip: impl Fn(usize, usize) -> bool,
+    pairs: &mut usize,
+) -> Option<Point3> {
+    let boxes: Vec<(Point3, Point3)> = tris
+        .iter()
+        .map(|t| (t[0].inf(&t[1]).inf(&t[2]), t[0].sup(&t[1]).sup(&t[2])))
+        .collect();
+    let mut order: Vec<usize> = (0..tris.len()).collect();
+    order.sort_by(|&a, &b| boxes[a].0.x.total_cmp(&boxes[b].0.x));
+
+    for (k, &i) in order.iter().enumerate() {
+        for &j in &order[k + 1..] {
+            if boxes[j].0.x > boxes[i].1.x {
+                break;
+            }
+            if skip(i, j) {
+                continue;
+            }
+            *pairs += 1;
+            let overlaps =
+                (1..3).all(|d| boxes[j].0[d] <= boxes[i].1[d] && boxes[i].0[d] <= boxes[j].1[d]);
+            if !overlaps {
+                continue;
+            }
+            let hit =
+                edge_piercing(&tris[i], &tris[j]).or_else(|| edge_piercing(&tris[j], &tris[i]));
+            if hit.is_some() {
+                return hit;
+            }
+        }
+    }
+    None
+}
+
+/// The point where an edge of `a` passes through the interior of `b`.
+fn edge_piercing(a: &[Point3; 3], b: &[Point3; 3]) -> Option<Point3> {
+    (0..3).find_map(|k| segment_piercing(&a[k], &a[(k + 1) % 3], b))
+}
+
+/// Tests the segment `p → q` against `tri`, requiring the hit to lie
+/// strictly inside both.
+fn segment_piercing(p: &Point3, q: &Point3, tri: &[Point3; 3]) -> Option<Point3> {
+    let dir = q - p;
+    // Scale-free parallel test, so tiny and huge meshes behave alike.
+    let min_det = TOLERANCE * dir.norm() * (tri[1] - tri[0]).norm() * (tri[2] - tri[0]).norm();
+    let (t, bary) = line_triangle_intersect(p, &dir, tri, min_det)?;
+    let inside = t > CROSS_EPS && t < 1.0 - CROSS_EPS && bary.iter().all(|&b| b > CROSS_EPS);
+    inside.then(|| p + dir * t)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -145,4 +305,106 @@ mod tests {
 
         assert!(IsValid::new(solid).execute(&store));
     }
+
+    #[test]
+    fn box_euler_characteristic() {
+        use crate::operations::creation::MakeBox;
+
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(p(0.0, 0.0, 0.0), p(2.0, 3.0, 4.0))
+            .execute(&mut store)
+            .unwrap();
+        let shell = store.solid(solid).unwrap().outer_shell;
+        assert_eq!(shell_euler_characteristic(&store, shell).unwrap(), (2, 0));
+        assert!(IsValid::solid_is_closed(&store, solid));
+
+        // Dropping a face opens the shell; the error reports χ.
+        store.shell_mut(shell).unwrap().faces.pop();
+        let err = IsValid::check_solid_closed(&store, solid).unwrap_err();
+        assert!(err.to_string().contains("V - E + F - R = 1"), "{err}");
+        assert!(!IsValid::solid_is_closed(&store, solid));
+    }
+
+    #[test]
+    fn box_with_through_hole_has_genus_one() {
+        let mut store = TopologyStore::new();
+        let outer = MakeWire::new(
+            vec![
+                p(0.0, 0.0, 0.0),
+                p(4.0, 0.0, 0.0),
+                p(4.0, 4.0, 0.0),
+                p(0.0, 4.0, 0.0),
+            ],
+            true,
+        )
+        .execute(&mut store)
+        .unwrap();
+        let hole = MakeWire::new(
+            vec![
+                p(1.0, 1.0, 0.0),
+                p(1.0, 3.0, 0.0),
+                p(3.0, 3.0, 0.0),
+                p(3.0, 1.0, 0.0),
+            ],
+            true,
+        )
+        .execute(&mut store)
+        .unwrap();
+        let face = MakeFace::new(outer, vec![hole])
+            .execute(&mut store)
+            .unwrap();
+        let solid = Extrude::new(face, Vector3::new(0.0, 0.0, 2.0))
+            .execute(&mut store)
+            .unwrap();
+
+        let shell = store.solid(solid).unwrap().outer_shell;
+        assert_eq!(shell_euler_characteristic(&store, shell).unwrap(), (0, 0));
+        assert!(IsValid::solid_is_closed(&store, solid));
+    }
+
+    #[test]
+    fn extruded_bowtie_is_self_intersecting() {
+        let mut store = TopologyStore::new();
+        let cube = {
+            let wire = MakeWire::new(
+                vec![
+                    p(0.0, 0.0, 0.0),
+                    p(1.0, 0.0, 0.0),
+                    p(1.0, 1.0, 0.0),
+                    p(0.0, 1.0, 0.0),
+                ],
+                true,
+            )
+            .execute(&mut store)
+            .unwrap();
+            let face = MakeFace::new(wire, vec![]).execute(&mut store).unwrap();
+            Extrude::new(face, Vector3::new(0.0, 0.0, 1.0))
+                .execute(&mut store)
+                .unwrap()
+        };
+        assert!(!IsValid::solid_is_self_intersecting(&store, cube));
+
+        // The two diagonal side walls of the (lopsided, so it still has a
+        // net area) bowtie cross along x = y = 1.2.
+        let wire = MakeWire::new(
+            vec![
+                p(0.0, 0.0, 0.0),
+                p(3.0, 3.0, 0.0),
+                p(3.0, 0.0, 0.0),
+                p(0.0, 2.0, 0.0),
+            ],
+            true,
+        )
+        .execute(&mut store)
+        .unwrap();
+        let face = MakeFace::new(wire, vec![]).execute(&mut store).unwrap();
+        let bowtie = Extrude::new(face, Vector3::new(0.0, 0.0, 1.0))
+            .execute(&mut store)
+            .unwrap();
+        let hit = IsValid::find_self_intersection(&store, bowtie)
+            .unwrap()
+            .unwrap();
+        assert!((hit.x - 1.2).abs() < 1e-9 && (hit.y - 1.2).abs() < 1e-9);
+        assert!(IsValid::solid_is_self_intersecting(&store, bowtie));
+    }
 }
@@ -1,7 +1,59 @@
-use crate::error::Result;
+use crate::error::{OperationError, Result};
 use crate::geometry::curve::Curve;
+use crate::tessellation::TriangleMesh;
 use crate::topology::{EdgeCurve, EdgeId, TopologyStore};
 
+/// Precomputed cumulative arc length of a curve at evenly spaced
+/// parameters, built by [`Length::build_table`].
+///
+/// Lookups are `O(log n)` binary searches with linear interpolation
+/// between samples; accuracy is bounded by the chord error of the
+/// sampling.
+#[derive(Debug, Clone)]
+pub struct ArcLengthTable {
+    params: Vec<f64>,
+    lengths: Vec<f64>,
+}
+
+impl ArcLengthTable {
+    /// Returns the total tabulated length.
+    #[must_use]
+    pub fn total_length(&self) -> f64 {
+        self.lengths.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the curve parameter at arc length `s` from the start,
+    /// clamped to the curve's domain.
+    #[must_use]
+    pub fn param_at_length(&self, s: f64) -> f64 {
+        interpolate(&self.lengths, &self.params, s)
+    }
+
+    /// Returns the arc length from the start to parameter `t`, clamped to
+    /// the curve's domain.
+    #[must_use]
+    pub fn length_at_param(&self, t: f64) -> f64 {
+        interpolate(&self.params, &self.lengths, t)
+    }
+}
+
+/// Piecewise-linear lookup of `x` in the ascending `xs`, mapped onto `ys`.
+fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let i = xs.partition_point(|&v| v < x);
+    if i == 0 {
+        return ys[0];
+    }
+    if i == xs.len() {
+        return ys[ys.len() - 1];
+    }
+    let span = xs[i] - xs[i - 1];
+    if span <= 0.0 {
+        return ys[i];
+    }
+    let t = (x - xs[i - 1]) / span;
+    ys[i - 1] + t * (ys[i] - ys[i - 1])
+}
+
 /// Computes the length of a curve (edge).
 pub struct Length {
     edge: EdgeId,
@@ -14,6 +66,49 @@ impl Length {
         Self { edge }
     }
 
+    /// Returns the total length of the boundary edges of `mesh` (edges used
+    /// by exactly one triangle, see [`TriangleMesh::boundary_edges`]).
+    ///
+    /// A closed, welded manifold mesh returns `0.0`.
+    #[must_use]
+    pub fn mesh_boundary(mesh: &TriangleMesh) -> f64 {
+        mesh.boundary_edges()
+            .iter()
+            .map(|&(a, b)| (mesh.vertices[b as usize] - mesh.vertices[a as usize]).norm())
+            .sum()
+    }
+
+    /// Builds an [`ArcLengthTable`] for `curve` from `samples` equal
+    /// parameter steps over its domain, accumulating chord lengths.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `samples` is zero, the curve's domain is
+    /// unbounded, or evaluation fails.
+    pub fn build_table(curve: &dyn Curve, samples: usize) -> Result<ArcLengthTable> {
+        let domain = curve.domain();
+        if samples == 0 || !domain.t_min.is_finite() || !domain.t_max.is_finite() {
+            return Err(OperationError::InvalidInput(
+                "arc-length table needs at least one sample over a bounded domain".into(),
+            )
+            .into());
+        }
+        let mut params = Vec::with_capacity(samples + 1);
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut prev = curve.evaluate(domain.t_min)?;
+        params.push(domain.t_min);
+        lengths.push(0.0);
+        for idx in 1..=samples {
+            #[allow(clippy::cast_precision_loss)]
+            let t = domain.t_min + (domain.t_max - domain.t_min) * idx as f64 / samples as f64;
+            let p = curve.evaluate(t)?;
+            lengths.push(lengths[idx - 1] + (p - prev).norm());
+            params.push(t);
+            prev = p;
+        }
+        Ok(ArcLengthTable { params, lengths })
+    }
+
     /// Executes the query, returning the curve length.
     ///
     /// For a `Line`, this is `|t_end - t_start|`.
@@ -102,6 +197,28 @@ mod tests {
     use crate::operations::creation::MakeWire;
     use crate::topology::TopologyStore;
 
+    #[test]
+    fn mesh_boundary_of_cube_and_holed_cube() {
+        use crate::operations::creation::MakeBox;
+        use crate::tessellation::{TessellateSolid, TessellationParams};
+
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(1.0, 1.0, 1.0))
+            .execute(&mut store)
+            .unwrap();
+        let mut mesh = TessellateSolid::new(solid, TessellationParams::default())
+            .with_vertex_dedup(1e-9)
+            .execute(&store)
+            .unwrap();
+        assert!(Length::mesh_boundary(&mesh).abs() < 1e-12);
+
+        // Dropping one triangle of a unit face leaves a right-triangle hole.
+        mesh.indices.pop();
+        let expected = 2.0 + std::f64::consts::SQRT_2;
+        assert!((Length::mesh_boundary(&mesh) - expected).abs() < 1e-9);
+        assert_eq!(mesh.boundary_edges().len(), 3);
+    }
+
     #[test]
     fn line_length_3_4_5() {
         let mut store = TopologyStore::new();
@@ -131,4 +248,35 @@ mod tests {
         let len = Length::new(edge_id).execute(&store).unwrap();
         assert!((len - 1.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn arc_length_table_matches_arc_integration() {
+        use crate::geometry::curve::Arc;
+        use crate::math::Vector3;
+        use std::f64::consts::PI;
+
+        let (r, t0) = (2.0, 0.25);
+        let arc = Arc::new(Point3::origin(), r, Vector3::z(), Vector3::x(), t0, t0 + PI).unwrap();
+        let samples = 256;
+        let table = Length::build_table(&arc, samples).unwrap();
+
+        // Chord error per sample bounds the table's resolution.
+        #[allow(clippy::cast_precision_loss)]
+        let step = PI / samples as f64;
+        let resolution = r * PI * step * step / 24.0 + 1e-12;
+        assert!((table.total_length() - r * PI).abs() <= resolution);
+
+        for k in 0..=10 {
+            let t = t0 + PI * f64::from(k) / 10.0;
+            let s = r * (t - t0);
+            assert!((table.length_at_param(t) - s).abs() <= resolution, "t={t}");
+            assert!(
+                (table.param_at_length(s) - t).abs() <= resolution / r * 2.0,
+                "s={s}"
+            );
+        }
+        // Queries outside the tabulated range clamp to the ends.
+        assert!((table.param_at_length(-1.0) - t0).abs() < 1e-15);
+        assert!((table.param_at_length(1e9) - (t0 + PI)).abs() < 1e-15);
+    }
 }
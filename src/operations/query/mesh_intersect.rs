@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+
+use crate::math::{Point3, Vector3};
+use crate::tessellation::TriangleMesh;
+
+use super::is_valid::crossing_point;
+
+/// Fraction of a point cloud's bounding-box diagonal within which a point
+/// counts as lying on a hull face.
+const HULL_EPS: f64 = 1e-9;
+
+/// Finds where the surfaces of two triangle meshes cross.
+///
+/// The triangle phase sweeps the triangles' bounding boxes along x and
+/// tests every overlapping pair across the two meshes for an edge piercing
+/// the other triangle's interior; triangles that merely touch, or overlap
+/// while coplanar, are not reported. With
+/// [`with_hull_prefilter`](Self::with_hull_prefilter), the convex hulls of
+/// the two meshes are first tested for a separating axis, so distant
+/// meshes are rejected without visiting any triangle pair.
+pub struct MeshMeshIntersect {
+    a: TriangleMesh,
+    b: TriangleMesh,
+    hull_prefilter: bool,
+}
+
+impl MeshMeshIntersect {
+    /// Creates a new `MeshMeshIntersect` query.
+    #[must_use]
+    pub fn new(a: TriangleMesh, b: TriangleMesh) -> Self {
+        Self {
+            a,
+            b,
+            hull_prefilter: false,
+        }
+    }
+
+    /// Sets whether a convex-hull separation test runs before the triangle
+    /// phase (default `false`).
+    ///
+    /// The hulls are tested on the coordinate axes and on the face normals
+    /// of both hulls. Edge–edge axes are skipped, so a few separated pairs
+    /// still fall through to the triangle phase; the prefilter never
+    /// rejects meshes that cross.
+    #[must_use]
+    pub fn with_hull_prefilter(mut self, hull_prefilter: bool) -> Self {
+        self.hull_prefilter = hull_prefilter;
+        self
+    }
+
+    /// Executes the query, returning a point where a triangle of the first
+    /// mesh crosses one of the second, or `None` if the surfaces do not
+    /// cross.
+    #[must_use]
+    pub fn execute(&self) -> Option<Point3> {
+        self.crossing(&mut 0)
+    }
+
+    /// [`Self::execute`], counting in `pairs` the triangle pairs whose
+    /// bounding boxes the triangle phase compared.
+    fn crossing(&self, pairs: &mut usize) -> Option<Point3> {
+        if self.hull_prefilter && hulls_separated(&self.a.vertices, &self.b.vertices) {
+            return None;
+        }
+
+        let split = self.a.indices.len();
+        let tris: Vec<[Point3; 3]> = triangles(&self.a).chain(triangles(&self.b)).collect();
+        // Only pairs across the two meshes count.
+        crossing_point(&tris, |i, j| (i < split) == (j < split), pairs)
+    }
+}
+
+/// The triangles of `mesh` as vertex positions.
+fn triangles(mesh: &TriangleMesh) -> impl Iterator<Item = [Point3; 3]> + '_ {
+    mesh.indices
+        .iter()
+        .map(|tri| tri.map(|i| mesh.vertices[i as usize]))
+}
+
+/// Returns `true` if some axis separates the convex hulls of `a` and `b`.
+fn hulls_separated(a: &[Point3], b: &[Point3]) -> bool {
+    if a.is_empty() || b.is_empty() {
+        return true;
+    }
+    let extent = |points: &[Point3], axis: &Vector3| {
+        points
+            .iter()
+            .map(|p| p.coords.dot(axis))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), d| {
+                (lo.min(d), hi.max(d))
+            })
+    };
+    [Vector3::x(), Vector3::y(), Vector3::z()]
+        .into_iter()
+        .chain(hull_normals(a).unwrap_or_default())
+        .chain(hull_normals(b).unwrap_or_default())
+        .any(|axis| {
+            let (a_lo, a_hi) = extent(a, &axis);
+            let (b_lo, b_hi) = extent(b, &axis);
+            a_hi < b_lo || b_hi < a_lo
+        })
+}
+
+/// Outward unit face normals of the convex hull of `points`, built
+/// incrementally from an initial tetrahedron of extreme points, or `None`
+/// when the points span no volume.
+fn hull_normals(points: &[Point3]) -> Option<Vec<Vector3>> {
+    let first = points.first()?;
+    let (lo, hi) = points
+        .iter()
+        .fold((*first, *first), |(lo, hi), p| (lo.inf(p), hi.sup(p)));
+    let eps = HULL_EPS * (hi - lo).norm();
+    let farthest = |key: &dyn Fn(&Point3) -> f64| {
+        (0..points.len()).max_by(|&i, &j| key(&points[i]).total_cmp(&key(&points[j])))
+    };
+
+    let i0 = farthest(&|p| -p.x)?;
+    let p0 = points[i0];
+    let i1 = farthest(&|p| (p - p0).norm())?;
+    let axis = points[i1] - p0;
+    if axis.norm() <= eps {
+        return None;
+    }
+    let i2 = farthest(&|p| (p - p0).cross(&axis).norm())?;
+    let normal = axis.cross(&(points[i2] - p0));
+    if normal.norm() <= eps * axis.norm() {
+        return None;
+    }
+    let i3 = farthest(&|p| (p - p0).dot(&normal).abs())?;
+    if (points[i3] - p0).dot(&normal).abs() <= eps * normal.norm() {
+        return None;
+    }
+
+    let face_normal =
+        |[a, b, c]: [usize; 3]| (points[b] - points[a]).cross(&(points[c] - points[a]));
+    let above = |face: [usize; 3], p: &Point3| {
+        let n = face_normal(face);
+        (p - points[face[0]]).dot(&n) > eps * n.norm()
+    };
+    let tet = [i0, i1, i2, i3];
+    let mut faces: Vec<[usize; 3]> = (0..4)
+        .map(|k| {
+            let face = [tet[k], tet[(k + 1) % 4], tet[(k + 2) % 4]];
+            if above(face, &points[tet[(k + 3) % 4]]) {
+                [face[0], face[2], face[1]]
+            } else {
+                face
+            }
+        })
+        .collect();
+
+    for (idx, p) in points.iter().enumerate() {
+        let (visible, kept): (Vec<[usize; 3]>, Vec<[usize; 3]>) =
+            faces.iter().partition(|&&face| above(face, p));
+        if visible.is_empty() {
+            continue;
+        }
+        let edges: Vec<(usize, usize)> = visible
+            .iter()
+            .flat_map(|f| [(f[0], f[1]), (f[1], f[2]), (f[2], f[0])])
+            .collect();
+        let lookup: HashSet<(usize, usize)> = edges.iter().copied().collect();
+        faces = kept;
+        faces.extend(
+            edges
+                .into_iter()
+                .filter(|&(a, b)| !lookup.contains(&(b, a)))
+                .map(|(a, b)| [a, b, idx]),
+        );
+    }
+
+    Some(
+        faces
+            .into_iter()
+            .filter_map(|face| face_normal(face).try_normalize(0.0))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::Matrix4;
+    use crate::operations::creation::MakeBox;
+    use crate::tessellation::{TessellateSolid, TessellationParams};
+    use crate::topology::TopologyStore;
+
+    fn box_mesh(min: Point3, max: Point3) -> TriangleMesh {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(min, max).execute(&mut store).unwrap();
+        TessellateSolid::new(solid, TessellationParams::default())
+            .execute(&store)
+            .unwrap()
+    }
+
+    #[test]
+    fn hull_prefilter_rejects_distant_cubes() {
+        let unit = box_mesh(Point3::origin(), Point3::new(1.0, 1.0, 1.0));
+        // Overlaps the unit cube in x, so the sweep pairs their triangles.
+        let far = box_mesh(Point3::new(0.5, 3.0, 0.0), Point3::new(1.5, 4.0, 1.0));
+
+        let mut pairs = 0;
+        let plain = MeshMeshIntersect::new(unit.clone(), far.clone());
+        assert!(plain.crossing(&mut pairs).is_none());
+        assert!(pairs > 0);
+
+        let mut pairs = 0;
+        let filtered = MeshMeshIntersect::new(unit.clone(), far).with_hull_prefilter(true);
+        assert!(filtered.crossing(&mut pairs).is_none());
+        assert_eq!(pairs, 0);
+
+        // A cube turned 45° about z whose bounding box overlaps the unit
+        // cube's is still separated by one of its own face normals.
+        let mut turned = box_mesh(Point3::new(-0.5, -0.5, 0.0), Point3::new(0.5, 0.5, 1.0));
+        turned.transform(
+            &(Matrix4::new_translation(&Vector3::new(1.6, 1.6, 0.0))
+                * Matrix4::new_rotation(Vector3::z() * std::f64::consts::FRAC_PI_4)),
+        );
+        let mut pairs = 0;
+        let filtered = MeshMeshIntersect::new(unit.clone(), turned).with_hull_prefilter(true);
+        assert!(filtered.crossing(&mut pairs).is_none());
+        assert_eq!(pairs, 0);
+
+        // Overlapping cubes pass the prefilter and cross.
+        let (lo, hi) = (Point3::new(0.3, 0.6, 0.45), Point3::new(1.3, 1.6, 1.45));
+        let hit = MeshMeshIntersect::new(unit, box_mesh(lo, hi))
+            .with_hull_prefilter(true)
+            .execute()
+            .unwrap();
+        for d in 0..3 {
+            assert!(hit[d] >= lo[d].max(0.0) - 1e-9 && hit[d] <= hi[d].min(1.0) + 1e-9);
+        }
+    }
+}
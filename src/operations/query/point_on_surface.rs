@@ -1,8 +1,10 @@
 use crate::error::Result;
 use crate::geometry::surface::Surface;
-use crate::math::Point3;
+use crate::math::{Point3, TOLERANCE};
 use crate::topology::{FaceId, FaceSurface, TopologyStore};
 
+use super::ClosestPointOnSurface;
+
 /// Evaluates a point on a surface at given parameters.
 pub struct PointOnSurface {
     face: FaceId,
@@ -31,8 +33,23 @@ impl PointOnSurface {
             FaceSurface::Sphere(sphere) => sphere.evaluate(self.u, self.v),
             FaceSurface::Torus(torus) => torus.evaluate(self.u, self.v),
             FaceSurface::Nurbs(nurbs) => nurbs.evaluate(self.u, self.v),
+            FaceSurface::Ruled(ruled) => ruled.evaluate(self.u, self.v),
+            FaceSurface::Extruded(extruded) => extruded.evaluate(self.u, self.v),
         }
     }
+
+    /// Projects `p` onto this query's face surface.
+    ///
+    /// Returns the nearest `(u, v)`, the footpoint, and whether `p` lies
+    /// within `TOLERANCE` of the surface. The stored `(u, v)` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the face is not found or the projection fails.
+    pub fn project(&self, store: &TopologyStore, p: &Point3) -> Result<(f64, f64, Point3, bool)> {
+        let hit = ClosestPointOnSurface::new(self.face, *p).execute(store)?;
+        Ok((hit.u, hit.v, hit.point, hit.distance <= TOLERANCE))
+    }
 }
 
 #[cfg(test)]
@@ -64,4 +81,44 @@ mod tests {
         // on the plane's u_dir/v_dir, but z should always be 0
         assert!(pt.z.abs() < 1e-10);
     }
+
+    fn square_face(store: &mut TopologyStore) -> FaceId {
+        let wire = MakeWire::new(
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(4.0, 0.0, 0.0),
+                Point3::new(4.0, 4.0, 0.0),
+                Point3::new(0.0, 4.0, 0.0),
+            ],
+            true,
+        )
+        .execute(store)
+        .unwrap();
+        MakeFace::new(wire, vec![]).execute(store).unwrap()
+    }
+
+    #[test]
+    fn project_off_plane_returns_orthogonal_footpoint() {
+        let mut store = TopologyStore::new();
+        let face = square_face(&mut store);
+        let query = PointOnSurface::new(face, 0.0, 0.0);
+
+        let (u, v, foot, on) = query.project(&store, &Point3::new(1.0, 3.0, 0.5)).unwrap();
+        assert!((foot - Point3::new(1.0, 3.0, 0.0)).norm() < 1e-10);
+        assert!(!on);
+
+        let back = PointOnSurface::new(face, u, v).execute(&store).unwrap();
+        assert!((back - foot).norm() < 1e-10);
+    }
+
+    #[test]
+    fn project_on_plane_is_on_surface() {
+        let mut store = TopologyStore::new();
+        let face = square_face(&mut store);
+        let (_, _, foot, on) = PointOnSurface::new(face, 0.0, 0.0)
+            .project(&store, &Point3::new(2.5, 1.0, 0.0))
+            .unwrap();
+        assert!((foot - Point3::new(2.5, 1.0, 0.0)).norm() < 1e-10);
+        assert!(on);
+    }
 }
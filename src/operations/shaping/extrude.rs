@@ -8,6 +8,9 @@ use crate::topology::{
 };
 
 /// Extrudes a face along a direction vector to create a solid.
+///
+/// The direction need not be the face normal: an oblique direction sweeps
+/// the profile into a sheared prism whose side faces are parallelograms.
 pub struct Extrude {
     face: FaceId,
     direction: Vector3,
@@ -28,7 +31,8 @@ impl Extrude {
     ///
     /// # Errors
     ///
-    /// Returns [`OperationError::InvalidInput`] if the direction is zero-length.
+    /// Returns [`OperationError::InvalidInput`] if the direction is zero-length
+    /// or parallel to the face plane (zero sweep thickness).
     pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
         // Validate direction is non-zero
         if self.direction.norm() < TOLERANCE {
@@ -46,6 +50,12 @@ impl Extrude {
 
         // Compute Newell normal of the base polygon
         let normal = newell_normal(&base_points)?;
+        if normal.dot(&self.direction).abs() < TOLERANCE * self.direction.norm() {
+            return Err(OperationError::InvalidInput(
+                "extrude direction must not be parallel to the face plane".into(),
+            )
+            .into());
+        }
 
         // Ensure base_points are ordered so their Newell normal aligns with the
         // extrude direction. Then:
@@ -286,6 +296,7 @@ pub(super) fn create_line_edge(
         curve: EdgeCurve::Line(line),
         t_start: 0.0,
         t_end,
+        tessellation_tolerance: None,
     }))
 }
 
@@ -438,6 +449,61 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn direction_in_face_plane_returns_error() {
+        let mut store = TopologyStore::new();
+        let face = make_face(
+            &mut store,
+            vec![p(0.0, 0.0, 0.0), p(1.0, 0.0, 0.0), p(1.0, 1.0, 0.0)],
+        );
+        let result = Extrude::new(face, Vector3::new(1.0, 2.0, 0.0)).execute(&mut store);
+        assert!(result.is_err());
+    }
+
+    // ── Oblique extrusion ──────────────────────────────────────
+
+    #[test]
+    fn oblique_extrusion_shears_square() {
+        let mut store = TopologyStore::new();
+        let base = vec![
+            p(0.0, 0.0, 0.0),
+            p(2.0, 0.0, 0.0),
+            p(2.0, 2.0, 0.0),
+            p(0.0, 2.0, 0.0),
+        ];
+        let face = make_face(&mut store, base.clone());
+        let direction = Vector3::new(1.0, 0.5, 3.0);
+        let solid = Extrude::new(face, direction).execute(&mut store).unwrap();
+
+        let shell = store
+            .shell(store.solid(solid).unwrap().outer_shell)
+            .unwrap();
+        assert_eq!(shell.faces.len(), 6);
+        let mut sides = 0;
+        for &face_id in &shell.faces {
+            let pts = collect_wire_points(&store, store.face(face_id).unwrap().outer_wire).unwrap();
+            assert_eq!(pts.len(), 4);
+            if pts.iter().all(|q| (q.z - 3.0).abs() < 1e-12) {
+                // Top: every base corner translated by `direction`.
+                for b in &base {
+                    let moved = b + direction;
+                    assert!(pts.iter().any(|q| (q - moved).norm() < 1e-12));
+                }
+            } else if pts.iter().any(|q| q.z.abs() > 1e-12) {
+                // Side: opposite edges are equal and parallel.
+                sides += 1;
+                assert!(((pts[1] - pts[0]) - (pts[2] - pts[3])).norm() < 1e-12);
+                assert!(((pts[3] - pts[0]) - (pts[2] - pts[1])).norm() < 1e-12);
+                let rising = (0..4).any(|i| {
+                    let d = pts[(i + 1) % 4] - pts[i];
+                    (d - direction).norm() < 1e-12 || (d + direction).norm() < 1e-12
+                });
+                assert!(rising, "side edges should follow the direction");
+            }
+        }
+        assert_eq!(sides, 4);
+    }
+
     #[test]
     fn face_with_hole_creates_solid() {
         let mut store = TopologyStore::new();
@@ -3,7 +3,7 @@ use std::f64::consts::TAU;
 use crate::error::{OperationError, Result};
 use crate::geometry::curve::{Arc, Circle, Line};
 use crate::geometry::surface::{Cone, Cylinder, Plane};
-use crate::math::{Point3, Vector3, TOLERANCE};
+use crate::math::{orthonormalize, Point3, Vector3, TOLERANCE};
 use crate::operations::creation::{MakeFace, MakeSolid};
 use crate::topology::{
     EdgeCurve, EdgeData, EdgeId, FaceData, FaceId, FaceSurface, OrientedEdge, ShellData, SolidId,
@@ -163,6 +163,7 @@ impl Revolve {
                             curve: EdgeCurve::Circle(c),
                             t_start: 0.0,
                             t_end: TAU,
+                            tessellation_tolerance: None,
                         })),
                         Err(_) => None,
                     }
@@ -278,6 +279,7 @@ impl Revolve {
                             curve: EdgeCurve::Arc(a),
                             t_start: 0.0,
                             t_end: angle,
+                            tessellation_tolerance: None,
                         })),
                         Err(_) => None,
                     }
@@ -398,11 +400,14 @@ fn compute_vertex_info(point: &Point3, axis_origin: &Point3, axis: &Vector3) ->
 
 /// Finds a reference direction perpendicular to the axis, pointing towards the first
 /// off-axis profile vertex.
-fn compute_ref_dir(vert_info: &[VertexInfo], _axis: &Vector3) -> Result<Vector3> {
+fn compute_ref_dir(vert_info: &[VertexInfo], axis: &Vector3) -> Result<Vector3> {
     for vi in vert_info {
         if vi.radius > TOLERANCE {
-            let radial = vi.point - vi.axis_foot;
-            return Ok(radial / vi.radius);
+            // Re-orthogonalize so rounding in the axis foot cannot tilt the
+            // circle frames off the axis plane.
+            if let Some((_, ref_dir)) = orthonormalize(*axis, vi.point - vi.axis_foot) {
+                return Ok(ref_dir);
+            }
         }
     }
     // All vertices on axis: degenerate profile
@@ -442,6 +447,7 @@ fn create_line_edge(
         curve: EdgeCurve::Line(line),
         t_start: 0.0,
         t_end,
+        tessellation_tolerance: None,
     }))
 }
 
@@ -525,6 +531,8 @@ fn create_side_face(
         same_sense,
         trim: None,
         pcurves: Vec::new(),
+        // `ref_dir` points at the profile, so the seam sits at u = 0.
+        u_seam: Some(0.0),
     }))
 }
 
@@ -594,6 +602,8 @@ fn create_partial_side_face(
         same_sense,
         trim: None,
         pcurves: Vec::new(),
+        // `ref_dir` points at the profile, so the seam sits at u = 0.
+        u_seam: Some(0.0),
     }))
 }
 
@@ -949,6 +959,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn revolve_side_uvs_continuous_across_seam() {
+        let mut store = TopologyStore::new();
+        let face = make_face(
+            &mut store,
+            vec![
+                p(2.0, 0.0, 0.0),
+                p(4.0, 0.0, 0.0),
+                p(4.0, 0.0, 3.0),
+                p(2.0, 0.0, 3.0),
+            ],
+        );
+        let solid = Revolve::new(face, Point3::origin(), Vector3::z())
+            .execute(&mut store)
+            .unwrap();
+
+        let shell = store
+            .shell(store.solid(solid).unwrap().outer_shell)
+            .unwrap();
+        let mut checked = 0;
+        for &fid in &shell.faces {
+            if !matches!(store.face(fid).unwrap().surface, FaceSurface::Cylinder(_)) {
+                continue;
+            }
+            let mesh = TessellateFace::new(fid, TessellationParams::default())
+                .execute(&store)
+                .unwrap();
+
+            // One ring of the grid, ordered by texture u.
+            let z0 = mesh.vertices[0].z;
+            let mut ring: Vec<usize> = (0..mesh.vertices.len())
+                .filter(|&i| (mesh.vertices[i].z - z0).abs() < 1e-9)
+                .collect();
+            ring.sort_by(|&a, &b| mesh.uvs[a].x.total_cmp(&mesh.uvs[b].x));
+            assert!(mesh.uvs[ring[0]].x.abs() < 1e-9, "ring starts at u = 0");
+            assert!(
+                (mesh.uvs[ring[ring.len() - 1]].x - 1.0).abs() < 1e-9,
+                "ring ends at u = 1"
+            );
+            // Each u step covers exactly the matching angle: no wrap jump.
+            for w in ring.windows(2) {
+                let (a, b) = (mesh.vertices[w[0]], mesh.vertices[w[1]]);
+                let du = mesh.uvs[w[1]].x - mesh.uvs[w[0]].x;
+                let angle = Vector3::new(a.x, a.y, 0.0).angle(&Vector3::new(b.x, b.y, 0.0));
+                assert!(du > 0.0, "u must increase strictly");
+                assert!(
+                    (angle - du * TAU).abs() < 1e-6,
+                    "u step {du} does not match angle {angle}"
+                );
+            }
+
+            // Texture not mirrored: every triangle keeps a positive UV area
+            // in its (outward-facing) winding order.
+            for tri in &mesh.indices {
+                let [a, b, c] = tri.map(|i| mesh.uvs[i as usize]);
+                let area = (b - a).perp(&(c - a));
+                assert!(area > 0.0, "mirrored UV triangle on face {fid:?}");
+            }
+            checked += 1;
+        }
+        assert_eq!(checked, 2, "inner and outer cylinder walls");
+    }
+
     #[test]
     fn revolve_solid_has_many_triangles() {
         let mut store = TopologyStore::new();
@@ -2,7 +2,8 @@ use std::collections::HashSet;
 
 use crate::error::Result;
 use crate::geometry::curve::Line;
-use crate::math::{Matrix4, Point3, Vector3};
+use crate::math::{transform_point, transform_vector, Matrix4, Point3, Vector3};
+use crate::tessellation::TriangleMesh;
 use crate::topology::{EdgeCurve, SolidId, TopologyStore, VertexId};
 
 /// Applies an arbitrary 4x4 transformation matrix to a solid.
@@ -18,6 +19,29 @@ impl GeneralTransform {
         Self { solid, matrix }
     }
 
+    /// Returns the transform undoing this one on the same solid, or `None`
+    /// when the matrix is singular (e.g. a zero scale).
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        self.matrix.try_inverse().map(|matrix| Self {
+            solid: self.solid,
+            matrix,
+        })
+    }
+
+    /// Maps a point through the matrix.
+    #[must_use]
+    pub fn transform_point(&self, point: Point3) -> Point3 {
+        transform_point(&self.matrix, point)
+    }
+
+    /// Maps a point back through the inverse matrix, or returns `None` when
+    /// the matrix is singular.
+    #[must_use]
+    pub fn inverse_transform_point(&self, point: Point3) -> Option<Point3> {
+        self.inverse().map(|inv| inv.transform_point(point))
+    }
+
     /// Executes the transformation, modifying the solid in-place.
     ///
     /// Transforms all vertex positions using the 4x4 matrix, then rebuilds
@@ -34,7 +58,7 @@ impl GeneralTransform {
         // Transform all vertices
         for &vid in &vertex_ids {
             let vertex = store.vertex_mut(vid)?;
-            vertex.point = transform_point(&self.matrix, &vertex.point);
+            vertex.point = transform_point(&self.matrix, vertex.point);
         }
 
         // Collect all unique edge IDs and rebuild curves
@@ -53,8 +77,8 @@ impl GeneralTransform {
                 }
                 EdgeCurve::Arc(arc) => {
                     // Transform the arc's geometric properties
-                    let center = transform_point(&self.matrix, arc.center());
-                    let normal = transform_direction(&self.matrix, arc.normal());
+                    let center = transform_point(&self.matrix, *arc.center());
+                    let normal = transform_vector(&self.matrix, *arc.normal());
                     let normal_len = normal.norm();
                     let normal = normal / normal_len;
 
@@ -88,7 +112,7 @@ impl GeneralTransform {
                     let new_points = nurbs
                         .control_points()
                         .iter()
-                        .map(|p| transform_point(&self.matrix, p))
+                        .map(|p| transform_point(&self.matrix, *p))
                         .collect();
                     let new_nurbs = crate::geometry::nurbs::NurbsCurve3D::new(
                         new_points,
@@ -130,18 +154,13 @@ impl GeneralTransform {
 
         Ok(())
     }
-}
 
-/// Transforms a point by a 4x4 matrix (homogeneous coordinates).
-fn transform_point(matrix: &Matrix4, point: &Point3) -> Point3 {
-    let v = matrix * nalgebra::Vector4::new(point.x, point.y, point.z, 1.0);
-    Point3::new(v.x, v.y, v.z)
-}
-
-/// Transforms a direction vector by a 4x4 matrix (ignoring translation).
-fn transform_direction(matrix: &Matrix4, dir: &Vector3) -> Vector3 {
-    let v = matrix * nalgebra::Vector4::new(dir.x, dir.y, dir.z, 0.0);
-    Vector3::new(v.x, v.y, v.z)
+    /// Applies the same matrix to a triangle mesh in place.
+    ///
+    /// See [`TriangleMesh::transform`] for how normals and winding follow.
+    pub fn apply_to_mesh(&self, mesh: &mut TriangleMesh) {
+        mesh.transform(&self.matrix);
+    }
 }
 
 /// Collects all unique vertex IDs referenced by a solid.
@@ -321,4 +340,29 @@ mod tests {
             assert!(pt.z >= -1e-10 && pt.z <= 2.0 + 1e-10);
         }
     }
+
+    #[test]
+    fn inverse_round_trips_points() {
+        let mut store = TopologyStore::new();
+        let solid = make_unit_cube(&mut store);
+
+        let matrix = Matrix4::new_translation(&Vector3::new(1.0, -2.0, 3.0))
+            * Matrix4::new_rotation(Vector3::new(0.3, -0.5, 0.9))
+            * Matrix4::new_nonuniform_scaling(&Vector3::new(2.0, 0.5, 3.0));
+        let transform = GeneralTransform::new(solid, matrix);
+        let point = p(0.7, -1.3, 2.9);
+        let mapped = transform.transform_point(point);
+        assert!((mapped - point).norm() > 1.0);
+        let back = transform.inverse_transform_point(mapped).unwrap();
+        assert!((back - point).norm() < 1e-9);
+        let inverse = transform.inverse().unwrap();
+        assert!((inverse.transform_point(mapped) - point).norm() < 1e-9);
+
+        let flat = GeneralTransform::new(
+            solid,
+            Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 0.0, 1.0)),
+        );
+        assert!(flat.inverse().is_none());
+        assert!(flat.inverse_transform_point(point).is_none());
+    }
 }
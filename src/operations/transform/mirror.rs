@@ -1,6 +1,7 @@
 use crate::error::{OperationError, Result};
 use crate::geometry::curve::Line;
-use crate::math::{Point3, Vector3, TOLERANCE};
+use crate::math::{Matrix4, Point3, Vector3, TOLERANCE};
+use crate::tessellation::TriangleMesh;
 use crate::topology::{
     EdgeCurve, EdgeData, FaceData, FaceSurface, OrientedEdge, ShellData, SolidId, TopologyStore,
     VertexData, WireData,
@@ -33,14 +34,7 @@ impl Mirror {
     ///
     /// Returns an error if the plane normal is zero-length.
     pub fn execute(&self, store: &mut TopologyStore) -> Result<SolidId> {
-        let len = self.plane_normal.norm();
-        if len < TOLERANCE {
-            return Err(OperationError::InvalidInput(
-                "mirror plane normal must be non-zero".into(),
-            )
-            .into());
-        }
-        let normal = self.plane_normal / len;
+        let normal = self.unit_normal()?;
 
         // Collect the source solid structure
         let solid = store.solid(self.solid)?;
@@ -56,6 +50,40 @@ impl Mirror {
         crate::operations::creation::MakeSolid::new(new_outer_shell, new_inner_shells)
             .execute(store)
     }
+
+    /// Mirrors a triangle mesh in place.
+    ///
+    /// Reflection reverses handedness, so triangle winding is reversed along
+    /// with the normals, keeping outward-facing triangles outward-facing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plane normal is zero-length.
+    pub fn apply_to_mesh(&self, mesh: &mut TriangleMesh) -> Result<()> {
+        let n = self.unit_normal()?;
+        // Householder reflection `I - 2nnᵀ` about the plane through the origin,
+        // then shifted back by twice the plane's offset along `n`.
+        let mut matrix = Matrix4::identity();
+        matrix
+            .fixed_view_mut::<3, 3>(0, 0)
+            .copy_from(&(nalgebra::Matrix3::identity() - 2.0 * n * n.transpose()));
+        let shift = 2.0 * self.plane_origin.coords.dot(&n) * n;
+        matrix.fixed_view_mut::<3, 1>(0, 3).copy_from(&shift);
+        mesh.transform(&matrix);
+        Ok(())
+    }
+
+    /// The normalized plane normal.
+    fn unit_normal(&self) -> Result<Vector3> {
+        let len = self.plane_normal.norm();
+        if len < TOLERANCE {
+            return Err(OperationError::InvalidInput(
+                "mirror plane normal must be non-zero".into(),
+            )
+            .into());
+        }
+        Ok(self.plane_normal / len)
+    }
 }
 
 /// Reflects a point across a plane defined by origin and unit normal.
@@ -162,6 +190,7 @@ fn mirror_edges(
         let new_start = vertex_map[&edge.start];
         let new_end = vertex_map[&edge.end];
         let curve = edge.curve.clone();
+        let tessellation_tolerance = edge.tessellation_tolerance;
 
         let start_pt = store.vertex(new_start)?.point;
         let end_pt = store.vertex(new_end)?.point;
@@ -177,6 +206,7 @@ fn mirror_edges(
                     curve: EdgeCurve::Line(line),
                     t_start: 0.0,
                     t_end,
+                    tessellation_tolerance,
                 }
             }
             EdgeCurve::Arc(arc) => {
@@ -203,6 +233,7 @@ fn mirror_edges(
                     curve: EdgeCurve::Arc(new_arc),
                     t_start: domain.t_min,
                     t_end: domain.t_max,
+                    tessellation_tolerance,
                 }
             }
             EdgeCurve::Circle(_) | EdgeCurve::Ellipse(_) => {
@@ -228,6 +259,7 @@ fn mirror_edges(
                     curve: EdgeCurve::Nurbs(new_nurbs),
                     t_start: edge.t_start,
                     t_end: edge.t_end,
+                    tessellation_tolerance,
                 }
             }
         };
@@ -277,6 +309,7 @@ fn build_mirrored_faces(
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         });
         new_faces.push(new_face_id);
     }
@@ -371,6 +404,51 @@ mod tests {
         Point3::new(x, y, z)
     }
 
+    #[test]
+    fn apply_to_mesh_reverses_winding_keeping_normals_outward() {
+        use crate::operations::creation::MakeBox;
+        use crate::tessellation::{TessellateSolid, TessellationParams};
+
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(p(0.0, 0.0, 0.0), p(1.0, 2.0, 3.0))
+            .execute(&mut store)
+            .unwrap();
+        let original = TessellateSolid::new(solid, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+
+        let mut mesh = original.clone();
+        Mirror::new(solid, p(3.0, 0.0, 0.0), Vector3::new(2.0, 0.0, 0.0))
+            .apply_to_mesh(&mut mesh)
+            .unwrap();
+
+        for (a, b) in original.vertices.iter().zip(&mesh.vertices) {
+            assert!((b - p(6.0 - a.x, a.y, a.z)).norm() < 1e-12);
+        }
+        for (tri, orig) in mesh.indices.iter().zip(&original.indices) {
+            assert_eq!(*tri, [orig[0], orig[2], orig[1]], "winding reversed");
+            let [a, b, c] = tri.map(|i| mesh.vertices[i as usize]);
+            let winding_normal = (b - a).cross(&(c - a));
+            let stored = mesh.normals[tri[0] as usize];
+            assert!(
+                winding_normal.dot(&stored) > 0.0,
+                "winding must agree with the mirrored normal"
+            );
+            // Mirrored box spans x in [5, 6]: normals point away from its center.
+            let centroid = (a.coords + b.coords + c.coords) / 3.0;
+            let outward = centroid - Vector3::new(5.5, 1.0, 1.5);
+            assert!(stored.dot(&outward) > 0.0, "normal must face outward");
+        }
+    }
+
+    #[test]
+    fn apply_to_mesh_zero_normal_returns_error() {
+        let mut mesh = TriangleMesh::default();
+        let result = Mirror::new(SolidId::default(), p(0.0, 0.0, 0.0), Vector3::zeros())
+            .apply_to_mesh(&mut mesh);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn mirror_across_yz_plane() {
         let mut store = TopologyStore::new();
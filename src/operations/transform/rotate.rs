@@ -1,6 +1,7 @@
 use crate::error::{OperationError, Result};
 use crate::math::{Matrix4, Point3, Vector3, TOLERANCE};
-use crate::topology::{SolidId, TopologyStore};
+use crate::tessellation::TriangleMesh;
+use crate::topology::{EdgeCurve, EdgeId, SolidId, TopologyStore};
 
 use super::GeneralTransform;
 
@@ -26,12 +27,54 @@ impl Rotate {
         }
     }
 
+    /// Creates a `Rotate` operation about the line through a linear edge,
+    /// directed from its start vertex to its end vertex.
+    ///
+    /// * `angle` - Rotation angle in radians, counter-clockwise looking
+    ///   down the edge direction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the edge or its vertices cannot be found, or
+    /// `OperationError::InvalidInput` if the edge is not a line.
+    pub fn about_edge(
+        store: &TopologyStore,
+        solid: SolidId,
+        edge: EdgeId,
+        angle: f64,
+    ) -> Result<Self> {
+        let data = store.edge(edge)?;
+        if !matches!(data.curve, EdgeCurve::Line(_)) {
+            return Err(
+                OperationError::InvalidInput("rotation axis edge must be a line".into()).into(),
+            );
+        }
+        let start = store.vertex(data.start)?.point;
+        let end = store.vertex(data.end)?.point;
+        Ok(Self::new(solid, start, end - start, angle))
+    }
+
     /// Executes the rotation, modifying the solid in-place.
     ///
     /// # Errors
     ///
     /// Returns an error if the axis direction is zero-length.
     pub fn execute(&self, store: &mut TopologyStore) -> Result<()> {
+        GeneralTransform::new(self.solid, self.matrix()?).execute(store)
+    }
+
+    /// Applies the same rotation to a triangle mesh in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the axis direction is zero-length.
+    pub fn apply_to_mesh(&self, mesh: &mut TriangleMesh) -> Result<()> {
+        mesh.transform(&self.matrix()?);
+        Ok(())
+    }
+
+    /// The homogeneous rotation matrix about the (offset) axis.
+    fn matrix(&self) -> Result<Matrix4> {
         let len = self.axis_direction.norm();
         if len < TOLERANCE {
             return Err(
@@ -44,9 +87,7 @@ impl Rotate {
         let t_neg = Matrix4::new_translation(&(-self.axis_origin.coords));
         let rot = rotation_matrix(&axis, self.angle);
         let t_pos = Matrix4::new_translation(&self.axis_origin.coords);
-        let matrix = t_pos * rot * t_neg;
-
-        GeneralTransform::new(self.solid, matrix).execute(store)
+        Ok(t_pos * rot * t_neg)
     }
 }
 
@@ -85,7 +126,8 @@ mod tests {
     use std::f64::consts::FRAC_PI_2;
 
     use super::*;
-    use crate::operations::creation::{MakeFace, MakeWire};
+    use crate::operations::creation::{MakeBox, MakeFace, MakeWire};
+    use crate::operations::query::BoundingBox;
     use crate::operations::shaping::Extrude;
     use crate::topology::TopologyStore;
 
@@ -164,4 +206,72 @@ mod tests {
             .execute(&mut store);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn rotate_box_about_vertical_edge() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(p(0.0, 0.0, 0.0), p(2.0, 1.0, 1.0))
+            .execute(&mut store)
+            .unwrap();
+
+        // The vertical edge along the Z axis.
+        let shell = store
+            .shell(store.solid(solid).unwrap().outer_shell)
+            .unwrap();
+        let edge = shell
+            .faces
+            .iter()
+            .flat_map(|&f| {
+                store
+                    .wire(store.face(f).unwrap().outer_wire)
+                    .unwrap()
+                    .edges
+                    .clone()
+            })
+            .map(|oe| oe.edge)
+            .find(|&e| {
+                let data = store.edge(e).unwrap();
+                let a = store.vertex(data.start).unwrap().point;
+                let b = store.vertex(data.end).unwrap().point;
+                a.xy().coords.norm() < 1e-12 && b.xy().coords.norm() < 1e-12
+            })
+            .unwrap();
+        let data = store.edge(edge).unwrap();
+        let up =
+            store.vertex(data.end).unwrap().point.z > store.vertex(data.start).unwrap().point.z;
+
+        // A quarter turn counter-clockwise about +Z.
+        let angle = if up { FRAC_PI_2 } else { -FRAC_PI_2 };
+        Rotate::about_edge(&store, solid, edge, angle)
+            .unwrap()
+            .execute(&mut store)
+            .unwrap();
+
+        // The face opposite the edge (x = 2) now lies on y = 2.
+        let bb = BoundingBox::new(solid).execute(&store).unwrap();
+        assert!((bb.min - p(-1.0, 0.0, 0.0)).norm() < 1e-9, "{bb:?}");
+        assert!((bb.max - p(0.0, 2.0, 1.0)).norm() < 1e-9, "{bb:?}");
+    }
+
+    #[test]
+    fn about_edge_rejects_curved_edge() {
+        use crate::geometry::curve::Circle;
+        use crate::topology::{EdgeData, VertexData};
+
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(p(0.0, 0.0, 0.0), p(1.0, 1.0, 1.0))
+            .execute(&mut store)
+            .unwrap();
+        let v = store.add_vertex(VertexData::new(p(1.0, 0.0, 0.0)));
+        let circle = Circle::new(p(0.0, 0.0, 0.0), 1.0, Vector3::z(), Vector3::x()).unwrap();
+        let edge = store.add_edge(EdgeData {
+            start: v,
+            end: v,
+            curve: EdgeCurve::Circle(circle),
+            t_start: 0.0,
+            t_end: std::f64::consts::TAU,
+            tessellation_tolerance: None,
+        });
+        assert!(Rotate::about_edge(&store, solid, edge, 1.0).is_err());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::error::{OperationError, Result};
 use crate::math::{Matrix4, Point3, TOLERANCE};
+use crate::tessellation::TriangleMesh;
 use crate::topology::{SolidId, TopologyStore};
 
 use super::GeneralTransform;
@@ -28,6 +29,21 @@ impl Scale {
     ///
     /// Returns an error if the scale factor is near zero.
     pub fn execute(&self, store: &mut TopologyStore) -> Result<()> {
+        GeneralTransform::new(self.solid, self.matrix()?).execute(store)
+    }
+
+    /// Applies the same scaling to a triangle mesh in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scale factor is near zero.
+    pub fn apply_to_mesh(&self, mesh: &mut TriangleMesh) -> Result<()> {
+        mesh.transform(&self.matrix()?);
+        Ok(())
+    }
+
+    /// The homogeneous scaling matrix about `center`.
+    fn matrix(&self) -> Result<Matrix4> {
         if self.factor.abs() < TOLERANCE {
             return Err(
                 OperationError::InvalidInput("scale factor must be non-zero".into()).into(),
@@ -38,9 +54,7 @@ impl Scale {
         let t_neg = Matrix4::new_translation(&(-self.center.coords));
         let s = Matrix4::new_scaling(self.factor);
         let t_pos = Matrix4::new_translation(&self.center.coords);
-        let matrix = t_pos * s * t_neg;
-
-        GeneralTransform::new(self.solid, matrix).execute(store)
+        Ok(t_pos * s * t_neg)
     }
 }
 
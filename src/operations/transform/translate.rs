@@ -1,5 +1,6 @@
 use crate::error::Result;
 use crate::math::{Matrix4, Vector3};
+use crate::tessellation::TriangleMesh;
 use crate::topology::{SolidId, TopologyStore};
 
 use super::GeneralTransform;
@@ -26,11 +27,21 @@ impl Translate {
     ///
     /// Returns an error if the underlying transform fails.
     pub fn execute(&self, store: &mut TopologyStore) -> Result<()> {
+        GeneralTransform::new(self.solid, self.matrix()).execute(store)
+    }
+
+    /// Applies the same translation to a triangle mesh in place.
+    pub fn apply_to_mesh(&self, mesh: &mut TriangleMesh) {
+        mesh.transform(&self.matrix());
+    }
+
+    /// The homogeneous translation matrix.
+    fn matrix(&self) -> Matrix4 {
         let mut matrix = Matrix4::identity();
         matrix[(0, 3)] = self.displacement.x;
         matrix[(1, 3)] = self.displacement.y;
         matrix[(2, 3)] = self.displacement.z;
-        GeneralTransform::new(self.solid, matrix).execute(store)
+        matrix
     }
 }
 
@@ -86,6 +97,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn apply_to_mesh_shifts_vertices() {
+        let mut mesh = TriangleMesh {
+            vertices: vec![p(0.0, 0.0, 0.0), p(1.0, 0.0, 0.0), p(0.0, 1.0, 0.0)],
+            normals: vec![Vector3::z(); 3],
+            uvs: Vec::new(),
+            indices: vec![[0, 1, 2]],
+        };
+        Translate::new(
+            crate::topology::SolidId::default(),
+            Vector3::new(1.0, 2.0, 3.0),
+        )
+        .apply_to_mesh(&mut mesh);
+        assert!((mesh.vertices[1] - p(2.0, 2.0, 3.0)).norm() < 1e-12);
+        assert!((mesh.normals[0] - Vector3::z()).norm() < 1e-12);
+    }
+
     #[test]
     fn cloned_store_is_independent_of_the_original() {
         let mut store = TopologyStore::new();
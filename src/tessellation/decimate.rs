@@ -0,0 +1,439 @@
+//! Quadric-error edge-collapse decimation of triangle meshes.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use nalgebra::Vector4;
+
+use crate::math::{Matrix4, Point3, TOLERANCE};
+
+use super::TriangleMesh;
+
+/// Collapses whose quadric error exceeds `(MAX_ERROR_FRACTION * d)²`, `d`
+/// the bounding-box diagonal, are never performed.
+const MAX_ERROR_FRACTION: f64 = 0.05;
+
+/// Minimum cosine between a triangle's normal before and after a collapse;
+/// a collapse turning any triangle further is rejected as a fold.
+const MIN_NORMAL_COS: f64 = 0.2;
+
+/// A candidate half-edge collapse moving `from` onto `to`.
+#[derive(Debug, Clone, Copy)]
+struct Collapse {
+    cost: f64,
+    from: u32,
+    to: u32,
+    /// Endpoint stamps when the candidate was queued; a mismatch marks it
+    /// stale.
+    stamps: (u32, u32),
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collapse {
+    /// Reversed so the max-heap pops the cheapest collapse, ties broken by
+    /// lowest vertex indices.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| (other.from, other.to).cmp(&(self.from, self.to)))
+    }
+}
+
+/// Working state of a decimation.
+struct Decimator<'a> {
+    vertices: &'a [Point3],
+    triangles: Vec<[u32; 3]>,
+    alive: Vec<bool>,
+    vertex_triangles: Vec<Vec<usize>>,
+    quadrics: Vec<Matrix4>,
+    locked: Vec<bool>,
+    stamps: Vec<u32>,
+    heap: BinaryHeap<Collapse>,
+}
+
+impl TriangleMesh {
+    /// Returns a copy reduced toward `target_triangles` triangles by
+    /// quadric-error edge collapses.
+    ///
+    /// The cheapest collapse (Garland–Heckbert quadric error) is applied
+    /// first. Collapses are half-edge: a vertex moves onto a neighbour, so
+    /// every surviving vertex keeps its original position, normal and UV.
+    /// Vertices on boundary edges and vertices sharing their exact position
+    /// with another vertex (UV or normal seams) never move, so boundaries
+    /// and seams are preserved. Collapses that would fold a triangle or
+    /// break manifoldness are skipped, and decimation stops early once the
+    /// cheapest remaining collapse exceeds an error of 5% of the bounding
+    /// box diagonal; the result may therefore keep more triangles than
+    /// requested.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn decimate(&self, target_triangles: usize) -> Self {
+        if self.indices.len() <= target_triangles || self.vertices.is_empty() {
+            return self.clone();
+        }
+        let mut d = Decimator::new(self);
+        let max_error = (MAX_ERROR_FRACTION * bbox_diagonal(&self.vertices)).powi(2);
+
+        let mut live = self.indices.len();
+        while live > target_triangles {
+            let Some(c) = d.heap.pop() else {
+                break;
+            };
+            if c.stamps != (d.stamps[c.from as usize], d.stamps[c.to as usize]) {
+                continue;
+            }
+            if c.cost > max_error {
+                break;
+            }
+            if d.can_collapse(c.from, c.to) {
+                live -= d.collapse(c.from, c.to);
+            }
+        }
+
+        // Compact, keeping surviving vertices in their original order.
+        let mut remap = vec![u32::MAX; self.vertices.len()];
+        for (tri, _) in d.triangles.iter().zip(&d.alive).filter(|(_, &a)| a) {
+            for &v in tri {
+                remap[v as usize] = 0;
+            }
+        }
+        let mut out = Self::default();
+        let has_normals = self.normals.len() == self.vertices.len();
+        let has_uvs = self.uvs.len() == self.vertices.len();
+        for (i, slot) in remap.iter_mut().enumerate() {
+            if *slot == u32::MAX {
+                continue;
+            }
+            *slot = out.vertices.len() as u32;
+            out.vertices.push(self.vertices[i]);
+            if has_normals {
+                out.normals.push(self.normals[i]);
+            }
+            if has_uvs {
+                out.uvs.push(self.uvs[i]);
+            }
+        }
+        out.indices = d
+            .triangles
+            .iter()
+            .zip(&d.alive)
+            .filter(|(_, &a)| a)
+            .map(|(tri, _)| tri.map(|v| remap[v as usize]))
+            .collect();
+        out
+    }
+}
+
+impl<'a> Decimator<'a> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(mesh: &'a TriangleMesh) -> Self {
+        let n = mesh.vertices.len();
+        let mut vertex_triangles = vec![Vec::new(); n];
+        let mut quadrics = vec![Matrix4::zeros(); n];
+        for (t, tri) in mesh.indices.iter().enumerate() {
+            let q = plane_quadric(tri.map(|v| mesh.vertices[v as usize]));
+            for &v in tri {
+                vertex_triangles[v as usize].push(t);
+                quadrics[v as usize] += q;
+            }
+        }
+
+        let mut locked = vec![false; n];
+        for (a, b) in mesh.boundary_edges() {
+            locked[a as usize] = true;
+            locked[b as usize] = true;
+        }
+        let mut by_position: HashMap<[u64; 3], u32> = HashMap::new();
+        for (i, p) in mesh.vertices.iter().enumerate() {
+            let key = [p.x.to_bits(), p.y.to_bits(), p.z.to_bits()];
+            if let Some(&other) = by_position.get(&key) {
+                locked[i] = true;
+                locked[other as usize] = true;
+            } else {
+                by_position.insert(key, i as u32);
+            }
+        }
+
+        let mut d = Self {
+            vertices: &mesh.vertices,
+            triangles: mesh.indices.clone(),
+            alive: vec![true; mesh.indices.len()],
+            vertex_triangles,
+            quadrics,
+            locked,
+            stamps: vec![0; n],
+            heap: BinaryHeap::new(),
+        };
+        let mut edges = HashSet::new();
+        for &[a, b, c] in &mesh.indices {
+            for (p, q) in [(a, b), (b, c), (c, a)] {
+                if edges.insert((p.min(q), p.max(q))) {
+                    d.queue_edge(p.min(q), p.max(q));
+                }
+            }
+        }
+        d
+    }
+
+    /// Queues the cheaper allowed direction of collapsing edge `a`–`b`.
+    #[allow(clippy::many_single_char_names)]
+    fn queue_edge(&mut self, a: u32, b: u32) {
+        let q = self.quadrics[a as usize] + self.quadrics[b as usize];
+        let cost_onto = |v: u32| {
+            let p = self.vertices[v as usize];
+            let h = Vector4::new(p.x, p.y, p.z, 1.0);
+            h.dot(&(q * h))
+        };
+        let mut best: Option<(f64, u32, u32)> = None;
+        for (from, to) in [(a, b), (b, a)] {
+            if self.locked[from as usize] {
+                continue;
+            }
+            let cost = cost_onto(to);
+            if best.is_none_or(|(c, _, _)| cost < c) {
+                best = Some((cost, from, to));
+            }
+        }
+        if let Some((cost, from, to)) = best {
+            self.heap.push(Collapse {
+                cost,
+                from,
+                to,
+                stamps: (self.stamps[from as usize], self.stamps[to as usize]),
+            });
+        }
+    }
+
+    fn live_triangles(&self, v: u32) -> impl Iterator<Item = usize> + '_ {
+        self.vertex_triangles[v as usize]
+            .iter()
+            .copied()
+            .filter(|&t| self.alive[t])
+    }
+
+    fn neighbours(&self, v: u32) -> HashSet<u32> {
+        self.live_triangles(v)
+            .flat_map(|t| self.triangles[t])
+            .filter(|&u| u != v)
+            .collect()
+    }
+
+    /// Whether moving `from` onto `to` keeps the mesh manifold and unfolded.
+    fn can_collapse(&self, from: u32, to: u32) -> bool {
+        let shared = self
+            .live_triangles(from)
+            .filter(|&t| self.triangles[t].contains(&to))
+            .count();
+        if shared != 2 {
+            return false;
+        }
+        // Link condition: the edge's two opposite vertices are the only
+        // common neighbours.
+        let common = self
+            .neighbours(from)
+            .intersection(&self.neighbours(to))
+            .count();
+        if common != 2 {
+            return false;
+        }
+        let target = self.vertices[to as usize];
+        self.live_triangles(from)
+            .filter(|&t| !self.triangles[t].contains(&to))
+            .all(|t| {
+                let tri = self.triangles[t];
+                let before = tri.map(|v| self.vertices[v as usize]);
+                let after = tri.map(|v| {
+                    if v == from {
+                        target
+                    } else {
+                        self.vertices[v as usize]
+                    }
+                });
+                let n0 = (before[1] - before[0]).cross(&(before[2] - before[0]));
+                let n1 = (after[1] - after[0]).cross(&(after[2] - after[0]));
+                n1.norm() > TOLERANCE * TOLERANCE
+                    && n0.dot(&n1) >= MIN_NORMAL_COS * n0.norm() * n1.norm()
+            })
+    }
+
+    /// Moves `from` onto `to`, returning the number of triangles removed.
+    fn collapse(&mut self, from: u32, to: u32) -> usize {
+        let mut removed = 0;
+        for t in std::mem::take(&mut self.vertex_triangles[from as usize]) {
+            if !self.alive[t] {
+                continue;
+            }
+            let tri = &mut self.triangles[t];
+            if tri.contains(&to) {
+                self.alive[t] = false;
+                removed += 1;
+            } else {
+                for v in tri.iter_mut().filter(|v| **v == from) {
+                    *v = to;
+                }
+                self.vertex_triangles[to as usize].push(t);
+            }
+        }
+        let q = self.quadrics[from as usize];
+        self.quadrics[to as usize] += q;
+        self.stamps[from as usize] += 1;
+        self.stamps[to as usize] += 1;
+        let mut neighbours: Vec<u32> = self.neighbours(to).into_iter().collect();
+        neighbours.sort_unstable();
+        for n in neighbours {
+            self.queue_edge(to.min(n), to.max(n));
+        }
+        removed
+    }
+}
+
+/// Fundamental error quadric of a triangle's plane; zero for a degenerate
+/// triangle.
+#[allow(clippy::many_single_char_names)]
+fn plane_quadric([a, b, c]: [Point3; 3]) -> Matrix4 {
+    let Some(n) = (b - a).cross(&(c - a)).try_normalize(TOLERANCE * TOLERANCE) else {
+        return Matrix4::zeros();
+    };
+    let p = Vector4::new(n.x, n.y, n.z, -n.dot(&a.coords));
+    p * p.transpose()
+}
+
+fn bbox_diagonal(points: &[Point3]) -> f64 {
+    let (mut min, mut max) = (points[0], points[0]);
+    for p in points {
+        min = min.inf(p);
+        max = max.sup(p);
+    }
+    (max - min).norm()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::{Point2, Vector3};
+
+    /// Octahedron subdivided three times and projected onto the unit
+    /// sphere: 512 triangles, welded and closed.
+    fn unit_sphere() -> TriangleMesh {
+        let axes = [
+            Vector3::x(),
+            Vector3::y(),
+            -Vector3::x(),
+            -Vector3::y(),
+            Vector3::z(),
+            -Vector3::z(),
+        ];
+        let mut mesh = TriangleMesh {
+            vertices: axes.iter().map(|a| Point3::from(*a)).collect(),
+            normals: axes.to_vec(),
+            uvs: Vec::new(),
+            indices: vec![
+                [0, 1, 4],
+                [1, 2, 4],
+                [2, 3, 4],
+                [3, 0, 4],
+                [1, 0, 5],
+                [2, 1, 5],
+                [3, 2, 5],
+                [0, 3, 5],
+            ],
+        };
+        for _ in 0..3 {
+            mesh = mesh.subdivide_midpoint();
+        }
+        for (p, n) in mesh.vertices.iter_mut().zip(&mut mesh.normals) {
+            *n = p.coords.normalize();
+            *p = Point3::from(*n);
+        }
+        mesh
+    }
+
+    /// Largest distance from the unit sphere to a triangle's edge
+    /// midpoints and centroid.
+    fn max_sag(mesh: &TriangleMesh) -> f64 {
+        mesh.indices
+            .iter()
+            .flat_map(|tri| {
+                let [a, b, c] = tri.map(|i| mesh.vertices[i as usize].coords);
+                [
+                    (a + b) / 2.0,
+                    (b + c) / 2.0,
+                    (c + a) / 2.0,
+                    (a + b + c) / 3.0,
+                ]
+            })
+            .map(|p| 1.0 - p.norm())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn sphere_halves_with_bounded_deviation() {
+        let sphere = unit_sphere();
+        assert_eq!(sphere.indices.len(), 512);
+        let reduced = sphere.decimate(256);
+        assert!(
+            (240..=256).contains(&reduced.indices.len()),
+            "{} triangles",
+            reduced.indices.len()
+        );
+        reduced.validate().unwrap();
+        assert!(reduced.boundary_edges().is_empty());
+        // Surviving vertices stay on the sphere; triangle interiors sag
+        // less than a tenth of the radius inside it.
+        assert!(max_sag(&sphere) < 0.02);
+        let sag = max_sag(&reduced);
+        assert!(sag < 0.1, "sag {sag}");
+        let volume = |m: &TriangleMesh| m.signed_volume(Point3::origin());
+        assert!((volume(&reduced) - volume(&sphere)).abs() < 0.05 * volume(&sphere));
+    }
+
+    #[test]
+    fn boundary_of_flat_grid_is_preserved() {
+        // A 4 × 4 grid of unit quads in the XY plane.
+        let mut grid = TriangleMesh::default();
+        for j in 0..5 {
+            for i in 0..5 {
+                grid.vertices
+                    .push(Point3::new(f64::from(i), f64::from(j), 0.0));
+                grid.normals.push(Vector3::z());
+                grid.uvs.push(Point2::new(f64::from(i), f64::from(j)));
+            }
+        }
+        for j in 0..4 {
+            for i in 0..4 {
+                let v = j * 5 + i;
+                grid.indices.push([v, v + 1, v + 6]);
+                grid.indices.push([v, v + 6, v + 5]);
+            }
+        }
+        let reduced = grid.decimate(1);
+        // Only the 9 interior vertices can go; the 16 on the boundary stay.
+        assert_eq!(reduced.vertices.len(), 16);
+        assert_eq!(reduced.indices.len(), 14);
+        assert_eq!(reduced.boundary_edges().len(), 16);
+        let area: f64 = reduced
+            .indices
+            .iter()
+            .map(|tri| {
+                let [a, b, c] = tri.map(|i| reduced.vertices[i as usize]);
+                (b - a).cross(&(c - a)).z / 2.0
+            })
+            .sum();
+        assert!((area - 16.0).abs() < 1e-12);
+    }
+}
@@ -15,7 +15,8 @@
 //!   preserved bit-for-bit.
 //! - Line: the two endpoints.
 //! - Arc / Circle / Ellipse: the sagitta-bounded segment count previously
-//!   local to the planar face path.
+//!   local to the planar face path, at the edge's own tessellation tolerance
+//!   when it sets one.
 
 use std::collections::HashMap;
 
@@ -79,6 +80,7 @@ fn sample_edge(
 ) -> Result<EdgeSamples> {
     let data = store.edge(edge)?;
     let (t_start, t_end) = (data.t_start, data.t_end);
+    let params = &params.for_edge(data);
 
     let ts: Vec<f64> = match &data.curve {
         EdgeCurve::Line(_) => vec![t_start, t_end],
@@ -213,6 +215,7 @@ mod tests {
             curve,
             t_start,
             t_end,
+            tessellation_tolerance: None,
         });
         (store, edge)
     }
@@ -0,0 +1,4 @@
+//! Mesh file writers.
+
+pub mod ply;
+pub mod stl;
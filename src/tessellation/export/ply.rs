@@ -0,0 +1,157 @@
+//! Stanford PLY writer for triangle meshes.
+
+use std::io::{self, Write};
+
+use crate::tessellation::TriangleMesh;
+
+/// Writes `mesh` as a PLY file.
+///
+/// Vertices carry `x/y/z` as `float`, plus `nx/ny/nz` when the mesh has
+/// one normal per vertex and `red/green/blue` as `uchar` when `colors` is
+/// given. Faces are `list uchar uint vertex_indices`. `binary` selects
+/// `binary_little_endian 1.0` instead of `ascii 1.0`.
+///
+/// # Errors
+///
+/// Returns [`io::ErrorKind::InvalidInput`] if `colors` does not have one
+/// entry per vertex, and propagates any error from `w`.
+#[allow(clippy::cast_possible_truncation)]
+pub fn write_ply<W: Write>(
+    mesh: &TriangleMesh,
+    colors: Option<&[[u8; 3]]>,
+    w: &mut W,
+    binary: bool,
+) -> io::Result<()> {
+    let n = mesh.vertices.len();
+    if let Some(colors) = colors {
+        if colors.len() != n {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} colors given for {n} vertices", colors.len()),
+            ));
+        }
+    }
+    let normals = (mesh.normals.len() == n && n > 0).then_some(mesh.normals.as_slice());
+
+    writeln!(w, "ply")?;
+    if binary {
+        writeln!(w, "format binary_little_endian 1.0")?;
+    } else {
+        writeln!(w, "format ascii 1.0")?;
+    }
+    writeln!(w, "element vertex {n}")?;
+    for axis in ["x", "y", "z"] {
+        writeln!(w, "property float {axis}")?;
+    }
+    if normals.is_some() {
+        for axis in ["nx", "ny", "nz"] {
+            writeln!(w, "property float {axis}")?;
+        }
+    }
+    if colors.is_some() {
+        for channel in ["red", "green", "blue"] {
+            writeln!(w, "property uchar {channel}")?;
+        }
+    }
+    writeln!(w, "element face {}", mesh.indices.len())?;
+    writeln!(w, "property list uchar uint vertex_indices")?;
+    writeln!(w, "end_header")?;
+
+    for (i, p) in mesh.vertices.iter().enumerate() {
+        let mut floats = vec![p.x as f32, p.y as f32, p.z as f32];
+        if let Some(normals) = normals {
+            let nv = normals[i];
+            floats.extend([nv.x as f32, nv.y as f32, nv.z as f32]);
+        }
+        let color = colors.map(|c| c[i]);
+        if binary {
+            for f in &floats {
+                w.write_all(&f.to_le_bytes())?;
+            }
+            if let Some(c) = color {
+                w.write_all(&c)?;
+            }
+        } else {
+            let mut fields: Vec<String> = floats.iter().map(ToString::to_string).collect();
+            if let Some(c) = color {
+                fields.extend(c.iter().map(ToString::to_string));
+            }
+            writeln!(w, "{}", fields.join(" "))?;
+        }
+    }
+
+    for tri in &mesh.indices {
+        if binary {
+            w.write_all(&[3])?;
+            for idx in tri {
+                w.write_all(&idx.to_le_bytes())?;
+            }
+        } else {
+            writeln!(w, "3 {} {} {}", tri[0], tri[1], tri[2])?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::{Point2, Point3, Vector3};
+
+    fn triangle() -> TriangleMesh {
+        TriangleMesh {
+            vertices: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            normals: vec![Vector3::z(); 3],
+            uvs: vec![Point2::origin(); 3],
+            indices: vec![[0, 1, 2]],
+        }
+    }
+
+    const COLORS: [[u8; 3]; 3] = [[255, 0, 0], [0, 255, 0], [0, 0, 255]];
+
+    #[test]
+    fn ascii_colored_triangle_header_and_body() {
+        let mut out = Vec::new();
+        write_ply(&triangle(), Some(&COLORS), &mut out, false).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let (header, body) = text.split_once("end_header\n").unwrap();
+
+        assert!(header.starts_with("ply\nformat ascii 1.0\n"));
+        assert!(header.contains("element vertex 3\n"));
+        assert!(header.contains("element face 1\n"));
+        for channel in ["red", "green", "blue"] {
+            assert!(header.contains(&format!("property uchar {channel}\n")));
+        }
+
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "1 0 0 0 0 1 0 255 0");
+        assert_eq!(lines[3], "3 0 1 2");
+    }
+
+    #[test]
+    fn binary_body_has_expected_size() {
+        let mut out = Vec::new();
+        write_ply(&triangle(), Some(&COLORS), &mut out, true).unwrap();
+        let end = b"end_header\n";
+        let pos = out.windows(end.len()).position(|w| w == end).unwrap() + end.len();
+        let header = std::str::from_utf8(&out[..pos]).unwrap();
+        assert!(header.contains("format binary_little_endian 1.0\n"));
+        // 3 vertices × (6 floats + 3 bytes) + 1 face × (1 + 3 × 4 bytes).
+        assert_eq!(out.len() - pos, 3 * (6 * 4 + 3) + (1 + 3 * 4));
+        let x = f32::from_le_bytes(out[pos + 27..pos + 31].try_into().unwrap());
+        assert!((x - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn mismatched_colors_are_rejected() {
+        let mut out = Vec::new();
+        let err = write_ply(&triangle(), Some(&COLORS[..2]), &mut out, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
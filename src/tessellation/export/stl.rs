@@ -0,0 +1,164 @@
+//! ASCII STL writer, from a finished mesh or streamed straight from a solid.
+
+use std::io::{self, Write};
+
+use crate::error::{Result, TessellationError};
+use crate::math::{Point3, Vector3};
+use crate::tessellation::edge_samples::EdgeSampleCache;
+use crate::tessellation::tessellate_solid::{solid_faces, FaceBudget};
+use crate::tessellation::{TessellateFace, TessellationParams, TriangleMesh};
+use crate::topology::{SolidId, TopologyStore};
+
+/// Name written after `solid` / `endsolid`.
+const SOLID_NAME: &str = "geolis";
+
+/// Writes `mesh` as an ASCII STL file.
+///
+/// Facet normals are computed from each triangle's winding; degenerate
+/// triangles get a zero normal.
+///
+/// # Errors
+///
+/// Propagates any error from `w`.
+pub fn write_mesh<W: Write>(mesh: &TriangleMesh, w: &mut W) -> io::Result<()> {
+    writeln!(w, "solid {SOLID_NAME}")?;
+    for tri in &mesh.indices {
+        write_facet(w, tri.map(|i| mesh.vertices[i as usize]))?;
+    }
+    writeln!(w, "endsolid {SOLID_NAME}")
+}
+
+/// Tessellates `solid` face by face and streams the triangles to `w` as
+/// an ASCII STL file, returning the number of triangles written.
+///
+/// Only one face mesh is held at a time, so memory stays bounded by the
+/// largest face rather than the whole solid. The output is identical to
+/// [`write_mesh`] applied to
+/// [`TessellateSolid::execute`](crate::tessellation::TessellateSolid::execute)
+/// with the same `params`, including how an adaptive
+/// [`max_triangles`](TessellationParams::max_triangles) is split across
+/// faces; under that budget the faces that ignore it are meshed twice.
+///
+/// # Errors
+///
+/// Returns an error if the solid or a face cannot be tessellated, or
+/// [`TessellationError::Io`] if writing fails.
+pub fn write_solid<W: Write>(
+    store: &TopologyStore,
+    solid: SolidId,
+    params: TessellationParams,
+    w: &mut W,
+) -> Result<usize> {
+    let faces = solid_faces(store, solid)?;
+    let mut cache = EdgeSampleCache::new(params);
+    // Faces that ignore the budget are meshed once here to size it, then
+    // again in order below, so only one mesh is ever held.
+    let mut budget = FaceBudget::plan(store, &faces, params, &mut cache, |_, _| Ok(()))?;
+    writeln!(w, "solid {SOLID_NAME}").map_err(TessellationError::from)?;
+    let mut count = 0;
+    for &face_id in &faces {
+        let face = TessellateFace::new(face_id, params);
+        let mesh = match budget.as_mut() {
+            Some(budget) if face.follows_triangle_budget(store)? => {
+                let mesh = TessellateFace::new(face_id, budget.next_face_params(params))
+                    .execute_with_cache(store, &mut cache)?;
+                budget.spend(mesh.indices.len());
+                mesh
+            }
+            _ => face.execute_with_cache(store, &mut cache)?,
+        };
+        for tri in &mesh.indices {
+            write_facet(w, tri.map(|i| mesh.vertices[i as usize]))
+                .map_err(TessellationError::from)?;
+        }
+        count += mesh.indices.len();
+    }
+    writeln!(w, "endsolid {SOLID_NAME}").map_err(TessellationError::from)?;
+    Ok(count)
+}
+
+/// Writes one `facet` block.
+#[allow(clippy::cast_possible_truncation, clippy::many_single_char_names)]
+fn write_facet<W: Write>(w: &mut W, [a, b, c]: [Point3; 3]) -> io::Result<()> {
+    let n = (b - a)
+        .cross(&(c - a))
+        .try_normalize(0.0)
+        .unwrap_or_else(Vector3::zeros);
+    writeln!(
+        w,
+        "  facet normal {} {} {}",
+        n.x as f32, n.y as f32, n.z as f32
+    )?;
+    writeln!(w, "    outer loop")?;
+    for p in [a, b, c] {
+        writeln!(
+            w,
+            "      vertex {} {} {}",
+            p.x as f32, p.y as f32, p.z as f32
+        )?;
+    }
+    writeln!(w, "    endloop")?;
+    writeln!(w, "  endfacet")
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::operations::creation::{MakeBox, MakeCylinder};
+    use crate::tessellation::{TessellateSolid, TessellationMode};
+
+    #[test]
+    fn streamed_box_matches_mesh_export() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(1.0, 2.0, 3.0))
+            .execute(&mut store)
+            .unwrap();
+        let params = TessellationParams::default();
+
+        let mut streamed = Vec::new();
+        let count = write_solid(&store, solid, params, &mut streamed).unwrap();
+
+        let mesh = TessellateSolid::new(solid, params).execute(&store).unwrap();
+        let mut buffered = Vec::new();
+        write_mesh(&mesh, &mut buffered).unwrap();
+
+        assert_eq!(count, mesh.indices.len());
+        assert_eq!(count, 12);
+        let text = String::from_utf8(streamed).unwrap();
+        assert_eq!(text.matches("facet normal").count(), count);
+        assert_eq!(text, String::from_utf8(buffered).unwrap());
+    }
+
+    #[test]
+    fn streamed_cylinder_follows_triangle_budget() {
+        let mut store = TopologyStore::new();
+        let solid = MakeCylinder::new(Point3::origin(), 1.0, Vector3::z(), 2.0)
+            .execute(&mut store)
+            .unwrap();
+        let unlimited = TessellationParams {
+            tolerance: 1e-4,
+            mode: TessellationMode::Adaptive,
+            ..TessellationParams::default()
+        };
+        let full = TessellateSolid::new(solid, unlimited)
+            .execute(&store)
+            .unwrap();
+        let budget = full.indices.len() - 100;
+        let params = TessellationParams {
+            max_triangles: Some(budget),
+            ..unlimited
+        };
+
+        let mut streamed = Vec::new();
+        let count = write_solid(&store, solid, params, &mut streamed).unwrap();
+
+        let mesh = TessellateSolid::new(solid, params).execute(&store).unwrap();
+        let mut buffered = Vec::new();
+        write_mesh(&mesh, &mut buffered).unwrap();
+
+        assert_eq!(count, mesh.indices.len());
+        assert!(count <= budget, "{count} triangles");
+        assert_eq!(streamed, buffered);
+    }
+}
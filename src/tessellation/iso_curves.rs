@@ -0,0 +1,111 @@
+//! Iso-parametric curves of a surface, for drawing surface wireframes.
+
+use crate::error::{Result, TessellationError};
+use crate::geometry::surface::Surface;
+use crate::math::Point3;
+
+use super::Polyline;
+
+/// Samples the curve of constant `v` on `surface`, with `u` running over
+/// `u_span` (or the surface's U domain when `None`) in `segments` equal
+/// steps.
+///
+/// # Errors
+///
+/// Returns [`TessellationError::InvalidParameters`] if `segments` is zero
+/// or the span is unbounded, or propagates surface evaluation errors.
+pub fn iso_u(
+    surface: &dyn Surface,
+    v: f64,
+    u_span: Option<(f64, f64)>,
+    segments: u32,
+) -> Result<Polyline> {
+    let d = surface.domain();
+    let (u0, u1) = u_span.unwrap_or((d.u_min, d.u_max));
+    sample(u0, u1, segments, |u| surface.evaluate(u, v))
+}
+
+/// Samples the curve of constant `u` on `surface`, with `v` running over
+/// `v_span` (or the surface's V domain when `None`) in `segments` equal
+/// steps.
+///
+/// # Errors
+///
+/// Returns [`TessellationError::InvalidParameters`] if `segments` is zero
+/// or the span is unbounded, or propagates surface evaluation errors.
+pub fn iso_v(
+    surface: &dyn Surface,
+    u: f64,
+    v_span: Option<(f64, f64)>,
+    segments: u32,
+) -> Result<Polyline> {
+    let d = surface.domain();
+    let (v0, v1) = v_span.unwrap_or((d.v_min, d.v_max));
+    sample(v0, v1, segments, |v| surface.evaluate(u, v))
+}
+
+/// Evaluates `point_at` at `segments + 1` evenly spaced parameters.
+fn sample(
+    t0: f64,
+    t1: f64,
+    segments: u32,
+    point_at: impl Fn(f64) -> Result<Point3>,
+) -> Result<Polyline> {
+    if segments == 0 {
+        return Err(TessellationError::InvalidParameters(
+            "iso-curve needs at least one segment".into(),
+        )
+        .into());
+    }
+    if !t0.is_finite() || !t1.is_finite() {
+        return Err(
+            TessellationError::InvalidParameters("iso-curve span must be bounded".into()).into(),
+        );
+    }
+    let points = (0..=segments)
+        .map(|i| point_at(t0 + (t1 - t0) * f64::from(i) / f64::from(segments)))
+        .collect::<Result<_>>()?;
+    Ok(Polyline { points })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::surface::Cylinder;
+    use crate::math::Vector3;
+
+    fn cylinder() -> Cylinder {
+        Cylinder::new(Point3::new(1.0, 2.0, 0.0), 3.0, Vector3::z(), Vector3::x()).unwrap()
+    }
+
+    #[test]
+    fn cylinder_iso_v_is_vertical_line() {
+        let line = iso_v(&cylinder(), 0.7, Some((-1.0, 4.0)), 10).unwrap();
+        assert_eq!(line.points.len(), 11);
+        let first = line.points[0];
+        for p in &line.points {
+            assert!((p.x - first.x).abs() < 1e-12 && (p.y - first.y).abs() < 1e-12);
+        }
+        assert!((first.z + 1.0).abs() < 1e-12);
+        assert!((line.points[10].z - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn cylinder_iso_u_is_circle_of_radius() {
+        let circle = iso_u(&cylinder(), 2.5, None, 32).unwrap();
+        assert_eq!(circle.points.len(), 33);
+        for p in &circle.points {
+            let r = ((p.x - 1.0).powi(2) + (p.y - 2.0).powi(2)).sqrt();
+            assert!((r - 3.0).abs() < 1e-12, "r={r}");
+            assert!((p.z - 2.5).abs() < 1e-12);
+        }
+        assert!((circle.points[0] - circle.points[32]).norm() < 1e-9);
+    }
+
+    #[test]
+    fn unbounded_span_is_rejected() {
+        assert!(iso_v(&cylinder(), 0.0, None, 4).is_err());
+        assert!(iso_u(&cylinder(), 0.0, None, 0).is_err());
+    }
+}
@@ -1,4 +1,7 @@
+mod decimate;
 mod edge_samples;
+pub mod export;
+mod iso_curves;
 mod stroke_style;
 mod tessellate_curve;
 pub(crate) mod tessellate_face;
@@ -7,8 +10,10 @@ mod tessellate_solid;
 mod tessellate_stroke;
 mod tessellate_trimmed;
 mod tessellate_with_holes;
+mod vertex_dedup;
 
-pub use stroke_style::{LineJoin, StrokeStyle};
+pub use iso_curves::{iso_u, iso_v};
+pub use stroke_style::{LineJoin, StrokeStyle, DEFAULT_MITER_LIMIT};
 pub use tessellate_curve::TessellateCurve;
 pub use tessellate_face::TessellateFace;
 pub(crate) use tessellate_nurbs::nurbs_surface_is_open;
@@ -28,8 +33,13 @@ pub(crate) use tessellate_trimmed::{
 #[cfg(test)]
 pub(crate) use tessellate_solid::max_adjacent_boundary_deviation;
 pub use tessellate_with_holes::TessellateWithHoles;
+pub use vertex_dedup::VertexDedup;
 
-use crate::math::{Point2, Point3, Vector3};
+use std::collections::HashMap;
+
+use crate::error::{Result, TessellationError};
+use crate::math::{transform_normal, transform_point, Matrix4, Point2, Point3, Vector3, TOLERANCE};
+use crate::topology::EdgeData;
 
 /// Tessellation mode controlling how curved surfaces are meshed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -42,6 +52,41 @@ pub enum TessellationMode {
     Adaptive,
 }
 
+/// How vertex normals are assigned to tessellated faces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalPolicy {
+    /// Analytic surface normals, with planar faces forced to the exact
+    /// plane normal at every vertex.
+    Exact,
+    /// Analytic surface normals evaluated at each vertex, shared between
+    /// adjacent triangles for smooth shading.
+    #[default]
+    Smooth,
+    /// One normal per triangle: vertices are duplicated per triangle so each
+    /// facet shades flat, for a low-poly look.
+    ///
+    /// [`TessellateSolid::with_vertex_dedup`] re-welds the duplicates, so
+    /// the two are not meant to be combined.
+    Faceted,
+}
+
+/// How texture coordinates are assigned to planar faces triangulated in
+/// the plane's `(u_dir, v_dir)` frame.
+///
+/// Discs and annuli meshed on a polar grid keep their `(θ, r)` parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PlanarUv {
+    /// Plane coordinates in world units.
+    #[default]
+    WorldUnits,
+    /// Plane coordinates mapped so the face's bounding box spans `0..1`
+    /// in each direction.
+    Normalized,
+    /// Plane coordinates in world units multiplied by the given factor,
+    /// e.g. `0.5` repeats a texture every two units.
+    Scaled(f64),
+}
+
 /// Parameters controlling tessellation quality.
 #[derive(Debug, Clone, Copy)]
 pub struct TessellationParams {
@@ -53,6 +98,37 @@ pub struct TessellationParams {
     pub max_segments: usize,
     /// Tessellation mode for curved surfaces.
     pub mode: TessellationMode,
+    /// Triangle budget for adaptive refinement of curved surfaces in
+    /// [`TessellationMode::Adaptive`]; `None` refines without limit.
+    ///
+    /// Highest-deviation cells are refined first, and once the budget is
+    /// reached the remaining cells are emitted unrefined. Only curved
+    /// analytic and swept faces in adaptive mode follow it: such a face
+    /// never emits fewer than 2 triangles, and planar and NURBS faces (and
+    /// every face in the default mode) are triangulated in full.
+    ///
+    /// [`TessellateFace`] spends it on its one face. For [`TessellateSolid`]
+    /// in adaptive mode it caps the whole solid: the faces that ignore it
+    /// are meshed first, their triangles are taken off the cap, and each
+    /// budgeted face in turn gets an even share of the rest. The floor is
+    /// those fixed triangles plus 2 per budgeted face; a cap below it
+    /// meshes at the floor. Outside adaptive mode it has no effect.
+    pub max_triangles: Option<usize>,
+    /// How vertex normals are assigned.
+    pub normal_policy: NormalPolicy,
+    /// How texture coordinates of planar faces are scaled.
+    pub planar_uv: PlanarUv,
+}
+
+impl TessellationParams {
+    /// Returns these parameters with `tolerance` replaced by the edge's
+    /// own tessellation tolerance, if it has one.
+    pub(crate) fn for_edge(&self, edge: &EdgeData) -> Self {
+        Self {
+            tolerance: edge.tessellation_tolerance.unwrap_or(self.tolerance),
+            ..*self
+        }
+    }
 }
 
 impl Default for TessellationParams {
@@ -62,6 +138,9 @@ impl Default for TessellationParams {
             min_segments: 4,
             max_segments: 256,
             mode: TessellationMode::Default,
+            max_triangles: None,
+            normal_policy: NormalPolicy::Smooth,
+            planar_uv: PlanarUv::WorldUnits,
         }
     }
 }
@@ -73,6 +152,69 @@ pub struct Polyline {
     pub points: Vec<Point3>,
 }
 
+impl Polyline {
+    /// Formats this polyline as one line of JSON:
+    /// `{"points":[[x,y,z],...]}`. Non-finite coordinates become `null`.
+    #[cfg(feature = "debug-dump")]
+    #[must_use]
+    pub fn dump_json(&self) -> String {
+        use crate::geometry::pline::dump::json_f64;
+
+        let points: Vec<String> = self
+            .points
+            .iter()
+            .map(|p| format!("[{},{},{}]", json_f64(p.x), json_f64(p.y), json_f64(p.z)))
+            .collect();
+        format!("{{\"points\":[{}]}}", points.join(","))
+    }
+
+    /// Appends `other` to the end of this polyline.
+    ///
+    /// When `other` starts where this polyline ends (within [`TOLERANCE`]),
+    /// the shared joint point is kept only once.
+    pub fn append(&mut self, other: &Self) {
+        let skip = match (self.points.last(), other.points.first()) {
+            (Some(last), Some(first)) => usize::from((last - first).norm() < TOLERANCE),
+            _ => 0,
+        };
+        self.points.extend_from_slice(&other.points[skip..]);
+    }
+
+    /// Reverses the point order in place.
+    pub fn reverse(&mut self) {
+        self.points.reverse();
+    }
+
+    /// Closes the polyline by repeating its first point at the end.
+    ///
+    /// Does nothing if the polyline is already closed within [`TOLERANCE`]
+    /// or has fewer than 2 points.
+    pub fn close(&mut self) {
+        if self.points.len() < 2 || self.is_closed(TOLERANCE) {
+            return;
+        }
+        let first = self.points[0];
+        self.points.push(first);
+    }
+
+    /// Returns `true` if the first and last points coincide within `tol`.
+    ///
+    /// Polylines with fewer than 3 points are never closed.
+    #[must_use]
+    pub fn is_closed(&self, tol: f64) -> bool {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) if self.points.len() >= 3 => (last - first).norm() <= tol,
+            _ => false,
+        }
+    }
+
+    /// Returns the sum of the segment lengths.
+    #[must_use]
+    pub fn total_length(&self) -> f64 {
+        self.points.windows(2).map(|w| (w[1] - w[0]).norm()).sum()
+    }
+}
+
 /// A triangle mesh approximation of a surface.
 #[derive(Debug, Clone, Default)]
 pub struct TriangleMesh {
@@ -87,6 +229,170 @@ pub struct TriangleMesh {
 }
 
 impl TriangleMesh {
+    /// Applies an affine 4x4 transformation to the mesh in place.
+    ///
+    /// Vertices are transformed as points, normals by the inverse transpose
+    /// of the linear part (then renormalized). A handedness-reversing matrix
+    /// (negative determinant, e.g. a mirror) also reverses every triangle's
+    /// winding so the winding keeps agreeing with the normals.
+    pub fn transform(&mut self, matrix: &Matrix4) {
+        for p in &mut self.vertices {
+            *p = transform_point(matrix, *p);
+        }
+        for n in &mut self.normals {
+            *n = transform_normal(matrix, *n);
+        }
+        if matrix.fixed_view::<3, 3>(0, 0).determinant() < 0.0 {
+            for tri in &mut self.indices {
+                tri.swap(1, 2);
+            }
+        }
+    }
+
+    /// Reverses every triangle's winding and negates every stored normal,
+    /// turning the mesh inside out.
+    pub fn flip_winding(&mut self) {
+        for tri in &mut self.indices {
+            tri.swap(1, 2);
+        }
+        for n in &mut self.normals {
+            *n = -*n;
+        }
+    }
+
+    /// Returns the signed volume enclosed by the triangles, summed as
+    /// tetrahedra with apex `reference_point`.
+    ///
+    /// For a closed mesh the result is independent of the reference point:
+    /// positive when the triangles wind counter-clockwise seen from outside
+    /// (outward orientation), negative when inward.
+    #[must_use]
+    pub fn signed_volume(&self, reference_point: Point3) -> f64 {
+        self.indices
+            .iter()
+            .map(|tri| {
+                let [a, b, c] = tri.map(|i| self.vertices[i as usize] - reference_point);
+                a.dot(&b.cross(&c))
+            })
+            .sum::<f64>()
+            / 6.0
+    }
+
+    /// Flips the whole mesh with [`flip_winding`](Self::flip_winding) if its
+    /// signed volume about `reference_point` is negative (inward
+    /// orientation). Returns `true` if the mesh was flipped.
+    ///
+    /// Only meaningful for closed meshes whose triangles are consistently
+    /// oriented; a mixed mesh is flipped as a whole or not at all.
+    pub fn fix_winding_outward(&mut self, reference_point: Point3) -> bool {
+        let flip = self.signed_volume(reference_point) < 0.0;
+        if flip {
+            self.flip_winding();
+        }
+        flip
+    }
+
+    /// Checks the mesh for structural defects.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TessellationError::Failed`] if the normal (or non-empty UV)
+    /// count differs from the vertex count, an index is out of range, or a
+    /// triangle has zero area (below `TOLERANCE²`).
+    pub fn validate(&self) -> Result<()> {
+        let n = self.vertices.len();
+        if self.normals.len() != n || !(self.uvs.is_empty() || self.uvs.len() == n) {
+            return Err(TessellationError::Failed(format!(
+                "{n} vertices but {} normals and {} uvs",
+                self.normals.len(),
+                self.uvs.len()
+            ))
+            .into());
+        }
+        for (t, tri) in self.indices.iter().enumerate() {
+            if let Some(&i) = tri.iter().find(|&&i| i as usize >= n) {
+                return Err(TessellationError::Failed(format!(
+                    "triangle {t} references vertex {i} of {n}"
+                ))
+                .into());
+            }
+            let [a, b, c] = tri.map(|i| self.vertices[i as usize]);
+            if (b - a).cross(&(c - a)).norm() / 2.0 <= TOLERANCE * TOLERANCE {
+                return Err(
+                    TessellationError::Failed(format!("triangle {t} has zero area")).into(),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a copy with every triangle split into four at its edge
+    /// midpoints.
+    ///
+    /// Triangles sharing an edge (by vertex index) share its midpoint
+    /// vertex, so a watertight mesh stays watertight. Midpoint normals are
+    /// the renormalized average of the endpoint normals and midpoint UVs
+    /// the average of the endpoint UVs; positions are not smoothed.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn subdivide_midpoint(&self) -> Self {
+        let mut out = self.clone();
+        out.indices = Vec::with_capacity(self.indices.len() * 4);
+        let has_normals = self.normals.len() == self.vertices.len();
+        let has_uvs = self.uvs.len() == self.vertices.len();
+
+        let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+        let mut midpoint = |out: &mut Self, a: u32, b: u32| -> u32 {
+            *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                let (ia, ib) = (a as usize, b as usize);
+                let (pa, pb) = (self.vertices[ia], self.vertices[ib]);
+                out.vertices.push(pa + (pb - pa) * 0.5);
+                if has_normals {
+                    let sum = self.normals[ia] + self.normals[ib];
+                    out.normals
+                        .push(sum.try_normalize(0.0).unwrap_or(self.normals[ia]));
+                }
+                if has_uvs {
+                    let (ua, ub) = (self.uvs[ia], self.uvs[ib]);
+                    out.uvs.push(ua + (ub - ua) * 0.5);
+                }
+                (out.vertices.len() - 1) as u32
+            })
+        };
+
+        for &[a, b, c] in &self.indices {
+            let ab = midpoint(&mut out, a, b);
+            let bc = midpoint(&mut out, b, c);
+            let ca = midpoint(&mut out, c, a);
+            out.indices
+                .extend([[a, ab, ca], [ab, b, bc], [ca, bc, c], [ab, bc, ca]]);
+        }
+        out
+    }
+
+    /// Returns the edges used by exactly one triangle, as `(a, b)` index
+    /// pairs in the winding of that triangle.
+    ///
+    /// Edges are matched by vertex index, so a mesh tessellated face by
+    /// face should be welded first (see [`VertexDedup`]). A closed manifold
+    /// mesh has none.
+    #[must_use]
+    pub fn boundary_edges(&self) -> Vec<(u32, u32)> {
+        let mut uses: HashMap<(u32, u32), (usize, (u32, u32))> = HashMap::new();
+        for &[a, b, c] in &self.indices {
+            for (p, q) in [(a, b), (b, c), (c, a)] {
+                uses.entry((p.min(q), p.max(q))).or_insert((0, (p, q))).0 += 1;
+            }
+        }
+        let mut edges: Vec<(u32, u32)> = uses
+            .into_values()
+            .filter(|&(count, _)| count == 1)
+            .map(|(_, edge)| edge)
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
     /// Merges another mesh into this one, offsetting indices appropriately.
     #[allow(clippy::cast_possible_truncation)]
     pub fn merge(&mut self, other: &Self) {
@@ -99,6 +405,25 @@ impl TriangleMesh {
                 .push([tri[0] + offset, tri[1] + offset, tri[2] + offset]);
         }
     }
+
+    /// Concatenates `meshes` into one mesh, reserving the combined capacity
+    /// up front.
+    ///
+    /// Produces the same result as merging each mesh in turn into an empty
+    /// mesh with [`merge`](Self::merge), without the repeated reallocation.
+    #[must_use]
+    pub fn from_meshes(meshes: &[Self]) -> Self {
+        let mut out = Self {
+            vertices: Vec::with_capacity(meshes.iter().map(|m| m.vertices.len()).sum()),
+            normals: Vec::with_capacity(meshes.iter().map(|m| m.normals.len()).sum()),
+            uvs: Vec::with_capacity(meshes.iter().map(|m| m.uvs.len()).sum()),
+            indices: Vec::with_capacity(meshes.iter().map(|m| m.indices.len()).sum()),
+        };
+        for mesh in meshes {
+            out.merge(mesh);
+        }
+        out
+    }
 }
 
 #[cfg(test)]
@@ -127,6 +452,186 @@ mod tests {
         }
     }
 
+    #[test]
+    fn subdivide_midpoint_splits_triangle_in_four() {
+        let mesh = make_triangle_mesh(0.0, 0).subdivide_midpoint();
+        mesh.validate().unwrap();
+        assert_eq!(mesh.indices.len(), 4);
+        assert_eq!(mesh.vertices.len(), 6);
+        assert_eq!(mesh.uvs[3], Point2::new(0.5, 0.0));
+        let area: f64 = mesh
+            .indices
+            .iter()
+            .map(|&[a, b, c]| {
+                let [pa, pb, pc] = [a, b, c].map(|i| mesh.vertices[i as usize]);
+                0.5 * (pb - pa).cross(&(pc - pa)).z
+            })
+            .sum();
+        assert!((area - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn subdivide_midpoint_keeps_cube_closed() {
+        use crate::operations::creation::MakeBox;
+        use crate::topology::TopologyStore;
+
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(1.0, 1.0, 1.0))
+            .execute(&mut store)
+            .unwrap();
+        let mesh = TessellateSolid::new(solid, TessellationParams::default())
+            .with_vertex_dedup(1e-9)
+            .execute(&store)
+            .unwrap()
+            .subdivide_midpoint();
+        mesh.validate().unwrap();
+        assert_eq!(mesh.indices.len(), 12 * 4);
+
+        let mut directed: HashMap<(u32, u32), usize> = HashMap::new();
+        for &[a, b, c] in &mesh.indices {
+            for e in [(a, b), (b, c), (c, a)] {
+                *directed.entry(e).or_default() += 1;
+            }
+        }
+        for (&(a, b), &count) in &directed {
+            assert_eq!(count, 1);
+            assert_eq!(directed.get(&(b, a)), Some(&1), "edge {a}->{b} has no twin");
+        }
+    }
+
+    #[test]
+    fn validate_rejects_zero_area_and_bad_indices() {
+        let mut mesh = make_triangle_mesh(0.0, 0);
+        mesh.validate().unwrap();
+
+        mesh.vertices[2] = Point3::new(0.5, 0.0, 0.0);
+        assert!(mesh.validate().is_err());
+
+        let mut mesh = make_triangle_mesh(0.0, 1);
+        assert!(mesh.validate().is_err());
+        mesh.indices[0] = [0, 1, 2];
+        mesh.normals.pop();
+        assert!(mesh.validate().is_err());
+    }
+
+    fn polyline(points: &[(f64, f64)]) -> Polyline {
+        Polyline {
+            points: points
+                .iter()
+                .map(|&(x, y)| Point3::new(x, y, 0.0))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn polyline_append_reverse_and_length() {
+        let mut a = polyline(&[(0.0, 0.0), (1.0, 0.0)]);
+        let b = polyline(&[(1.0, 0.0), (1.0, 2.0), (4.0, 2.0)]);
+        a.append(&b);
+        // Shared joint (1, 0) is not duplicated.
+        assert_eq!(a.points.len(), 4);
+        assert!((a.total_length() - 6.0).abs() < 1e-12);
+
+        a.reverse();
+        assert!((a.points[0] - Point3::new(4.0, 2.0, 0.0)).norm() < 1e-12);
+        assert!((a.points[3] - Point3::new(0.0, 0.0, 0.0)).norm() < 1e-12);
+        assert!((a.total_length() - 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn polyline_append_disjoint_keeps_all_points() {
+        let mut a = polyline(&[(0.0, 0.0), (1.0, 0.0)]);
+        a.append(&polyline(&[(2.0, 0.0), (3.0, 0.0)]));
+        assert_eq!(a.points.len(), 4);
+        assert!((a.total_length() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn polyline_close_is_idempotent() {
+        let mut p = polyline(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+        assert!(!p.is_closed(1e-9));
+        p.close();
+        assert!(p.is_closed(1e-9));
+        assert_eq!(p.points.len(), 4);
+        p.close();
+        assert_eq!(p.points.len(), 4);
+        assert!((p.total_length() - (2.0 + 2.0_f64.sqrt())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn transform_translates_and_rotates_normals() {
+        let mut mesh = make_triangle_mesh(0.0, 0);
+        // 90° about X, then shift by (0, 0, 5).
+        let matrix = Matrix4::new_translation(&Vector3::new(0.0, 0.0, 5.0))
+            * Matrix4::from_axis_angle(&Vector3::x_axis(), std::f64::consts::FRAC_PI_2);
+        mesh.transform(&matrix);
+        assert!((mesh.vertices[2] - Point3::new(0.0, 0.0, 6.0)).norm() < 1e-12);
+        assert!((mesh.normals[0] - Vector3::new(0.0, -1.0, 0.0)).norm() < 1e-12);
+        assert_eq!(mesh.indices[0], [0, 1, 2], "rotation keeps winding");
+    }
+
+    #[test]
+    fn transform_non_uniform_scale_keeps_normals_unit() {
+        let mut mesh = make_triangle_mesh(0.0, 0);
+        mesh.transform(&Matrix4::new_nonuniform_scaling(&Vector3::new(
+            2.0, 3.0, 4.0,
+        )));
+        for n in &mesh.normals {
+            assert!((n - Vector3::z()).norm() < 1e-12);
+        }
+    }
+
+    /// Unit cube `[0, 1]³` with outward winding and per-vertex normals
+    /// pointing away from its center.
+    fn unit_cube_mesh() -> TriangleMesh {
+        let vertices: Vec<Point3> = (0..8)
+            .map(|i| Point3::new(f64::from(i & 1), f64::from((i >> 1) & 1), f64::from(i >> 2)))
+            .collect();
+        let center = Point3::new(0.5, 0.5, 0.5);
+        TriangleMesh {
+            normals: vertices.iter().map(|p| (p - center).normalize()).collect(),
+            uvs: vec![Point2::origin(); 8],
+            vertices,
+            indices: vec![
+                // z = 0
+                [0, 2, 3],
+                [0, 3, 1],
+                // z = 1
+                [4, 5, 7],
+                [4, 7, 6],
+                // y = 0
+                [0, 1, 5],
+                [0, 5, 4],
+                // y = 1
+                [2, 6, 7],
+                [2, 7, 3],
+                // x = 0
+                [0, 4, 6],
+                [0, 6, 2],
+                // x = 1
+                [1, 3, 7],
+                [1, 7, 5],
+            ],
+        }
+    }
+
+    #[test]
+    fn flip_winding_inverts_volume_and_fix_restores_it() {
+        let mut cube = unit_cube_mesh();
+        let reference = Point3::new(0.3, -2.0, 0.7);
+        assert!((cube.signed_volume(reference) - 1.0).abs() < 1e-12);
+        assert!(!cube.fix_winding_outward(reference));
+
+        let original_normal = cube.normals[0];
+        cube.flip_winding();
+        assert!((cube.signed_volume(reference) + 1.0).abs() < 1e-12);
+        assert_eq!(cube.normals[0], -original_normal);
+
+        assert!(cube.fix_winding_outward(Point3::origin()));
+        assert!((cube.signed_volume(reference) - 1.0).abs() < 1e-12);
+        assert_eq!(cube.normals[0], original_normal);
+    }
+
     #[test]
     fn merge_offsets_indices() {
         let mut a = make_triangle_mesh(0.0, 0);
@@ -141,6 +646,23 @@ mod tests {
         assert_eq!(a.indices[1], [3, 4, 5]); // offset by 3
     }
 
+    #[test]
+    fn from_meshes_matches_repeated_merge() {
+        let meshes: Vec<TriangleMesh> = (0..100)
+            .map(|i| make_triangle_mesh(f64::from(i), 0))
+            .collect();
+        let mut expected = TriangleMesh::default();
+        for m in &meshes {
+            expected.merge(m);
+        }
+        let combined = TriangleMesh::from_meshes(&meshes);
+        assert_eq!(combined.vertices, expected.vertices);
+        assert_eq!(combined.normals, expected.normals);
+        assert_eq!(combined.uvs, expected.uvs);
+        assert_eq!(combined.indices, expected.indices);
+        assert_eq!(combined.indices[99], [297, 298, 299]);
+    }
+
     #[test]
     fn merge_into_empty() {
         let mut a = TriangleMesh::default();
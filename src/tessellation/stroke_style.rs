@@ -1,13 +1,19 @@
 use crate::error::{Result, TessellationError};
 
+/// Default miter limit: joins whose miter would reach past twice the
+/// stroke width are beveled.
+pub const DEFAULT_MITER_LIMIT: f64 = 2.0;
+
 /// Line join style at polyline vertices.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum LineJoin {
-    /// Extend edges to their intersection point (clamped to prevent spikes).
+    /// Extend edges to their intersection point (clamped to the miter
+    /// limit to prevent spikes).
     Miter,
     /// Flat cap at every interior vertex — no miter extension.
     Bevel,
-    /// Miter for gentle angles, bevel for sharp angles (default).
+    /// Miter for gentle angles, bevel where the miter would exceed the miter
+    /// limit, as SVG's `stroke-linejoin="miter"` (default).
     #[default]
     Auto,
 }
@@ -17,6 +23,7 @@ pub enum LineJoin {
 pub struct StrokeStyle {
     width: f64,
     line_join: LineJoin,
+    miter_limit: f64,
 }
 
 impl StrokeStyle {
@@ -35,6 +42,7 @@ impl StrokeStyle {
         Ok(Self {
             width,
             line_join: LineJoin::default(),
+            miter_limit: DEFAULT_MITER_LIMIT,
         })
     }
 
@@ -45,6 +53,24 @@ impl StrokeStyle {
         self
     }
 
+    /// Sets the miter limit with SVG `stroke-miterlimit` semantics: a join
+    /// whose miter length divided by the stroke width, `1 / sin(θ / 2)` for
+    /// an interior angle `θ`, exceeds `limit` is beveled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `limit` is less than `1` (or NaN).
+    pub fn with_miter_limit(mut self, limit: f64) -> Result<Self> {
+        if limit.is_nan() || limit < 1.0 {
+            return Err(TessellationError::InvalidParameters(
+                "miter limit must be at least 1".to_owned(),
+            )
+            .into());
+        }
+        self.miter_limit = limit;
+        Ok(self)
+    }
+
     /// Returns the stroke width.
     #[must_use]
     pub fn width(&self) -> f64 {
@@ -62,6 +88,12 @@ impl StrokeStyle {
     pub fn line_join(&self) -> LineJoin {
         self.line_join
     }
+
+    /// Returns the miter limit.
+    #[must_use]
+    pub fn miter_limit(&self) -> f64 {
+        self.miter_limit
+    }
 }
 
 #[cfg(test)]
@@ -85,6 +117,14 @@ mod tests {
         assert_eq!(style.line_join(), LineJoin::Bevel);
     }
 
+    #[test]
+    fn with_miter_limit_rejects_below_one() {
+        let style = StrokeStyle::new(1.0).unwrap();
+        assert!((style.miter_limit() - DEFAULT_MITER_LIMIT).abs() < f64::EPSILON);
+        assert!((style.with_miter_limit(4.0).unwrap().miter_limit() - 4.0).abs() < f64::EPSILON);
+        assert!(style.with_miter_limit(0.5).is_err());
+    }
+
     #[test]
     fn new_with_zero_width_fails() {
         let result = StrokeStyle::new(0.0);
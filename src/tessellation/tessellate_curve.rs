@@ -166,6 +166,7 @@ mod tests {
             curve: EdgeCurve::Arc(arc),
             t_start: 0.0,
             t_end: std::f64::consts::PI,
+            tessellation_tolerance: None,
         });
 
         let polyline = TessellateCurve::new(edge_id, TessellationParams::default())
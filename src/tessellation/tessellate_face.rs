@@ -1,4 +1,5 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use std::f64::consts::TAU;
 
 use spade::handles::FixedFaceHandle;
@@ -8,11 +9,18 @@ use spade::{
 
 use crate::error::{Result, TessellationError};
 use crate::geometry::surface::Surface;
-use crate::math::{Point2, Vector3};
-use crate::topology::{EdgeCurve, FaceId, FaceSurface, TopologyStore, WireId};
+use crate::math::{Point2, Vector3, TOLERANCE};
+use crate::topology::{EdgeCurve, EdgeData, FaceId, FaceSurface, TopologyStore, WireId};
 
 use super::edge_samples::EdgeSampleCache;
-use super::{SurfaceTessellationOptions, TessellationMode, TessellationParams, TriangleMesh};
+use super::tessellate_trimmed::point_in_polygon;
+use super::{
+    NormalPolicy, PlanarUv, SurfaceTessellationOptions, TessellationMode, TessellationParams,
+    TriangleMesh, VertexDedup,
+};
+
+/// Samples used to estimate iso-curve lengths on swept faces.
+const ISO_LENGTH_SAMPLES: u32 = 16;
 
 /// Tessellates a face into a triangle mesh.
 pub struct TessellateFace {
@@ -37,6 +45,56 @@ impl TessellateFace {
         self.execute_with_cache(store, &mut cache)
     }
 
+    /// Tessellates the face and appends it to `mesh`, sharing one index
+    /// between its vertices and those `dedup` has already seen (see
+    /// [`VertexDedup`]).
+    ///
+    /// Calling this for each face of a solid with the same `dedup`, `mesh`
+    /// and parameters yields a watertight mesh directly, without a separate
+    /// weld pass; [`TessellateSolid::with_vertex_dedup`] does exactly that.
+    ///
+    /// [`TessellateSolid::with_vertex_dedup`]: super::TessellateSolid::with_vertex_dedup
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the face cannot be tessellated; `mesh` is left
+    /// unchanged.
+    pub fn execute_into(
+        &self,
+        store: &TopologyStore,
+        dedup: &mut VertexDedup,
+        mesh: &mut TriangleMesh,
+    ) -> Result<()> {
+        let mut cache = EdgeSampleCache::new(self.params);
+        self.execute_into_with_cache(store, &mut cache, dedup, mesh)
+    }
+
+    /// [`execute_into`](Self::execute_into) against a shared per-solid
+    /// edge-sample cache.
+    pub(crate) fn execute_into_with_cache(
+        &self,
+        store: &TopologyStore,
+        cache: &mut EdgeSampleCache,
+        dedup: &mut VertexDedup,
+        mesh: &mut TriangleMesh,
+    ) -> Result<()> {
+        let face_mesh = self.execute_with_cache(store, cache)?;
+        dedup.merge_into(mesh, &face_mesh);
+        Ok(())
+    }
+
+    /// Whether this face spends `params.max_triangles`: only curved
+    /// analytic and swept faces in [`TessellationMode::Adaptive`] are
+    /// refined within the budget; planar and NURBS faces, and every face in
+    /// the default mode, are meshed in full regardless of it.
+    pub(crate) fn follows_triangle_budget(&self, store: &TopologyStore) -> Result<bool> {
+        Ok(self.params.mode == TessellationMode::Adaptive
+            && !matches!(
+                store.face(self.face)?.surface,
+                FaceSurface::Plane(_) | FaceSurface::Nurbs(_)
+            ))
+    }
+
     /// Executes the tessellation against a shared per-solid edge-sample cache,
     /// so faces sharing boundary edges emit identical boundary vertices.
     ///
@@ -55,12 +113,22 @@ impl TessellateFace {
 
         let full_rev = wire_has_full_circle(store, outer_wire_id);
 
-        match &face.surface {
+        let mut mesh = match &face.surface {
             FaceSurface::Plane(plane) => {
                 let plane = plane.clone();
-                if full_rev {
-                    // Annular disc (or full disc) from revolve — use polar grid
+                let annulus = if full_rev {
                     let (r_min, r_max, center) = extract_annular_radii(store, outer_wire_id)?;
+                    match face.inner_wires.as_slice() {
+                        [] => Some((r_min, r_max, center)),
+                        [hole] => concentric_hole_radius(store, *hole, &center)
+                            .map(|r_hole| (r_hole, r_max, center)),
+                        _ => None,
+                    }
+                } else {
+                    None
+                };
+                if let Some((r_min, r_max, center)) = annulus {
+                    // Annular disc (or full disc) — use polar grid
                     tessellate_annular_disc(&plane, &center, r_min, r_max, same_sense, &self.params)
                 } else {
                     let inner_wire_ids = face.inner_wires.clone();
@@ -71,6 +139,7 @@ impl TessellateFace {
                         same_sense,
                         outer_wire_id,
                         &inner_wire_ids,
+                        self.params.planar_uv,
                     )
                 }
             }
@@ -84,7 +153,87 @@ impl TessellateFace {
                 let n = n.clone();
                 tessellate_nurbs_face(store, cache, face, &n, same_sense)
             }
+            FaceSurface::Ruled(_) | FaceSurface::Extruded(_) => {
+                self.tessellate_swept(store, outer_wire_id, same_sense)
+            }
+        }?;
+
+        match self.params.normal_policy {
+            NormalPolicy::Smooth => {}
+            NormalPolicy::Exact => {
+                if let FaceSurface::Plane(plane) = &face.surface {
+                    let n = if same_sense {
+                        *plane.plane_normal()
+                    } else {
+                        -plane.plane_normal()
+                    };
+                    mesh.normals.fill(n);
+                }
+            }
+            NormalPolicy::Faceted => facet_normals(&mut mesh),
         }
+        Ok(mesh)
+    }
+
+    /// Tessellates ruled / extruded faces on a UV grid spanning the outer
+    /// wire's inverse-mapped bounds, clamped to the surface domain.
+    fn tessellate_swept(
+        &self,
+        store: &TopologyStore,
+        outer_wire_id: WireId,
+        same_sense: bool,
+    ) -> Result<TriangleMesh> {
+        let face = store.face(self.face)?;
+        let outer_3d = collect_wire_points_tessellated(store, outer_wire_id, &self.params)?;
+        let (surface, (u_min, u_max, v_min, v_max)): (&dyn Surface, _) = match &face.surface {
+            FaceSurface::Ruled(ruled) => {
+                (ruled, compute_uv_bounds(&outer_3d, |p| ruled.inverse(p)))
+            }
+            FaceSurface::Extruded(extruded) => (
+                extruded,
+                compute_uv_bounds(&outer_3d, |p| extruded.inverse(p)),
+            ),
+            _ => {
+                return Err(TessellationError::Failed(
+                    "tessellate_swept called for a non-swept face".into(),
+                )
+                .into())
+            }
+        };
+        let d = surface.domain();
+        let (u_min, u_max) = (u_min.max(d.u_min), u_max.min(d.u_max));
+        let (v_min, v_max) = (v_min.max(d.v_min), v_max.min(d.v_max));
+        if u_max - u_min < crate::math::TOLERANCE || v_max - v_min < crate::math::TOLERANCE {
+            return Err(TessellationError::Failed("degenerate swept face bounds".into()).into());
+        }
+
+        // Segment counts from the iso-curve lengths through the patch centre.
+        let iso_length = |along_u: bool| -> Result<f64> {
+            let mut length = 0.0;
+            let mut prev: Option<crate::math::Point3> = None;
+            for i in 0..=ISO_LENGTH_SAMPLES {
+                let t = f64::from(i) / f64::from(ISO_LENGTH_SAMPLES);
+                let p = if along_u {
+                    surface.evaluate(u_min + t * (u_max - u_min), f64::midpoint(v_min, v_max))?
+                } else {
+                    surface.evaluate(f64::midpoint(u_min, u_max), v_min + t * (v_max - v_min))?
+                };
+                if let Some(q) = prev {
+                    length += (p - q).norm();
+                }
+                prev = Some(p);
+            }
+            Ok(length)
+        };
+        let n_u = adaptive_linear_segments(iso_length(true)?, &self.params);
+        let n_v = adaptive_linear_segments(iso_length(false)?, &self.params);
+        #[rustfmt::skip]
+        let mut mesh = tessellate_surface(
+            surface, u_min, u_max, v_min, v_max, n_u, n_v, same_sense, &self.params,
+        )?;
+        let holes = hole_uv_loops(store, &face.inner_wires, &self.params, &face.surface, false)?;
+        cut_uv_holes(&mut mesh, &holes, false);
+        Ok(mesh)
     }
 
     /// Tessellates the analytic revolved-surface arms (cylinder / sphere /
@@ -99,15 +248,14 @@ impl TessellateFace {
     ) -> Result<TriangleMesh> {
         let face = store.face(self.face)?;
         let outer_3d = collect_wire_points_tessellated(store, outer_wire_id, &self.params)?;
+        let seam = face.u_seam;
 
-        match &face.surface {
+        let mut mesh = match &face.surface {
             FaceSurface::Cylinder(cyl) => {
                 let (_, _, v_min, v_max) = compute_uv_bounds(&outer_3d, |p| cyl.inverse(p));
-                let (u_min, u_max) = if full_rev {
-                    (0.0, TAU)
-                } else {
+                let (u_min, u_max) = periodic_u_range(full_rev, seam, || {
                     compute_unwrapped_u_bounds(&outer_3d, |p| cyl.inverse(p))
-                };
+                });
                 let n_u = adaptive_angular_segments(cyl.radius(), u_max - u_min, &self.params);
                 let n_v = adaptive_linear_segments(v_max - v_min, &self.params);
                 #[rustfmt::skip]
@@ -118,11 +266,9 @@ impl TessellateFace {
             }
             FaceSurface::Sphere(sph) => {
                 let (_, _, v_min, v_max) = compute_uv_bounds(&outer_3d, |p| sph.inverse(p));
-                let (u_min, u_max) = if full_rev {
-                    (0.0, TAU)
-                } else {
+                let (u_min, u_max) = periodic_u_range(full_rev, seam, || {
                     compute_unwrapped_u_bounds(&outer_3d, |p| sph.inverse(p))
-                };
+                });
                 let n_u = adaptive_angular_segments(sph.radius(), u_max - u_min, &self.params);
                 let n_v = adaptive_angular_segments(sph.radius(), v_max - v_min, &self.params);
                 #[rustfmt::skip]
@@ -133,11 +279,9 @@ impl TessellateFace {
             }
             FaceSurface::Cone(cone) => {
                 let (_, _, v_min, v_max) = compute_uv_bounds(&outer_3d, |p| cone.inverse(p));
-                let (u_min, u_max) = if full_rev {
-                    (0.0, TAU)
-                } else {
+                let (u_min, u_max) = periodic_u_range(full_rev, seam, || {
                     compute_unwrapped_u_bounds(&outer_3d, |p| cone.inverse(p))
-                };
+                });
                 let max_radius = v_max * cone.half_angle().sin();
                 let n_u = adaptive_angular_segments(max_radius, u_max - u_min, &self.params);
                 let n_v = adaptive_linear_segments(v_max - v_min, &self.params);
@@ -149,11 +293,9 @@ impl TessellateFace {
             }
             FaceSurface::Torus(torus) => {
                 let (_, _, v_min, v_max) = compute_uv_bounds(&outer_3d, |p| torus.inverse(p));
-                let (u_min, u_max) = if full_rev {
-                    (0.0, TAU)
-                } else {
+                let (u_min, u_max) = periodic_u_range(full_rev, seam, || {
                     compute_unwrapped_u_bounds(&outer_3d, |p| torus.inverse(p))
-                };
+                });
                 let n_u = adaptive_angular_segments(
                     torus.major_radius() + torus.minor_radius(),
                     u_max - u_min,
@@ -167,11 +309,20 @@ impl TessellateFace {
                 );
                 mesh
             }
-            FaceSurface::Plane(_) | FaceSurface::Nurbs(_) => Err(TessellationError::Failed(
+            FaceSurface::Plane(_)
+            | FaceSurface::Nurbs(_)
+            | FaceSurface::Ruled(_)
+            | FaceSurface::Extruded(_) => Err(TessellationError::Failed(
                 "tessellate_analytic called for a non-analytic face".into(),
             )
             .into()),
+        }?;
+        let holes = hole_uv_loops(store, &face.inner_wires, &self.params, &face.surface, true)?;
+        cut_uv_holes(&mut mesh, &holes, true);
+        if let Some(seam) = seam {
+            normalize_seam_uvs(&mut mesh, seam, same_sense);
         }
+        Ok(mesh)
     }
 }
 
@@ -229,12 +380,15 @@ fn tessellate_nurbs_face(
     Ok(mesh)
 }
 
-/// Tessellates a planar face using CDT.
+/// Tessellates a planar face using CDT, falling back to
+/// [`ear_clip_sliver`] when the CDT has no interior, and rescales the UVs
+/// of either mesh as `planar_uv` directs. With the `tracing` feature the
+/// fallback logs a warning.
 ///
 /// Boundary polylines come from the shared per-edge sample cache, so a planar
 /// cap referencing the same ring edge as an adjacent NURBS side wall emits the
 /// identical boundary vertices (shared-edge conformance).
-#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::too_many_arguments)]
 fn tessellate_plane(
     store: &TopologyStore,
     cache: &mut EdgeSampleCache,
@@ -242,6 +396,7 @@ fn tessellate_plane(
     same_sense: bool,
     outer_wire_id: crate::topology::WireId,
     inner_wire_ids: &[crate::topology::WireId],
+    planar_uv: PlanarUv,
 ) -> Result<TriangleMesh> {
     let outer_3d = wire_points_from_cache(store, cache, outer_wire_id)?;
     let mut inner_3d_list = Vec::new();
@@ -276,7 +431,30 @@ fn tessellate_plane(
     }
 
     let interior_faces = classify_interior_faces(&cdt);
+    let mut mesh = if interior_faces.is_empty() {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            "planar face CDT produced no interior triangles; ear-clipping the outer loop"
+        );
+        ear_clip_sliver(&outer_3d, &outer_2d, normal)
+    } else {
+        cdt_mesh(&cdt, &interior_faces, plane, normal, same_sense)
+    };
+    remap_planar_uvs(&mut mesh.uvs, planar_uv);
+    Ok(mesh)
+}
 
+/// Emits the interior triangles of a planar face's CDT, lifted back onto
+/// `plane`, with `normal` at every vertex and the plane coordinates as UVs.
+#[allow(clippy::cast_possible_truncation)]
+fn cdt_mesh(
+    cdt: &ConstrainedDelaunayTriangulation<SpadePoint2<f64>>,
+    interior_faces: &HashSet<usize>,
+    plane: &crate::geometry::surface::Plane,
+    normal: Vector3,
+    same_sense: bool,
+) -> TriangleMesh {
+    let (origin, u_dir, v_dir) = (plane.origin(), plane.u_dir(), plane.v_dir());
     let mut mesh = TriangleMesh::default();
     let mut vertex_map: HashMap<usize, u32> = HashMap::new();
 
@@ -319,8 +497,129 @@ fn tessellate_plane(
         }
         mesh.indices.push(tri_indices);
     }
+    mesh
+}
 
-    Ok(mesh)
+/// Rescales the world-unit plane coordinates of a planar face's UVs as
+/// `mode` directs. A degenerate bounding-box extent maps to `0`.
+fn remap_planar_uvs(uvs: &mut [Point2], mode: PlanarUv) {
+    match mode {
+        PlanarUv::WorldUnits => {}
+        PlanarUv::Scaled(factor) => {
+            for uv in uvs {
+                uv.coords *= factor;
+            }
+        }
+        PlanarUv::Normalized => {
+            let Some(first) = uvs.first().copied() else {
+                return;
+            };
+            let (min, max) = uvs
+                .iter()
+                .fold((first, first), |(lo, hi), p| (lo.inf(p), hi.sup(p)));
+            let extent = max - min;
+            let inv = |e: f64| if e > TOLERANCE { 1.0 / e } else { 0.0 };
+            let (su, sv) = (inv(extent.x), inv(extent.y));
+            for uv in uvs {
+                *uv = Point2::new((uv.x - min.x) * su, (uv.y - min.y) * sv);
+            }
+        }
+    }
+}
+
+/// Fallback mesh for a planar face whose CDT came out empty.
+///
+/// That happens when the outer loop collapses onto a line in the face's
+/// plane — a sliver whose stored plane no longer matches its boundary.
+/// The loop is ear-clipped in its own best-fit plane instead, keeping the
+/// original 3D positions, with triangles and normals facing the side of
+/// `normal`; `uvs` are the face-plane coordinates `outer_2d`. Holes are
+/// ignored, since a face this thin cannot meaningfully contain any.
+#[allow(clippy::cast_possible_truncation)]
+fn ear_clip_sliver(
+    outer_3d: &[crate::math::Point3],
+    outer_2d: &[SpadePoint2<f64>],
+    normal: Vector3,
+) -> TriangleMesh {
+    let mut mesh = TriangleMesh::default();
+    // Newell's normal of the loop.
+    let loop_normal: Vector3 = outer_3d
+        .iter()
+        .zip(outer_3d.iter().cycle().skip(1))
+        .map(|(a, b)| a.coords.cross(&b.coords))
+        .sum();
+    let Some(mut n) = loop_normal.try_normalize(TOLERANCE) else {
+        return mesh;
+    };
+    if n.dot(&normal) < 0.0 {
+        n = -n;
+    }
+    let (u, v) = crate::math::ortho_basis(n);
+    let local: Vec<SpadePoint2<f64>> = outer_3d
+        .iter()
+        .map(|p| SpadePoint2::new(p.coords.dot(&u), p.coords.dot(&v)))
+        .collect();
+    for tri in ear_clip(&local) {
+        let base = mesh.vertices.len() as u32;
+        for i in tri {
+            mesh.vertices.push(outer_3d[i]);
+            mesh.normals.push(n);
+            mesh.uvs.push(Point2::new(outer_2d[i].x, outer_2d[i].y));
+        }
+        mesh.indices.push([base, base + 1, base + 2]);
+    }
+    mesh
+}
+
+/// Triangulates a simple polygon by ear clipping, returning triangles as
+/// indices into `points`, wound counter-clockwise. Returns no triangles for
+/// a loop of zero area.
+#[allow(clippy::many_single_char_names)]
+fn ear_clip(points: &[SpadePoint2<f64>]) -> Vec<[usize; 3]> {
+    let cross = |a: usize, b: usize, c: usize| {
+        let (pa, pb, pc) = (points[a], points[b], points[c]);
+        (pb.x - pa.x) * (pc.y - pa.y) - (pb.y - pa.y) * (pc.x - pa.x)
+    };
+    let n = points.len();
+    let twice_area: f64 = (0..n).map(|i| cross(0, i, (i + 1) % n)).sum();
+    if n < 3 || twice_area.abs() <= TOLERANCE * TOLERANCE {
+        return Vec::new();
+    }
+    let mut ring: Vec<usize> = (0..n).collect();
+    if twice_area < 0.0 {
+        ring.reverse();
+    }
+
+    let mut tris = Vec::with_capacity(n - 2);
+    while ring.len() > 3 {
+        let m = ring.len();
+        let is_ear = |k: usize| {
+            let (a, b, c) = (ring[(k + m - 1) % m], ring[k], ring[(k + 1) % m]);
+            cross(a, b, c) > 0.0
+                && ring.iter().all(|&q| {
+                    q == a
+                        || q == b
+                        || q == c
+                        || cross(a, b, q) < 0.0
+                        || cross(b, c, q) < 0.0
+                        || cross(c, a, q) < 0.0
+                })
+        };
+        // Without a clean ear (numerically degenerate input), clip the most
+        // convex corner so the loop still terminates.
+        let k = (0..m).find(|&k| is_ear(k)).unwrap_or_else(|| {
+            (0..m)
+                .max_by(|&i, &j| {
+                    let turn = |k: usize| cross(ring[(k + m - 1) % m], ring[k], ring[(k + 1) % m]);
+                    turn(i).total_cmp(&turn(j))
+                })
+                .unwrap_or(0)
+        });
+        tris.push([ring[(k + m - 1) % m], ring[k], ring[(k + 1) % m]]);
+        ring.remove(k);
+    }
+    tris.push([ring[0], ring[1], ring[2]]);
+    tris
 }
 
 /// Checks if a wire contains a full-circle edge (sweep ≈ TAU).
@@ -332,16 +631,30 @@ fn wire_has_full_circle(store: &TopologyStore, wire_id: WireId) -> bool {
         let Ok(edge) = store.edge(oe.edge) else {
             continue;
         };
-        if matches!(&edge.curve, EdgeCurve::Circle(_)) {
-            let sweep = (edge.t_end - edge.t_start).abs();
-            if sweep > TAU - 0.01 {
-                return true;
-            }
+        if is_full_turn(edge) {
+            return true;
         }
     }
     false
 }
 
+/// Whether `edge` is a circle or arc edge whose parameter range spans a full
+/// turn. Both curves are parameterized by angle, so the range is the sweep.
+///
+/// Circle edges keep their historical `TAU - 0.01` allowance, which
+/// accepts circles closed from slightly rounded parameters; arc edges must
+/// be full to within `TOLERANCE`, like [`Arc::is_full_circle`].
+///
+/// [`Arc::is_full_circle`]: crate::geometry::curve::Arc::is_full_circle
+fn is_full_turn(edge: &EdgeData) -> bool {
+    let sweep = (edge.t_end - edge.t_start).abs();
+    match edge.curve {
+        EdgeCurve::Circle(_) => sweep > TAU - 0.01,
+        EdgeCurve::Arc(_) => sweep >= TAU - TOLERANCE,
+        _ => false,
+    }
+}
+
 /// Computes u-bounds by unwrapping `atan2` values along the wire boundary.
 ///
 /// The surface's `inverse()` returns `u` via `atan2`, which has a discontinuity
@@ -382,6 +695,139 @@ fn compute_unwrapped_u_bounds(
     (u_min, u_max)
 }
 
+/// Returns the `u` range to tessellate a periodic analytic face over.
+///
+/// Full revolutions span one period starting at the seam (`0` without one).
+/// Partial faces use the unwrapped boundary range; with a seam it is shifted
+/// by whole periods so it starts in `[seam, seam + TAU)`, keeping the texture
+/// coordinate continuous across the face.
+fn periodic_u_range(
+    full_rev: bool,
+    seam: Option<f64>,
+    unwrapped: impl FnOnce() -> (f64, f64),
+) -> (f64, f64) {
+    let start = seam.unwrap_or(0.0);
+    if full_rev {
+        return (start, start + TAU);
+    }
+    let (u_min, u_max) = unwrapped();
+    let Some(seam) = seam else {
+        return (u_min, u_max);
+    };
+    let mut offset = (u_min - seam).rem_euclid(TAU);
+    // A boundary sitting on the seam may unwrap a hair below it.
+    if TAU - offset < 1e-9 {
+        offset = 0.0;
+    }
+    (seam + offset, seam + offset + (u_max - u_min))
+}
+
+/// Projects a curved face's inner wires (holes) into UV loops through the
+/// surface inverse. With `periodic_u` the `u` values are unwrapped along
+/// each loop so a hole straddling the `atan2` branch cut stays contiguous.
+fn hole_uv_loops(
+    store: &TopologyStore,
+    inner_wires: &[WireId],
+    params: &TessellationParams,
+    surface: &FaceSurface,
+    periodic_u: bool,
+) -> Result<Vec<Vec<Point2>>> {
+    let inverse = |p: &crate::math::Point3| match surface {
+        FaceSurface::Cylinder(s) => Ok(s.inverse(p)),
+        FaceSurface::Sphere(s) => Ok(s.inverse(p)),
+        FaceSurface::Cone(s) => Ok(s.inverse(p)),
+        FaceSurface::Torus(s) => Ok(s.inverse(p)),
+        FaceSurface::Ruled(s) => Ok(s.inverse(p)),
+        FaceSurface::Extruded(s) => Ok(s.inverse(p)),
+        FaceSurface::Plane(_) | FaceSurface::Nurbs(_) => Err(TessellationError::Failed(
+            "hole projection needs an analytic or swept surface".into(),
+        )),
+    };
+
+    let mut loops = Vec::with_capacity(inner_wires.len());
+    for &wire_id in inner_wires {
+        let points = collect_wire_points_tessellated(store, wire_id, params)?;
+        let mut uv_loop: Vec<Point2> = Vec::with_capacity(points.len());
+        for p in &points {
+            let (mut u, v) = inverse(p)?;
+            if let (true, Some(prev)) = (periodic_u, uv_loop.last()) {
+                u += ((prev.x - u) / TAU).round() * TAU;
+            }
+            uv_loop.push(Point2::new(u, v));
+        }
+        loops.push(uv_loop);
+    }
+    Ok(loops)
+}
+
+/// Drops the triangles whose UV centroid lies inside any hole loop, then
+/// drops the vertices no remaining triangle references.
+///
+/// Whole grid triangles are removed, so the hole's edge follows the grid
+/// cells (jagged) rather than the inner wire itself. For periodic surfaces
+/// the centroid is also tested one period either side, since the hole loop
+/// and the grid may sit on different `u` branches.
+#[allow(clippy::cast_possible_truncation)]
+fn cut_uv_holes(mesh: &mut TriangleMesh, holes: &[Vec<Point2>], periodic_u: bool) {
+    if holes.is_empty() {
+        return;
+    }
+    let shifts: &[f64] = if periodic_u {
+        &[0.0, -TAU, TAU]
+    } else {
+        &[0.0]
+    };
+    let uvs = &mesh.uvs;
+    mesh.indices.retain(|tri| {
+        let c = tri.iter().fold(Point2::origin(), |acc, &i| {
+            acc + uvs[i as usize].coords / 3.0
+        });
+        !holes.iter().any(|hole| {
+            shifts
+                .iter()
+                .any(|&du| point_in_polygon(&Point2::new(c.x + du, c.y), hole))
+        })
+    });
+
+    // Compact, keeping surviving vertices in their original order.
+    let mut remap = vec![u32::MAX; mesh.vertices.len()];
+    for tri in &mesh.indices {
+        for &v in tri {
+            remap[v as usize] = 0;
+        }
+    }
+    let mut kept = 0;
+    for (i, slot) in remap.iter_mut().enumerate() {
+        if *slot == u32::MAX {
+            continue;
+        }
+        *slot = kept as u32;
+        mesh.vertices[kept] = mesh.vertices[i];
+        mesh.normals[kept] = mesh.normals[i];
+        mesh.uvs[kept] = mesh.uvs[i];
+        kept += 1;
+    }
+    mesh.vertices.truncate(kept);
+    mesh.normals.truncate(kept);
+    mesh.uvs.truncate(kept);
+    for tri in &mut mesh.indices {
+        *tri = tri.map(|v| remap[v as usize]);
+    }
+}
+
+/// Maps raw periodic `u` coordinates to `[0, 1]` measured from `seam`.
+///
+/// The grid tessellators emit `(u, v)` surface parameters whose frame is
+/// right-handed about the surface normal. A face oriented against its
+/// surface (`same_sense == false`) reverses `u` so the texture reads
+/// unmirrored from the face's outward side.
+fn normalize_seam_uvs(mesh: &mut TriangleMesh, seam: f64, same_sense: bool) {
+    for uv in &mut mesh.uvs {
+        let t = (uv.x - seam) / TAU;
+        uv.x = if same_sense { t } else { 1.0 - t };
+    }
+}
+
 /// Extracts the min/max radii and center from circle edges in a wire.
 ///
 /// Used for annular disc tessellation. If only one circle is found,
@@ -396,7 +842,12 @@ fn extract_annular_radii(
 
     for oe in &wire.edges {
         let edge = store.edge(oe.edge)?;
-        if let EdgeCurve::Circle(circle) = &edge.curve {
+        let circle = match &edge.curve {
+            EdgeCurve::Circle(circle) => Some(circle.clone()),
+            EdgeCurve::Arc(arc) => arc.to_circle(),
+            _ => None,
+        };
+        if let Some(circle) = circle {
             radii.push(circle.radius());
             if center.is_none() {
                 center = Some(*circle.center());
@@ -417,6 +868,62 @@ fn extract_annular_radii(
     Ok((r_min, r_max, center))
 }
 
+/// Splits `mesh` so every triangle owns its three vertices, all carrying the
+/// triangle's facet normal. The facet normal is oriented to agree with the
+/// triangle's smooth vertex normals, so winding quirks cannot flip it.
+fn facet_normals(mesh: &mut TriangleMesh) {
+    let mut out = TriangleMesh::default();
+    for tri in &mesh.indices {
+        let [a, b, c] = tri.map(|i| i as usize);
+        let (pa, pb, pc) = (mesh.vertices[a], mesh.vertices[b], mesh.vertices[c]);
+        let smooth: Vector3 = [a, b, c].iter().filter_map(|&i| mesh.normals.get(i)).sum();
+        let mut n = (pb - pa).cross(&(pc - pa));
+        let len = n.norm();
+        if len < crate::math::TOLERANCE {
+            n = smooth.try_normalize(0.0).unwrap_or(smooth);
+        } else {
+            n /= len;
+            if n.dot(&smooth) < 0.0 {
+                n = -n;
+            }
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let base = out.vertices.len() as u32;
+        for i in [a, b, c] {
+            out.vertices.push(mesh.vertices[i]);
+            out.normals.push(n);
+            if let Some(uv) = mesh.uvs.get(i) {
+                out.uvs.push(*uv);
+            }
+        }
+        out.indices.push([base, base + 1, base + 2]);
+    }
+    *mesh = out;
+}
+
+/// Returns the radius of `wire` when it is a single full circle centred on
+/// `center`, i.e. a hole the annular-disc grid can represent.
+fn concentric_hole_radius(
+    store: &TopologyStore,
+    wire_id: WireId,
+    center: &crate::math::Point3,
+) -> Option<f64> {
+    let wire = store.wire(wire_id).ok()?;
+    let [oe] = wire.edges.as_slice() else {
+        return None;
+    };
+    let edge = store.edge(oe.edge).ok()?;
+    match &edge.curve {
+        EdgeCurve::Circle(circle)
+            if is_full_turn(edge)
+                && (circle.center() - center).norm() < crate::math::TOLERANCE * 1e3 =>
+        {
+            Some(circle.radius())
+        }
+        _ => None,
+    }
+}
+
 /// Tessellates an annular disc (or full disc) on a plane using a polar grid.
 ///
 /// Instead of CDT (which struggles with slit-annulus constraint polygons from
@@ -553,7 +1060,8 @@ fn tessellate_uv_grid(
 ///
 /// In adaptive mode, the curvature-computed `n_u`/`n_v` are ignored; instead a
 /// coarse base grid (`min_segments × min_segments`) is used, and cells are
-/// recursively subdivided where the midpoint deviation exceeds the tolerance.
+/// subdivided where the midpoint deviation exceeds the tolerance, within
+/// `params.max_triangles`.
 #[allow(clippy::too_many_arguments)]
 fn tessellate_surface(
     surface: &dyn Surface,
@@ -571,18 +1079,7 @@ fn tessellate_surface(
             tessellate_uv_grid(surface, u_min, u_max, v_min, v_max, n_u, n_v, same_sense)
         }
         TessellationMode::Adaptive => {
-            let base = params.min_segments;
-            tessellate_uv_adaptive(
-                surface,
-                u_min,
-                u_max,
-                v_min,
-                v_max,
-                base,
-                base,
-                same_sense,
-                params.tolerance,
-            )
+            tessellate_uv_adaptive(surface, u_min, u_max, v_min, v_max, same_sense, params)
         }
     }
 }
@@ -593,10 +1090,14 @@ fn tessellate_surface(
 /// yields up to 64× the base resolution in each direction.
 const MAX_ADAPTIVE_DEPTH: usize = 6;
 
-/// Tessellates a parametric surface using adaptive midpoint subdivision.
+/// Tessellates a surface by adaptive best-first subdivision of a base grid.
 ///
-/// Starts with a `base_n_u × base_n_v` grid and recursively subdivides cells
-/// whose midpoint deviation from bilinear interpolation exceeds `tolerance`.
+/// Cells are kept in a priority queue keyed by midpoint deviation, so the
+/// worst-approximated cell is always refined next. A cell is split into 4
+/// while its deviation exceeds the tolerance, it is shallower than
+/// [`MAX_ADAPTIVE_DEPTH`] and the split fits `params.max_triangles`; every
+/// other cell is emitted as 2 triangles. The base grid is shrunk when it
+/// alone would exceed the budget, so the whole UV domain is always covered.
 #[allow(clippy::too_many_arguments, clippy::similar_names)]
 fn tessellate_uv_adaptive(
     surface: &dyn Surface,
@@ -604,11 +1105,11 @@ fn tessellate_uv_adaptive(
     u_max: f64,
     v_min: f64,
     v_max: f64,
-    base_n_u: usize,
-    base_n_v: usize,
     same_sense: bool,
-    tolerance: f64,
+    params: &TessellationParams,
 ) -> Result<TriangleMesh> {
+    let budget = params.max_triangles.unwrap_or(usize::MAX);
+    let (base_n_u, base_n_v) = fit_base_grid(params.min_segments, params.min_segments, budget);
     let mut mesh = TriangleMesh::default();
     let mut vertex_cache: HashMap<(u64, u64), u32> = HashMap::new();
 
@@ -617,6 +1118,7 @@ fn tessellate_uv_adaptive(
     #[allow(clippy::cast_precision_loss)]
     let dv = (v_max - v_min) / base_n_v as f64;
 
+    let mut queue = BinaryHeap::with_capacity(base_n_u * base_n_v);
     for iv in 0..base_n_v {
         #[allow(clippy::cast_precision_loss)]
         let cv0 = v_min + dv * iv as f64;
@@ -627,123 +1129,140 @@ fn tessellate_uv_adaptive(
             let cu0 = u_min + du * iu as f64;
             #[allow(clippy::cast_precision_loss)]
             let cu1 = u_min + du * (iu + 1) as f64;
-            subdivide_cell(
-                surface,
-                cu0,
-                cu1,
-                cv0,
-                cv1,
-                same_sense,
-                tolerance,
-                0,
-                &mut mesh,
-                &mut vertex_cache,
-            )?;
+            queue.push(AdaptiveCell::new(surface, cu0, cu1, cv0, cv1, 0)?);
+        }
+    }
+
+    // Splitting a cell replaces its 2 triangles with 8.
+    let mut triangles = 2 * queue.len();
+    while let Some(cell) = queue.pop() {
+        if cell.deviation > params.tolerance
+            && cell.depth < MAX_ADAPTIVE_DEPTH
+            && triangles + 6 <= budget
+        {
+            triangles += 6;
+            let AdaptiveCell { u0, u1, v0, v1, .. } = cell;
+            let (mid_u, mid_v) = (f64::midpoint(u0, u1), f64::midpoint(v0, v1));
+            let depth = cell.depth + 1;
+            queue.push(AdaptiveCell::new(surface, u0, mid_u, v0, mid_v, depth)?);
+            queue.push(AdaptiveCell::new(surface, mid_u, u1, v0, mid_v, depth)?);
+            queue.push(AdaptiveCell::new(surface, u0, mid_u, mid_v, v1, depth)?);
+            queue.push(AdaptiveCell::new(surface, mid_u, u1, mid_v, v1, depth)?);
+        } else {
+            emit_cell(surface, &cell, same_sense, &mut mesh, &mut vertex_cache)?;
         }
     }
 
     Ok(mesh)
 }
 
-/// Recursively subdivides a UV cell if its midpoint deviation exceeds the tolerance.
-///
-/// If the surface midpoint deviates from the bilinear interpolation of the 4 corners
-/// by more than `tolerance`, the cell is split into 4 sub-cells. Otherwise, 2 triangles
-/// are emitted for the cell.
-#[allow(clippy::too_many_arguments)]
-fn subdivide_cell(
-    surface: &dyn Surface,
+/// Shrinks an `n_u × n_v` base grid until its 2 triangles per cell fit
+/// `budget`, reducing the larger dimension first and stopping at `1 × 1`.
+fn fit_base_grid(mut n_u: usize, mut n_v: usize, budget: usize) -> (usize, usize) {
+    n_u = n_u.max(1);
+    n_v = n_v.max(1);
+    while 2 * n_u * n_v > budget && (n_u > 1 || n_v > 1) {
+        if n_u >= n_v {
+            n_u -= 1;
+        } else {
+            n_v -= 1;
+        }
+    }
+    (n_u, n_v)
+}
+
+/// A UV cell awaiting refinement, ordered by its midpoint deviation.
+#[derive(Debug, Clone, Copy)]
+struct AdaptiveCell {
     u0: f64,
     u1: f64,
     v0: f64,
     v1: f64,
-    same_sense: bool,
-    tolerance: f64,
     depth: usize,
-    mesh: &mut TriangleMesh,
-    cache: &mut HashMap<(u64, u64), u32>,
-) -> Result<()> {
-    let mid_u = f64::midpoint(u0, u1);
-    let mid_v = f64::midpoint(v0, v1);
-
-    let p00 = surface.evaluate(u0, v0)?;
-    let p10 = surface.evaluate(u1, v0)?;
-    let p01 = surface.evaluate(u0, v1)?;
-    let p11 = surface.evaluate(u1, v1)?;
-    let actual_mid = surface.evaluate(mid_u, mid_v)?;
-
-    let bilinear_mid = crate::math::Point3::new(
-        (p00.x + p10.x + p01.x + p11.x) / 4.0,
-        (p00.y + p10.y + p01.y + p11.y) / 4.0,
-        (p00.z + p10.z + p01.z + p11.z) / 4.0,
-    );
-
-    let deviation = (actual_mid - bilinear_mid).norm();
-
-    if deviation > tolerance && depth < MAX_ADAPTIVE_DEPTH {
-        subdivide_cell(
-            surface,
+    /// Distance between the surface midpoint and the bilinear
+    /// interpolation of the 4 corners.
+    deviation: f64,
+}
+
+impl AdaptiveCell {
+    fn new(
+        surface: &dyn Surface,
+        u0: f64,
+        u1: f64,
+        v0: f64,
+        v1: f64,
+        depth: usize,
+    ) -> Result<Self> {
+        let p00 = surface.evaluate(u0, v0)?;
+        let p10 = surface.evaluate(u1, v0)?;
+        let p01 = surface.evaluate(u0, v1)?;
+        let p11 = surface.evaluate(u1, v1)?;
+        let actual_mid = surface.evaluate(f64::midpoint(u0, u1), f64::midpoint(v0, v1))?;
+
+        let bilinear_mid = crate::math::Point3::new(
+            (p00.x + p10.x + p01.x + p11.x) / 4.0,
+            (p00.y + p10.y + p01.y + p11.y) / 4.0,
+            (p00.z + p10.z + p01.z + p11.z) / 4.0,
+        );
+
+        Ok(Self {
             u0,
-            mid_u,
-            v0,
-            mid_v,
-            same_sense,
-            tolerance,
-            depth + 1,
-            mesh,
-            cache,
-        )?;
-        subdivide_cell(
-            surface,
-            mid_u,
             u1,
             v0,
-            mid_v,
-            same_sense,
-            tolerance,
-            depth + 1,
-            mesh,
-            cache,
-        )?;
-        subdivide_cell(
-            surface,
-            u0,
-            mid_u,
-            mid_v,
-            v1,
-            same_sense,
-            tolerance,
-            depth + 1,
-            mesh,
-            cache,
-        )?;
-        subdivide_cell(
-            surface,
-            mid_u,
-            u1,
-            mid_v,
             v1,
-            same_sense,
-            tolerance,
-            depth + 1,
-            mesh,
-            cache,
-        )?;
-    } else {
-        let i00 = get_or_insert_vertex(mesh, cache, surface, u0, v0, same_sense)?;
-        let i10 = get_or_insert_vertex(mesh, cache, surface, u1, v0, same_sense)?;
-        let i01 = get_or_insert_vertex(mesh, cache, surface, u0, v1, same_sense)?;
-        let i11 = get_or_insert_vertex(mesh, cache, surface, u1, v1, same_sense)?;
-
-        if same_sense {
-            mesh.indices.push([i00, i10, i11]);
-            mesh.indices.push([i00, i11, i01]);
-        } else {
-            mesh.indices.push([i00, i11, i10]);
-            mesh.indices.push([i00, i01, i11]);
-        }
+            depth,
+            deviation: (actual_mid - bilinear_mid).norm(),
+        })
+    }
+}
+
+impl PartialEq for AdaptiveCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for AdaptiveCell {}
+
+impl PartialOrd for AdaptiveCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
+}
 
+impl Ord for AdaptiveCell {
+    /// Orders by deviation; ties pop shallowest first, then by lowest
+    /// `(v0, u0)`, so the refinement and emission order never depends on
+    /// the heap's internal tie handling.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deviation
+            .total_cmp(&other.deviation)
+            .then_with(|| other.depth.cmp(&self.depth))
+            .then_with(|| other.v0.total_cmp(&self.v0))
+            .then_with(|| other.u0.total_cmp(&self.u0))
+    }
+}
+
+/// Emits the 2 triangles of a UV cell, sharing corner vertices via `cache`.
+fn emit_cell(
+    surface: &dyn Surface,
+    cell: &AdaptiveCell,
+    same_sense: bool,
+    mesh: &mut TriangleMesh,
+    cache: &mut HashMap<(u64, u64), u32>,
+) -> Result<()> {
+    let i00 = get_or_insert_vertex(mesh, cache, surface, cell.u0, cell.v0, same_sense)?;
+    let i10 = get_or_insert_vertex(mesh, cache, surface, cell.u1, cell.v0, same_sense)?;
+    let i01 = get_or_insert_vertex(mesh, cache, surface, cell.u0, cell.v1, same_sense)?;
+    let i11 = get_or_insert_vertex(mesh, cache, surface, cell.u1, cell.v1, same_sense)?;
+
+    if same_sense {
+        mesh.indices.push([i00, i10, i11]);
+        mesh.indices.push([i00, i11, i01]);
+    } else {
+        mesh.indices.push([i00, i11, i10]);
+        mesh.indices.push([i00, i01, i11]);
+    }
     Ok(())
 }
 
@@ -843,7 +1362,8 @@ fn wire_points_from_cache(
 /// Collects 3D points from a wire, tessellating curved edges into polylines.
 ///
 /// For Line edges, only the start point is included (avoiding duplicates).
-/// For Circle/Arc/Ellipse edges, intermediate points are sampled along the curve.
+/// For Circle/Arc/Ellipse edges, intermediate points are sampled along the
+/// curve, honouring the edge's own tessellation tolerance when set.
 fn collect_wire_points_tessellated(
     store: &TopologyStore,
     wire_id: crate::topology::WireId,
@@ -862,6 +1382,7 @@ fn collect_wire_points_tessellated(
         } else {
             (edge.t_end, edge.t_start)
         };
+        let params = &params.for_edge(edge);
 
         match &edge.curve {
             EdgeCurve::Line(line) => {
@@ -924,7 +1445,11 @@ fn add_curve_samples(
 }
 
 /// Inserts a closed polygon as constraint edges into the CDT.
-fn insert_constraint_loop(
+///
+/// A constraint crossing an earlier one is split at the crossing rather
+/// than rejected, so a self-intersecting loop triangulates (even-odd, via
+/// [`classify_interior_faces`]) instead of aborting.
+pub(crate) fn insert_constraint_loop(
     cdt: &mut ConstrainedDelaunayTriangulation<SpadePoint2<f64>>,
     points: &[SpadePoint2<f64>],
 ) -> Result<()> {
@@ -946,7 +1471,7 @@ fn insert_constraint_loop(
         let from = handles[i];
         let to = handles[(i + 1) % handles.len()];
         if from != to {
-            cdt.add_constraint(from, to);
+            cdt.add_constraint_and_split(from, to, |p| p);
         }
     }
 
@@ -1016,6 +1541,7 @@ pub(crate) fn classify_interior_faces(
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::geometry::surface::Plane;
     use crate::math::Point3;
     use crate::operations::creation::{MakeFace, MakeWire};
 
@@ -1031,6 +1557,30 @@ mod tests {
         MakeFace::new(wire, vec![]).execute(store).unwrap()
     }
 
+    #[test]
+    fn self_intersecting_loop_triangulates_even_odd() {
+        // Lopsided bow tie whose edges cross at (1.2, 1.2): crossing
+        // constraints are split there instead of panicking inside the CDT.
+        let mut store = crate::topology::TopologyStore::new();
+        let face = make_face_from_points(
+            &mut store,
+            vec![p(0.0, 0.0), p(3.0, 3.0), p(3.0, 0.0), p(0.0, 2.0)],
+        );
+        let mesh = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        let area: f64 = mesh
+            .indices
+            .iter()
+            .map(|tri| {
+                let [a, b, c] = tri.map(|i| mesh.vertices[i as usize]);
+                (b - a).cross(&(c - a)).norm() / 2.0
+            })
+            .sum();
+        // Lobes (0,0)-(1.2,1.2)-(0,2) and (1.2,1.2)-(3,3)-(3,0).
+        assert!((area - (1.2 + 2.7)).abs() < 1e-9, "{area}");
+    }
+
     #[test]
     fn triangle_produces_1_triangle() {
         let mut store = crate::topology::TopologyStore::new();
@@ -1058,6 +1608,41 @@ mod tests {
         assert_eq!(mesh.vertices.len(), 4);
     }
 
+    #[test]
+    fn normalized_planar_uvs_span_unit_square() {
+        let mut store = crate::topology::TopologyStore::new();
+        let face = make_face_from_points(
+            &mut store,
+            vec![p(1.0, 2.0), p(5.0, 2.0), p(5.0, 6.0), p(1.0, 6.0)],
+        );
+        let params = TessellationParams {
+            planar_uv: PlanarUv::Normalized,
+            ..TessellationParams::default()
+        };
+        let mesh = TessellateFace::new(face, params).execute(&store).unwrap();
+        let (mut lo, mut hi) = (
+            Point2::new(f64::MAX, f64::MAX),
+            Point2::new(f64::MIN, f64::MIN),
+        );
+        for uv in &mesh.uvs {
+            lo = lo.inf(uv);
+            hi = hi.sup(uv);
+        }
+        assert_eq!((lo.x, lo.y, hi.x, hi.y), (0.0, 0.0, 1.0, 1.0));
+
+        let params = TessellationParams {
+            planar_uv: PlanarUv::Scaled(0.5),
+            ..TessellationParams::default()
+        };
+        let scaled = TessellateFace::new(face, params).execute(&store).unwrap();
+        let world = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        for (s, w) in scaled.uvs.iter().zip(&world.uvs) {
+            assert!((s.coords * 2.0 - w.coords).norm() < 1e-12);
+        }
+    }
+
     #[test]
     fn l_shape_concave_tessellates() {
         let mut store = crate::topology::TopologyStore::new();
@@ -1143,6 +1728,45 @@ mod tests {
     use crate::topology::{EdgeCurve, EdgeData, FaceData, OrientedEdge, VertexData, WireData};
     use std::f64::consts::TAU;
 
+    #[test]
+    fn full_turn_allows_loose_circle_edges_only() {
+        let mut store = crate::topology::TopologyStore::new();
+        let v = store.add_vertex(VertexData::new(Point3::new(1.0, 0.0, 0.0)));
+        let circle = Circle::new(Point3::origin(), 1.0, Vector3::z(), Vector3::x()).unwrap();
+        let edge = |curve, sweep| EdgeData {
+            start: v,
+            end: v,
+            curve,
+            t_start: 0.0,
+            t_end: sweep,
+            tessellation_tolerance: None,
+        };
+        let arc = |sweep| {
+            EdgeCurve::Arc(
+                crate::geometry::curve::Arc::new(
+                    Point3::origin(),
+                    1.0,
+                    Vector3::z(),
+                    Vector3::x(),
+                    0.0,
+                    sweep,
+                )
+                .unwrap(),
+            )
+        };
+
+        // A circle edge closed from slightly rounded parameters still
+        // counts as a full turn; an arc that short does not.
+        let short = TAU - 0.005;
+        assert!(is_full_turn(&edge(
+            EdgeCurve::Circle(circle.clone()),
+            short
+        )));
+        assert!(!is_full_turn(&edge(arc(short), short)));
+        assert!(is_full_turn(&edge(arc(TAU), TAU)));
+        assert!(!is_full_turn(&edge(EdgeCurve::Circle(circle), TAU - 0.02)));
+    }
+
     /// Helper: creates a cylindrical face with a wire of 4 vertices
     /// spanning the cylinder from u=0..TAU, v=0..height.
     fn make_cylinder_face(
@@ -1178,6 +1802,7 @@ mod tests {
             curve: EdgeCurve::Circle(bottom_circle),
             t_start: 0.0,
             t_end: TAU,
+            tessellation_tolerance: None,
         });
         let e_top = store.add_edge(EdgeData {
             start: v1,
@@ -1185,6 +1810,7 @@ mod tests {
             curve: EdgeCurve::Circle(top_circle),
             t_start: 0.0,
             t_end: TAU,
+            tessellation_tolerance: None,
         });
         let e_seam = store.add_edge(EdgeData {
             start: v0,
@@ -1198,6 +1824,7 @@ mod tests {
             ),
             t_start: 0.0,
             t_end: height,
+            tessellation_tolerance: None,
         });
 
         let wire = store.add_wire(WireData {
@@ -1217,6 +1844,7 @@ mod tests {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         })
     }
 
@@ -1232,6 +1860,72 @@ mod tests {
         assert_eq!(mesh.vertices.len(), mesh.uvs.len());
     }
 
+    #[test]
+    fn cylinder_face_hole_is_cut_out() {
+        let mut store = crate::topology::TopologyStore::new();
+        let face = make_cylinder_face(&mut store, 2.0, 5.0);
+        let full = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+
+        // Square hole spanning u ∈ [1.0, 1.6], v ∈ [2.0, 3.0], straight edges
+        // between corners lying on the cylinder.
+        let hole_uv = [(1.0, 2.0), (1.6, 2.0), (1.6, 3.0), (1.0, 3.0)];
+        let corners: Vec<_> = hole_uv
+            .iter()
+            .map(|&(u, v): &(f64, f64)| {
+                let pt = Point3::new(2.0 * u.cos(), 2.0 * u.sin(), v);
+                (pt, store.add_vertex(VertexData::new(pt)))
+            })
+            .collect();
+        let edges = (0..4)
+            .map(|i| {
+                let ((a, va), (b, vb)) = (corners[i], corners[(i + 1) % 4]);
+                let e = store.add_edge(EdgeData {
+                    start: va,
+                    end: vb,
+                    curve: EdgeCurve::Line(crate::geometry::curve::Line::new(a, b - a).unwrap()),
+                    t_start: 0.0,
+                    t_end: (b - a).norm(),
+                    tessellation_tolerance: None,
+                });
+                OrientedEdge::new(e, true)
+            })
+            .collect();
+        let hole = store.add_wire(WireData {
+            edges,
+            is_closed: true,
+        });
+        store.face_mut(face).unwrap().inner_wires.push(hole);
+
+        let mesh = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        assert!(mesh.indices.len() < full.indices.len());
+        // The cut leaves no orphan vertices behind.
+        assert!(mesh.vertices.len() < full.vertices.len());
+        assert_eq!(mesh.vertices.len(), mesh.normals.len());
+        assert_eq!(mesh.vertices.len(), mesh.uvs.len());
+        let mut used = vec![false; mesh.vertices.len()];
+        for &i in mesh.indices.iter().flatten() {
+            used[i as usize] = true;
+        }
+        assert!(used.iter().all(|&u| u));
+        // Test a hair inside to stay clear of the hole boundary itself.
+        let (u0, u1, v0, v1) = (1.0 + 1e-3, 1.6 - 1e-3, 2.0 + 1e-3, 3.0 - 1e-3);
+        for tri in &mesh.indices {
+            let c = tri.iter().fold(Point2::origin(), |acc, &i| {
+                acc + mesh.uvs[i as usize].coords / 3.0
+            });
+            let u = c.x.rem_euclid(TAU);
+            assert!(
+                !(u > u0 && u < u1 && c.y > v0 && c.y < v1),
+                "triangle centroid ({u}, {}) inside the hole",
+                c.y
+            );
+        }
+    }
+
     #[test]
     fn cylinder_normals_point_outward() {
         let mut store = crate::topology::TopologyStore::new();
@@ -1253,11 +1947,22 @@ mod tests {
 
     /// Helper: creates a full sphere face (u=0..TAU, v=-PI/2..PI/2).
     fn make_sphere_face(store: &mut crate::topology::TopologyStore, radius: f64) -> FaceId {
-        let sph = Sphere::new(Point3::origin(), radius, Vector3::z(), Vector3::x()).unwrap();
+        make_sphere_face_about(store, radius, Vector3::z(), Vector3::x())
+    }
+
+    /// Helper: a full sphere face about the origin with the given axis.
+    fn make_sphere_face_about(
+        store: &mut crate::topology::TopologyStore,
+        radius: f64,
+        axis: Vector3,
+        ref_dir: Vector3,
+    ) -> FaceId {
+        let sph = Sphere::new(Point3::origin(), radius, axis, ref_dir).unwrap();
+        let axis = axis.normalize();
 
         // For a full sphere, we need a wire. Use two poles + two meridian seams.
-        let south = Point3::new(0.0, 0.0, -radius);
-        let north = Point3::new(0.0, 0.0, radius);
+        let south = Point3::from(-axis * radius);
+        let north = Point3::from(axis * radius);
         let v_south = store.add_vertex(VertexData::new(south));
         let v_north = store.add_vertex(VertexData::new(north));
 
@@ -1269,6 +1974,7 @@ mod tests {
             curve: EdgeCurve::Line(crate::geometry::curve::Line::new(south, seam_dir).unwrap()),
             t_start: 0.0,
             t_end: seam_dir.norm(),
+            tessellation_tolerance: None,
         });
 
         let wire = store.add_wire(WireData {
@@ -1286,6 +1992,7 @@ mod tests {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         })
     }
 
@@ -1318,6 +2025,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn tilted_sphere_pole_normals_follow_axis() {
+        let mut store = crate::topology::TopologyStore::new();
+        let axis = Vector3::new(1.0, 1.0, 1.0).normalize();
+        let ref_dir = Vector3::new(1.0, -1.0, 0.0).normalize();
+        let face = make_sphere_face_about(&mut store, 2.0, axis, ref_dir);
+        let mesh = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        let mut poles = 0;
+        for (v, n) in mesh.vertices.iter().zip(&mesh.normals) {
+            for pole in [axis, -axis] {
+                if (v.coords - pole * 2.0).norm() < 1e-9 {
+                    poles += 1;
+                    assert!((n - pole).norm() < 1e-9, "pole normal {n:?}");
+                }
+            }
+        }
+        assert!(poles >= 2);
+    }
+
     /// Helper: creates a full torus face.
     fn make_torus_face(
         store: &mut crate::topology::TopologyStore,
@@ -1352,6 +2080,7 @@ mod tests {
             ),
             t_start: 0.0,
             t_end: TAU,
+            tessellation_tolerance: None,
         });
 
         let wire = store.add_wire(WireData {
@@ -1366,6 +2095,7 @@ mod tests {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         })
     }
 
@@ -1399,6 +2129,28 @@ mod tests {
         assert_eq!(mesh.vertices.len(), mesh.uvs.len());
     }
 
+    #[test]
+    fn adaptive_sphere_is_reproducible() {
+        let mut store = crate::topology::TopologyStore::new();
+        let face = make_sphere_face(&mut store, 3.0);
+        let params = TessellationParams {
+            tolerance: 0.01,
+            mode: TessellationMode::Adaptive,
+            ..TessellationParams::default()
+        };
+        let bits = |mesh: &TriangleMesh| -> Vec<u64> {
+            mesh.vertices
+                .iter()
+                .flat_map(|p| p.iter().map(|c| c.to_bits()).collect::<Vec<_>>())
+                .collect()
+        };
+        let a = TessellateFace::new(face, params).execute(&store).unwrap();
+        let b = TessellateFace::new(face, params).execute(&store).unwrap();
+        assert!(!a.indices.is_empty());
+        assert_eq!(bits(&a), bits(&b));
+        assert_eq!(a.indices, b.indices);
+    }
+
     #[test]
     fn adaptive_cylinder_tessellates() {
         let mut store = crate::topology::TopologyStore::new();
@@ -1438,6 +2190,9 @@ mod tests {
             min_segments: 4,
             max_segments: 256,
             mode: TessellationMode::Default,
+            max_triangles: None,
+            normal_policy: NormalPolicy::Smooth,
+            planar_uv: PlanarUv::WorldUnits,
         };
         let default_mesh = TessellateFace::new(face, coarse).execute(&store).unwrap();
 
@@ -1446,6 +2201,9 @@ mod tests {
             min_segments: 4,
             max_segments: 256,
             mode: TessellationMode::Adaptive,
+            max_triangles: None,
+            normal_policy: NormalPolicy::Smooth,
+            planar_uv: PlanarUv::WorldUnits,
         };
         let adaptive_mesh = TessellateFace::new(face, adaptive).execute(&store).unwrap();
 
@@ -1460,6 +2218,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn adaptive_sphere_respects_triangle_budget() {
+        let mut store = crate::topology::TopologyStore::new();
+        let solid = crate::operations::creation::MakeSphere::new(Point3::origin(), 3.0)
+            .execute(&mut store)
+            .unwrap();
+        let face = store
+            .shell(store.solid(solid).unwrap().outer_shell)
+            .unwrap()
+            .faces[0];
+        let uv_area = |mesh: &TriangleMesh| -> f64 {
+            mesh.indices
+                .iter()
+                .map(|t| {
+                    let [a, b, c] = t.map(|i| mesh.uvs[i as usize]);
+                    ((b - a).perp(&(c - a)) / 2.0).abs()
+                })
+                .sum()
+        };
+
+        let unlimited = TessellationParams {
+            tolerance: 0.001,
+            mode: TessellationMode::Adaptive,
+            ..TessellationParams::default()
+        };
+        let full = TessellateFace::new(face, unlimited)
+            .execute(&store)
+            .unwrap();
+        let full_area = uv_area(&full);
+        assert!(full_area > 1.0);
+
+        for budget in [10, 100, 500] {
+            assert!(full.indices.len() > budget);
+            let params = TessellationParams {
+                max_triangles: Some(budget),
+                ..unlimited
+            };
+            let mesh = TessellateFace::new(face, params).execute(&store).unwrap();
+            assert!(
+                mesh.indices.len() <= budget,
+                "budget {budget}: {} triangles",
+                mesh.indices.len()
+            );
+            // The cells still tile the whole UV domain.
+            let area = uv_area(&mesh);
+            assert!(
+                (area - full_area).abs() < 1e-9,
+                "budget {budget}: uv area {area} vs {full_area}"
+            );
+        }
+    }
+
     #[test]
     fn adaptive_sphere_normals_outward() {
         let mut store = crate::topology::TopologyStore::new();
@@ -1585,4 +2395,409 @@ mod tests {
         assert_eq!(mesh.indices.len(), mesh2.indices.len());
         assert_eq!(mesh.vertices.len(), mesh2.vertices.len());
     }
+
+    #[test]
+    fn ruled_surface_between_parallel_lines_tessellates_flat() {
+        use crate::geometry::curve::Curve;
+        use crate::geometry::nurbs::{KnotVector, NurbsCurve3D};
+        use crate::geometry::surface::RuledSurface;
+        use std::sync::Arc;
+
+        let segment = |a: Point3, b: Point3| -> Arc<dyn Curve> {
+            Arc::new(
+                NurbsCurve3D::from_unweighted(
+                    vec![a, b],
+                    KnotVector::new(vec![0.0, 0.0, 1.0, 1.0]).unwrap(),
+                    1,
+                )
+                .unwrap(),
+            )
+        };
+        // Two parallel lines in the plane x + z = 1.
+        let ruled = RuledSurface::new(
+            segment(Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0)),
+            segment(Point3::new(0.0, 2.0, 1.0), Point3::new(1.0, 2.0, 0.0)),
+        )
+        .unwrap();
+        let d = ruled.domain();
+        let mesh = tessellate_surface(
+            &ruled,
+            d.u_min,
+            d.u_max,
+            d.v_min,
+            d.v_max,
+            4,
+            4,
+            true,
+            &TessellationParams::default(),
+        )
+        .unwrap();
+
+        assert!(!mesh.indices.is_empty());
+        let plane_n = Vector3::new(1.0, 0.0, 1.0).normalize();
+        for (p, n) in mesh.vertices.iter().zip(&mesh.normals) {
+            assert!((p.x + p.z - 1.0).abs() < 1e-12, "off-plane vertex {p:?}");
+            assert!(n.cross(&plane_n).norm() < 1e-6, "normal {n:?}");
+        }
+    }
+
+    #[test]
+    #[allow(clippy::many_single_char_names)]
+    fn extruded_arc_face_tessellates_with_outward_normals() {
+        use crate::geometry::curve::Arc as ArcCurve;
+        use crate::geometry::surface::ExtrudedSurface;
+        use std::f64::consts::FRAC_PI_2;
+
+        let mut store = crate::topology::TopologyStore::new();
+        let (r, h) = (2.0, 3.0);
+        let arc_at = |z: f64| {
+            ArcCurve::new(
+                Point3::new(0.0, 0.0, z),
+                r,
+                Vector3::z(),
+                Vector3::x(),
+                0.0,
+                FRAC_PI_2,
+            )
+            .unwrap()
+        };
+        let surface =
+            ExtrudedSurface::new(std::sync::Arc::new(arc_at(0.0)), Vector3::new(0.0, 0.0, h))
+                .unwrap();
+
+        let corners = [
+            Point3::new(r, 0.0, 0.0),
+            Point3::new(0.0, r, 0.0),
+            Point3::new(0.0, r, h),
+            Point3::new(r, 0.0, h),
+        ];
+        let v: Vec<_> = corners
+            .iter()
+            .map(|&p| store.add_vertex(VertexData::new(p)))
+            .collect();
+        let line = |a: Point3, b: Point3| {
+            EdgeCurve::Line(crate::geometry::curve::Line::new(a, b - a).unwrap())
+        };
+        let edges = [
+            (v[0], v[1], EdgeCurve::Arc(arc_at(0.0)), 0.0, FRAC_PI_2),
+            (v[1], v[2], line(corners[1], corners[2]), 0.0, h),
+            (v[3], v[2], EdgeCurve::Arc(arc_at(h)), 0.0, FRAC_PI_2),
+            (v[0], v[3], line(corners[0], corners[3]), 0.0, h),
+        ];
+        let ids: Vec<_> = edges
+            .into_iter()
+            .map(|(start, end, curve, t_start, t_end)| {
+                store.add_edge(EdgeData {
+                    start,
+                    end,
+                    curve,
+                    t_start,
+                    t_end,
+                    tessellation_tolerance: None,
+                })
+            })
+            .collect();
+        let wire = store.add_wire(WireData {
+            edges: vec![
+                OrientedEdge::new(ids[0], true),
+                OrientedEdge::new(ids[1], true),
+                OrientedEdge::new(ids[2], false),
+                OrientedEdge::new(ids[3], false),
+            ],
+            is_closed: true,
+        });
+        let face = store.add_face(FaceData {
+            surface: FaceSurface::Extruded(surface),
+            outer_wire: wire,
+            inner_wires: vec![],
+            same_sense: true,
+            trim: None,
+            pcurves: Vec::new(),
+            u_seam: None,
+        });
+
+        let mesh = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        assert!(!mesh.indices.is_empty());
+        let (mut z_min, mut z_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for (p, n) in mesh.vertices.iter().zip(&mesh.normals) {
+            let radial = Vector3::new(p.x, p.y, 0.0);
+            assert!(
+                (radial.norm() - r).abs() < 1e-6,
+                "vertex off the arc: {p:?}"
+            );
+            assert!((n - radial / r).norm() < 1e-6, "normal {n:?} at {p:?}");
+            z_min = z_min.min(p.z);
+            z_max = z_max.max(p.z);
+        }
+        assert!(z_min.abs() < 1e-6 && (z_max - h).abs() < 1e-6);
+
+        // Triangle winding agrees with the outward normals.
+        for tri in &mesh.indices {
+            let [a, b, c] = tri.map(|i| mesh.vertices[i as usize]);
+            let wn = (b - a).cross(&(c - a));
+            let centroid = Vector3::new(a.x + b.x + c.x, a.y + b.y + c.y, 0.0);
+            assert!(wn.dot(&centroid) > 0.0);
+        }
+    }
+
+    #[test]
+    fn edge_tolerance_refines_only_that_edge() {
+        use crate::geometry::curve::Arc;
+        use std::f64::consts::PI;
+
+        let mut store = crate::topology::TopologyStore::new();
+        let a = store.add_vertex(VertexData::new(Point3::new(1.0, 0.0, 0.0)));
+        let b = store.add_vertex(VertexData::new(Point3::new(-1.0, 0.0, 0.0)));
+        let mut half = |start, end, t0: f64| {
+            let arc = Arc::new(
+                Point3::origin(),
+                1.0,
+                Vector3::z(),
+                Vector3::x(),
+                t0,
+                t0 + PI,
+            )
+            .unwrap();
+            store.add_edge(EdgeData {
+                start,
+                end,
+                curve: EdgeCurve::Arc(arc),
+                t_start: t0,
+                t_end: t0 + PI,
+                tessellation_tolerance: None,
+            })
+        };
+        let upper = half(a, b, 0.0);
+        let lower = half(b, a, PI);
+        let wire = store.add_wire(WireData {
+            edges: vec![
+                OrientedEdge::new(upper, true),
+                OrientedEdge::new(lower, true),
+            ],
+            is_closed: true,
+        });
+
+        let params = TessellationParams {
+            tolerance: 0.01,
+            ..TessellationParams::default()
+        };
+        let count = |store: &crate::topology::TopologyStore| {
+            let points = collect_wire_points_tessellated(store, wire, &params).unwrap();
+            (
+                points.iter().filter(|p| p.y > 1e-12).count(),
+                points.iter().filter(|p| p.y < -1e-12).count(),
+            )
+        };
+        let (upper_before, lower_before) = count(&store);
+        store.edge_mut(upper).unwrap().tessellation_tolerance = Some(1e-4);
+        let (upper_after, lower_after) = count(&store);
+        assert!(
+            upper_after > upper_before,
+            "{upper_before} -> {upper_after}"
+        );
+        assert_eq!(lower_after, lower_before);
+    }
+
+    // ── Normal policy tests ──────────────────────────────────────
+
+    #[test]
+    fn faceted_cylinder_has_per_facet_normals() {
+        let mut store = crate::topology::TopologyStore::new();
+        let face = make_cylinder_face(&mut store, 2.0, 5.0);
+        let faceted = TessellationParams {
+            tolerance: 0.1,
+            normal_policy: NormalPolicy::Faceted,
+            ..TessellationParams::default()
+        };
+        let mesh = TessellateFace::new(face, faceted).execute(&store).unwrap();
+        assert_eq!(mesh.vertices.len(), 3 * mesh.indices.len());
+        let mut facets: Vec<Vector3> = Vec::new();
+        for tri in &mesh.indices {
+            let n = mesh.normals[tri[0] as usize];
+            // All three corners share the facet normal, which is horizontal
+            // and points away from the axis.
+            for &i in tri {
+                assert!((mesh.normals[i as usize] - n).norm() < 1e-12);
+            }
+            let c = tri
+                .iter()
+                .map(|&i| mesh.vertices[i as usize].coords)
+                .sum::<Vector3>()
+                / 3.0;
+            assert!(n.z.abs() < 1e-9 && n.dot(&Vector3::new(c.x, c.y, 0.0)) > 0.0);
+            if !facets.iter().any(|f| (f - n).norm() < 1e-9) {
+                facets.push(n);
+            }
+        }
+        // One distinct normal per ring facet, not one per vertex.
+        assert!(facets.len() >= 8, "{} facets", facets.len());
+        assert!(facets.len() < mesh.indices.len());
+
+        let smooth = TessellationParams {
+            tolerance: 0.1,
+            ..TessellationParams::default()
+        };
+        let mesh = TessellateFace::new(face, smooth).execute(&store).unwrap();
+        assert!(mesh.vertices.len() < 3 * mesh.indices.len());
+        for (v, n) in mesh.vertices.iter().zip(&mesh.normals) {
+            let radial = Vector3::new(v.x, v.y, 0.0).normalize();
+            assert!((n - radial).norm() < 1e-9, "n={n:?} at {v:?}");
+        }
+    }
+
+    #[test]
+    fn exact_policy_uses_plane_normal() {
+        let mut store = crate::topology::TopologyStore::new();
+        let face = make_face_from_points(
+            &mut store,
+            vec![p(0.0, 0.0), p(4.0, 0.0), p(4.0, 4.0), p(0.0, 4.0)],
+        );
+        let params = TessellationParams {
+            normal_policy: NormalPolicy::Exact,
+            ..TessellationParams::default()
+        };
+        let mesh = TessellateFace::new(face, params).execute(&store).unwrap();
+        let plane = match &store.face(face).unwrap().surface {
+            FaceSurface::Plane(plane) => plane.clone(),
+            _ => unreachable!(),
+        };
+        for n in &mesh.normals {
+            assert_eq!(n, plane.plane_normal());
+        }
+
+        // A needle whose stored plane has gone stale meshes through the
+        // ear-clip fallback, which shades with the loop's averaged (Newell)
+        // normal; Exact replaces it with the face plane's normal.
+        let (needle, _) = stale_plane_needle(&mut store);
+        let stale_normal = match &store.face(needle).unwrap().surface {
+            FaceSurface::Plane(plane) => *plane.plane_normal(),
+            _ => unreachable!(),
+        };
+        let smooth = TessellateFace::new(needle, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        let exact = TessellateFace::new(needle, params).execute(&store).unwrap();
+        assert_eq!(smooth.vertices, exact.vertices);
+        for (averaged, n) in smooth.normals.iter().zip(&exact.normals) {
+            assert!(averaged.z.abs() > 1.0 - 1e-12, "{averaged:?}");
+            assert_eq!(*n, stale_normal);
+        }
+    }
+
+    /// A 1000:1 needle in z = 0 whose face plane has gone stale: seen
+    /// along y it projects onto a line, so the CDT has no interior and the
+    /// face can only mesh through the ear-clip fallback.
+    fn stale_plane_needle(store: &mut crate::topology::TopologyStore) -> (FaceId, [Point3; 3]) {
+        let needle = [p(0.0, 0.0), p(1000.0, 0.0), p(1000.0, 1.0)];
+        let face = make_face_from_points(store, needle.to_vec());
+        let stale = Plane::new(Point3::origin(), Vector3::x(), Vector3::z()).unwrap();
+        store.face_mut(face).unwrap().surface = FaceSurface::Plane(stale);
+        (face, needle)
+    }
+
+    #[test]
+    fn needle_collapsed_in_face_plane_falls_back_to_ear_clip() {
+        let mut store = crate::topology::TopologyStore::new();
+        let (face, needle) = stale_plane_needle(&mut store);
+
+        let mesh = TessellateFace::new(face, TessellationParams::default())
+            .execute(&store)
+            .unwrap();
+        assert_eq!(mesh.indices.len(), 1);
+        let [a, b, c] = mesh.indices[0].map(|i| mesh.vertices[i as usize]);
+        for q in needle {
+            assert!([a, b, c].contains(&q), "{q:?} missing");
+        }
+        let winding = (b - a).cross(&(c - a)).normalize();
+        assert!((winding - mesh.normals[0]).norm() < 1e-12);
+        assert!(winding.z.abs() > 1.0 - 1e-12);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn ear_clip_fallback_logs_a_warning() {
+        use std::io::Write;
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Captured(Arc<Mutex<Vec<u8>>>);
+        impl Write for Captured {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut store = crate::topology::TopologyStore::new();
+        let (face, _) = stale_plane_needle(&mut store);
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            TessellateFace::new(face, TessellationParams::default())
+                .execute(&store)
+                .unwrap();
+        });
+        let log = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        assert!(log.contains("WARN"), "{log}");
+        assert!(log.contains("ear-clipping the outer loop"), "{log}");
+    }
+
+    #[test]
+    fn ear_clip_fallback_gets_planar_post_processing() {
+        // The stale-plane needle again, with UV scaling and faceted normals.
+        let mut store = crate::topology::TopologyStore::new();
+        let (face, _) = stale_plane_needle(&mut store);
+
+        let params = TessellationParams {
+            planar_uv: PlanarUv::Normalized,
+            normal_policy: NormalPolicy::Faceted,
+            ..TessellationParams::default()
+        };
+        let mesh = TessellateFace::new(face, params).execute(&store).unwrap();
+        assert_eq!(mesh.indices.len(), 1);
+        let us: Vec<f64> = mesh.uvs.iter().map(|uv| uv.x).collect();
+        assert!(us.contains(&0.0) && us.contains(&1.0), "{us:?}");
+        let [a, b, c] = mesh.indices[0].map(|i| mesh.vertices[i as usize]);
+        let facet = (b - a).cross(&(c - a)).normalize();
+        for n in &mesh.normals {
+            assert!((n - facet).norm() < 1e-12, "{n:?}");
+        }
+    }
+
+    #[test]
+    fn ear_clip_covers_thin_concave_loop() {
+        // A 1000:1 sliver with a notch, given clockwise.
+        let pts: Vec<SpadePoint2<f64>> = [
+            (0.0, 0.0),
+            (0.0, 1.0),
+            (500.0, 0.4),
+            (1000.0, 1.0),
+            (1000.0, 0.0),
+        ]
+        .iter()
+        .map(|&(x, y)| SpadePoint2::new(x, y))
+        .collect();
+        let tris = ear_clip(&pts);
+        assert_eq!(tris.len(), 3);
+        let area: f64 = tris
+            .iter()
+            .map(|&[a, b, c]| {
+                let (pa, pb, pc) = (pts[a], pts[b], pts[c]);
+                let twice = (pb.x - pa.x) * (pc.y - pa.y) - (pb.y - pa.y) * (pc.x - pa.x);
+                assert!(twice > 0.0, "triangle must wind counter-clockwise");
+                twice / 2.0
+            })
+            .sum();
+        assert!((area - 700.0).abs() < 1e-9, "area={area}");
+        assert!(ear_clip(&pts[..2]).is_empty());
+    }
 }
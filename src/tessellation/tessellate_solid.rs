@@ -1,44 +1,242 @@
-use crate::error::Result;
-use crate::topology::{SolidId, TopologyStore};
+use std::collections::HashSet;
+
+use crate::error::{Result, TessellationError};
+use crate::topology::{FaceId, SolidId, TopologyStore};
 
 use super::edge_samples::EdgeSampleCache;
-use super::{TessellateFace, TessellationParams, TriangleMesh};
+use super::{
+    Polyline, TessellateFace, TessellationMode, TessellationParams, TriangleMesh, VertexDedup,
+};
 
 /// Tessellates all faces of a solid into a combined triangle mesh.
 pub struct TessellateSolid {
     solid: SolidId,
     params: TessellationParams,
+    dedup_tolerance: Option<f64>,
 }
 
 impl TessellateSolid {
     /// Creates a new `TessellateSolid` operation.
     #[must_use]
     pub fn new(solid: SolidId, params: TessellationParams) -> Self {
-        Self { solid, params }
+        Self {
+            solid,
+            params,
+            dedup_tolerance: None,
+        }
+    }
+
+    /// Shares one mesh index between face vertices closer than
+    /// `tolerance` (see [`VertexDedup`]), so a closed solid comes out as a
+    /// watertight mesh without a separate weld pass. Each face is appended
+    /// through [`TessellateFace::execute_into`].
+    ///
+    /// Off by default: shared vertices carry a single normal, which
+    /// smooths shading across sharp edges.
+    #[must_use]
+    pub fn with_vertex_dedup(mut self, tolerance: f64) -> Self {
+        self.dedup_tolerance = Some(tolerance);
+        self
     }
 
     /// Executes the tessellation, returning a combined triangle mesh.
     ///
     /// # Errors
     ///
-    /// Returns an error if the solid or any of its faces cannot be tessellated.
+    /// Returns an error if the solid or any of its faces cannot be
+    /// tessellated.
     pub fn execute(&self, store: &TopologyStore) -> Result<TriangleMesh> {
-        let solid = store.solid(self.solid)?;
-        let shell = store.shell(solid.outer_shell)?;
+        self.execute_with(store, |_, _| true)
+    }
+
+    /// Executes the tessellation, reporting progress after each face.
+    ///
+    /// `progress(done_faces, total_faces)` is called once per tessellated
+    /// face; returning `false` aborts the tessellation. Under an adaptive
+    /// `max_triangles`, faces that do not follow the budget are meshed and
+    /// reported first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TessellationError::Cancelled`] if `progress` returns
+    /// `false`, or an error if the solid or any of its faces cannot be
+    /// tessellated.
+    pub fn execute_with<F: FnMut(usize, usize) -> bool>(
+        &self,
+        store: &TopologyStore,
+        mut progress: F,
+    ) -> Result<TriangleMesh> {
+        let faces = solid_faces(store, self.solid)?;
 
-        // One edge-sample cache for the whole shell: faces sharing an edge
+        // One edge-sample cache for the whole solid: faces sharing an edge
         // consume the identical boundary polyline (structural conformance).
         let mut cache = EdgeSampleCache::new(self.params);
 
+        // Faces that ignore the budget are meshed (and reported) up front.
+        let total = faces.len();
+        let mut done = 0;
+        let mut fixed: Vec<Option<TriangleMesh>> = Vec::new();
+        fixed.resize_with(total, || None);
+        let mut budget = FaceBudget::plan(store, &faces, self.params, &mut cache, |i, mesh| {
+            fixed[i] = Some(mesh);
+            done += 1;
+            if progress(done, total) {
+                Ok(())
+            } else {
+                Err(TessellationError::Cancelled.into())
+            }
+        })?;
+
+        let mut dedup = self.dedup_tolerance.map(VertexDedup::new);
         let mut combined = TriangleMesh::default();
-        for &face_id in &shell.faces {
-            let face_mesh =
-                TessellateFace::new(face_id, self.params).execute_with_cache(store, &mut cache)?;
-            combined.merge(&face_mesh);
+        for (&face_id, fixed) in faces.iter().zip(fixed) {
+            if let Some(mesh) = fixed {
+                match dedup.as_mut() {
+                    Some(dedup) => dedup.merge_into(&mut combined, &mesh),
+                    None => combined.merge(&mesh),
+                }
+                continue;
+            }
+            let before = combined.indices.len();
+            let params = budget
+                .as_ref()
+                .map_or(self.params, |budget| budget.next_face_params(self.params));
+            let face = TessellateFace::new(face_id, params);
+            match dedup.as_mut() {
+                Some(dedup) => {
+                    face.execute_into_with_cache(store, &mut cache, dedup, &mut combined)?;
+                }
+                None => combined.merge(&face.execute_with_cache(store, &mut cache)?),
+            }
+            if let Some(budget) = budget.as_mut() {
+                budget.spend(combined.indices.len() - before);
+            }
+            done += 1;
+            if !progress(done, total) {
+                return Err(TessellationError::Cancelled.into());
+            }
         }
 
         Ok(combined)
     }
+
+    /// Tessellates every edge of `solid` into a wireframe polyline, once
+    /// per [`EdgeId`](crate::topology::EdgeId) across all of its shells.
+    ///
+    /// Straight edges become two-point polylines; curved edges are sampled
+    /// exactly as the face tessellation samples their boundaries, so the
+    /// wireframe lies on the mesh edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the solid's topology cannot be read or an edge
+    /// curve cannot be evaluated.
+    pub fn wireframe(
+        store: &TopologyStore,
+        solid: SolidId,
+        params: TessellationParams,
+    ) -> Result<Vec<Polyline>> {
+        let solid = store.solid(solid)?;
+        let mut cache = EdgeSampleCache::new(params);
+        let mut seen = HashSet::new();
+        let mut polylines = Vec::new();
+        for shell_id in std::iter::once(solid.outer_shell).chain(solid.inner_shells.iter().copied())
+        {
+            for &face_id in &store.shell(shell_id)?.faces {
+                let face = store.face(face_id)?;
+                for wire_id in
+                    std::iter::once(face.outer_wire).chain(face.inner_wires.iter().copied())
+                {
+                    for oe in &store.wire(wire_id)?.edges {
+                        if seen.insert(oe.edge) {
+                            polylines.push(Polyline {
+                                points: cache.get(store, oe.edge)?.points.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(polylines)
+    }
+}
+
+/// Faces of `solid`: the outer shell's, then each void shell's.
+///
+/// Void shell faces point into the cavity.
+pub(crate) fn solid_faces(store: &TopologyStore, solid: SolidId) -> Result<Vec<FaceId>> {
+    let solid = store.solid(solid)?;
+    let mut faces = Vec::new();
+    for shell_id in std::iter::once(solid.outer_shell).chain(solid.inner_shells.iter().copied()) {
+        faces.extend_from_slice(&store.shell(shell_id)?.faces);
+    }
+    Ok(faces)
+}
+
+/// A solid-wide [`TessellationParams::max_triangles`] split across faces.
+///
+/// Faces that ignore the budget are meshed first and their triangles come
+/// off the cap; each budgeted face in turn gets an even share of what is
+/// left. A share below a face's 2-triangle minimum still meshes at that
+/// minimum, so a cap below the floor yields the floor.
+pub(crate) struct FaceBudget {
+    pool: usize,
+    budgeted: usize,
+}
+
+impl FaceBudget {
+    /// Meshes the faces of `faces` that ignore the budget, handing each
+    /// mesh to `fixed(index, mesh)`, and plans the split for the rest.
+    ///
+    /// Returns `None` when `params` has no budget or is not in
+    /// [`TessellationMode::Adaptive`], where no face follows one.
+    pub(crate) fn plan<F>(
+        store: &TopologyStore,
+        faces: &[FaceId],
+        params: TessellationParams,
+        cache: &mut EdgeSampleCache,
+        mut fixed: F,
+    ) -> Result<Option<Self>>
+    where
+        F: FnMut(usize, TriangleMesh) -> Result<()>,
+    {
+        let Some(cap) = params
+            .max_triangles
+            .filter(|_| params.mode == TessellationMode::Adaptive)
+        else {
+            return Ok(None);
+        };
+        let mut fixed_triangles = 0;
+        let mut budgeted = 0;
+        for (i, &face_id) in faces.iter().enumerate() {
+            let face = TessellateFace::new(face_id, params);
+            if face.follows_triangle_budget(store)? {
+                budgeted += 1;
+            } else {
+                let mesh = face.execute_with_cache(store, cache)?;
+                fixed_triangles += mesh.indices.len();
+                fixed(i, mesh)?;
+            }
+        }
+        Ok(Some(Self {
+            pool: cap.saturating_sub(fixed_triangles),
+            budgeted,
+        }))
+    }
+
+    /// `params` for the next budgeted face, carrying its share of the pool.
+    pub(crate) fn next_face_params(&self, params: TessellationParams) -> TessellationParams {
+        TessellationParams {
+            max_triangles: Some(self.pool / self.budgeted.max(1)),
+            ..params
+        }
+    }
+
+    /// Records that a budgeted face emitted `triangles`.
+    pub(crate) fn spend(&mut self, triangles: usize) {
+        self.pool = self.pool.saturating_sub(triangles);
+        self.budgeted = self.budgeted.saturating_sub(1);
+    }
 }
 
 /// Squared distance from point `p` to segment `[a, b]`.
@@ -151,7 +349,59 @@ pub(crate) fn max_adjacent_boundary_deviation(store: &TopologyStore, solid: Soli
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
-    use crate::operations::creation::MakeCurvedSlab;
+    use crate::math::{Point3, Vector3};
+    use crate::operations::creation::{MakeBox, MakeCurvedSlab, MakeCylinder, MakeSphere};
+    use crate::tessellation::TessellationMode;
+    use crate::topology::FaceSurface;
+    use std::collections::HashMap;
+
+    #[test]
+    fn box_wireframe_has_one_segment_per_edge() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(1.0, 2.0, 3.0))
+            .execute(&mut store)
+            .unwrap();
+        let lines =
+            TessellateSolid::wireframe(&store, solid, TessellationParams::default()).unwrap();
+        assert_eq!(lines.len(), 12);
+        for line in &lines {
+            assert_eq!(line.points.len(), 2);
+            let d = line.points[1] - line.points[0];
+            // Box edges are axis-aligned with lengths 1, 2 or 3.
+            assert_eq!(d.iter().filter(|c| c.abs() > 1e-12).count(), 1);
+        }
+    }
+
+    #[test]
+    fn progress_callback_can_cancel() {
+        let mut store = TopologyStore::new();
+        let solid = MakeCurvedSlab::new(6.0, 0.0, 1.5, 1.0)
+            .execute(&mut store)
+            .unwrap();
+        let op = TessellateSolid::new(solid, TessellationParams::default());
+
+        let mut calls = Vec::new();
+        let mesh = op.execute_with(&store, |done, total| {
+            calls.push((done, total));
+            true
+        });
+        assert!(mesh.is_ok());
+        let total = calls[0].1;
+        assert_eq!(calls, (1..=total).map(|d| (d, total)).collect::<Vec<_>>());
+
+        let mut seen = 0;
+        let result = op.execute_with(&store, |done, _| {
+            seen = done;
+            done < 1
+        });
+        assert!(matches!(
+            result,
+            Err(crate::error::GeolisError::Tessellation(
+                TessellationError::Cancelled
+            ))
+        ));
+        assert_eq!(seen, 1);
+    }
 
     /// The plain curved slab's adjacent faces (curved top/bottom vs ruled side
     /// walls) now tessellate their shared boundary curves at identical
@@ -196,4 +446,206 @@ mod tests {
         let dev = max_adjacent_boundary_deviation(&store, solid);
         assert!(dev < 1e-6, "revolved cap/wall deviation {dev} exceeds 1e-6");
     }
+
+    #[test]
+    fn box_with_vertex_dedup_is_closed_manifold() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(1.0, 2.0, 3.0))
+            .execute(&mut store)
+            .unwrap();
+        let mesh = TessellateSolid::new(solid, TessellationParams::default())
+            .with_vertex_dedup(1e-9)
+            .execute(&store)
+            .unwrap();
+        mesh.validate().unwrap();
+        assert_eq!(mesh.vertices.len(), 8);
+
+        // Every directed edge appears once and its twin once: closed and
+        // consistently oriented.
+        let mut directed: HashMap<(u32, u32), usize> = HashMap::new();
+        for &[a, b, c] in &mesh.indices {
+            for e in [(a, b), (b, c), (c, a)] {
+                *directed.entry(e).or_default() += 1;
+            }
+        }
+        for (&(a, b), &count) in &directed {
+            assert_eq!(count, 1, "edge {a}->{b} used {count} times");
+            assert_eq!(directed.get(&(b, a)), Some(&1), "edge {a}->{b} has no twin");
+        }
+    }
+
+    #[test]
+    fn faces_appended_with_shared_dedup_match_solid() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(1.0, 2.0, 3.0))
+            .execute(&mut store)
+            .unwrap();
+        let params = TessellationParams::default();
+        let expected = TessellateSolid::new(solid, params)
+            .with_vertex_dedup(1e-9)
+            .execute(&store)
+            .unwrap();
+
+        // Driving TessellateFace directly gives the same welded mesh.
+        let shell = store.solid(solid).unwrap().outer_shell;
+        let mut dedup = VertexDedup::new(1e-9);
+        let mut mesh = TriangleMesh::default();
+        for &face in &store.shell(shell).unwrap().faces {
+            TessellateFace::new(face, params)
+                .execute_into(&store, &mut dedup, &mut mesh)
+                .unwrap();
+        }
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.vertices, expected.vertices);
+        assert_eq!(mesh.indices, expected.indices);
+    }
+
+    #[test]
+    fn triangle_budget_meshes_at_floor() {
+        let mut store = TopologyStore::new();
+        // Two planar caps are meshed in full; the curved side gets the rest.
+        let cylinder = MakeCylinder::new(Point3::origin(), 1.0, Vector3::z(), 2.0)
+            .execute(&mut store)
+            .unwrap();
+        let adaptive = TessellationParams {
+            tolerance: 1e-4,
+            mode: TessellationMode::Adaptive,
+            ..TessellationParams::default()
+        };
+        let faces = &store
+            .shell(store.solid(cylinder).unwrap().outer_shell)
+            .unwrap()
+            .faces;
+        let (planar, curved): (Vec<_>, Vec<_>) = faces
+            .iter()
+            .partition(|&&f| matches!(store.face(f).unwrap().surface, FaceSurface::Plane(_)));
+        let caps: usize = planar
+            .iter()
+            .map(|&&f| {
+                TessellateFace::new(f, adaptive)
+                    .execute(&store)
+                    .unwrap()
+                    .indices
+                    .len()
+            })
+            .sum();
+        let sides = curved.len();
+        let floor = caps + 2 * sides;
+
+        let params = |budget| TessellationParams {
+            max_triangles: Some(budget),
+            ..adaptive
+        };
+        let mesh = TessellateSolid::new(cylinder, params(floor))
+            .execute(&store)
+            .unwrap();
+        assert_eq!(mesh.indices.len(), floor);
+        // A cap below the floor still meshes, at the floor.
+        let mesh = TessellateSolid::new(cylinder, params(floor - 1))
+            .execute(&store)
+            .unwrap();
+        assert_eq!(mesh.indices.len(), floor);
+    }
+
+    #[test]
+    fn triangle_budget_ignored_outside_adaptive_mode() {
+        let mut store = TopologyStore::new();
+        let cylinder = MakeCylinder::new(Point3::origin(), 1.0, Vector3::z(), 2.0)
+            .execute(&mut store)
+            .unwrap();
+        let params = TessellationParams::default();
+        let full = TessellateSolid::new(cylinder, params)
+            .execute(&store)
+            .unwrap();
+        let capped = TessellateSolid::new(
+            cylinder,
+            TessellationParams {
+                max_triangles: Some(2),
+                ..params
+            },
+        )
+        .execute(&store)
+        .unwrap();
+        assert_eq!(capped.vertices, full.vertices);
+        assert_eq!(capped.indices, full.indices);
+    }
+
+    #[test]
+    fn triangle_budget_caps_whole_solid() {
+        let mut store = TopologyStore::new();
+        let solid = MakeSphere::new(Point3::origin(), 2.0)
+            .execute(&mut store)
+            .unwrap();
+        let unlimited = TessellationParams {
+            tolerance: 1e-4,
+            mode: TessellationMode::Adaptive,
+            ..TessellationParams::default()
+        };
+        let full = TessellateSolid::new(solid, unlimited)
+            .execute(&store)
+            .unwrap();
+
+        for budget in [40, 400] {
+            assert!(full.indices.len() > budget);
+            let params = TessellationParams {
+                max_triangles: Some(budget),
+                ..unlimited
+            };
+            let mesh = TessellateSolid::new(solid, params).execute(&store).unwrap();
+            assert!(
+                mesh.indices.len() <= budget,
+                "budget {budget}: {} triangles",
+                mesh.indices.len()
+            );
+            // Coarser, but still a closed surface around the whole sphere.
+            let volume = mesh.signed_volume(Point3::origin());
+            let full_volume = full.signed_volume(Point3::origin());
+            assert!(volume / full_volume > 0.3, "{volume} vs {full_volume}");
+        }
+    }
+
+    #[test]
+    fn triangle_budget_reports_and_cancels_fixed_faces() {
+        let mut store = TopologyStore::new();
+        let cylinder = MakeCylinder::new(Point3::origin(), 1.0, Vector3::z(), 2.0)
+            .execute(&mut store)
+            .unwrap();
+        let params = TessellationParams {
+            mode: TessellationMode::Adaptive,
+            max_triangles: Some(100_000),
+            ..TessellationParams::default()
+        };
+        let op = TessellateSolid::new(cylinder, params);
+
+        let mut calls = Vec::new();
+        op.execute_with(&store, |done, total| {
+            calls.push((done, total));
+            true
+        })
+        .unwrap();
+        let total = calls[0].1;
+        assert_eq!(calls, (1..=total).map(|d| (d, total)).collect::<Vec<_>>());
+
+        // The planar caps are meshed and reported before the budgeted side,
+        // so a cap can be cancelled before any budget is spent.
+        let tiny = TessellateSolid::new(
+            cylinder,
+            TessellationParams {
+                max_triangles: Some(2),
+                ..params
+            },
+        );
+        let mut seen = 0;
+        let result = tiny.execute_with(&store, |done, _| {
+            seen = done;
+            false
+        });
+        assert!(matches!(
+            result,
+            Err(crate::error::GeolisError::Tessellation(
+                TessellationError::Cancelled
+            ))
+        ));
+        assert_eq!(seen, 1);
+    }
 }
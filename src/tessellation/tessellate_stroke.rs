@@ -1,10 +1,17 @@
 use crate::error::{Result, TessellationError};
+use crate::geometry::pline::{Pline, PlineVertex};
+use crate::geometry::surface::Plane;
 use crate::math::{Point2, Point3, Vector3};
+use crate::operations::boolean_2d::{
+    signed_area, union_all_with_holes, Polygon, PolygonWithHoles, WALL_EPS_SQ,
+};
 
 use super::stroke_style::{LineJoin, StrokeStyle};
+use super::tessellate_with_holes::TessellateWithHoles;
 use super::TriangleMesh;
 
-/// When the miter scale exceeds this limit, switch to a bevel join.
+/// Clamp on how far the inside vertex of a bevel join moves along the
+/// bisector, in half-widths, so short segments are not overshot.
 const BEVEL_THRESHOLD: f64 = 2.0;
 
 /// Up direction for the flat ribbon (Z+).
@@ -40,13 +47,19 @@ struct VertexSlot {
 /// Generates a flat ribbon triangle mesh from a polyline and stroke style.
 ///
 /// The ribbon lies in the XY plane with normals pointing in the Z+ direction.
-/// At sharp angles (miter scale > [`BEVEL_THRESHOLD`]) a bevel join is used
-/// instead of a miter to prevent spikes.
+/// With [`LineJoin::Auto`], joins whose miter scale (miter length over
+/// stroke width) exceeds the style's miter limit are beveled instead of
+/// mitered to prevent spikes, as SVG's `stroke-miterlimit` specifies.
+///
+/// A stroke whose centerline crosses itself overlaps its own ribbon; with
+/// [`with_union_outline`](Self::with_union_outline) the overlap is resolved
+/// into a single non-overlapping outline before triangulation.
 #[derive(Debug)]
 pub struct TessellateStroke {
     points: Vec<Point3>,
     style: StrokeStyle,
     closed: bool,
+    union_outline: bool,
 }
 
 impl TessellateStroke {
@@ -57,17 +70,41 @@ impl TessellateStroke {
             points,
             style,
             closed,
+            union_outline: false,
         }
     }
 
+    /// Sets whether the ribbon is replaced by the triangulated union of its
+    /// own outline (default `false`).
+    ///
+    /// The union is taken in the XY plane and the mesh lies at the height of
+    /// the first point. Its UVs are the XY coordinates rather than the
+    /// ribbon's across/along parameterization.
+    #[must_use]
+    pub fn with_union_outline(mut self, union_outline: bool) -> Self {
+        self.union_outline = union_outline;
+        self
+    }
+
     /// Executes the tessellation, producing a ribbon mesh.
     ///
     /// # Errors
     ///
     /// Returns an error if fewer than 2 points are provided, or if consecutive
-    /// points are coincident (zero-length segment).
-    #[allow(clippy::cast_possible_truncation, clippy::too_many_lines)]
+    /// points are coincident (zero-length segment). With the union outline
+    /// enabled, union and triangulation failures are propagated.
     pub fn execute(&self) -> Result<TriangleMesh> {
+        let ribbon = self.ribbon()?;
+        if self.union_outline {
+            self.union_mesh(&ribbon)
+        } else {
+            Ok(ribbon)
+        }
+    }
+
+    /// Builds the ribbon mesh, one quad per segment plus bevel triangles.
+    #[allow(clippy::cast_possible_truncation, clippy::too_many_lines)]
+    fn ribbon(&self) -> Result<TriangleMesh> {
         let n = self.points.len();
         if n < 2 {
             return Err(TessellationError::InvalidParameters(
@@ -201,10 +238,63 @@ impl TessellateStroke {
         })
     }
 
+    /// Unions the XY footprints of the ribbon's triangles and triangulates
+    /// the resulting faces at the height of the first point.
+    fn union_mesh(&self, ribbon: &TriangleMesh) -> Result<TriangleMesh> {
+        let pieces: Vec<PolygonWithHoles> = ribbon
+            .indices
+            .iter()
+            .filter_map(|tri| {
+                let mut outer: Polygon = tri
+                    .iter()
+                    .map(|&i| {
+                        let p = ribbon.vertices[i as usize];
+                        (p.x, p.y)
+                    })
+                    .collect();
+                let area = signed_area(&outer);
+                if area.abs() < WALL_EPS_SQ {
+                    return None;
+                }
+                if area < 0.0 {
+                    outer.reverse();
+                }
+                Some(PolygonWithHoles {
+                    outer,
+                    holes: Vec::new(),
+                })
+            })
+            .collect();
+
+        let plane = Plane::new(
+            Point3::new(0.0, 0.0, self.points[0].z),
+            Vector3::x(),
+            Vector3::y(),
+        )?;
+        let to_pline = |ring: &Polygon| Pline {
+            vertices: ring.iter().map(|&(x, y)| PlineVertex::line(x, y)).collect(),
+            closed: true,
+        };
+        let meshes = union_all_with_holes(&pieces)?
+            .faces
+            .iter()
+            .map(|face| {
+                TessellateWithHoles::new(
+                    to_pline(&face.outer),
+                    face.holes.iter().map(to_pline).collect(),
+                    plane.clone(),
+                )
+                .execute()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TriangleMesh::from_meshes(&meshes))
+    }
+
     /// Determines the join kind (miter or bevel) at each polyline vertex.
     fn compute_joins(&self) -> Result<Vec<JoinKind>> {
         let n = self.points.len();
         let line_join = self.style.line_join();
+        let miter_limit = self.style.miter_limit();
         let mut joins = Vec::with_capacity(n);
 
         for i in 0..n {
@@ -227,7 +317,7 @@ impl TessellateStroke {
                 let use_bevel = match line_join {
                     LineJoin::Miter => false,
                     LineJoin::Bevel => true,
-                    LineJoin::Auto => miter_scale > BEVEL_THRESHOLD,
+                    LineJoin::Auto => miter_scale > miter_limit,
                 };
 
                 if use_bevel {
@@ -253,7 +343,7 @@ impl TessellateStroke {
                     let perp = normalize_perp(tangent)?;
                     joins.push(JoinKind::Miter {
                         dir: perp,
-                        scale: miter_scale.min(BEVEL_THRESHOLD),
+                        scale: miter_scale.min(miter_limit),
                     });
                 }
             } else {
@@ -354,7 +444,7 @@ mod tests {
 
     #[test]
     fn l_shape_three_points() {
-        // 90° turn: miter_scale = 1.414 < BEVEL_THRESHOLD → miter join
+        // 90° turn: miter_scale = 1.414 < DEFAULT_MITER_LIMIT → miter join
         let points = vec![
             Point3::new(0.0, 0.0, 0.0),
             Point3::new(5.0, 0.0, 0.0),
@@ -370,7 +460,7 @@ mod tests {
 
     #[test]
     fn closed_triangle_uses_bevel() {
-        // Closed right triangle: two vertices exceed BEVEL_THRESHOLD.
+        // Closed right triangle: two vertices exceed DEFAULT_MITER_LIMIT.
         let points = vec![
             Point3::new(0.0, 0.0, 0.0),
             Point3::new(10.0, 0.0, 0.0),
@@ -420,9 +510,38 @@ mod tests {
         assert!((mesh.uvs[1].x - 1.0).abs() < 1e-10);
     }
 
+    /// A 10° interior angle has a miter ratio of `1 / sin(5°) ≈ 11.47`:
+    /// past SVG's default limit of 4, within a limit of 20.
+    #[test]
+    fn miter_limit_follows_svg_ratio() {
+        let dir = Vector3::new(170_f64.to_radians().cos(), 170_f64.to_radians().sin(), 0.0);
+        let corner = Point3::origin();
+        let points = vec![Point3::new(-10.0, 0.0, 0.0), corner, corner + dir * 10.0];
+        let ratio = 1.0 / 5_f64.to_radians().sin();
+
+        let beveled = style(1.0).with_miter_limit(4.0).unwrap();
+        let mesh = TessellateStroke::new(points.clone(), beveled, false)
+            .execute()
+            .unwrap();
+        // 2 endpoints (2 each) + 1 bevel (3) = 7 vertices
+        assert_eq!(mesh.vertices.len(), 7);
+
+        let mitered = style(1.0).with_miter_limit(20.0).unwrap();
+        let mesh = TessellateStroke::new(points, mitered, false)
+            .execute()
+            .unwrap();
+        assert_eq!(mesh.vertices.len(), 6);
+        // The miter tip sits `ratio * width / 2` from the corner.
+        let tip = mesh.vertices[2..4]
+            .iter()
+            .map(|v| (v - corner).norm())
+            .fold(0.0, f64::max);
+        assert!((tip - 0.5 * ratio).abs() < 1e-9, "tip at {tip}");
+    }
+
     #[test]
     fn hairpin_uses_bevel() {
-        // Near-reversal: miter_scale >> BEVEL_THRESHOLD → bevel
+        // Near-reversal: miter_scale >> DEFAULT_MITER_LIMIT → bevel
         let points = vec![
             Point3::new(0.0, 0.0, 0.0),
             Point3::new(1.0, 4.0, 0.0),
@@ -450,4 +569,50 @@ mod tests {
         let op = TessellateStroke::new(points, style(1.0), false);
         assert!(op.execute().is_err());
     }
+
+    /// Sum of the XY areas of the mesh's triangles.
+    fn footprint_area(mesh: &TriangleMesh) -> f64 {
+        mesh.indices
+            .iter()
+            .map(|[a, b, c]| {
+                let (pa, pb, pc) = (
+                    mesh.vertices[*a as usize],
+                    mesh.vertices[*b as usize],
+                    mesh.vertices[*c as usize],
+                );
+                ((pb - pa).cross(&(pc - pa))).z.abs() / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn union_outline_removes_figure_eight_overlap() {
+        // Closed rectilinear figure eight whose vertical leg x = 3 crosses
+        // the bottom edge y = 0 at (3, 0). Every join is a right-angle miter,
+        // so the ribbon covers centerline length × width = 26 × 0.5, and the
+        // two strips overlap in one 0.5 × 0.5 square at the crossing.
+        let points = vec![
+            Point3::new(0.0, 0.0, 1.5),
+            Point3::new(6.0, 0.0, 1.5),
+            Point3::new(6.0, 4.0, 1.5),
+            Point3::new(3.0, 4.0, 1.5),
+            Point3::new(3.0, -3.0, 1.5),
+            Point3::new(0.0, -3.0, 1.5),
+        ];
+        let ribbon = TessellateStroke::new(points.clone(), style(0.5), true)
+            .execute()
+            .unwrap();
+        let mesh = TessellateStroke::new(points, style(0.5), true)
+            .with_union_outline(true)
+            .execute()
+            .unwrap();
+
+        let ribbon_area = footprint_area(&ribbon);
+        assert!((ribbon_area - 13.0).abs() < 1e-9, "{ribbon_area}");
+        // The crossing square is covered once.
+        let area = footprint_area(&mesh);
+        assert!((area - (13.0 - 0.25)).abs() < 1e-9, "{area}");
+        assert!(mesh.vertices.iter().all(|p| (p.z - 1.5).abs() < 1e-12));
+        assert!(mesh.normals.iter().all(|n| (n - UP).norm() < 1e-12));
+    }
 }
@@ -645,7 +645,7 @@ fn point_in_region(p: &Point2, outer: &[Point2], holes: &[Vec<Point2>]) -> bool
 }
 
 /// Even-odd ray-cast point-in-polygon test in UV.
-fn point_in_polygon(p: &Point2, poly: &[Point2]) -> bool {
+pub(super) fn point_in_polygon(p: &Point2, poly: &[Point2]) -> bool {
     let n = poly.len();
     if n < 3 {
         return false;
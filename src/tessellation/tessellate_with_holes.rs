@@ -1,28 +1,175 @@
-use crate::error::Result;
-use crate::topology::{FaceId, TopologyStore};
+use std::collections::HashMap;
 
+use spade::{ConstrainedDelaunayTriangulation, Point2 as SpadePoint2, Triangulation};
+
+use crate::error::{Result, TessellationError};
+use crate::geometry::pline::Pline;
+use crate::geometry::surface::Plane;
+use crate::math::Point2;
+
+use super::tessellate_face::{classify_interior_faces, insert_constraint_loop};
 use super::{TessellationParams, TriangleMesh};
 
-/// Tessellates a face that has holes (inner wires) into a triangle mesh.
+/// Triangulates a planar region bounded by closed polylines, without
+/// creating any topology.
+///
+/// The polylines live in the plane's `(u, v)` coordinates: a vertex
+/// `(x, y)` maps to `origin + x * u_dir + y * v_dir`. Arc segments are
+/// flattened to within the tessellation tolerance, the loops are inserted
+/// as constraints of a CDT, and the triangles inside the outer loop but
+/// outside every hole are lifted onto the plane.
 pub struct TessellateWithHoles {
-    face: FaceId,
+    outer: Pline,
+    holes: Vec<Pline>,
+    plane: Plane,
     params: TessellationParams,
 }
 
 impl TessellateWithHoles {
     /// Creates a new `TessellateWithHoles` operation.
     #[must_use]
-    pub fn new(face: FaceId, params: TessellationParams) -> Self {
-        Self { face, params }
+    pub fn new(outer: Pline, holes: Vec<Pline>, plane: Plane) -> Self {
+        Self {
+            outer,
+            holes,
+            plane,
+            params: TessellationParams::default(),
+        }
+    }
+
+    /// Sets the tessellation parameters; only `tolerance` is used, for arc
+    /// flattening.
+    #[must_use]
+    pub fn with_params(mut self, params: TessellationParams) -> Self {
+        self.params = params;
+        self
     }
 
     /// Executes the tessellation, returning a triangle mesh with holes.
     ///
+    /// Triangles wind counter-clockwise about the plane normal, and every
+    /// vertex carries that normal and its `(u, v)` coordinates.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the operation fails.
-    pub fn execute(&self, _store: &TopologyStore) -> Result<TriangleMesh> {
-        let _ = (self.face, self.params);
-        todo!()
+    /// Returns [`TessellationError::InvalidParameters`] if the tolerance is
+    /// not positive or any polyline is open, and
+    /// [`TessellationError::Failed`] if a loop has fewer than three
+    /// distinct points or the triangulation fails.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn execute(&self) -> Result<TriangleMesh> {
+        if self.params.tolerance <= 0.0 {
+            return Err(
+                TessellationError::InvalidParameters("tolerance must be positive".into()).into(),
+            );
+        }
+
+        let mut cdt = ConstrainedDelaunayTriangulation::<SpadePoint2<f64>>::new();
+        for pline in std::iter::once(&self.outer).chain(&self.holes) {
+            insert_constraint_loop(&mut cdt, &self.flatten(pline)?)?;
+        }
+        let interior_faces = classify_interior_faces(&cdt);
+
+        let origin = self.plane.origin();
+        let u_dir = self.plane.u_dir();
+        let v_dir = self.plane.v_dir();
+        let normal = *self.plane.plane_normal();
+
+        let mut mesh = TriangleMesh::default();
+        let mut vertex_map: HashMap<usize, u32> = HashMap::new();
+        for face_handle in cdt.inner_faces() {
+            if !interior_faces.contains(&face_handle.fix().index()) {
+                continue;
+            }
+            let mut tri_indices = [0u32; 3];
+            for (i, vh) in face_handle.vertices().iter().enumerate() {
+                tri_indices[i] = *vertex_map.entry(vh.fix().index()).or_insert_with(|| {
+                    let pos = vh.position();
+                    mesh.vertices
+                        .push(*origin + *u_dir * pos.x + *v_dir * pos.y);
+                    mesh.normals.push(normal);
+                    mesh.uvs.push(Point2::new(pos.x, pos.y));
+                    (mesh.vertices.len() - 1) as u32
+                });
+            }
+            mesh.indices.push(tri_indices);
+        }
+
+        Ok(mesh)
+    }
+
+    /// Flattens a closed polyline into CDT points, dropping the repeated
+    /// closing point.
+    fn flatten(&self, pline: &Pline) -> Result<Vec<SpadePoint2<f64>>> {
+        if !pline.closed {
+            return Err(TessellationError::InvalidParameters(
+                "boundary polylines must be closed".into(),
+            )
+            .into());
+        }
+        let mut points = pline.to_points(self.params.tolerance);
+        if points.len() > 1 && (points[0] - points[points.len() - 1]).norm() < 1e-12 {
+            points.pop();
+        }
+        Ok(points.iter().map(|p| SpadePoint2::new(p.x, p.y)).collect())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::geometry::pline::PlineVertex;
+    use crate::math::{Point3, Vector3};
+
+    /// Closed two-arc circle of `radius` centred at the origin.
+    fn circle(radius: f64) -> Pline {
+        Pline {
+            vertices: vec![
+                PlineVertex::new(radius, 0.0, 1.0),
+                PlineVertex::new(-radius, 0.0, 1.0),
+            ],
+            closed: true,
+        }
+    }
+
+    #[test]
+    fn ring_leaves_hole_empty() {
+        let plane = Plane::new(Point3::new(0.0, 0.0, 2.0), Vector3::y(), Vector3::z()).unwrap();
+        let mesh = TessellateWithHoles::new(circle(2.0), vec![circle(1.0)], plane.clone())
+            .execute()
+            .unwrap();
+        mesh.validate().unwrap();
+        assert!(!mesh.indices.is_empty());
+
+        let normal = *plane.plane_normal();
+        let mut area = 0.0;
+        for &[a, b, c] in &mesh.indices {
+            let [pa, pb, pc] = [a, b, c].map(|i| mesh.vertices[i as usize]);
+            let cross = (pb - pa).cross(&(pc - pa));
+            // Counter-clockwise about the plane normal.
+            assert!(cross.dot(&normal) > 0.0);
+            area += 0.5 * cross.norm();
+
+            let [ua, ub, uc] = [a, b, c].map(|i| mesh.uvs[i as usize]);
+            let centroid = Point2::from((ua.coords + ub.coords + uc.coords) / 3.0);
+            let r = centroid.coords.norm();
+            assert!(r > 1.0 && r < 2.0, "triangle centroid at radius {r}");
+        }
+        for n in &mesh.normals {
+            assert!((n - normal).norm() < 1e-12);
+        }
+        let ring = std::f64::consts::PI * (4.0 - 1.0);
+        assert!((area - ring).abs() < 0.05 * ring, "area {area}");
+    }
+
+    #[test]
+    fn open_boundary_is_rejected() {
+        let mut outer = circle(1.0);
+        outer.closed = false;
+        let plane = Plane::from_normal(Point3::origin(), Vector3::z()).unwrap();
+        assert!(TessellateWithHoles::new(outer, Vec::new(), plane)
+            .execute()
+            .is_err());
     }
 }
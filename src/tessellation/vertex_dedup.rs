@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::math::Point3;
+
+use super::TriangleMesh;
+
+/// Shared vertex table that gives coincident vertices of separately
+/// tessellated faces a single mesh index.
+///
+/// Positions are hashed on a grid of `tolerance`-sized cells; a vertex
+/// within `tolerance` of an already-emitted one (searched in the 27
+/// surrounding cells) reuses its index. The first face to emit a shared
+/// vertex decides its normal and UV, so shading across sharp edges is
+/// smoothed toward that face.
+#[derive(Debug, Clone)]
+pub struct VertexDedup {
+    tolerance: f64,
+    cells: HashMap<[i64; 3], Vec<u32>>,
+}
+
+impl VertexDedup {
+    /// Creates an empty table merging vertices closer than `tolerance`.
+    #[must_use]
+    pub fn new(tolerance: f64) -> Self {
+        Self {
+            tolerance: tolerance.max(f64::MIN_POSITIVE),
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Appends `face` to `combined`, reusing the index of any vertex of
+    /// `combined` that this table has already seen at the same position.
+    ///
+    /// Triangles that collapse (two corners merged into one vertex) are
+    /// dropped.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn merge_into(&mut self, combined: &mut TriangleMesh, face: &TriangleMesh) {
+        let remap: Vec<u32> = (0..face.vertices.len())
+            .map(|i| {
+                let p = face.vertices[i];
+                if let Some(existing) = self.find(combined, &p) {
+                    return existing;
+                }
+                let index = combined.vertices.len() as u32;
+                combined.vertices.push(p);
+                if let Some(n) = face.normals.get(i) {
+                    combined.normals.push(*n);
+                }
+                if let Some(uv) = face.uvs.get(i) {
+                    combined.uvs.push(*uv);
+                }
+                self.cells.entry(self.cell(&p)).or_default().push(index);
+                index
+            })
+            .collect();
+
+        for tri in &face.indices {
+            let [a, b, c] = tri.map(|i| remap[i as usize]);
+            if a != b && b != c && c != a {
+                combined.indices.push([a, b, c]);
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn cell(&self, p: &Point3) -> [i64; 3] {
+        [p.x, p.y, p.z].map(|c| (c / self.tolerance).floor() as i64)
+    }
+
+    fn find(&self, combined: &TriangleMesh, p: &Point3) -> Option<u32> {
+        let [x, y, z] = self.cell(p);
+        let tol2 = self.tolerance * self.tolerance;
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(bucket) = self.cells.get(&[x + dx, y + dy, z + dz]) else {
+                        continue;
+                    };
+                    if let Some(&i) = bucket
+                        .iter()
+                        .find(|&&i| (combined.vertices[i as usize] - p).norm_squared() <= tol2)
+                    {
+                        return Some(i);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,90 @@
+//! Adjacency queries between faces and the edges they share.
+
+use std::collections::HashSet;
+
+use super::{EdgeId, FaceId, TopologyStore};
+
+impl TopologyStore {
+    /// Returns the faces whose boundary wires use `edge`, in ascending
+    /// face order and without duplicates.
+    ///
+    /// Only faces reachable from a solid's shells are considered, so
+    /// leftovers of earlier operations do not count. Dangling references
+    /// are skipped.
+    #[must_use]
+    pub fn faces_using_edge(&self, edge: EdgeId) -> Vec<FaceId> {
+        let mut seen = HashSet::new();
+        let mut faces = Vec::new();
+        let shells = self
+            .solids
+            .values()
+            .flat_map(|s| std::iter::once(s.outer_shell).chain(s.inner_shells.iter().copied()));
+        for shell in shells.filter_map(|s| self.shells.get(s)) {
+            for &face_id in &shell.faces {
+                if seen.insert(face_id) && self.face_uses_edge(face_id, edge) {
+                    faces.push(face_id);
+                }
+            }
+        }
+        faces.sort_unstable();
+        faces
+    }
+
+    /// Returns the first edge of `a`'s boundary (outer wire first) that
+    /// also bounds `b`, or `None` if the faces share no edge.
+    #[must_use]
+    pub fn find_shared_edge(&self, a: FaceId, b: FaceId) -> Option<EdgeId> {
+        self.face_edges(a)
+            .into_iter()
+            .find(|&e| self.face_uses_edge(b, e))
+    }
+
+    /// Edges of every wire of `face`, outer wire first.
+    fn face_edges(&self, face: FaceId) -> Vec<EdgeId> {
+        let Some(face) = self.faces.get(face) else {
+            return Vec::new();
+        };
+        std::iter::once(face.outer_wire)
+            .chain(face.inner_wires.iter().copied())
+            .filter_map(|w| self.wires.get(w))
+            .flat_map(|w| w.edges.iter().map(|oe| oe.edge))
+            .collect()
+    }
+
+    fn face_uses_edge(&self, face: FaceId, edge: EdgeId) -> bool {
+        self.face_edges(face).contains(&edge)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::Point3;
+    use crate::operations::creation::MakeBox;
+
+    #[test]
+    fn box_edges_join_two_faces() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(1.0, 2.0, 3.0))
+            .execute(&mut store)
+            .unwrap();
+        let shell = store.solid(solid).unwrap().outer_shell;
+        let faces = store.shell(shell).unwrap().faces.clone();
+
+        let edges: HashSet<EdgeId> = faces.iter().flat_map(|&f| store.face_edges(f)).collect();
+        assert_eq!(edges.len(), 12);
+        for &edge in &edges {
+            let users = store.faces_using_edge(edge);
+            assert_eq!(users.len(), 2);
+            assert_eq!(store.find_shared_edge(users[0], users[1]), Some(edge));
+        }
+        // Opposite faces of a box share nothing.
+        let opposite = faces
+            .iter()
+            .copied()
+            .filter(|&f| store.find_shared_edge(faces[0], f).is_none())
+            .collect::<Vec<_>>();
+        assert_eq!(opposite.len(), 1);
+    }
+}
@@ -39,4 +39,7 @@ pub struct EdgeData {
     pub t_start: f64,
     /// Parameter on the curve corresponding to the end vertex.
     pub t_end: f64,
+    /// Chord tolerance used when tessellating this edge, overriding
+    /// `TessellationParams::tolerance`; `None` uses the global value.
+    pub tessellation_tolerance: Option<f64>,
 }
@@ -1,5 +1,7 @@
 use crate::geometry::nurbs::{NurbsCurve2D, NurbsSurface};
-use crate::geometry::surface::{Cone, Cylinder, Plane, Sphere, Torus};
+use crate::geometry::surface::{
+    Cone, Cylinder, ExtrudedSurface, Plane, RuledSurface, Sphere, Torus,
+};
 
 use super::edge::EdgeId;
 use super::wire::WireId;
@@ -24,6 +26,10 @@ pub enum FaceSurface {
     Torus(Torus),
     /// A free-form NURBS surface.
     Nurbs(NurbsSurface),
+    /// A ruled surface between two curves.
+    Ruled(RuledSurface),
+    /// A linear sweep of a profile curve.
+    Extruded(ExtrudedSurface),
 }
 
 /// The UV image of a boundary edge in one face's parameter space.
@@ -65,6 +71,12 @@ pub struct FaceData {
     /// whose builder predates shared-edge topology; consumers fall back to
     /// geometric boundary sampling in that case.
     pub pcurves: Vec<FacePcurve>,
+    /// Surface `u` parameter at which a periodic face's texture coordinates
+    /// wrap. When set, tessellation emits `u` texture coordinates normalized
+    /// to `[0, 1]` over one full period starting at the seam, oriented so the
+    /// texture is not mirrored when viewed from the face's outward side.
+    /// `None` keeps raw surface parameters.
+    pub u_seam: Option<f64>,
 }
 
 impl FaceData {
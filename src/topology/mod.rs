@@ -1,9 +1,12 @@
+pub mod adjacency;
 pub mod dump;
 pub mod edge;
 pub mod face;
 pub mod name;
+pub mod orientation;
 pub mod shell;
 pub mod solid;
+pub mod stats;
 pub mod trim;
 pub mod vertex;
 pub mod wire;
@@ -13,6 +16,7 @@ pub use face::{FaceData, FaceId, FacePcurve, FaceSurface};
 pub use name::{EdgeName, EdgeRole, FaceName, FaceRole, NameRegistry, OpId, SegmentTag, SplitSide};
 pub use shell::{ShellData, ShellId};
 pub use solid::{SolidData, SolidId};
+pub use stats::{CountRange, TopologyStats};
 pub use trim::{FaceTrim, TrimLoop};
 pub use vertex::{VertexData, VertexId};
 pub use wire::{OrientedEdge, WireData, WireId};
@@ -226,4 +230,9 @@ impl TopologyStore {
             .get_mut(id)
             .ok_or_else(|| TopologyError::EntityNotFound("solid".into()))
     }
+
+    /// Returns the IDs of all solids in the store.
+    pub fn solid_ids(&self) -> impl Iterator<Item = SolidId> + '_ {
+        self.solids.keys()
+    }
 }
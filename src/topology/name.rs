@@ -526,6 +526,7 @@ mod tests {
             same_sense: true,
             trim: None,
             pcurves: Vec::new(),
+            u_seam: None,
         })
     }
 
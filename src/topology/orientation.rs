@@ -0,0 +1,307 @@
+//! Orientation queries: outward face normals, dihedral angles and shell
+//! orientation.
+
+use crate::error::{Result, TopologyError};
+use crate::geometry::curve::Curve;
+use crate::geometry::nurbs::InversionOptions;
+use crate::geometry::surface::{Plane, Surface};
+use crate::math::polygon_3d::point_in_polygon_3d;
+use crate::math::{Point3, Vector3, TOLERANCE};
+
+use super::{EdgeCurve, EdgeId, FaceId, FaceSurface, ShellId, TopologyStore, WireId};
+
+/// Ray hits closer than this to the ray origin or a face boundary make a
+/// cast ambiguous.
+const CAST_TOL: f64 = TOLERANCE * 100.0;
+
+/// A planar face flattened to boundary polygons for ray casting.
+struct PlanarFace {
+    plane: Plane,
+    outer: Vec<Point3>,
+    holes: Vec<Vec<Point3>>,
+    outward: Vector3,
+}
+
+impl PlanarFace {
+    fn contains(&self, p: &Point3) -> bool {
+        point_in_polygon_3d(p, &self.outer, &self.plane)
+            && !self
+                .holes
+                .iter()
+                .any(|h| point_in_polygon_3d(p, h, &self.plane))
+    }
+
+    fn near_boundary(&self, p: &Point3) -> bool {
+        std::iter::once(&self.outer).chain(&self.holes).any(|poly| {
+            (0..poly.len()).any(|i| {
+                let (a, b) = (poly[i], poly[(i + 1) % poly.len()]);
+                let ab = b - a;
+                let t = ((p - a).dot(&ab) / ab.norm_squared().max(TOLERANCE)).clamp(0.0, 1.0);
+                (p - (a + ab * t)).norm() < CAST_TOL
+            })
+        })
+    }
+
+    /// A point strictly inside the face: the polygon centroid when it lies
+    /// inside, otherwise the first fan-triangle centroid that does.
+    fn interior_point(&self) -> Option<Point3> {
+        #[allow(clippy::cast_precision_loss)]
+        let centroid = Point3::from(
+            self.outer.iter().map(|p| p.coords).sum::<Vector3>() / self.outer.len() as f64,
+        );
+        let candidates =
+            std::iter::once(centroid).chain((1..self.outer.len().saturating_sub(1)).map(|i| {
+                Point3::from(
+                    (self.outer[0].coords + self.outer[i].coords + self.outer[i + 1].coords) / 3.0,
+                )
+            }));
+        candidates
+            .into_iter()
+            .find(|p| self.contains(p) && !self.near_boundary(p))
+    }
+}
+
+impl TopologyStore {
+    /// Returns the unit normal of `face` pointing out of its solid, i.e. the
+    /// surface normal negated when the face is not `same_sense`.
+    ///
+    /// Curved surfaces are evaluated at the surface point closest to the
+    /// centroid of the outer wire's vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the face or its boundary cannot be read, or the
+    /// surface normal is degenerate at the representative point.
+    pub fn face_outward_normal(&self, face: FaceId) -> Result<Vector3> {
+        let c = self.wire_centroid(self.face(face)?.outer_wire)?;
+        self.outward_normal_near(face, &c)
+    }
+
+    /// Returns the signed dihedral angle at `edge`, in radians: the angle
+    /// between the outward normals of the two faces meeting there,
+    /// evaluated at the edge's parametric midpoint.
+    ///
+    /// The angle is `0` where the faces are tangent, positive where the
+    /// edge is convex (the faces fold away from the outward side, `π/2`
+    /// on a box) and negative where it is concave. Convexity is judged by
+    /// which side of the first face the second face's outer-wire centroid
+    /// lies on.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TopologyError::InvalidTopology` if `edge` is not used by
+    /// exactly two faces (see [`faces_using_edge`](Self::faces_using_edge)),
+    /// or an error if the edge curve or a face normal cannot be evaluated.
+    pub fn dihedral_angle(&self, edge: EdgeId) -> Result<f64> {
+        let faces = self.faces_using_edge(edge);
+        let &[first, second] = faces.as_slice() else {
+            return Err(TopologyError::InvalidTopology(format!(
+                "dihedral angle needs an edge shared by exactly two faces, found {}",
+                faces.len()
+            ))
+            .into());
+        };
+        let data = self.edge(edge)?;
+        let t = 0.5 * (data.t_start + data.t_end);
+        let mid = match &data.curve {
+            EdgeCurve::Line(c) => c.evaluate(t)?,
+            EdgeCurve::Arc(c) => c.evaluate(t)?,
+            EdgeCurve::Circle(c) => c.evaluate(t)?,
+            EdgeCurve::Ellipse(c) => c.evaluate(t)?,
+            EdgeCurve::Nurbs(c) => c.point_at(t)?,
+        };
+        let n1 = self.outward_normal_near(first, &mid)?;
+        let n2 = self.outward_normal_near(second, &mid)?;
+        let angle = n1.dot(&n2).clamp(-1.0, 1.0).acos();
+        let inward = self.wire_centroid(self.face(second)?.outer_wire)? - mid;
+        Ok(if inward.dot(&n1) > 0.0 { -angle } else { angle })
+    }
+
+    /// Outward unit normal of `face` at the surface point closest to `p`.
+    fn outward_normal_near(&self, face: FaceId, c: &Point3) -> Result<Vector3> {
+        let data = self.face(face)?;
+        let c = *c;
+        let normal = match &data.surface {
+            FaceSurface::Plane(s) => *s.plane_normal(),
+            FaceSurface::Cylinder(s) => normal_at(s, s.inverse(&c))?,
+            FaceSurface::Cone(s) => normal_at(s, s.inverse(&c))?,
+            FaceSurface::Sphere(s) => normal_at(s, s.inverse(&c))?,
+            FaceSurface::Torus(s) => normal_at(s, s.inverse(&c))?,
+            FaceSurface::Ruled(s) => normal_at(s, s.inverse(&c))?,
+            FaceSurface::Extruded(s) => normal_at(s, s.inverse(&c))?,
+            FaceSurface::Nurbs(s) => {
+                let inv = s.closest_point(&c, &InversionOptions::default())?;
+                normal_at(s, (inv.u, inv.v))?
+            }
+        };
+        Ok(if data.same_sense { normal } else { -normal })
+    }
+
+    /// Returns `true` if the faces of `shell` are oriented outward.
+    ///
+    /// Casts a ray from an interior point of a planar face along its
+    /// outward normal and counts the faces it crosses: leaving the solid
+    /// through an outward face, the ray crosses the rest of the shell an
+    /// even number of times. Only planar faces take part in the cast, so
+    /// the answer is reliable for all-planar shells; a shell that cannot be
+    /// read or has no usable planar face reports `false`.
+    #[must_use]
+    pub fn shell_is_outward(&self, shell: ShellId) -> bool {
+        let Ok(shell) = self.shell(shell) else {
+            return false;
+        };
+        let mut faces = Vec::with_capacity(shell.faces.len());
+        for &face_id in &shell.faces {
+            let Ok(face) = self.face(face_id) else {
+                return false;
+            };
+            let FaceSurface::Plane(ref plane) = face.surface else {
+                continue;
+            };
+            let Ok(outer) = self.wire_polygon(face.outer_wire) else {
+                return false;
+            };
+            let holes: std::result::Result<Vec<_>, _> = face
+                .inner_wires
+                .iter()
+                .map(|&w| self.wire_polygon(w))
+                .collect();
+            let Ok(holes) = holes else {
+                return false;
+            };
+            let normal = *plane.plane_normal();
+            faces.push(PlanarFace {
+                plane: plane.clone(),
+                outer,
+                holes,
+                outward: if face.same_sense { normal } else { -normal },
+            });
+        }
+
+        faces
+            .iter()
+            .filter_map(|seed| Some((seed.interior_point()?, seed.outward)))
+            .find_map(|(origin, dir)| count_crossings(&origin, &dir, &faces))
+            .is_some_and(|crossings| crossings % 2 == 0)
+    }
+
+    /// Mean of a wire's vertex positions.
+    fn wire_centroid(&self, wire: WireId) -> Result<Point3> {
+        let polygon = self.wire_polygon(wire)?;
+        #[allow(clippy::cast_precision_loss)]
+        let c = Point3::from(
+            polygon.iter().map(|p| p.coords).sum::<Vector3>() / polygon.len().max(1) as f64,
+        );
+        Ok(c)
+    }
+
+    /// Start vertices of a wire's oriented edges, in order.
+    fn wire_polygon(&self, wire: WireId) -> Result<Vec<Point3>> {
+        let wire = self.wire(wire)?;
+        let mut points = Vec::with_capacity(wire.edges.len());
+        for oe in &wire.edges {
+            let edge = self.edge(oe.edge)?;
+            let v = if oe.forward { edge.start } else { edge.end };
+            points.push(self.vertex(v)?.point);
+        }
+        Ok(points)
+    }
+}
+
+/// Unit normal of `surface` at `(u, v)`.
+fn normal_at(surface: &dyn Surface, (u, v): (f64, f64)) -> Result<Vector3> {
+    surface.normal(u, v)
+}
+
+/// Counts the faces crossed by the ray `origin + t * dir`, `t > 0`, or
+/// `None` when the ray grazes a face boundary or runs inside a face plane.
+fn count_crossings(origin: &Point3, dir: &Vector3, faces: &[PlanarFace]) -> Option<usize> {
+    let mut crossings = 0;
+    for face in faces {
+        let n = face.plane.plane_normal();
+        let denom = dir.dot(n);
+        let dist = (face.plane.origin() - origin).dot(n);
+        if denom.abs() < TOLERANCE {
+            if dist.abs() < CAST_TOL && face.contains(origin) && !face.near_boundary(origin) {
+                return None;
+            }
+            continue;
+        }
+        let t = dist / denom;
+        if t <= CAST_TOL {
+            continue;
+        }
+        let hit = origin + dir * t;
+        if face.near_boundary(&hit) {
+            return None;
+        }
+        if face.contains(&hit) {
+            crossings += 1;
+        }
+    }
+    Some(crossings)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::operations::creation::MakeBox;
+
+    #[test]
+    fn box_faces_point_away_from_center() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(2.0, 4.0, 6.0))
+            .execute(&mut store)
+            .unwrap();
+        let center = Point3::new(1.0, 2.0, 3.0);
+        let shell_id = store.solid(solid).unwrap().outer_shell;
+        let faces = store.shell(shell_id).unwrap().faces.clone();
+        assert_eq!(faces.len(), 6);
+        for &face in &faces {
+            let n = store.face_outward_normal(face).unwrap();
+            let polygon = store
+                .wire_polygon(store.face(face).unwrap().outer_wire)
+                .unwrap();
+            let c = Point3::from(polygon.iter().map(|p| p.coords).sum::<Vector3>() / 4.0);
+            assert!((n.norm() - 1.0).abs() < 1e-12);
+            // Axis-aligned and pointing from the centre towards the face.
+            assert!((n.dot(&(c - center)) - (c - center).norm()).abs() < 1e-9);
+        }
+        assert!(store.shell_is_outward(shell_id));
+
+        for &face in &faces {
+            let data = store.face_mut(face).unwrap();
+            data.same_sense = !data.same_sense;
+        }
+        assert!(!store.shell_is_outward(shell_id));
+    }
+
+    #[test]
+    fn box_edges_have_right_dihedral_angle() {
+        let mut store = TopologyStore::new();
+        let solid = MakeBox::new(Point3::origin(), Point3::new(2.0, 4.0, 6.0))
+            .execute(&mut store)
+            .unwrap();
+        let shell = store.solid(solid).unwrap().outer_shell;
+        let faces = store.shell(shell).unwrap().faces.clone();
+        let mut edges: Vec<EdgeId> = Vec::new();
+        for &a in &faces {
+            for &b in &faces {
+                if let Some(e) = store.find_shared_edge(a, b).filter(|_| a != b) {
+                    edges.push(e);
+                }
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+        assert_eq!(edges.len(), 12);
+        for edge in edges {
+            let angle = store.dihedral_angle(edge).unwrap();
+            assert!(
+                (angle - std::f64::consts::FRAC_PI_2).abs() < 1e-9,
+                "{angle}"
+            );
+        }
+    }
+}
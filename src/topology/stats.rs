@@ -0,0 +1,173 @@
+//! Entity counts for a [`TopologyStore`], for quick debugging output.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use super::TopologyStore;
+
+/// Minimum, maximum and mean of a per-entity child count.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CountRange {
+    /// Smallest count (0 when there are no parents).
+    pub min: usize,
+    /// Largest count (0 when there are no parents).
+    pub max: usize,
+    /// Mean count (0.0 when there are no parents).
+    pub mean: f64,
+}
+
+impl CountRange {
+    fn from_counts(counts: impl Iterator<Item = usize>) -> Self {
+        let (mut min, mut max, mut total, mut n) = (usize::MAX, 0, 0, 0_usize);
+        for c in counts {
+            min = min.min(c);
+            max = max.max(c);
+            total += c;
+            n += 1;
+        }
+        if n == 0 {
+            return Self::default();
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let mean = total as f64 / n as f64;
+        Self { min, max, mean }
+    }
+}
+
+impl fmt::Display for CountRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{} (mean {:.2})", self.min, self.max, self.mean)
+    }
+}
+
+/// A summary of the entities held by a [`TopologyStore`].
+///
+/// The per-type counts cover entities reachable from the store's solids
+/// (solid → shells → faces → wires → edges → vertices); everything else,
+/// such as the construction faces left behind by an extrusion, is tallied
+/// in `unreferenced`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TopologyStats {
+    pub vertices: usize,
+    pub edges: usize,
+    pub wires: usize,
+    pub faces: usize,
+    pub shells: usize,
+    pub solids: usize,
+    /// Shells per solid, counting the outer shell and any voids.
+    pub shells_per_solid: CountRange,
+    /// Faces per reachable shell.
+    pub faces_per_shell: CountRange,
+    /// Entities of any type in the store that no solid reaches.
+    pub unreferenced: usize,
+}
+
+impl fmt::Display for TopologyStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "vertices: {}, edges: {}, wires: {}, faces: {}, shells: {}, solids: {}",
+            self.vertices, self.edges, self.wires, self.faces, self.shells, self.solids
+        )?;
+        writeln!(f, "unreferenced: {}", self.unreferenced)?;
+        writeln!(f, "shells/solid: {}", self.shells_per_solid)?;
+        write!(f, "faces/shell: {}", self.faces_per_shell)
+    }
+}
+
+impl TopologyStore {
+    /// Counts the entities reachable from the store's solids, plus the
+    /// number of unreachable ones.
+    ///
+    /// Dangling references are skipped rather than reported.
+    #[must_use]
+    pub fn stats(&self) -> TopologyStats {
+        let mut shells = HashSet::new();
+        for solid in self.solids.values() {
+            shells.insert(solid.outer_shell);
+            shells.extend(solid.inner_shells.iter().copied());
+        }
+        let shell_data: Vec<_> = shells.iter().filter_map(|&s| self.shells.get(s)).collect();
+        let faces: HashSet<_> = shell_data
+            .iter()
+            .flat_map(|s| s.faces.iter().copied())
+            .collect();
+        let mut wires = HashSet::new();
+        for face in faces.iter().filter_map(|&f| self.faces.get(f)) {
+            wires.insert(face.outer_wire);
+            wires.extend(face.inner_wires.iter().copied());
+        }
+        let edges: HashSet<_> = wires
+            .iter()
+            .filter_map(|&w| self.wires.get(w))
+            .flat_map(|w| w.edges.iter().map(|oe| oe.edge))
+            .collect();
+        let vertices: HashSet<_> = edges
+            .iter()
+            .filter_map(|&e| self.edges.get(e))
+            .flat_map(|e| [e.start, e.end])
+            .collect();
+
+        let reachable = vertices.len() + edges.len() + wires.len() + faces.len() + shells.len();
+        let total = self.vertices.len()
+            + self.edges.len()
+            + self.wires.len()
+            + self.faces.len()
+            + self.shells.len();
+        TopologyStats {
+            vertices: vertices.len(),
+            edges: edges.len(),
+            wires: wires.len(),
+            faces: faces.len(),
+            shells: shells.len(),
+            solids: self.solids.len(),
+            shells_per_solid: CountRange::from_counts(
+                self.solids.values().map(|s| 1 + s.inner_shells.len()),
+            ),
+            faces_per_shell: CountRange::from_counts(shell_data.iter().map(|s| s.faces.len())),
+            unreferenced: total.saturating_sub(reachable),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::math::Point3;
+    use crate::operations::creation::MakeBox;
+
+    #[test]
+    fn make_box_stats() {
+        let mut store = TopologyStore::new();
+        MakeBox::new(Point3::origin(), Point3::new(1.0, 2.0, 3.0))
+            .execute(&mut store)
+            .unwrap();
+        let stats = store.stats();
+        assert_eq!(
+            (
+                stats.vertices,
+                stats.edges,
+                stats.faces,
+                stats.shells,
+                stats.solids
+            ),
+            (8, 12, 6, 1, 1)
+        );
+        assert_eq!(stats.wires, 6);
+        // The extruded base face is left behind in the store.
+        assert!(stats.unreferenced > 0);
+        assert_eq!(stats.faces_per_shell.max, 6);
+        assert_eq!(stats.shells_per_solid.min, 1);
+
+        let text = stats.to_string();
+        assert!(text.contains("vertices: 8, edges: 12"), "{text}");
+        assert!(text.contains("faces/shell: 6..6"), "{text}");
+    }
+
+    #[test]
+    fn empty_store_stats() {
+        let stats = TopologyStore::new().stats();
+        assert_eq!(stats, TopologyStats::default());
+    }
+}